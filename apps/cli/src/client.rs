@@ -0,0 +1,101 @@
+use anyhow::{bail, Result};
+use reqwest::{Method, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+struct DataResponse<T> {
+    data: T,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    error: ErrorBody,
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    message: String,
+}
+
+/// Thin wrapper over the REST API: every success body is `{"data": ...}` and
+/// every failure `{"error": {"code", "message"}}`.
+pub struct ApiClient {
+    http: reqwest::Client,
+    base_url: String,
+    token: Option<String>,
+}
+
+impl ApiClient {
+    pub fn new(base_url: &str, token: Option<String>) -> Self {
+        Self {
+            http: reqwest::Client::builder()
+                .user_agent(concat!("statuspage-cli/", env!("CARGO_PKG_VERSION")))
+                .build()
+                .expect("default HTTP client builds"),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token,
+        }
+    }
+
+    pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        self.send(Method::GET, path, None::<&()>).await
+    }
+
+    pub async fn post<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T> {
+        self.send(Method::POST, path, Some(body)).await
+    }
+
+    pub async fn patch<B: Serialize, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T> {
+        self.send(Method::PATCH, path, Some(body)).await
+    }
+
+    pub async fn delete(&self, path: &str) -> Result<()> {
+        let response = self.request(Method::DELETE, path).send().await?;
+        check(response).await.map(|_| ())
+    }
+
+    async fn send<B: Serialize, T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+    ) -> Result<T> {
+        let mut request = self.request(method, path);
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        let body = check(request.send().await?).await?;
+        Ok(serde_json::from_str::<DataResponse<T>>(&body)?.data)
+    }
+
+    fn request(&self, method: Method, path: &str) -> reqwest::RequestBuilder {
+        let request = self
+            .http
+            .request(method, format!("{}{path}", self.base_url));
+        match &self.token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+}
+
+async fn check(response: reqwest::Response) -> Result<String> {
+    let status = response.status();
+    let body = response.text().await?;
+    if status.is_success() {
+        return Ok(body);
+    }
+
+    let message = serde_json::from_str::<ErrorResponse>(&body)
+        .map(|error| error.error.message)
+        .unwrap_or(body);
+    if status == StatusCode::UNAUTHORIZED {
+        bail!("not signed in or the credential was revoked; run `statuspage login`");
+    }
+    bail!("{message} ({status})");
+}
@@ -0,0 +1,561 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::{bail, Context as _, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use shared::enums::{IncidentStatus, UpdateVisibility};
+use shared::models::cli_auth::{
+    CliLoginPollResponse, CliLoginStart, CliLoginStatus, PollCliLoginRequest, StartCliLoginRequest,
+};
+use shared::models::incident::{CreateIncidentRequest, Incident};
+use shared::models::incident_update::{CreateIncidentUpdateRequest, IncidentUpdate};
+use shared::models::monitor::{Monitor, UpdateMonitorRequest};
+use shared::models::organization::Organization;
+use shared::models::service::{Service, UpdateServiceRequest};
+
+use crate::client::ApiClient;
+use crate::credentials::{self, Credentials};
+use crate::{
+    Cli, Command, DeliveriesCommand, IncidentCommand, LoginArgs, MonitorCommand, ServiceCommand,
+    DEFAULT_API_URL,
+};
+
+/// Body of the delivery retry endpoints.
+#[derive(Debug, Serialize, Deserialize)]
+struct Message {
+    message: String,
+}
+
+/// Resolved connection settings shared by every command.
+struct Context {
+    client: ApiClient,
+    org: Option<String>,
+    json: bool,
+}
+
+impl Context {
+    /// The organization to act on: `--org`, then the one saved at login,
+    /// then the user's only organization.
+    async fn org(&self) -> Result<String> {
+        if let Some(org) = &self.org {
+            return Ok(org.clone());
+        }
+
+        let orgs: Vec<Organization> = self.client.get("/api/organizations").await?;
+        match orgs.as_slice() {
+            [org] => Ok(org.slug.clone()),
+            [] => bail!("you are not a member of any organization"),
+            _ => bail!(
+                "pass --org or set STATUSPAGE_ORG; you belong to {}",
+                orgs.iter()
+                    .map(|org| org.slug.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+
+    fn print_json<T: Serialize>(&self, value: &T) -> Result<()> {
+        println!("{}", serde_json::to_string_pretty(value)?);
+        Ok(())
+    }
+}
+
+pub async fn run(cli: Cli) -> Result<()> {
+    let saved = credentials::load()?;
+    let api_url = cli
+        .api_url
+        .clone()
+        .or_else(|| saved.as_ref().map(|saved| saved.api_url.clone()))
+        .unwrap_or_else(|| DEFAULT_API_URL.to_string());
+
+    match cli.command {
+        Command::Login(args) => return login(&api_url, cli.org, cli.json, args).await,
+        Command::Logout => return logout(saved, cli.json).await,
+        _ => {}
+    }
+
+    let token = std::env::var("STATUSPAGE_TOKEN")
+        .ok()
+        .filter(|token| !token.is_empty())
+        .or_else(|| saved.as_ref().map(|saved| saved.token.clone()))
+        .context("not signed in; run `statuspage login` or set STATUSPAGE_TOKEN")?;
+    let context = Context {
+        client: ApiClient::new(&api_url, Some(token)),
+        org: cli
+            .org
+            .or_else(|| saved.and_then(|saved| saved.default_org)),
+        json: cli.json,
+    };
+
+    match cli.command {
+        Command::Login(_) | Command::Logout => unreachable!("handled above"),
+        Command::Status => status(&context).await,
+        Command::Incident(command) => incident(&context, command).await,
+        Command::Monitor(command) => monitor(&context, command).await,
+        Command::Service(command) => service(&context, command).await,
+        Command::Deliveries(command) => deliveries(&context, command).await,
+    }
+}
+
+async fn login(api_url: &str, org: Option<String>, json: bool, args: LoginArgs) -> Result<()> {
+    let client = ApiClient::new(api_url, None);
+    let start: CliLoginStart = client
+        .post(
+            "/api/cli/login",
+            &StartCliLoginRequest {
+                client_name: Some(args.name.unwrap_or_else(default_client_name)),
+            },
+        )
+        .await?;
+
+    eprintln!(
+        "Open {} in a browser where you are signed in,",
+        start.verification_url
+    );
+    eprintln!("and confirm the code {}.", start.user_code);
+    eprintln!("Waiting for approval...");
+
+    let interval = Duration::from_secs(start.interval_seconds.max(1));
+    let credential = loop {
+        tokio::time::sleep(interval).await;
+        let poll: CliLoginPollResponse = client
+            .post(
+                "/api/cli/login/poll",
+                &PollCliLoginRequest {
+                    device_code: start.device_code.clone(),
+                },
+            )
+            .await?;
+        match poll.status {
+            CliLoginStatus::Approved => {
+                break poll
+                    .credential
+                    .context("login was approved but no credential was issued")?
+            }
+            CliLoginStatus::Denied => bail!("the login was denied"),
+            CliLoginStatus::Expired => bail!("the code expired; run `statuspage login` again"),
+            CliLoginStatus::Pending if Utc::now() >= start.expires_at => {
+                bail!("the code expired; run `statuspage login` again")
+            }
+            CliLoginStatus::Pending => {}
+        }
+    };
+
+    let saved = Credentials {
+        api_url: api_url.trim_end_matches('/').to_string(),
+        token: credential.token,
+        credential_id: credential.id,
+        user_email: credential.user_email,
+        expires_at: credential.expires_at,
+        default_org: org,
+    };
+    let path = credentials::save(&saved)?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "user_email": saved.user_email,
+                "credential_id": saved.credential_id,
+                "expires_at": saved.expires_at,
+                "default_org": saved.default_org,
+                "path": path,
+            }))?
+        );
+    } else {
+        println!(
+            "Signed in as {}. Credential saved to {} (expires {}).",
+            saved.user_email,
+            path.display(),
+            saved.expires_at.format("%Y-%m-%d")
+        );
+    }
+    Ok(())
+}
+
+fn default_client_name() -> String {
+    let host = std::env::var("HOSTNAME")
+        .ok()
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .map(|host| host.trim().to_string())
+        .filter(|host| !host.is_empty());
+    match host {
+        Some(host) => format!("statuspage CLI on {host}"),
+        None => "statuspage CLI".to_string(),
+    }
+}
+
+async fn logout(saved: Option<Credentials>, json: bool) -> Result<()> {
+    let Some(saved) = saved else {
+        bail!("not signed in");
+    };
+
+    let client = ApiClient::new(&saved.api_url, Some(saved.token.clone()));
+    // An already revoked or expired credential only needs forgetting.
+    if let Err(error) = client
+        .delete(&format!("/api/cli/credentials/{}", saved.credential_id))
+        .await
+    {
+        eprintln!("warning: could not revoke the credential: {error:#}");
+    }
+    credentials::remove()?;
+
+    if json {
+        println!("{}", serde_json::json!({ "signed_out": saved.user_email }));
+    } else {
+        println!("Signed out {}.", saved.user_email);
+    }
+    Ok(())
+}
+
+async fn status(context: &Context) -> Result<()> {
+    let org = context.org().await?;
+    let services: Vec<Service> = context
+        .client
+        .get(&format!("/api/organizations/{org}/services"))
+        .await?;
+    let incidents: Vec<Incident> = context
+        .client
+        .get(&format!("/api/organizations/{org}/incidents?per_page=100"))
+        .await?;
+    let open: Vec<&Incident> = incidents
+        .iter()
+        .filter(|incident| incident.status != IncidentStatus::Resolved)
+        .collect();
+
+    if context.json {
+        return context.print_json(&serde_json::json!({
+            "organization": org,
+            "services": services,
+            "open_incidents": open,
+        }));
+    }
+
+    println!("Services ({org})");
+    let width = services
+        .iter()
+        .map(|service| service.name.len())
+        .max()
+        .unwrap_or(0);
+    for service in &services {
+        println!("  {:width$}  {}", service.name, service.effective_status());
+    }
+    if open.is_empty() {
+        println!("\nNo open incidents.");
+    } else {
+        println!("\nOpen incidents");
+        for incident in open {
+            println!(
+                "  {}  {}  {} impact  {} (since {})",
+                incident.id,
+                incident.status,
+                incident.impact.as_str(),
+                incident.title,
+                incident.started_at.format("%Y-%m-%d %H:%M UTC")
+            );
+        }
+    }
+    Ok(())
+}
+
+async fn incident(context: &Context, command: IncidentCommand) -> Result<()> {
+    let org = context.org().await?;
+    match command {
+        IncidentCommand::Open {
+            title,
+            impact,
+            message,
+            services,
+            status,
+            no_notify,
+        } => {
+            let affected_service_ids = if services.is_empty() {
+                Vec::new()
+            } else {
+                let known: Vec<Service> = context
+                    .client
+                    .get(&format!("/api/organizations/{org}/services"))
+                    .await?;
+                services
+                    .iter()
+                    .map(|reference| find_service(&known, reference).map(|service| service.id))
+                    .collect::<Result<_>>()?
+            };
+            let incident: Incident = context
+                .client
+                .post(
+                    &format!("/api/organizations/{org}/incidents"),
+                    &CreateIncidentRequest {
+                        title,
+                        status,
+                        impact,
+                        message,
+                        affected_service_ids,
+                        service_statuses: Vec::new(),
+                        started_at: None,
+                        suppress_notifications: no_notify,
+                    },
+                )
+                .await?;
+
+            if context.json {
+                return context.print_json(&incident);
+            }
+            println!(
+                "Opened incident {} \"{}\" ({}).",
+                incident.id, incident.title, incident.status
+            );
+        }
+        IncidentCommand::Update {
+            id,
+            status,
+            message,
+            internal,
+        } => {
+            let visibility = if internal {
+                UpdateVisibility::Internal
+            } else {
+                UpdateVisibility::Public
+            };
+            let update = post_update(context, &org, id, status, message, visibility).await?;
+            if context.json {
+                return context.print_json(&update);
+            }
+            println!("Posted update to incident {id} ({}).", update.status);
+        }
+        IncidentCommand::Resolve { id, message } => {
+            let update = post_update(
+                context,
+                &org,
+                id,
+                IncidentStatus::Resolved,
+                message,
+                UpdateVisibility::Public,
+            )
+            .await?;
+            if context.json {
+                return context.print_json(&update);
+            }
+            println!("Resolved incident {id}.");
+        }
+    }
+    Ok(())
+}
+
+async fn post_update(
+    context: &Context,
+    org: &str,
+    id: Uuid,
+    status: IncidentStatus,
+    message: String,
+    visibility: UpdateVisibility,
+) -> Result<IncidentUpdate> {
+    context
+        .client
+        .post(
+            &format!("/api/organizations/{org}/incidents/{id}/updates"),
+            &CreateIncidentUpdateRequest {
+                status,
+                message,
+                service_statuses: Vec::new(),
+                created_at: None,
+                suppress_notifications: false,
+                visibility,
+            },
+        )
+        .await
+}
+
+async fn monitor(context: &Context, command: MonitorCommand) -> Result<()> {
+    let org = context.org().await?;
+    let (id, is_active) = match command {
+        MonitorCommand::List => return list_monitors(context, &org).await,
+        MonitorCommand::Pause { id } => (id, false),
+        MonitorCommand::Resume { id } => (id, true),
+    };
+
+    let monitor: Monitor = context
+        .client
+        .patch(
+            &format!("/api/organizations/{org}/monitors/{id}"),
+            &UpdateMonitorRequest {
+                config: None,
+                interval_seconds: None,
+                timeout_ms: None,
+                failure_threshold: None,
+                recovery_threshold: None,
+                inherit_recovery_threshold: false,
+                is_active: Some(is_active),
+            },
+        )
+        .await?;
+
+    if context.json {
+        return context.print_json(&monitor);
+    }
+    let verb = if monitor.is_active {
+        "Resumed"
+    } else {
+        "Paused"
+    };
+    println!("{verb} monitor {}.", monitor.id);
+    Ok(())
+}
+
+async fn list_monitors(context: &Context, org: &str) -> Result<()> {
+    let monitors: Vec<Monitor> = context
+        .client
+        .get(&format!("/api/organizations/{org}/monitors"))
+        .await?;
+    if context.json {
+        return context.print_json(&monitors);
+    }
+
+    let services: Vec<Service> = context
+        .client
+        .get(&format!("/api/organizations/{org}/services"))
+        .await?;
+    let names: HashMap<Uuid, &str> = services
+        .iter()
+        .map(|service| (service.id, service.name.as_str()))
+        .collect();
+
+    if monitors.is_empty() {
+        println!("No monitors.");
+    }
+    for monitor in &monitors {
+        let state = match (monitor.is_active, monitor.consecutive_failures) {
+            (false, _) => "paused".to_string(),
+            (true, 0) => "passing".to_string(),
+            (true, failures) => format!("failing ({failures}x)"),
+        };
+        let last_checked = monitor
+            .last_checked_at
+            .map(|at| at.format("%Y-%m-%d %H:%M UTC").to_string())
+            .unwrap_or_else(|| "never".to_string());
+        println!(
+            "{}  {:5}  {:12}  {}  every {}s, last checked {last_checked}",
+            monitor.id,
+            monitor.monitor_type.as_str(),
+            state,
+            names.get(&monitor.service_id).unwrap_or(&"?"),
+            monitor.interval_seconds
+        );
+    }
+    Ok(())
+}
+
+async fn service(context: &Context, command: ServiceCommand) -> Result<()> {
+    let org = context.org().await?;
+    let ServiceCommand::SetStatus { service, status } = command;
+
+    let services: Vec<Service> = context
+        .client
+        .get(&format!("/api/organizations/{org}/services"))
+        .await?;
+    let target = find_service(&services, &service)?;
+    let updated: Service = context
+        .client
+        .patch(
+            &format!("/api/organizations/{org}/services/{}", target.id),
+            &UpdateServiceRequest {
+                name: None,
+                description: None,
+                current_status: Some(status),
+                group_id: None,
+                group_name: None,
+                is_visible: None,
+            },
+        )
+        .await?;
+
+    if context.json {
+        return context.print_json(&updated);
+    }
+    println!("{} is now {}.", updated.name, updated.current_status);
+    Ok(())
+}
+
+async fn deliveries(context: &Context, command: DeliveriesCommand) -> Result<()> {
+    let org = context.org().await?;
+    let DeliveriesCommand::Retry { channel, id } = command;
+
+    let response: Message = context
+        .client
+        .post(
+            &format!(
+                "/api/organizations/{org}/notifications/deliveries/{}/{id}/retry",
+                channel.path_segment()
+            ),
+            &serde_json::json!({}),
+        )
+        .await?;
+
+    if context.json {
+        return context.print_json(&response);
+    }
+    println!("{}", response.message);
+    Ok(())
+}
+
+/// Matches a service by id, or by case-insensitive name.
+fn find_service<'a>(services: &'a [Service], reference: &str) -> Result<&'a Service> {
+    if let Ok(id) = reference.parse::<Uuid>() {
+        return services
+            .iter()
+            .find(|service| service.id == id)
+            .with_context(|| format!("no service with id {id}"));
+    }
+
+    let matches: Vec<&Service> = services
+        .iter()
+        .filter(|service| service.name.eq_ignore_ascii_case(reference.trim()))
+        .collect();
+    match matches.as_slice() {
+        [service] => Ok(service),
+        [] => bail!("no service named \"{reference}\""),
+        _ => bail!("several services are named \"{reference}\"; use the service id"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::enums::ServiceStatus;
+
+    fn service(name: &str) -> Service {
+        Service {
+            id: Uuid::new_v4(),
+            org_id: Uuid::new_v4(),
+            name: name.to_string(),
+            description: None,
+            current_status: ServiceStatus::Operational,
+            display_order: 0,
+            group_name: None,
+            is_visible: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            upstream_cause_service_id: None,
+            group_id: None,
+        }
+    }
+
+    #[test]
+    fn find_service_matches_id_or_unique_name() {
+        let services = vec![service("API"), service("Dashboard"), service("dashboard")];
+
+        assert_eq!(find_service(&services, "api").unwrap().id, services[0].id);
+        assert_eq!(
+            find_service(&services, &services[1].id.to_string())
+                .unwrap()
+                .name,
+            "Dashboard"
+        );
+        assert!(find_service(&services, "Dashboard").is_err());
+        assert!(find_service(&services, "Billing").is_err());
+    }
+}
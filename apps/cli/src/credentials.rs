@@ -0,0 +1,79 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// What `statuspage login` saves so later commands can reach the API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Credentials {
+    pub api_url: String,
+    pub token: String,
+    pub credential_id: Uuid,
+    pub user_email: String,
+    pub expires_at: DateTime<Utc>,
+    /// Organization used when neither `--org` nor `STATUSPAGE_ORG` is given.
+    pub default_org: Option<String>,
+}
+
+/// `$STATUSPAGE_CONFIG_DIR`, else `$XDG_CONFIG_HOME/statuspage`, else
+/// `~/.config/statuspage`.
+fn config_dir() -> Result<PathBuf> {
+    if let Some(dir) = std::env::var_os("STATUSPAGE_CONFIG_DIR") {
+        return Ok(PathBuf::from(dir));
+    }
+    if let Some(dir) = std::env::var_os("XDG_CONFIG_HOME") {
+        return Ok(PathBuf::from(dir).join("statuspage"));
+    }
+    let home = std::env::var_os("HOME").context("HOME is not set; set STATUSPAGE_CONFIG_DIR")?;
+    Ok(PathBuf::from(home).join(".config").join("statuspage"))
+}
+
+pub fn path() -> Result<PathBuf> {
+    Ok(config_dir()?.join("credentials.json"))
+}
+
+pub fn load() -> Result<Option<Credentials>> {
+    let path = path()?;
+    match std::fs::read_to_string(&path) {
+        Ok(raw) => serde_json::from_str(&raw)
+            .map(Some)
+            .with_context(|| format!("reading {}", path.display())),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error).with_context(|| format!("reading {}", path.display())),
+    }
+}
+
+/// Writes the file readable by the current user only, since the token grants
+/// the same access as a browser session.
+pub fn save(credentials: &Credentials) -> Result<PathBuf> {
+    let path = path()?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+    }
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let file = options
+        .open(&path)
+        .with_context(|| format!("writing {}", path.display()))?;
+    serde_json::to_writer_pretty(file, credentials)
+        .with_context(|| format!("writing {}", path.display()))?;
+
+    Ok(path)
+}
+
+pub fn remove() -> Result<()> {
+    let path = path()?;
+    match std::fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(error) => Err(error).with_context(|| format!("removing {}", path.display())),
+    }
+}
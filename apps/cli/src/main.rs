@@ -0,0 +1,207 @@
+mod client;
+mod commands;
+mod credentials;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use uuid::Uuid;
+
+use shared::enums::{IncidentImpact, IncidentStatus, ServiceStatus};
+
+const DEFAULT_API_URL: &str = "http://localhost:4000";
+
+/// Command-line client for the StatusPage API.
+///
+/// Sign in once with `statuspage login`; the credential is saved under
+/// ~/.config/statuspage. Set STATUSPAGE_TOKEN to use a credential without
+/// saving it, e.g. in CI.
+#[derive(Debug, Parser)]
+#[command(name = "statuspage", version)]
+pub struct Cli {
+    /// API base URL [default: the one saved at login, else http://localhost:4000]
+    #[arg(long, global = true, env = "STATUSPAGE_API_URL")]
+    api_url: Option<String>,
+
+    /// Organization slug [default: the one saved at login, or your only organization]
+    #[arg(long, global = true, env = "STATUSPAGE_ORG")]
+    org: Option<String>,
+
+    /// Print the API's JSON instead of a human summary.
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Sign in by approving a code in the web app, and save a CLI credential.
+    Login(LoginArgs),
+    /// Revoke the saved credential and forget it.
+    Logout,
+    /// Show service statuses and open incidents.
+    Status,
+    /// Open, update and resolve incidents.
+    #[command(subcommand)]
+    Incident(IncidentCommand),
+    /// List, pause and resume monitors.
+    #[command(subcommand)]
+    Monitor(MonitorCommand),
+    /// Change services.
+    #[command(subcommand)]
+    Service(ServiceCommand),
+    /// Retry failed notification deliveries.
+    #[command(subcommand)]
+    Deliveries(DeliveriesCommand),
+}
+
+#[derive(Debug, Args)]
+struct LoginArgs {
+    /// Name shown when approving the login and in your credential list
+    /// [default: "statuspage CLI on <hostname>"]
+    #[arg(long)]
+    name: Option<String>,
+}
+
+#[derive(Debug, Subcommand)]
+enum IncidentCommand {
+    /// Open an incident and notify subscribers.
+    Open {
+        #[arg(long)]
+        title: String,
+        /// none, minor, major or critical.
+        #[arg(long, value_parser = parse_enum::<IncidentImpact>)]
+        impact: IncidentImpact,
+        /// First public update.
+        #[arg(long)]
+        message: String,
+        /// Affected service, by name or id. Repeat for several.
+        #[arg(long = "service")]
+        services: Vec<String>,
+        /// Starting status: investigating, identified or monitoring
+        /// [default: investigating]
+        #[arg(long, value_parser = parse_enum::<IncidentStatus>)]
+        status: Option<IncidentStatus>,
+        /// Skip subscriber emails and SMS.
+        #[arg(long)]
+        no_notify: bool,
+    },
+    /// Post an update to an incident.
+    Update {
+        id: Uuid,
+        /// investigating, identified, monitoring or resolved.
+        #[arg(long, value_parser = parse_enum::<IncidentStatus>)]
+        status: IncidentStatus,
+        #[arg(long)]
+        message: String,
+        /// Post an internal note that is never published.
+        #[arg(long)]
+        internal: bool,
+    },
+    /// Resolve an incident.
+    Resolve {
+        id: Uuid,
+        #[arg(long, default_value = "This incident has been resolved.")]
+        message: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum MonitorCommand {
+    /// List monitors and their last result.
+    List,
+    /// Stop checking a monitor.
+    Pause { id: Uuid },
+    /// Start checking a paused monitor again.
+    Resume { id: Uuid },
+}
+
+#[derive(Debug, Subcommand)]
+enum ServiceCommand {
+    /// Set a service's status by hand.
+    SetStatus {
+        /// Service name or id.
+        service: String,
+        /// operational, degraded_performance, partial_outage, major_outage or
+        /// under_maintenance.
+        #[arg(value_parser = parse_enum::<ServiceStatus>)]
+        status: ServiceStatus,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum DeliveriesCommand {
+    /// Queue a failed delivery to be sent again.
+    Retry {
+        #[arg(value_enum)]
+        channel: DeliveryChannel,
+        id: Uuid,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum DeliveryChannel {
+    Email,
+    Sms,
+    Webhook,
+}
+
+impl DeliveryChannel {
+    fn path_segment(self) -> &'static str {
+        match self {
+            Self::Email => "email",
+            Self::Sms => "sms",
+            Self::Webhook => "webhooks",
+        }
+    }
+}
+
+/// Parses a shared enum from the same snake_case spelling the API uses, so
+/// the accepted values cannot drift from the server's.
+fn parse_enum<T: serde::de::DeserializeOwned>(value: &str) -> Result<T, String> {
+    serde_json::from_value(serde_json::Value::String(value.to_string()))
+        .map_err(|_| format!("unknown value `{value}`"))
+}
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+    if let Err(error) = commands::run(cli).await {
+        eprintln!("error: {error:#}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn arguments_are_well_formed() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn enums_parse_with_api_spelling() {
+        let cli = Cli::try_parse_from([
+            "statuspage",
+            "service",
+            "set-status",
+            "API",
+            "major_outage",
+            "--json",
+        ])
+        .unwrap();
+        assert!(cli.json);
+        assert!(matches!(
+            cli.command,
+            Command::Service(ServiceCommand::SetStatus {
+                status: ServiceStatus::MajorOutage,
+                ..
+            })
+        ));
+
+        assert!(parse_enum::<IncidentImpact>("catastrophic").is_err());
+    }
+}
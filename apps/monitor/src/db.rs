@@ -1,7 +1,10 @@
 use chrono::{Datelike, NaiveDate, Utc};
-use shared::enums::{IncidentImpact, IncidentStatus, ServiceStatus};
+use shared::email::{self, EmailBranding};
+use shared::enums::{CheckStatus, IncidentImpact, IncidentStatus, ServiceStatus};
+use shared::i18n;
 use shared::models::incident_update::IncidentUpdate;
 use shared::models::monitor::Monitor;
+use shared::models::monitoring_policy::MonitoringPolicy;
 use sqlx::types::Json;
 use sqlx::{PgPool, Row};
 use uuid::Uuid;
@@ -13,6 +16,7 @@ pub struct ServiceSnapshot {
     pub service_id: Uuid,
     pub service_name: String,
     pub current_status: ServiceStatus,
+    pub upstream_cause_service_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone)]
@@ -27,6 +31,7 @@ pub struct AutoIncidentCreated {
 #[derive(Debug, Clone)]
 pub struct AutoIncidentResolved {
     pub incident_id: Uuid,
+    pub service_id: Uuid,
     pub update: IncidentUpdate,
 }
 
@@ -86,7 +91,10 @@ pub async fn increment_failures(
 ) -> anyhow::Result<bool> {
     let result = sqlx::query(
         r#"
-        UPDATE monitors SET consecutive_failures = consecutive_failures + 1, updated_at = NOW()
+        UPDATE monitors SET
+            consecutive_failures = consecutive_failures + 1,
+            consecutive_successes = 0,
+            updated_at = NOW()
         WHERE id = $1 AND consecutive_failures = $2
         "#,
     )
@@ -98,13 +106,71 @@ pub async fn increment_failures(
     Ok(result.rows_affected() > 0)
 }
 
-pub async fn reset_failures(pool: &PgPool, monitor_id: Uuid) -> anyhow::Result<()> {
-    sqlx::query("UPDATE monitors SET consecutive_failures = 0, updated_at = NOW() WHERE id = $1")
-        .bind(monitor_id)
-        .execute(pool)
-        .await?;
+/// Resets the failure streak and returns the length of the success streak,
+/// including this check.
+pub async fn record_success(pool: &PgPool, monitor_id: Uuid) -> anyhow::Result<i32> {
+    let consecutive_successes: i32 = sqlx::query_scalar(
+        r#"
+        UPDATE monitors SET
+            consecutive_failures = 0,
+            consecutive_successes = consecutive_successes + 1,
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING consecutive_successes
+        "#,
+    )
+    .bind(monitor_id)
+    .fetch_one(pool)
+    .await?;
 
-    Ok(())
+    Ok(consecutive_successes)
+}
+
+pub async fn get_monitoring_policy(
+    pool: &PgPool,
+    org_id: Uuid,
+) -> anyhow::Result<MonitoringPolicy> {
+    sqlx::query(
+        r#"
+        INSERT INTO monitoring_policies (org_id)
+        VALUES ($1)
+        ON CONFLICT (org_id) DO NOTHING
+        "#,
+    )
+    .bind(org_id)
+    .execute(pool)
+    .await?;
+
+    let policy = sqlx::query_as::<_, MonitoringPolicy>(
+        "SELECT * FROM monitoring_policies WHERE org_id = $1",
+    )
+    .bind(org_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(policy)
+}
+
+/// Latest check results for a monitor, newest first.
+pub async fn recent_check_statuses(
+    pool: &PgPool,
+    monitor_id: Uuid,
+    limit: i32,
+) -> anyhow::Result<Vec<CheckStatus>> {
+    let statuses = sqlx::query_scalar::<_, CheckStatus>(
+        r#"
+        SELECT status FROM monitor_checks
+        WHERE monitor_id = $1
+        ORDER BY checked_at DESC
+        LIMIT $2
+        "#,
+    )
+    .bind(monitor_id)
+    .bind(i64::from(limit))
+    .fetch_all(pool)
+    .await?;
+
+    Ok(statuses)
 }
 
 pub async fn update_service_status(
@@ -118,6 +184,19 @@ pub async fn update_service_status(
         .execute(pool)
         .await?;
 
+    sqlx::query(
+        "SELECT record_service_status_intervals(org_id, 'monitor') FROM services WHERE id = $1",
+    )
+    .bind(service_id)
+    .execute(pool)
+    .await?;
+
+    // Dependents pick up (or drop) the upstream outage
+    sqlx::query("SELECT refresh_upstream_impact(org_id) FROM services WHERE id = $1")
+        .bind(service_id)
+        .execute(pool)
+        .await?;
+
     Ok(())
 }
 
@@ -130,7 +209,8 @@ pub async fn get_service_snapshot(
         SELECT
             id as service_id,
             name as service_name,
-            current_status
+            current_status,
+            upstream_cause_service_id
         FROM services
         WHERE id = $1
         "#,
@@ -159,6 +239,36 @@ pub async fn has_active_auto_incident(pool: &PgPool, service_id: Uuid) -> anyhow
     Ok(exists)
 }
 
+/// Whether an auto-incident for this service resolved within the cool-down.
+pub async fn auto_incident_in_cooldown(
+    pool: &PgPool,
+    service_id: Uuid,
+    cooldown_minutes: i32,
+) -> anyhow::Result<bool> {
+    if cooldown_minutes <= 0 {
+        return Ok(false);
+    }
+
+    let exists: bool = sqlx::query_scalar(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM incidents i
+            JOIN incident_services isvc ON isvc.incident_id = i.id
+            WHERE isvc.service_id = $1
+              AND i.is_auto = true
+              AND i.status = 'resolved'
+              AND i.resolved_at > NOW() - make_interval(mins => $2)
+        )
+        "#,
+    )
+    .bind(service_id)
+    .bind(cooldown_minutes)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(exists)
+}
+
 pub async fn create_auto_incident(
     pool: &PgPool,
     org_id: Uuid,
@@ -201,11 +311,13 @@ pub async fn create_auto_incident(
     .execute(&mut *tx)
     .await?;
 
-    sqlx::query("INSERT INTO incident_services (incident_id, service_id) VALUES ($1, $2)")
-        .bind(incident_id)
-        .bind(service_id)
-        .execute(&mut *tx)
-        .await?;
+    sqlx::query(
+        "INSERT INTO incident_services (incident_id, service_id, service_status) VALUES ($1, $2, 'major_outage')",
+    )
+    .bind(incident_id)
+    .bind(service_id)
+    .execute(&mut *tx)
+    .await?;
 
     tx.commit().await?;
 
@@ -218,9 +330,12 @@ pub async fn create_auto_incident(
     })
 }
 
+/// Resolves the service's open auto-incident. Incidents shorter than
+/// `min_public_minutes` are hidden from public history.
 pub async fn resolve_auto_incident(
     pool: &PgPool,
     service_id: Uuid,
+    min_public_minutes: i32,
 ) -> anyhow::Result<Option<AutoIncidentResolved>> {
     // Find active auto-incident for this service
     let incident_id: Option<Uuid> = sqlx::query_scalar(
@@ -240,11 +355,16 @@ pub async fn resolve_auto_incident(
 
         sqlx::query(
             r#"
-            UPDATE incidents SET status = 'resolved', resolved_at = NOW(), updated_at = NOW()
+            UPDATE incidents SET
+                status = 'resolved',
+                resolved_at = NOW(),
+                updated_at = NOW(),
+                hidden_from_public = $2 > 0 AND NOW() - started_at < make_interval(mins => $2)
             WHERE id = $1
             "#,
         )
         .bind(id)
+        .bind(min_public_minutes)
         .execute(&mut *tx)
         .await?;
 
@@ -263,6 +383,7 @@ pub async fn resolve_auto_incident(
 
         return Ok(Some(AutoIncidentResolved {
             incident_id: id,
+            service_id,
             update,
         }));
     }
@@ -332,11 +453,21 @@ pub async fn enqueue_webhook_deliveries<T: serde::Serialize>(
     )
     .bind(org_id)
     .bind(event_type)
-    .bind(payload)
+    .bind(&payload)
     .execute(pool)
     .await?;
 
-    Ok(result.rows_affected())
+    // Customer webhook subscribers get every event, independent of admin
+    // webhook preferences.
+    let subscriber_deliveries =
+        sqlx::query_scalar::<_, i32>("SELECT enqueue_webhook_subscriber_deliveries($1, $2, $3)")
+            .bind(org_id)
+            .bind(event_type)
+            .bind(&payload)
+            .fetch_one(pool)
+            .await?;
+
+    Ok(result.rows_affected() + subscriber_deliveries.max(0) as u64)
 }
 
 pub async fn enqueue_incident_notification_emails(
@@ -346,26 +477,27 @@ pub async fn enqueue_incident_notification_emails(
     title: &str,
     status: IncidentStatus,
     message: &str,
+    service_ids: &[Uuid],
 ) -> anyhow::Result<u64> {
     if !email_event_enabled(pool, org_id, event_type).await? {
         return Ok(0);
     }
 
-    let org_slug: String = sqlx::query_scalar("SELECT slug FROM organizations WHERE id = $1")
-        .bind(org_id)
-        .fetch_one(pool)
-        .await?;
     let app_base_url =
         std::env::var("APP_BASE_URL").unwrap_or_else(|_| "http://localhost:3000".to_string());
 
-    let subscribers = sqlx::query(
+    let subscribers = sqlx::query(&format!(
         r#"
-        SELECT email, unsubscribe_token
-        FROM subscribers
-        WHERE org_id = $1 AND is_verified = TRUE
-        "#,
-    )
+        SELECT s.id, s.email, s.unsubscribe_token, {PAGE_BRANDING_COLUMNS}
+        FROM subscribers s
+        JOIN public_status_pages p
+          ON p.org_id = s.org_id
+         AND p.page_id IS NOT DISTINCT FROM s.status_page_id
+        WHERE s.org_id = $1 AND s.is_verified = TRUE AND subscriber_wants_services(s.id, $2)
+        "#
+    ))
     .bind(org_id)
+    .bind(service_ids)
     .fetch_all(pool)
     .await?;
 
@@ -379,26 +511,32 @@ pub async fn enqueue_incident_notification_emails(
 
     let mut inserted = 0;
     for subscriber in subscribers {
+        let subscriber_id: Uuid = subscriber.get("id");
         let email: String = subscriber.get("email");
         let unsubscribe_token: String = subscriber.get("unsubscribe_token");
-        let subject = if status == IncidentStatus::Resolved {
-            format!("Incident resolved: {title}")
-        } else if event_type == "incident.created" {
-            format!("New incident: {title}")
-        } else {
-            format!("Incident update: {title}")
-        };
+        let (page_slug, branding) = page_email_branding(&subscriber);
         let unsubscribe_link = format!(
             "{}/s/{}/unsubscribe?token={}",
-            app_base_url, org_slug, unsubscribe_token
+            app_base_url, page_slug, unsubscribe_token
         );
-        let body = format!(
-            "Status update for {title}\n\nStatus: {}\nMessage: {}\n\nFollow updates at:\n{}/s/{}/history\n\nUnsubscribe:\n{unsubscribe_link}",
-            status.as_str(),
-            message,
-            app_base_url,
-            org_slug,
+        let history_url = format!("{}/s/{}/history", app_base_url, page_slug);
+        let mut rendered = email::incident_updated(
+            &branding,
+            &email::IncidentUpdatedEmail {
+                title: Some(title),
+                status,
+                message,
+                history_url: &history_url,
+                unsubscribe_url: &unsubscribe_link,
+            },
         );
+        if event_type == "incident.created" && status != IncidentStatus::Resolved {
+            rendered.subject = i18n::format_message(
+                branding.locale,
+                "incident_created.subject",
+                &[("title", title)],
+            );
+        }
 
         sqlx::query(
             r#"
@@ -409,17 +547,27 @@ pub async fn enqueue_incident_notification_emails(
                 recipient_email,
                 subject,
                 body_text,
+                body_html,
+                list_unsubscribe_url,
+                subscriber_id,
                 status,
                 next_retry_at
             )
-            VALUES ($1, $2, 'subscriber', $3, $4, $5, 'pending', NOW())
+            VALUES (
+                $1, $2, 'subscriber', $3, $4, $5, $6, $7, $8,
+                CASE WHEN subscriber_holds_notifications($8) THEN 'held' ELSE 'pending' END,
+                NOW()
+            )
             "#,
         )
         .bind(org_id)
         .bind(notification_type)
         .bind(email)
-        .bind(subject)
-        .bind(body)
+        .bind(&rendered.subject)
+        .bind(&rendered.text)
+        .bind(&rendered.html)
+        .bind(&unsubscribe_link)
+        .bind(subscriber_id)
         .execute(pool)
         .await?;
         inserted += 1;
@@ -431,6 +579,7 @@ pub async fn enqueue_incident_notification_emails(
 pub async fn enqueue_service_status_notification_emails(
     pool: &PgPool,
     org_id: Uuid,
+    service_id: Uuid,
     service_name: &str,
     old_status: ServiceStatus,
     new_status: ServiceStatus,
@@ -439,39 +588,44 @@ pub async fn enqueue_service_status_notification_emails(
         return Ok(0);
     }
 
-    let org_slug: String = sqlx::query_scalar("SELECT slug FROM organizations WHERE id = $1")
-        .bind(org_id)
-        .fetch_one(pool)
-        .await?;
     let app_base_url =
         std::env::var("APP_BASE_URL").unwrap_or_else(|_| "http://localhost:3000".to_string());
 
-    let subscribers = sqlx::query(
+    let subscribers = sqlx::query(&format!(
         r#"
-        SELECT email, unsubscribe_token
-        FROM subscribers
-        WHERE org_id = $1 AND is_verified = TRUE
-        "#,
-    )
+        SELECT s.id, s.email, s.unsubscribe_token, {PAGE_BRANDING_COLUMNS}
+        FROM subscribers s
+        JOIN public_status_pages p
+          ON p.org_id = s.org_id
+         AND p.page_id IS NOT DISTINCT FROM s.status_page_id
+        WHERE s.org_id = $1 AND s.is_verified = TRUE AND subscriber_wants_services(s.id, $2)
+        "#
+    ))
     .bind(org_id)
+    .bind(&[service_id][..])
     .fetch_all(pool)
     .await?;
 
     let mut inserted = 0;
     for subscriber in subscribers {
+        let subscriber_id: Uuid = subscriber.get("id");
         let email: String = subscriber.get("email");
         let unsubscribe_token: String = subscriber.get("unsubscribe_token");
-        let subject = format!("Service status changed: {service_name}");
+        let (page_slug, branding) = page_email_branding(&subscriber);
         let unsubscribe_link = format!(
             "{}/s/{}/unsubscribe?token={}",
-            app_base_url, org_slug, unsubscribe_token
+            app_base_url, page_slug, unsubscribe_token
         );
-        let body = format!(
-            "{service_name} changed from {} to {}.\n\nSee current status at:\n{}/s/{}\n\nUnsubscribe:\n{unsubscribe_link}",
-            old_status.as_str(),
-            new_status.as_str(),
-            app_base_url,
-            org_slug,
+        let status_url = format!("{}/s/{}", app_base_url, page_slug);
+        let rendered = email::service_status_changed(
+            &branding,
+            &email::ServiceStatusChangedEmail {
+                service_name,
+                old_status,
+                new_status,
+                status_url: &status_url,
+                unsubscribe_url: &unsubscribe_link,
+            },
         );
 
         sqlx::query(
@@ -483,16 +637,26 @@ pub async fn enqueue_service_status_notification_emails(
                 recipient_email,
                 subject,
                 body_text,
+                body_html,
+                list_unsubscribe_url,
+                subscriber_id,
                 status,
                 next_retry_at
             )
-            VALUES ($1, 'service_status_changed', 'subscriber', $2, $3, $4, 'pending', NOW())
+            VALUES (
+                $1, 'service_status_changed', 'subscriber', $2, $3, $4, $5, $6, $7,
+                CASE WHEN subscriber_holds_notifications($7) THEN 'held' ELSE 'pending' END,
+                NOW()
+            )
             "#,
         )
         .bind(org_id)
         .bind(email)
-        .bind(subject)
-        .bind(body)
+        .bind(&rendered.subject)
+        .bind(&rendered.text)
+        .bind(&rendered.html)
+        .bind(&unsubscribe_link)
+        .bind(subscriber_id)
         .execute(pool)
         .await?;
         inserted += 1;
@@ -501,6 +665,25 @@ pub async fn enqueue_service_status_notification_emails(
     Ok(inserted)
 }
 
+/// Branding of the page a subscriber signed up on, selected alongside the
+/// subscriber from `public_status_pages`. The subscriber's own locale wins
+/// over the organization default.
+const PAGE_BRANDING_COLUMNS: &str = "p.slug AS page_slug, p.name AS page_name, \
+     p.logo_url AS page_logo_url, p.brand_color AS page_brand_color, \
+     COALESCE(s.locale, p.locale) AS email_locale";
+
+fn page_email_branding(row: &sqlx::postgres::PgRow) -> (String, EmailBranding) {
+    (
+        row.get("page_slug"),
+        EmailBranding {
+            org_name: row.get("page_name"),
+            logo_url: row.get("page_logo_url"),
+            brand_color: row.get("page_brand_color"),
+            locale: row.get("email_locale"),
+        },
+    )
+}
+
 async fn ensure_notification_preferences(pool: &PgPool, org_id: Uuid) -> anyhow::Result<()> {
     sqlx::query(
         r#"
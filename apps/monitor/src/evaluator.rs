@@ -1,5 +1,6 @@
 use shared::enums::{CheckStatus, ServiceStatus};
 use shared::models::monitor::Monitor;
+use shared::models::monitoring_policy::MonitoringPolicy;
 use sqlx::PgPool;
 
 use crate::checker::CheckResult;
@@ -30,55 +31,66 @@ async fn handle_success(
     monitor: &Monitor,
     publisher: Option<&RedisPublisher>,
 ) -> anyhow::Result<()> {
-    // Reset consecutive failures
-    if monitor.consecutive_failures > 0 {
-        db::reset_failures(pool, monitor.id).await?;
-    }
+    let consecutive_successes = db::record_success(pool, monitor.id).await?;
 
     // Check if service is in outage and should recover
     let service = db::get_service_snapshot(pool, monitor.service_id).await?;
-    if service.current_status != ServiceStatus::Operational
-        && service.current_status != ServiceStatus::UnderMaintenance
+    if service.current_status == ServiceStatus::Operational
+        || service.current_status == ServiceStatus::UnderMaintenance
     {
-        // Check if any OTHER monitors for this service are still failing
-        let others_failing =
-            db::get_other_failing_monitors_for_service(pool, monitor.service_id, monitor.id)
-                .await?;
+        return Ok(());
+    }
 
-        if !others_failing {
-            tracing::info!(
-                monitor_id = %monitor.id,
-                service_id = %monitor.service_id,
-                "Service recovered, setting to operational"
-            );
-            db::update_service_status(pool, monitor.service_id, ServiceStatus::Operational).await?;
+    let policy = db::get_monitoring_policy(pool, monitor.org_id).await?;
+    let recovery_threshold = policy.recovery_threshold_for(monitor.recovery_threshold);
+    if consecutive_successes < recovery_threshold {
+        tracing::debug!(
+            monitor_id = %monitor.id,
+            consecutive_successes,
+            recovery_threshold,
+            "Waiting for recovery threshold before restoring service"
+        );
+        return Ok(());
+    }
 
-            // Auto-resolve any auto-incidents for this service
-            let resolution = db::resolve_auto_incident(pool, monitor.service_id).await?;
+    if is_flapping(pool, monitor, &policy).await? {
+        hold_degraded(pool, monitor, &service, publisher, "monitor is flapping").await;
+        return Ok(());
+    }
 
-            publish_service_status(
-                pool,
-                publisher,
-                monitor.org_id,
-                service.service_id,
-                service.service_name.clone(),
-                service.current_status,
-                ServiceStatus::Operational,
-            )
-            .await;
+    // Check if any OTHER monitors for this service are still failing
+    let others_failing =
+        db::get_other_failing_monitors_for_service(pool, monitor.service_id, monitor.id).await?;
 
-            if let Some(resolution) = resolution {
-                publish_incident_updated(
-                    pool,
-                    publisher,
-                    monitor.org_id,
-                    resolution.incident_id,
-                    resolution.update.id,
-                    resolution.update.status,
-                    resolution.update.message,
-                )
-                .await;
-            }
+    if !others_failing {
+        tracing::info!(
+            monitor_id = %monitor.id,
+            service_id = %monitor.service_id,
+            "Service recovered, setting to operational"
+        );
+        db::update_service_status(pool, monitor.service_id, ServiceStatus::Operational).await?;
+
+        // Auto-resolve any auto-incidents for this service
+        let resolution = db::resolve_auto_incident(
+            pool,
+            monitor.service_id,
+            policy.min_public_auto_incident_minutes,
+        )
+        .await?;
+
+        publish_service_status(
+            pool,
+            publisher,
+            monitor.org_id,
+            service.service_id,
+            service.service_name.clone(),
+            service.current_status,
+            ServiceStatus::Operational,
+        )
+        .await;
+
+        if let Some(resolution) = resolution {
+            publish_incident_updated(pool, publisher, monitor.org_id, resolution).await;
         }
     }
 
@@ -113,6 +125,26 @@ async fn handle_failure(
         if service.current_status == ServiceStatus::Operational
             || service.current_status == ServiceStatus::DegradedPerformance
         {
+            // An upstream outage already explains the failure and carries
+            // its own incident; stay degraded until the upstream recovers.
+            if service.upstream_cause_service_id.is_some() {
+                hold_degraded(
+                    pool,
+                    monitor,
+                    &service,
+                    publisher,
+                    "upstream dependency outage",
+                )
+                .await;
+                return Ok(());
+            }
+
+            let policy = db::get_monitoring_policy(pool, monitor.org_id).await?;
+            if is_flapping(pool, monitor, &policy).await? {
+                hold_degraded(pool, monitor, &service, publisher, "monitor is flapping").await;
+                return Ok(());
+            }
+
             tracing::warn!(
                 monitor_id = %monitor.id,
                 service_id = %monitor.service_id,
@@ -135,23 +167,99 @@ async fn handle_failure(
 
             // Create auto-incident if one doesn't already exist
             let has_incident = db::has_active_auto_incident(pool, monitor.service_id).await?;
-            if !has_incident {
-                let error_msg = result
-                    .error_message
-                    .as_deref()
-                    .unwrap_or("Monitor check failed");
-                let incident =
-                    db::create_auto_incident(pool, monitor.org_id, monitor.service_id, error_msg)
-                        .await?;
-
-                publish_incident_created(pool, publisher, monitor.org_id, incident).await;
+            if has_incident {
+                return Ok(());
             }
+
+            if db::auto_incident_in_cooldown(
+                pool,
+                monitor.service_id,
+                policy.auto_incident_cooldown_minutes,
+            )
+            .await?
+            {
+                tracing::info!(
+                    monitor_id = %monitor.id,
+                    service_id = %monitor.service_id,
+                    cooldown_minutes = policy.auto_incident_cooldown_minutes,
+                    "Auto-incident resolved recently, not opening another during cool-down"
+                );
+                return Ok(());
+            }
+
+            let error_msg = result
+                .error_message
+                .as_deref()
+                .unwrap_or("Monitor check failed");
+            let incident =
+                db::create_auto_incident(pool, monitor.org_id, monitor.service_id, error_msg)
+                    .await?;
+
+            publish_incident_created(pool, publisher, monitor.org_id, incident).await;
         }
     }
 
     Ok(())
 }
 
+async fn is_flapping(
+    pool: &PgPool,
+    monitor: &Monitor,
+    policy: &MonitoringPolicy,
+) -> anyhow::Result<bool> {
+    if !policy.flap_detection_enabled {
+        return Ok(false);
+    }
+
+    let recent = db::recent_check_statuses(pool, monitor.id, policy.flap_window_checks).await?;
+    Ok(policy.is_flapping(&recent))
+}
+
+/// Keeps a service at degraded performance rather than toggling it between
+/// outage and recovery. Open auto-incidents stay open until the monitor
+/// settles.
+async fn hold_degraded(
+    pool: &PgPool,
+    monitor: &Monitor,
+    service: &db::ServiceSnapshot,
+    publisher: Option<&RedisPublisher>,
+    reason: &str,
+) {
+    if service.current_status == ServiceStatus::DegradedPerformance {
+        return;
+    }
+
+    tracing::warn!(
+        monitor_id = %monitor.id,
+        service_id = %monitor.service_id,
+        reason,
+        "Holding service at degraded performance"
+    );
+
+    if let Err(error) =
+        db::update_service_status(pool, monitor.service_id, ServiceStatus::DegradedPerformance)
+            .await
+    {
+        tracing::warn!(
+            error = %error,
+            service_id = %monitor.service_id,
+            "Failed to hold service at degraded performance"
+        );
+        return;
+    }
+
+    publish_service_status(
+        pool,
+        publisher,
+        monitor.org_id,
+        service.service_id,
+        service.service_name.clone(),
+        service.current_status,
+        ServiceStatus::DegradedPerformance,
+    )
+    .await;
+}
+
 async fn publish_service_status(
     pool: &PgPool,
     publisher: Option<&RedisPublisher>,
@@ -186,6 +294,7 @@ async fn publish_service_status(
     if let Err(error) = db::enqueue_service_status_notification_emails(
         pool,
         org_id,
+        service_id,
         &service_name,
         old_status,
         new_status,
@@ -250,6 +359,7 @@ async fn publish_incident_created(
         &incident.title,
         incident.status,
         "Automated monitoring detected failures.",
+        &incident.affected_services,
     )
     .await
     {
@@ -284,11 +394,15 @@ async fn publish_incident_updated(
     pool: &PgPool,
     publisher: Option<&RedisPublisher>,
     org_id: uuid::Uuid,
-    incident_id: uuid::Uuid,
-    update_id: uuid::Uuid,
-    status: shared::enums::IncidentStatus,
-    message: String,
+    resolution: db::AutoIncidentResolved,
 ) {
+    let db::AutoIncidentResolved {
+        incident_id,
+        service_id,
+        update,
+    } = resolution;
+    let (update_id, status, message) = (update.id, update.status, update.message);
+
     let webhook_event_type = if status == shared::enums::IncidentStatus::Resolved {
         "incident.resolved"
     } else {
@@ -323,6 +437,7 @@ async fn publish_incident_updated(
         "Automated incident update",
         status,
         &message,
+        &[service_id],
     )
     .await
     {
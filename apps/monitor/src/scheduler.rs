@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
-use shared::models::monitor::{normalize_monitor_config, Monitor, MonitorConfig};
+use shared::models::monitor::{parse_monitor_config, Monitor, MonitorConfig};
 use sqlx::PgPool;
 use tokio::sync::Semaphore;
 use tokio::task::JoinHandle;
@@ -172,20 +172,18 @@ async fn run_monitor_loop(
     cancel: CancellationToken,
     publisher: Option<RedisPublisher>,
 ) {
-    let config: MonitorConfig = match serde_json::from_value(normalize_monitor_config(
-        monitor.monitor_type,
-        monitor.config.clone(),
-    )) {
-        Ok(c) => c,
-        Err(e) => {
-            tracing::error!(
-                monitor_id = %monitor.id,
-                error = %e,
-                "Invalid monitor config, stopping"
-            );
-            return;
-        }
-    };
+    let config: MonitorConfig =
+        match parse_monitor_config(monitor.monitor_type, monitor.config.clone()) {
+            Ok(c) => c,
+            Err(e) => {
+                tracing::error!(
+                    monitor_id = %monitor.id,
+                    error = %e,
+                    "Invalid monitor config, stopping"
+                );
+                return;
+            }
+        };
 
     let check_impl = checker::create_checker(&config);
     let interval = Duration::from_secs(monitor.interval_seconds as u64);
@@ -17,7 +17,11 @@ import {
 import { Separator } from "@/components/ui/separator";
 import { toast } from "sonner";
 import type { IncidentWithDetails, IncidentStatus } from "@/lib/types";
-import { INCIDENT_STATUS_LABELS, INCIDENT_IMPACT_LABELS } from "@/lib/types";
+import {
+  INCIDENT_STATUS_LABELS,
+  INCIDENT_IMPACT_LABELS,
+  SERVICE_STATUS_LABELS,
+} from "@/lib/types";
 
 const statusColors: Record<IncidentStatus, string> = {
   investigating: "bg-red-500",
@@ -107,7 +111,9 @@ export default function IncidentDetailPage() {
           </Badge>
           <span className="text-sm text-muted-foreground">
             Affecting:{" "}
-            {incident.affected_services.map((s) => s.service_name).join(", ")}
+            {incident.affected_services
+              .map((s) => `${s.service_name} (${SERVICE_STATUS_LABELS[s.status]})`)
+              .join(", ")}
           </span>
         </div>
       </div>
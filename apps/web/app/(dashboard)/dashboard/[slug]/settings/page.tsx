@@ -7,9 +7,12 @@ import { Button } from "@/components/ui/button";
 import { Card, CardContent, CardHeader, CardTitle } from "@/components/ui/card";
 import { Input } from "@/components/ui/input";
 import { Label } from "@/components/ui/label";
+import { DataLifecycleCard } from "@/components/dashboard/data-lifecycle-card";
+import { PageAccessCard } from "@/components/dashboard/page-access-card";
 import type {
   BillingSummary,
   Invitation,
+  Locale,
   MemberRole,
   MemberWithUser,
   NotificationLogEntry,
@@ -34,6 +37,7 @@ const WEBHOOK_EVENT_OPTIONS = [
   { value: "incident.updated", label: "Incident updated" },
   { value: "incident.resolved", label: "Incident resolved" },
   { value: "service.status_changed", label: "Service status changed" },
+  { value: "incident.escalated", label: "Incident escalated (internal)" },
 ] as const;
 
 const DEFAULT_PREFERENCES: NotificationPreferences = {
@@ -92,6 +96,7 @@ export default function SettingsPage() {
   const [name, setName] = useState("");
   const [brandColor, setBrandColor] = useState("#3B82F6");
   const [timezone, setTimezone] = useState("UTC");
+  const [locale, setLocale] = useState<Locale>("en");
   const [logoUrl, setLogoUrl] = useState("");
   const [customDomain, setCustomDomain] = useState("");
   const [loading, setLoading] = useState(false);
@@ -124,11 +129,15 @@ export default function SettingsPage() {
     message: string | null;
     is_ready: boolean;
     expected_target: string | null;
+    txt_record_name: string | null;
+    txt_record_value: string | null;
   }>({
     loading: false,
     message: null,
     is_ready: false,
     expected_target: null,
+    txt_record_name: null,
+    txt_record_value: null,
   });
   const [newMember, setNewMember] = useState<{
     email: string;
@@ -265,6 +274,7 @@ export default function SettingsPage() {
         setName(orgBody.data.name);
         setBrandColor(orgBody.data.brand_color);
         setTimezone(orgBody.data.timezone);
+        setLocale(orgBody.data.locale);
         setLogoUrl(orgBody.data.logo_url ?? "");
         setCustomDomain(orgBody.data.custom_domain ?? "");
         setPreferences(preferencesBody.data);
@@ -359,6 +369,7 @@ export default function SettingsPage() {
           name: name.trim(),
           brand_color: brandColor,
           timezone: timezone.trim(),
+          locale,
           logo_url: logoUrl.trim(),
           custom_domain: customDomain.trim(),
         }),
@@ -372,6 +383,7 @@ export default function SettingsPage() {
       const body = await res.json();
       setOrg(body.data);
       setTimezone(body.data.timezone);
+      setLocale(body.data.locale);
       setLogoUrl(body.data.logo_url ?? "");
       setCustomDomain(body.data.custom_domain ?? "");
       toast.success("Organization settings saved");
@@ -749,6 +761,14 @@ export default function SettingsPage() {
               custom_domain_verified_at: body.data.is_ready
                 ? new Date().toISOString()
                 : current.custom_domain_verified_at,
+              custom_domain_status: body.data.is_ready
+                ? "verified"
+                : current.custom_domain_status,
+              custom_domain_verification_method:
+                body.data.verification_method ?? current.custom_domain_verification_method,
+              custom_domain_grace_ends_at: body.data.is_ready
+                ? null
+                : current.custom_domain_grace_ends_at,
             }
           : current,
       );
@@ -757,6 +777,8 @@ export default function SettingsPage() {
         message: body.data.message,
         is_ready: body.data.is_ready,
         expected_target: body.data.expected_target,
+        txt_record_name: body.data.txt_record_name,
+        txt_record_value: body.data.txt_record_value,
       });
       toast.success(body.data.message);
     } catch (error) {
@@ -767,6 +789,8 @@ export default function SettingsPage() {
         message,
         is_ready: false,
         expected_target: null,
+        txt_record_name: null,
+        txt_record_value: null,
       });
       toast.error(message);
     }
@@ -1138,6 +1162,24 @@ export default function SettingsPage() {
                 placeholder="UTC"
               />
             </div>
+            <div className="space-y-2">
+              <Label htmlFor="locale">Default language</Label>
+              <select
+                id="locale"
+                className="h-10 w-full rounded-md border bg-background px-3 text-sm text-foreground"
+                value={locale}
+                onChange={(e) => setLocale(e.target.value as Locale)}
+              >
+                <option value="en">English</option>
+                <option value="de">Deutsch</option>
+                <option value="es">Español</option>
+                <option value="fr">Français</option>
+              </select>
+              <p className="text-xs text-muted-foreground">
+                Used for subscriber emails and the public page unless a
+                subscriber or visitor picks another language.
+              </p>
+            </div>
             <div className="space-y-2">
               <Label htmlFor="logo-url">Logo URL</Label>
               <Input
@@ -1170,7 +1212,13 @@ export default function SettingsPage() {
                       ? "Blocked by plan"
                       : org.custom_domain_status === "verified"
                         ? "Verified"
-                        : "Verification pending"}
+                        : org.custom_domain_status === "certificate_failed"
+                          ? "Certificate issuance failed"
+                          : org.custom_domain_status === "certificate_expired"
+                            ? "Certificate expired"
+                            : org.custom_domain_status === "misconfigured"
+                              ? "DNS misconfigured"
+                              : "Verification pending"}
                   </Badge>
                   <Button
                     type="button"
@@ -1185,8 +1233,21 @@ export default function SettingsPage() {
                       Point DNS at {domainVerification.expected_target}
                     </span>
                   ) : null}
+                  {domainVerification.txt_record_name ? (
+                    <span className="text-xs text-muted-foreground">
+                      or add a TXT record {domainVerification.txt_record_name} ={" "}
+                      {domainVerification.txt_record_value}
+                    </span>
+                  ) : null}
                 </div>
               ) : null}
+              {!customDomainLocked && org.custom_domain_status === "misconfigured" ? (
+                <p className="text-xs text-destructive">
+                  {org.custom_domain_grace_ends_at
+                    ? `This domain no longer points at us. It keeps serving your page until ${new Date(org.custom_domain_grace_ends_at).toLocaleString()}; fix DNS and it recovers automatically.`
+                    : "This domain no longer points at us. Fix DNS and it recovers automatically."}
+                </p>
+              ) : null}
               {domainVerification.message ? (
                 <p className="text-xs text-muted-foreground">
                   {domainVerification.message}
@@ -1217,6 +1278,8 @@ export default function SettingsPage() {
         </CardContent>
       </Card>
 
+      <PageAccessCard slug={slug} />
+
       <Card>
         <CardHeader>
           <CardTitle>Team Members</CardTitle>
@@ -1950,6 +2013,8 @@ export default function SettingsPage() {
           </form>
         </CardContent>
       </Card>
+
+      <DataLifecycleCard slug={slug} />
     </div>
   );
 }
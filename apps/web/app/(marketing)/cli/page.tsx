@@ -0,0 +1,47 @@
+import { auth, signIn } from "@/lib/auth";
+import { Button } from "@/components/ui/button";
+import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
+import { ApproveCliLoginCard } from "@/components/cli/approve-cli-login-card";
+
+export default async function CliLoginPage({
+  searchParams,
+}: {
+  searchParams: Promise<{ code?: string }>;
+}) {
+  const { code = "" } = await searchParams;
+  const session = await auth();
+
+  if (!session) {
+    const redirectTo = code ? `/cli?code=${encodeURIComponent(code)}` : "/cli";
+    return (
+      <div className="flex min-h-[60vh] items-center justify-center">
+        <Card className="w-full max-w-md">
+          <CardHeader className="text-center">
+            <CardTitle>Sign in to approve the CLI</CardTitle>
+            <CardDescription>
+              The command-line client will act with your account and roles.
+            </CardDescription>
+          </CardHeader>
+          <CardContent>
+            <form
+              action={async () => {
+                "use server";
+                await signIn("github", { redirectTo });
+              }}
+            >
+              <Button className="w-full" type="submit">
+                Sign in with GitHub
+              </Button>
+            </form>
+          </CardContent>
+        </Card>
+      </div>
+    );
+  }
+
+  return (
+    <div className="flex min-h-[60vh] items-center justify-center">
+      <ApproveCliLoginCard initialCode={code} />
+    </div>
+  );
+}
@@ -0,0 +1,120 @@
+import { cookies } from "next/headers";
+import { redirect } from "next/navigation";
+import {
+  ApiClientError,
+  getViewerAccess,
+  requestViewerMagicLink,
+  signInWithPagePassword,
+  viewerCookieName,
+} from "@/lib/api-client";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { PublicMessagePage } from "@/components/status/public-message-page";
+
+interface Props {
+  params: Promise<{ slug: string }>;
+  searchParams: Promise<{ error?: string; sent?: string }>;
+}
+
+function errorMessage(error: unknown, fallback: string): string {
+  return error instanceof ApiClientError ? error.message : fallback;
+}
+
+export default async function ViewerAccessPage({ params, searchParams }: Props) {
+  const { slug } = await params;
+  const { error, sent } = await searchParams;
+
+  let access;
+  try {
+    access = await getViewerAccess(slug);
+  } catch {
+    return (
+      <PublicMessagePage
+        slug={slug}
+        title="Status page not found"
+        message="This status page does not exist."
+      />
+    );
+  }
+
+  if (access.authorized) {
+    redirect(`/s/${slug}`);
+  }
+
+  if (access.visibility === "ip_allowlist") {
+    return (
+      <PublicMessagePage
+        slug={slug}
+        title="Restricted status page"
+        message="This status page is only available from approved networks."
+      />
+    );
+  }
+
+  async function signIn(formData: FormData) {
+    "use server";
+    const password = String(formData.get("password") ?? "");
+    let session;
+    try {
+      session = await signInWithPagePassword(slug, password);
+    } catch (signInError) {
+      const message = errorMessage(signInError, "Sign-in failed");
+      redirect(`/s/${slug}/access?error=${encodeURIComponent(message)}`);
+    }
+    const cookieStore = await cookies();
+    cookieStore.set(viewerCookieName(slug), session.token, {
+      httpOnly: true,
+      sameSite: "lax",
+      secure: process.env.NODE_ENV === "production",
+      expires: new Date(session.expires_at),
+      path: "/",
+    });
+    redirect(`/s/${slug}`);
+  }
+
+  async function sendLink(formData: FormData) {
+    "use server";
+    const email = String(formData.get("email") ?? "");
+    try {
+      await requestViewerMagicLink(slug, email);
+    } catch (sendError) {
+      const message = errorMessage(sendError, "Could not send a sign-in link");
+      redirect(`/s/${slug}/access?error=${encodeURIComponent(message)}`);
+    }
+    redirect(`/s/${slug}/access?sent=1`);
+  }
+
+  return (
+    <div className="mx-auto max-w-md px-4 py-12">
+      <div className="rounded-lg border p-6">
+        <h1 className="text-2xl font-bold">Private status page</h1>
+        {access.visibility === "password" ? (
+          <form action={signIn} className="mt-4 space-y-3">
+            <p className="text-sm text-muted-foreground">
+              Enter the password to view this status page.
+            </p>
+            <Input name="password" type="password" required autoFocus />
+            <Button type="submit" className="w-full">
+              View status page
+            </Button>
+          </form>
+        ) : sent ? (
+          <p className="mt-3 text-muted-foreground">
+            If that address can view this page, a sign-in link is on its way.
+          </p>
+        ) : (
+          <form action={sendLink} className="mt-4 space-y-3">
+            <p className="text-sm text-muted-foreground">
+              Enter your work email and we will send you a sign-in link.
+            </p>
+            <Input name="email" type="email" required autoFocus />
+            <Button type="submit" className="w-full">
+              Email me a link
+            </Button>
+          </form>
+        )}
+        {error && <p className="mt-3 text-sm text-destructive">{error}</p>}
+      </div>
+    </div>
+  );
+}
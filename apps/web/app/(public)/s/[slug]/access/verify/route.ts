@@ -0,0 +1,27 @@
+import { NextRequest, NextResponse } from "next/server";
+import { verifyViewerMagicLink, viewerCookieName } from "@/lib/api-client";
+
+export async function GET(
+  request: NextRequest,
+  { params }: { params: Promise<{ slug: string }> },
+) {
+  const { slug } = await params;
+  const token = request.nextUrl.searchParams.get("token") ?? "";
+
+  try {
+    const session = await verifyViewerMagicLink(slug, token);
+    const response = NextResponse.redirect(new URL(`/s/${slug}`, request.url));
+    response.cookies.set(viewerCookieName(slug), session.token, {
+      httpOnly: true,
+      sameSite: "lax",
+      secure: process.env.NODE_ENV === "production",
+      expires: new Date(session.expires_at),
+      path: "/",
+    });
+    return response;
+  } catch {
+    const failed = new URL(`/s/${slug}/access`, request.url);
+    failed.searchParams.set("error", "This sign-in link is invalid or has expired");
+    return NextResponse.redirect(failed);
+  }
+}
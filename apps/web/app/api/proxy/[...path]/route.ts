@@ -29,6 +29,17 @@ async function proxyRequest(req: NextRequest) {
   if (sessionToken) {
     headers["Cookie"] = `authjs.session-token=${sessionToken}`;
   }
+  const publicSlug = path.match(/^\/api\/public\/([^/?]+)/)?.[1];
+  if (publicSlug) {
+    const viewerToken = cookieStore.get(`statuspage_viewer_${publicSlug}`)?.value;
+    if (viewerToken) {
+      headers["x-statuspage-viewer-token"] = viewerToken;
+    }
+    const forwardedFor = req.headers.get("x-forwarded-for");
+    if (forwardedFor) {
+      headers["x-forwarded-for"] = forwardedFor;
+    }
+  }
   const adminToken = req.headers.get("x-statuspage-admin-token");
   if (adminToken) {
     headers["x-statuspage-admin-token"] = adminToken;
@@ -50,10 +61,18 @@ async function proxyRequest(req: NextRequest) {
 
   const res = await fetch(targetUrl, fetchOptions);
 
-  const responseBody = await res.text();
-  return new NextResponse(responseBody || null, {
+  // Read bytes rather than text so archive downloads survive the proxy.
+  const responseBody = await res.arrayBuffer();
+  const responseHeaders: HeadersInit = {
+    "Content-Type": res.headers.get("content-type") || "application/json",
+  };
+  const disposition = res.headers.get("content-disposition");
+  if (disposition) {
+    responseHeaders["Content-Disposition"] = disposition;
+  }
+  return new NextResponse(responseBody.byteLength ? responseBody : null, {
     status: res.status,
-    headers: { "Content-Type": "application/json" },
+    headers: responseHeaders,
   });
 }
 
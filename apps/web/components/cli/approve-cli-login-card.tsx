@@ -0,0 +1,133 @@
+"use client";
+
+import { useState } from "react";
+import { Button } from "@/components/ui/button";
+import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { toast } from "sonner";
+import type { CliLoginDetails } from "@/lib/types";
+
+export function ApproveCliLoginCard({ initialCode }: { initialCode: string }) {
+  const [code, setCode] = useState(initialCode);
+  const [login, setLogin] = useState<CliLoginDetails | null>(null);
+  const [result, setResult] = useState<string | null>(null);
+  const [loading, setLoading] = useState(false);
+
+  async function lookUp(event: React.FormEvent) {
+    event.preventDefault();
+    setLoading(true);
+
+    try {
+      const res = await fetch(
+        `/api/proxy/api/cli/login/${encodeURIComponent(code.trim())}`,
+      );
+      const body = await res.json();
+
+      if (!res.ok) {
+        throw new Error(body.error?.message || "Login request not found");
+      }
+
+      setLogin(body.data);
+    } catch (error) {
+      toast.error(
+        error instanceof Error ? error.message : "Login request not found",
+      );
+    } finally {
+      setLoading(false);
+    }
+  }
+
+  async function decide(approve: boolean) {
+    if (!login) return;
+    setLoading(true);
+
+    try {
+      const res = await fetch("/api/proxy/api/cli/login/decision", {
+        method: "POST",
+        headers: { "Content-Type": "application/json" },
+        body: JSON.stringify({ user_code: login.user_code, approve }),
+      });
+      const body = await res.json();
+
+      if (!res.ok) {
+        throw new Error(body.error?.message || "Failed to update the login");
+      }
+
+      setResult(body.data.message);
+    } catch (error) {
+      toast.error(
+        error instanceof Error ? error.message : "Failed to update the login",
+      );
+    } finally {
+      setLoading(false);
+    }
+  }
+
+  if (result) {
+    return (
+      <Card className="w-full max-w-md">
+        <CardHeader className="text-center">
+          <CardTitle>Command-line login</CardTitle>
+          <CardDescription>{result}</CardDescription>
+        </CardHeader>
+      </Card>
+    );
+  }
+
+  if (login) {
+    return (
+      <Card className="w-full max-w-md">
+        <CardHeader className="text-center">
+          <CardTitle>Approve {login.client_name}?</CardTitle>
+          <CardDescription>
+            Only approve if your terminal shows the code {login.user_code}. The
+            CLI gets a credential that acts as you for a year, until revoked.
+          </CardDescription>
+        </CardHeader>
+        <CardContent className="flex gap-2">
+          <Button
+            className="flex-1"
+            variant="outline"
+            onClick={() => decide(false)}
+            disabled={loading}
+          >
+            Deny
+          </Button>
+          <Button className="flex-1" onClick={() => decide(true)} disabled={loading}>
+            {loading ? "Approving..." : "Approve"}
+          </Button>
+        </CardContent>
+      </Card>
+    );
+  }
+
+  return (
+    <Card className="w-full max-w-md">
+      <CardHeader className="text-center">
+        <CardTitle>Command-line login</CardTitle>
+        <CardDescription>
+          Enter the code shown by <code>statuspage login</code>.
+        </CardDescription>
+      </CardHeader>
+      <CardContent>
+        <form className="space-y-4" onSubmit={lookUp}>
+          <div className="space-y-2">
+            <Label htmlFor="cli-code">Code</Label>
+            <Input
+              id="cli-code"
+              value={code}
+              onChange={(event) => setCode(event.target.value)}
+              placeholder="XXXX-XXXX"
+              autoComplete="off"
+              required
+            />
+          </div>
+          <Button className="w-full" type="submit" disabled={loading}>
+            {loading ? "Checking..." : "Continue"}
+          </Button>
+        </form>
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,219 @@
+"use client";
+
+import { useEffect, useState } from "react";
+import { Badge } from "@/components/ui/badge";
+import { Button } from "@/components/ui/button";
+import { Card, CardContent, CardHeader, CardTitle } from "@/components/ui/card";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import type { DeletionState, OrganizationExport } from "@/lib/types";
+import { toast } from "sonner";
+
+function formatBytes(bytes: number | null) {
+  if (bytes === null) return "";
+  if (bytes < 1024) return `${bytes} B`;
+  if (bytes < 1024 * 1024) return `${(bytes / 1024).toFixed(1)} KB`;
+  return `${(bytes / (1024 * 1024)).toFixed(1)} MB`;
+}
+
+// Export and deletion endpoints are owner-only; other roles never see the card.
+export function DataLifecycleCard({ slug }: { slug: string }) {
+  const [allowed, setAllowed] = useState(false);
+  const [exports, setExports] = useState<OrganizationExport[]>([]);
+  const [deletionState, setDeletionState] = useState<DeletionState | null>(null);
+  const [confirmSlug, setConfirmSlug] = useState("");
+  const [reason, setReason] = useState("");
+  const [busy, setBusy] = useState(false);
+
+  async function loadExports() {
+    const res = await fetch(`/api/proxy/api/organizations/${slug}/exports`);
+    if (!res.ok) return false;
+    const body = await res.json();
+    setExports(body.data);
+    return true;
+  }
+
+  useEffect(() => {
+    async function load() {
+      const [exportsOk, deletionRes] = await Promise.all([
+        loadExports(),
+        fetch(`/api/proxy/api/organizations/${slug}/deletion`),
+      ]);
+      if (!exportsOk || !deletionRes.ok) return;
+      const body = await deletionRes.json();
+      setDeletionState(body.data);
+      setAllowed(true);
+    }
+    void load();
+  }, [slug]);
+
+  const exportInProgress = exports.some(
+    (item) => item.status === "pending" || item.status === "running",
+  );
+
+  useEffect(() => {
+    if (!exportInProgress) return;
+    const timer = setInterval(() => void loadExports(), 5000);
+    return () => clearInterval(timer);
+  }, [exportInProgress, slug]);
+
+  async function requestExport() {
+    setBusy(true);
+    try {
+      const res = await fetch(`/api/proxy/api/organizations/${slug}/exports`, {
+        method: "POST",
+      });
+      const body = await res.json();
+      if (!res.ok) {
+        throw new Error(body.error?.message || "Failed to start export");
+      }
+      setExports((current) => [body.data, ...current]);
+      toast.success("Export started. The archive will be ready shortly.");
+    } catch (error) {
+      toast.error(error instanceof Error ? error.message : "Failed to start export");
+    } finally {
+      setBusy(false);
+    }
+  }
+
+  async function scheduleDeletion(event: React.FormEvent) {
+    event.preventDefault();
+    setBusy(true);
+    try {
+      const res = await fetch(`/api/proxy/api/organizations/${slug}/deletion`, {
+        method: "POST",
+        headers: { "Content-Type": "application/json" },
+        body: JSON.stringify({ confirm_slug: confirmSlug, reason: reason || null }),
+      });
+      const body = await res.json();
+      if (!res.ok) {
+        throw new Error(body.error?.message || "Failed to schedule deletion");
+      }
+      setDeletionState((current) =>
+        current ? { ...current, deletion: body.data } : current,
+      );
+      setConfirmSlug("");
+      setReason("");
+      toast.success("Organization deletion scheduled");
+    } catch (error) {
+      toast.error(error instanceof Error ? error.message : "Failed to schedule deletion");
+    } finally {
+      setBusy(false);
+    }
+  }
+
+  async function cancelDeletion() {
+    setBusy(true);
+    try {
+      const res = await fetch(`/api/proxy/api/organizations/${slug}/deletion`, {
+        method: "DELETE",
+      });
+      const body = await res.json();
+      if (!res.ok) {
+        throw new Error(body.error?.message || "Failed to cancel deletion");
+      }
+      setDeletionState((current) => (current ? { ...current, deletion: null } : current));
+      toast.success("Organization deletion canceled");
+    } catch (error) {
+      toast.error(error instanceof Error ? error.message : "Failed to cancel deletion");
+    } finally {
+      setBusy(false);
+    }
+  }
+
+  if (!allowed || !deletionState) return null;
+
+  const deletion = deletionState.deletion;
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>Data Export &amp; Deletion</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-6">
+        <div className="space-y-3">
+          <p className="text-sm text-muted-foreground">
+            Download a zip archive of services, monitors (with secrets redacted), incidents and
+            their updates, subscribers, audit logs and uptime history.
+          </p>
+          <Button onClick={requestExport} disabled={busy || exportInProgress}>
+            {exportInProgress ? "Export in progress..." : "Export organization data"}
+          </Button>
+          {exports.length > 0 && (
+            <ul className="space-y-2 text-sm">
+              {exports.map((item) => (
+                <li key={item.id} className="flex items-center justify-between gap-4">
+                  <span>
+                    {new Date(item.created_at).toLocaleString()}{" "}
+                    <Badge variant="outline">{item.status}</Badge>{" "}
+                    {formatBytes(item.archive_size_bytes)}
+                    {item.error && <span className="text-destructive"> {item.error}</span>}
+                  </span>
+                  {item.status === "completed" && (
+                    <a
+                      className="underline"
+                      href={`/api/proxy/api/organizations/${slug}/exports/${item.id}/download`}
+                    >
+                      Download
+                    </a>
+                  )}
+                </li>
+              ))}
+            </ul>
+          )}
+        </div>
+
+        <div className="space-y-3 border-t pt-6">
+          <h3 className="font-medium text-destructive">Delete organization</h3>
+          {deletion ? (
+            <div className="space-y-3 text-sm">
+              <p>
+                {deletion.status === "processing"
+                  ? "Deletion is in progress and can no longer be canceled."
+                  : `This organization will be permanently deleted after ${new Date(
+                      deletion.scheduled_for,
+                    ).toLocaleString()}.`}
+              </p>
+              {deletion.status === "scheduled" && (
+                <Button variant="outline" onClick={cancelDeletion} disabled={busy}>
+                  Cancel deletion
+                </Button>
+              )}
+            </div>
+          ) : (
+            <form onSubmit={scheduleDeletion} className="space-y-3">
+              <p className="text-sm text-muted-foreground">
+                Deletion runs {deletionState.cool_off_hours} hours after you confirm and can be
+                canceled until then. It cancels the subscription and removes all services,
+                monitors, incidents, subscribers and check history.
+              </p>
+              <div className="space-y-2">
+                <Label htmlFor="delete-confirm-slug">Type {slug} to confirm</Label>
+                <Input
+                  id="delete-confirm-slug"
+                  value={confirmSlug}
+                  onChange={(e) => setConfirmSlug(e.target.value)}
+                />
+              </div>
+              <div className="space-y-2">
+                <Label htmlFor="delete-reason">Reason (optional)</Label>
+                <Input
+                  id="delete-reason"
+                  value={reason}
+                  onChange={(e) => setReason(e.target.value)}
+                />
+              </div>
+              <Button
+                type="submit"
+                variant="destructive"
+                disabled={busy || confirmSlug !== slug}
+              >
+                Schedule deletion
+              </Button>
+            </form>
+          )}
+        </div>
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,150 @@
+"use client";
+
+import { useEffect, useState } from "react";
+import { Button } from "@/components/ui/button";
+import { Card, CardContent, CardHeader, CardTitle } from "@/components/ui/card";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { Textarea } from "@/components/ui/textarea";
+import type {
+  PageAccessSettings,
+  PageVisibility,
+  UpdatePageAccessRequest,
+} from "@/lib/types";
+import { toast } from "sonner";
+
+const VISIBILITY_LABELS: Record<PageVisibility, string> = {
+  public: "Public",
+  password: "Password protected",
+  email_domain: "Allowlisted email domains (magic link)",
+  ip_allowlist: "Allowlisted IP ranges",
+};
+
+function splitList(value: string): string[] {
+  return value
+    .split(/[\s,]+/)
+    .map((entry) => entry.trim())
+    .filter(Boolean);
+}
+
+export function PageAccessCard({ slug }: { slug: string }) {
+  const [settings, setSettings] = useState<PageAccessSettings | null>(null);
+  const [visibility, setVisibility] = useState<PageVisibility>("public");
+  const [password, setPassword] = useState("");
+  const [emailDomains, setEmailDomains] = useState("");
+  const [cidrs, setCidrs] = useState("");
+  const [saving, setSaving] = useState(false);
+
+  useEffect(() => {
+    async function load() {
+      const res = await fetch(`/api/proxy/api/organizations/${slug}/page-access`);
+      if (!res.ok) return;
+      const body = await res.json();
+      const loaded: PageAccessSettings = body.data;
+      setSettings(loaded);
+      setVisibility(loaded.visibility);
+      setEmailDomains(loaded.allowed_email_domains.join("\n"));
+      setCidrs(loaded.allowed_cidrs.join("\n"));
+    }
+    void load();
+  }, [slug]);
+
+  async function handleSave(event: React.FormEvent) {
+    event.preventDefault();
+    setSaving(true);
+
+    const request: UpdatePageAccessRequest = {
+      visibility,
+      allowed_email_domains: splitList(emailDomains),
+      allowed_cidrs: splitList(cidrs),
+    };
+    if (password) {
+      request.password = password;
+    }
+
+    try {
+      const res = await fetch(`/api/proxy/api/organizations/${slug}/page-access`, {
+        method: "PUT",
+        headers: { "Content-Type": "application/json" },
+        body: JSON.stringify(request),
+      });
+      const body = await res.json();
+      if (!res.ok) {
+        throw new Error(body.error?.message || "Failed to save page access");
+      }
+      setSettings(body.data);
+      setPassword("");
+      toast.success("Page access saved. Existing viewers must sign in again.");
+    } catch (error) {
+      toast.error(error instanceof Error ? error.message : "Failed to save page access");
+    } finally {
+      setSaving(false);
+    }
+  }
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>Page Access</CardTitle>
+      </CardHeader>
+      <CardContent>
+        <form onSubmit={handleSave} className="space-y-4">
+          <div className="space-y-2">
+            <Label htmlFor="page-visibility">Who can view the status page</Label>
+            <select
+              id="page-visibility"
+              className="h-10 w-full rounded-md border bg-background px-3 text-sm text-foreground"
+              value={visibility}
+              onChange={(e) => setVisibility(e.target.value as PageVisibility)}
+            >
+              {(Object.keys(VISIBILITY_LABELS) as PageVisibility[]).map((value) => (
+                <option key={value} value={value}>
+                  {VISIBILITY_LABELS[value]}
+                </option>
+              ))}
+            </select>
+          </div>
+          {visibility === "password" && (
+            <div className="space-y-2">
+              <Label htmlFor="page-password">
+                {settings?.password_set ? "New password (optional)" : "Password"}
+              </Label>
+              <Input
+                id="page-password"
+                type="password"
+                value={password}
+                onChange={(e) => setPassword(e.target.value)}
+                minLength={8}
+              />
+            </div>
+          )}
+          {visibility === "email_domain" && (
+            <div className="space-y-2">
+              <Label htmlFor="page-email-domains">Email domains, one per line</Label>
+              <Textarea
+                id="page-email-domains"
+                value={emailDomains}
+                onChange={(e) => setEmailDomains(e.target.value)}
+                placeholder="example.com"
+              />
+            </div>
+          )}
+          {visibility === "ip_allowlist" && (
+            <div className="space-y-2">
+              <Label htmlFor="page-cidrs">IP addresses or CIDR ranges, one per line</Label>
+              <Textarea
+                id="page-cidrs"
+                value={cidrs}
+                onChange={(e) => setCidrs(e.target.value)}
+                placeholder="203.0.113.0/24"
+              />
+            </div>
+          )}
+          <Button type="submit" disabled={saving}>
+            {saving ? "Saving..." : "Save Access"}
+          </Button>
+        </form>
+      </CardContent>
+    </Card>
+  );
+}
@@ -1,7 +1,7 @@
 import { Badge } from "@/components/ui/badge";
 import { Card, CardContent, CardHeader, CardTitle } from "@/components/ui/card";
 import type { PublicIncident, IncidentStatus } from "@/lib/types";
-import { INCIDENT_STATUS_LABELS } from "@/lib/types";
+import { INCIDENT_STATUS_LABELS, SERVICE_STATUS_LABELS } from "@/lib/types";
 
 const statusDotColors: Record<IncidentStatus, string> = {
   investigating: "bg-red-500",
@@ -30,7 +30,10 @@ export function ActiveIncidents({
               </Badge>
             </div>
             <p className="text-sm text-muted-foreground">
-              Affecting: {incident.affected_services.join(", ")}
+              Affecting:{" "}
+              {incident.affected_services
+                .map((s) => `${s.name} (${SERVICE_STATUS_LABELS[s.status]})`)
+                .join(", ")}
             </p>
           </CardHeader>
           <CardContent>
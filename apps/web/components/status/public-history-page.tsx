@@ -1,7 +1,7 @@
 import Link from "next/link";
-import { notFound } from "next/navigation";
 import { getPublicIncidents, getPublicStatus } from "@/lib/api-client";
 import { buildPublicBasePath, buildPublicHref } from "@/lib/custom-domain";
+import { handlePublicPageError } from "@/lib/viewer-access";
 import type { ResolvedCustomDomain } from "@/lib/types";
 import {
   INCIDENT_IMPACT_COLORS,
@@ -20,8 +20,8 @@ export async function PublicHistoryPageContent({
 }) {
   try {
     await getPublicStatus(slug);
-  } catch {
-    notFound();
+  } catch (error) {
+    handlePublicPageError(slug, error);
   }
 
   const { data: incidents, pagination } = await getPublicIncidents(slug, page, 20);
@@ -1,6 +1,6 @@
-import { notFound } from "next/navigation";
 import { getPublicStatus, getPublicUptime } from "@/lib/api-client";
 import { buildPublicBasePath, buildPublicHref } from "@/lib/custom-domain";
+import { handlePublicPageError } from "@/lib/viewer-access";
 import type { ResolvedCustomDomain, PublicStatusResponse, UptimeResponse } from "@/lib/types";
 import { StatusBanner } from "@/components/status/status-banner";
 import { ServiceList } from "@/components/status/service-list";
@@ -24,8 +24,8 @@ export async function PublicStatusPageContent({
       getPublicStatus(slug),
       getPublicUptime(slug),
     ]);
-  } catch {
-    notFound();
+  } catch (error) {
+    handlePublicPageError(slug, error);
   }
 
   const basePath = buildPublicBasePath(slug, resolvedCustomDomain);
@@ -53,7 +53,7 @@ export async function PublicStatusPageContent({
       )}
 
       <div className="mt-8">
-        <ServiceList services={status.services} />
+        <ServiceList services={status.services} groups={status.groups} />
       </div>
 
       <div className="mt-8">
@@ -1,5 +1,5 @@
 import { cn } from "@/lib/utils";
-import type { PublicService, ServiceStatus } from "@/lib/types";
+import type { PublicService, PublicServiceGroup, ServiceStatus } from "@/lib/types";
 import { SERVICE_STATUS_LABELS } from "@/lib/types";
 import { CheckCircle, AlertTriangle, XCircle, Wrench } from "lucide-react";
 
@@ -19,49 +19,85 @@ const statusColors: Record<ServiceStatus, string> = {
   under_maintenance: "text-blue-500",
 };
 
-export function ServiceList({ services }: { services: PublicService[] }) {
-  // Group services by group_name
-  const grouped = new Map<string | null, PublicService[]>();
-  for (const service of services) {
-    const group = service.group_name;
-    const existing = grouped.get(group) || [];
-    existing.push(service);
-    grouped.set(group, existing);
-  }
+function ServiceRow({ service }: { service: PublicService }) {
+  const Icon = statusIcons[service.current_status];
+  return (
+    <div className="flex items-center justify-between px-4 py-3">
+      <div>
+        <span className="font-medium">{service.name}</span>
+        {service.upstream_cause && (
+          <p className="text-xs text-muted-foreground">
+            Affected by {service.upstream_cause}
+          </p>
+        )}
+      </div>
+      <StatusLabel status={service.current_status} icon={Icon} />
+    </div>
+  );
+}
+
+function StatusLabel({
+  status,
+  icon: Icon,
+}: {
+  status: ServiceStatus;
+  icon: React.ElementType;
+}) {
+  return (
+    <div className={cn("flex items-center gap-2 text-sm", statusColors[status])}>
+      <span>{SERVICE_STATUS_LABELS[status]}</span>
+      <Icon className="h-5 w-5" />
+    </div>
+  );
+}
+
+export function ServiceList({
+  services,
+  groups,
+}: {
+  services: PublicService[];
+  groups: PublicServiceGroup[];
+}) {
+  const ungrouped = services.filter(
+    (service) => !groups.some((group) => group.id === service.group_id),
+  );
 
   return (
     <div className="space-y-4">
-      {Array.from(grouped.entries()).map(([group, groupServices]) => (
-        <div key={group || "ungrouped"}>
-          {group && (
-            <h3 className="mb-2 text-sm font-medium text-muted-foreground">
-              {group}
-            </h3>
-          )}
-          <div className="rounded-lg border divide-y">
-            {groupServices.map((service) => {
-              const Icon = statusIcons[service.current_status];
-              return (
-                <div
-                  key={service.id}
-                  className="flex items-center justify-between px-4 py-3"
-                >
-                  <span className="font-medium">{service.name}</span>
-                  <div
-                    className={cn(
-                      "flex items-center gap-2 text-sm",
-                      statusColors[service.current_status],
-                    )}
-                  >
-                    <span>{SERVICE_STATUS_LABELS[service.current_status]}</span>
-                    <Icon className="h-5 w-5" />
-                  </div>
-                </div>
-              );
-            })}
-          </div>
+      {groups.map((group) => {
+        const members = services.filter((service) => service.group_id === group.id);
+        return (
+          <details
+            key={group.id}
+            open={!group.collapsed_by_default}
+            className="rounded-lg border"
+          >
+            <summary className="flex cursor-pointer items-center justify-between px-4 py-3">
+              <div>
+                <span className="font-semibold">{group.name}</span>
+                {group.description && (
+                  <p className="text-xs text-muted-foreground">
+                    {group.description}
+                  </p>
+                )}
+              </div>
+              <StatusLabel status={group.status} icon={statusIcons[group.status]} />
+            </summary>
+            <div className="divide-y border-t">
+              {members.map((service) => (
+                <ServiceRow key={service.id} service={service} />
+              ))}
+            </div>
+          </details>
+        );
+      })}
+      {ungrouped.length > 0 && (
+        <div className="rounded-lg border divide-y">
+          {ungrouped.map((service) => (
+            <ServiceRow key={service.id} service={service} />
+          ))}
         </div>
-      ))}
+      )}
     </div>
   );
 }
@@ -83,6 +83,9 @@ function ServiceUptimeRow({ service }: { service: ServiceUptime }) {
                 ? `${tooltip.day.uptime_percentage.toFixed(2)}%`
                 : "No data"}
             </div>
+            {tooltip.day.outage_minutes > 0 && (
+              <div>{tooltip.day.outage_minutes} min outage</div>
+            )}
             {tooltip.day.avg_response_time_ms !== null && (
               <div>{Math.round(tooltip.day.avg_response_time_ms)}ms avg</div>
             )}
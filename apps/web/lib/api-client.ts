@@ -1,4 +1,4 @@
-import { cookies } from "next/headers";
+import { cookies, headers as requestHeaders } from "next/headers";
 import type {
   ApiResponse,
   ApiListResponse,
@@ -33,6 +33,8 @@ import type {
   PublicIncident,
   ResolvedCustomDomain,
   UptimeResponse,
+  ViewerAccess,
+  ViewerSession,
 } from "./types";
 
 const INTERNAL_API_URL =
@@ -63,11 +65,40 @@ async function getSessionCookie(): Promise<string> {
   return `authjs.session-token=${sessionToken}`;
 }
 
+export function viewerCookieName(slug: string): string {
+  return `statuspage_viewer_${slug}`;
+}
+
+/**
+ * Restricted status pages need the visitor's viewer session and address,
+ * since the API sees this server rather than the browser.
+ */
+async function getViewerHeaders(path: string): Promise<Record<string, string>> {
+  const match = path.match(/^\/api\/public\/([^/?]+)/);
+  if (!match) return {};
+
+  const viewerHeaders: Record<string, string> = {};
+  const headerStore = await requestHeaders();
+  const forwardedFor =
+    headerStore.get("x-forwarded-for") ?? headerStore.get("x-real-ip");
+  if (forwardedFor) {
+    viewerHeaders["x-forwarded-for"] = forwardedFor;
+  }
+
+  const cookieStore = await cookies();
+  const viewerToken = cookieStore.get(viewerCookieName(match[1]))?.value;
+  if (viewerToken) {
+    viewerHeaders["x-statuspage-viewer-token"] = viewerToken;
+  }
+  return viewerHeaders;
+}
+
 async function fetchApi<T>(
   path: string,
   options: RequestInit = {},
 ): Promise<T> {
   const cookie = await getSessionCookie();
+  const viewerHeaders = await getViewerHeaders(path);
   const url = `${INTERNAL_API_URL}${path}`;
 
   const res = await fetch(url, {
@@ -75,6 +106,7 @@ async function fetchApi<T>(
     headers: {
       "Content-Type": "application/json",
       Cookie: cookie,
+      ...viewerHeaders,
       ...options.headers,
     },
     cache: "no-store",
@@ -566,4 +598,53 @@ export async function unsubscribePublicSubscriber(
   return res.data;
 }
 
+export async function getViewerAccess(slug: string): Promise<ViewerAccess> {
+  const res = await fetchApi<ApiResponse<ViewerAccess>>(
+    `/api/public/${slug}/access`,
+  );
+  return res.data;
+}
+
+export async function signInWithPagePassword(
+  slug: string,
+  password: string,
+): Promise<ViewerSession> {
+  const res = await fetchApi<ApiResponse<ViewerSession>>(
+    `/api/public/${slug}/access/password`,
+    {
+      method: "POST",
+      body: JSON.stringify({ password }),
+    },
+  );
+  return res.data;
+}
+
+export async function requestViewerMagicLink(
+  slug: string,
+  email: string,
+): Promise<PublicMessageResponse> {
+  const res = await fetchApi<ApiResponse<PublicMessageResponse>>(
+    `/api/public/${slug}/access/magic-link`,
+    {
+      method: "POST",
+      body: JSON.stringify({ email }),
+    },
+  );
+  return res.data;
+}
+
+export async function verifyViewerMagicLink(
+  slug: string,
+  token: string,
+): Promise<ViewerSession> {
+  const res = await fetchApi<ApiResponse<ViewerSession>>(
+    `/api/public/${slug}/access/magic-link/verify`,
+    {
+      method: "POST",
+      body: JSON.stringify({ token }),
+    },
+  );
+  return res.data;
+}
+
 export { ApiClientError };
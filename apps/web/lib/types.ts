@@ -45,10 +45,36 @@ export type CustomDomainStatus =
   | "not_configured"
   | "pending_verification"
   | "verified"
-  | "blocked_by_plan";
+  | "blocked_by_plan"
+  | "certificate_failed"
+  | "certificate_expired"
+  | "misconfigured";
+
+export type CustomDomainVerificationMethod = "a_record" | "cname" | "txt_token";
 
 export type DisabledReason = "plan_limit";
 
+export type Locale = "en" | "de" | "es" | "fr";
+
+export type PageVisibility =
+  | "public"
+  | "password"
+  | "email_domain"
+  | "ip_allowlist";
+
+export type ExportStatus =
+  | "pending"
+  | "running"
+  | "completed"
+  | "failed"
+  | "expired";
+
+export type OrganizationDeletionStatus =
+  | "scheduled"
+  | "processing"
+  | "canceled"
+  | "completed";
+
 export type InvitationDeliveryStatus =
   | "pending"
   | "accepted"
@@ -66,9 +92,14 @@ export interface Organization {
   logo_url: string | null;
   brand_color: string;
   timezone: string;
+  locale: Locale;
   custom_domain: string | null;
   custom_domain_verified_at: string | null;
   custom_domain_status: CustomDomainStatus;
+  custom_domain_verification_token: string;
+  custom_domain_verification_method: CustomDomainVerificationMethod | null;
+  custom_domain_checked_at: string | null;
+  custom_domain_grace_ends_at: string | null;
   stripe_customer_id: string | null;
   stripe_subscription_id: string | null;
   subscription_status: SubscriptionStatus;
@@ -82,10 +113,84 @@ export interface Organization {
   downgrade_grace_ends_at: string | null;
   downgrade_state: DowngradeState;
   downgrade_warning_stage: number;
+  page_visibility: PageVisibility;
+  page_allowed_email_domains: string[];
+  page_allowed_cidrs: string[];
+  created_at: string;
+  updated_at: string;
+}
+
+export interface PageAccessSettings {
+  visibility: PageVisibility;
+  password_set: boolean;
+  allowed_email_domains: string[];
+  allowed_cidrs: string[];
+}
+
+export interface UpdatePageAccessRequest {
+  visibility: PageVisibility;
+  password?: string;
+  allowed_email_domains?: string[];
+  allowed_cidrs?: string[];
+}
+
+export interface OrganizationExport {
+  id: string;
+  org_id: string;
+  requested_by: string | null;
+  status: ExportStatus;
+  archive_size_bytes: number | null;
+  error: string | null;
+  started_at: string | null;
+  completed_at: string | null;
+  expires_at: string | null;
+  created_at: string;
+}
+
+export interface OrganizationDeletion {
+  id: string;
+  org_id: string;
+  org_slug: string;
+  org_name: string;
+  requested_by: string | null;
+  requested_by_email: string | null;
+  reason: string | null;
+  status: OrganizationDeletionStatus;
+  scheduled_for: string;
+  canceled_at: string | null;
+  canceled_by: string | null;
+  stripe_subscription_id: string | null;
+  stripe_cancellation: "not_subscribed" | "canceled" | "already_canceled" | null;
+  purge_summary: Record<string, number>;
+  attempts: number;
+  last_error: string | null;
+  completed_at: string | null;
   created_at: string;
   updated_at: string;
 }
 
+export interface DeletionState {
+  deletion: OrganizationDeletion | null;
+  cool_off_hours: number;
+}
+
+export interface ViewerAccess {
+  visibility: PageVisibility;
+  authorized: boolean;
+}
+
+export interface ViewerSession {
+  token: string;
+  expires_at: string;
+}
+
+export interface CliLoginDetails {
+  user_code: string;
+  client_name: string;
+  created_at: string;
+  expires_at: string;
+}
+
 export interface Service {
   id: string;
   org_id: string;
@@ -93,10 +198,75 @@ export interface Service {
   description: string | null;
   current_status: ServiceStatus;
   display_order: number;
+  group_id: string | null;
   group_name: string | null;
   is_visible: boolean;
   created_at: string;
   updated_at: string;
+  upstream_cause_service_id: string | null;
+}
+
+export type GroupStatusRule = "worst_of" | "majority";
+
+export interface ServiceGroup {
+  id: string;
+  org_id: string;
+  name: string;
+  description: string | null;
+  display_order: number;
+  collapsed_by_default: boolean;
+  status_rule: GroupStatusRule;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface StatusPage {
+  id: string;
+  org_id: string;
+  slug: string;
+  name: string;
+  logo_url: string | null;
+  brand_color: string | null;
+  custom_domain: string | null;
+  custom_domain_status: CustomDomainStatus;
+  custom_domain_verified_at: string | null;
+  custom_domain_verification_token: string;
+  custom_domain_verification_method: CustomDomainVerificationMethod | null;
+  custom_domain_checked_at: string | null;
+  custom_domain_grace_ends_at: string | null;
+  page_visibility: PageVisibility;
+  page_allowed_email_domains: string[];
+  page_allowed_cidrs: string[];
+  service_ids: string[];
+  disabled_reason: DisabledReason | null;
+  created_at: string;
+  updated_at: string;
+}
+
+export type StatusChangeSource = "manual" | "incident" | "monitor";
+
+export interface ServiceStatusInterval {
+  id: string;
+  service_id: string;
+  status: ServiceStatus;
+  source: StatusChangeSource;
+  started_at: string;
+  ended_at: string | null;
+}
+
+export interface ServiceStatusHistory {
+  from: string;
+  to: string;
+  uptime_percentage: number | null;
+  outage_minutes: number;
+  intervals: ServiceStatusInterval[];
+}
+
+export interface ServiceDependency {
+  service_id: string;
+  depends_on_service_id: string;
+  depends_on_service_name: string;
+  created_at: string;
 }
 
 export interface Incident {
@@ -111,6 +281,21 @@ export interface Incident {
   created_by: string | null;
   created_at: string;
   updated_at: string;
+  assignee_user_id: string | null;
+  acknowledged_at: string | null;
+  acknowledged_by: string | null;
+  escalation_count: number;
+  last_escalated_at: string | null;
+  hidden_from_public: boolean;
+}
+
+export interface IncidentEvent {
+  id: string;
+  incident_id: string;
+  event_type: "acknowledged" | "assigned" | "escalated";
+  actor_user_id: string | null;
+  details: Record<string, unknown>;
+  created_at: string;
 }
 
 export interface IncidentUpdate {
@@ -120,16 +305,27 @@ export interface IncidentUpdate {
   message: string;
   created_by: string | null;
   created_at: string;
+  edited_at: string | null;
+  visibility: UpdateVisibility;
 }
 
+export type UpdateVisibility = "public" | "internal";
+
 export interface IncidentWithDetails extends Incident {
   updates: IncidentUpdate[];
   affected_services: AffectedService[];
+  events: IncidentEvent[];
 }
 
 export interface AffectedService {
   service_id: string;
   service_name: string;
+  status: ServiceStatus;
+}
+
+export interface ServiceStatusChange {
+  service_id: string;
+  status: ServiceStatus;
 }
 
 export interface Monitor {
@@ -144,6 +340,8 @@ export interface Monitor {
   is_active: boolean;
   disabled_reason: DisabledReason | null;
   consecutive_failures: number;
+  recovery_threshold: number | null;
+  consecutive_successes: number;
   last_checked_at: string | null;
   last_response_time_ms: number | null;
   created_at: string;
@@ -192,6 +390,7 @@ export interface WebhookConfig {
 export interface SubscriberListItem {
   id: string;
   email: string;
+  status_page_id: string | null;
   is_verified: boolean;
   verification_sent_at: string | null;
   verified_at: string | null;
@@ -242,6 +441,7 @@ export interface UpdateOrganizationRequest {
   slug?: string;
   brand_color?: string;
   timezone?: string;
+  locale?: Locale;
   logo_url?: string | null;
   custom_domain?: string | null;
 }
@@ -312,6 +512,7 @@ export interface BillingSummary {
 
 export interface BillingEntitlements {
   max_monitors: number | null;
+  max_status_pages: number | null;
   custom_domain_enabled: boolean;
   outbound_webhooks_enabled: boolean;
   priority_support: boolean;
@@ -402,16 +603,14 @@ export interface SupportOrganizationPayload {
 
 export interface ResolvedCustomDomain {
   slug: string;
-  organization: {
-    name: string;
-    logo_url: string | null;
-    brand_color: string;
-  };
+  organization: PublicOrganization;
+  access: ViewerAccess;
 }
 
 export interface CreateServiceRequest {
   name: string;
   description?: string;
+  group_id?: string;
   group_name?: string;
   is_visible?: boolean;
 }
@@ -420,10 +619,44 @@ export interface UpdateServiceRequest {
   name?: string;
   description?: string;
   current_status?: ServiceStatus;
+  group_id?: string;
   group_name?: string;
   is_visible?: boolean;
 }
 
+export interface CreateServiceGroupRequest {
+  name: string;
+  description?: string;
+  collapsed_by_default?: boolean;
+  status_rule?: GroupStatusRule;
+}
+
+export interface UpdateServiceGroupRequest {
+  name?: string;
+  description?: string;
+  display_order?: number;
+  collapsed_by_default?: boolean;
+  status_rule?: GroupStatusRule;
+}
+
+export interface CreateStatusPageRequest {
+  name: string;
+  slug?: string;
+  logo_url?: string;
+  brand_color?: string;
+  custom_domain?: string;
+  service_ids?: string[];
+}
+
+export interface UpdateStatusPageRequest {
+  name?: string;
+  slug?: string;
+  logo_url?: string;
+  brand_color?: string;
+  custom_domain?: string;
+  service_ids?: string[];
+}
+
 export interface ReorderServicesRequest {
   service_ids: string[];
 }
@@ -434,17 +667,30 @@ export interface CreateIncidentRequest {
   impact: IncidentImpact;
   message: string;
   affected_service_ids: string[];
+  service_statuses?: ServiceStatusChange[];
+  started_at?: string;
+  suppress_notifications?: boolean;
 }
 
 export interface UpdateIncidentRequest {
   title?: string;
   status?: IncidentStatus;
   impact?: IncidentImpact;
+  service_statuses?: ServiceStatusChange[];
 }
 
 export interface CreateIncidentUpdateRequest {
   status: IncidentStatus;
   message: string;
+  service_statuses?: ServiceStatusChange[];
+  created_at?: string;
+  suppress_notifications?: boolean;
+  visibility?: UpdateVisibility;
+}
+
+export interface EditIncidentUpdateRequest {
+  message?: string;
+  created_at?: string;
 }
 
 export interface CreateMonitorRequest {
@@ -454,6 +700,7 @@ export interface CreateMonitorRequest {
   interval_seconds?: number;
   timeout_ms?: number;
   failure_threshold?: number;
+  recovery_threshold?: number;
 }
 
 export interface UpdateMonitorRequest {
@@ -461,9 +708,33 @@ export interface UpdateMonitorRequest {
   interval_seconds?: number;
   timeout_ms?: number;
   failure_threshold?: number;
+  recovery_threshold?: number;
+  inherit_recovery_threshold?: boolean;
   is_active?: boolean;
 }
 
+export interface MonitoringPolicy {
+  id: string;
+  org_id: string;
+  recovery_threshold: number;
+  flap_detection_enabled: boolean;
+  flap_window_checks: number;
+  flap_state_changes: number;
+  auto_incident_cooldown_minutes: number;
+  min_public_auto_incident_minutes: number;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface UpdateMonitoringPolicyRequest {
+  recovery_threshold?: number;
+  flap_detection_enabled?: boolean;
+  flap_window_checks?: number;
+  flap_state_changes?: number;
+  auto_incident_cooldown_minutes?: number;
+  min_public_auto_incident_minutes?: number;
+}
+
 export interface UpdateNotificationPreferencesRequest {
   email_on_incident_created?: boolean;
   email_on_incident_updated?: boolean;
@@ -517,13 +788,20 @@ export interface ApiError {
 
 // --- Public API types ---
 
+export interface PublicOrganization {
+  name: string;
+  logo_url: string | null;
+  brand_color: string;
+  default_locale: Locale;
+  timezone: string;
+}
+
 export interface PublicStatusResponse {
-  organization: {
-    name: string;
-    logo_url: string | null;
-    brand_color: string;
-  };
+  organization: PublicOrganization;
+  locale: Locale;
   overall_status: ServiceStatus;
+  overall_status_label: string;
+  groups: PublicServiceGroup[];
   services: PublicService[];
   active_incidents: PublicIncident[];
 }
@@ -536,22 +814,57 @@ export interface PublicService {
   id: string;
   name: string;
   current_status: ServiceStatus;
+  status_label: string;
+  group_id: string | null;
   group_name: string | null;
+  upstream_cause: string | null;
+}
+
+export interface PublicServiceGroup {
+  id: string;
+  name: string;
+  description: string | null;
+  collapsed_by_default: boolean;
+  status: ServiceStatus;
+  status_label: string;
 }
 
 export interface PublicIncident {
   id: string;
   title: string;
   status: IncidentStatus;
+  status_label: string;
   impact: IncidentImpact;
+  impact_label: string;
   started_at: string;
+  started_at_display: string;
   resolved_at: string | null;
-  updates: IncidentUpdate[];
-  affected_services: string[];
+  resolved_at_display: string | null;
+  updates: PublicIncidentUpdate[];
+  affected_services: PublicAffectedService[];
+}
+
+export interface PublicIncidentUpdate extends IncidentUpdate {
+  status_label: string;
+  created_at_display: string;
+}
+
+export interface PublicAffectedService {
+  name: string;
+  status: ServiceStatus;
+  status_label: string;
 }
 
 export interface UptimeResponse {
   services: ServiceUptime[];
+  groups: GroupUptime[];
+}
+
+export interface GroupUptime {
+  group_id: string;
+  group_name: string;
+  days: UptimeDay[];
+  overall_uptime: number | null;
 }
 
 export interface ServiceUptime {
@@ -565,6 +878,7 @@ export interface UptimeDay {
   date: string;
   uptime_percentage: number | null;
   avg_response_time_ms: number | null;
+  outage_minutes: number;
 }
 
 // --- Display helpers ---
@@ -608,18 +922,21 @@ export const PLAN_MONITOR_LIMITS: Record<OrganizationPlan, number | null> = {
 export const PLAN_FEATURES: Record<OrganizationPlan, BillingEntitlements> = {
   free: {
     max_monitors: 3,
+    max_status_pages: 1,
     custom_domain_enabled: false,
     outbound_webhooks_enabled: false,
     priority_support: false,
   },
   pro: {
     max_monitors: 20,
+    max_status_pages: 3,
     custom_domain_enabled: true,
     outbound_webhooks_enabled: true,
     priority_support: false,
   },
   team: {
     max_monitors: null,
+    max_status_pages: null,
     custom_domain_enabled: true,
     outbound_webhooks_enabled: true,
     priority_support: true,
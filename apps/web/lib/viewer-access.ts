@@ -0,0 +1,17 @@
+import { notFound, redirect } from "next/navigation";
+import { ApiClientError } from "@/lib/api-client";
+
+export function viewerAccessHref(slug: string): string {
+  return `/s/${slug}/access`;
+}
+
+/**
+ * Sends visitors of a restricted page to its sign-in screen; any other
+ * failure renders as a missing page.
+ */
+export function handlePublicPageError(slug: string, error: unknown): never {
+  if (error instanceof ApiClientError && error.status === 401) {
+    redirect(viewerAccessHref(slug));
+  }
+  notFound();
+}
@@ -0,0 +1,3 @@
+ALTER TABLE notification_logs
+ADD COLUMN IF NOT EXISTS body_html TEXT,
+ADD COLUMN IF NOT EXISTS list_unsubscribe_url TEXT;
@@ -0,0 +1,55 @@
+ALTER TABLE subscribers
+ADD COLUMN IF NOT EXISTS digest_frequency VARCHAR(16) NOT NULL DEFAULT 'immediate'
+    CHECK (digest_frequency IN ('immediate', 'hourly', 'daily')),
+ADD COLUMN IF NOT EXISTS timezone VARCHAR(120) NOT NULL DEFAULT 'UTC',
+ADD COLUMN IF NOT EXISTS quiet_hours_start TIME,
+ADD COLUMN IF NOT EXISTS quiet_hours_end TIME,
+ADD COLUMN IF NOT EXISTS last_digest_sent_at TIMESTAMPTZ;
+
+ALTER TABLE subscribers
+ADD CONSTRAINT subscribers_quiet_hours_pair
+    CHECK ((quiet_hours_start IS NULL) = (quiet_hours_end IS NULL));
+
+-- Subscriber notifications can now be held (status = 'held') for a digest.
+-- Held rows are folded into a single 'subscriber_digest' row and then marked
+-- 'digested' with a pointer to that row.
+ALTER TABLE notification_logs
+ADD COLUMN IF NOT EXISTS subscriber_id UUID REFERENCES subscribers(id) ON DELETE SET NULL,
+ADD COLUMN IF NOT EXISTS bypass_digest BOOLEAN NOT NULL DEFAULT FALSE,
+ADD COLUMN IF NOT EXISTS digest_id UUID REFERENCES notification_logs(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_notification_logs_held
+ON notification_logs (subscriber_id, created_at)
+WHERE status = 'held';
+
+CREATE OR REPLACE FUNCTION subscriber_in_quiet_hours(
+    tz TEXT,
+    starts TIME,
+    ends TIME,
+    at_time TIMESTAMPTZ
+)
+RETURNS BOOLEAN AS $$
+    SELECT CASE
+        WHEN starts IS NULL OR ends IS NULL OR starts = ends THEN FALSE
+        WHEN starts < ends THEN
+            (at_time AT TIME ZONE tz)::TIME >= starts AND (at_time AT TIME ZONE tz)::TIME < ends
+        ELSE
+            (at_time AT TIME ZONE tz)::TIME >= starts OR (at_time AT TIME ZONE tz)::TIME < ends
+    END;
+$$ LANGUAGE sql STABLE;
+
+-- Whether a new notification for this subscriber should be held for a digest
+-- instead of being sent right away.
+CREATE OR REPLACE FUNCTION subscriber_holds_notifications(target UUID)
+RETURNS BOOLEAN AS $$
+    SELECT COALESCE(
+        (
+            SELECT
+                s.digest_frequency <> 'immediate'
+                OR subscriber_in_quiet_hours(s.timezone, s.quiet_hours_start, s.quiet_hours_end, NOW())
+            FROM subscribers s
+            WHERE s.id = target
+        ),
+        FALSE
+    );
+$$ LANGUAGE sql STABLE;
@@ -0,0 +1,37 @@
+-- Subscribers may add a phone number for SMS alerts. The number only receives
+-- alerts once a one-time code sent to it has been confirmed.
+ALTER TABLE subscribers
+ADD COLUMN IF NOT EXISTS phone_number VARCHAR(16),
+ADD COLUMN IF NOT EXISTS phone_verified_at TIMESTAMPTZ,
+ADD COLUMN IF NOT EXISTS phone_otp_hash VARCHAR(64),
+ADD COLUMN IF NOT EXISTS phone_otp_expires_at TIMESTAMPTZ,
+ADD COLUMN IF NOT EXISTS phone_otp_attempts INT NOT NULL DEFAULT 0;
+
+CREATE INDEX IF NOT EXISTS idx_subscribers_sms_recipients
+ON subscribers (org_id)
+WHERE phone_verified_at IS NOT NULL;
+
+CREATE TABLE IF NOT EXISTS sms_logs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    subscriber_id UUID REFERENCES subscribers(id) ON DELETE SET NULL,
+    message_type VARCHAR(100) NOT NULL,
+    recipient_phone VARCHAR(16) NOT NULL,
+    body TEXT NOT NULL,
+    -- pending, sending, sent, failed, quota_exceeded, canceled
+    status VARCHAR(50) NOT NULL DEFAULT 'pending',
+    error_message TEXT,
+    provider_message_id VARCHAR(255),
+    attempt_count INT NOT NULL DEFAULT 0,
+    max_attempts INT NOT NULL DEFAULT 5,
+    next_retry_at TIMESTAMPTZ,
+    sent_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_sms_logs_org_created
+ON sms_logs (org_id, created_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_sms_logs_pending
+ON sms_logs (next_retry_at)
+WHERE status = 'pending';
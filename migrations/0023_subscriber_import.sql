@@ -0,0 +1,6 @@
+-- Track how each subscriber was added. Imported subscribers that skipped
+-- email confirmation keep the admin's consent attestation for compliance.
+ALTER TABLE subscribers
+ADD COLUMN IF NOT EXISTS source VARCHAR(32) NOT NULL DEFAULT 'self_service'
+    CHECK (source IN ('self_service', 'import')),
+ADD COLUMN IF NOT EXISTS consent_attestation TEXT;
@@ -0,0 +1,71 @@
+-- Customer-owned webhook endpoints subscribed through the public status page.
+-- Unlike webhook_configs these are created anonymously, verified with a
+-- challenge echo, and signed with a secret we generate.
+CREATE TABLE IF NOT EXISTS webhook_subscribers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    url VARCHAR(2048) NOT NULL,
+    secret VARCHAR(255) NOT NULL,
+    unsubscribe_token VARCHAR(255) NOT NULL UNIQUE,
+    verified_at TIMESTAMPTZ,
+    consecutive_failures INT NOT NULL DEFAULT 0,
+    disabled_at TIMESTAMPTZ,
+    disabled_reason VARCHAR(255),
+    last_delivered_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (org_id, url)
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_subscribers_active
+ON webhook_subscribers (org_id)
+WHERE verified_at IS NOT NULL AND disabled_at IS NULL;
+
+CREATE TABLE IF NOT EXISTS webhook_subscriber_deliveries (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    webhook_subscriber_id UUID NOT NULL REFERENCES webhook_subscribers(id) ON DELETE CASCADE,
+    event_type VARCHAR(100) NOT NULL,
+    payload JSONB NOT NULL,
+    status VARCHAR(50) NOT NULL DEFAULT 'pending', -- pending, sending, success, failed, canceled
+    response_status_code INT,
+    response_body TEXT,
+    error_message TEXT,
+    attempt_count INT NOT NULL DEFAULT 0,
+    max_attempts INT NOT NULL DEFAULT 5,
+    next_retry_at TIMESTAMPTZ,
+    delivered_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_subscriber_deliveries_subscriber
+ON webhook_subscriber_deliveries (webhook_subscriber_id, created_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_subscriber_deliveries_retry
+ON webhook_subscriber_deliveries (next_retry_at)
+WHERE status = 'pending';
+
+-- Fans an event out to every verified, active webhook subscriber of an
+-- organization. Shared by the API server and the monitor.
+CREATE OR REPLACE FUNCTION enqueue_webhook_subscriber_deliveries(
+    target_org UUID,
+    event TEXT,
+    body JSONB
+)
+RETURNS INT AS $$
+    WITH inserted AS (
+        INSERT INTO webhook_subscriber_deliveries (
+            webhook_subscriber_id,
+            event_type,
+            payload,
+            status,
+            next_retry_at
+        )
+        SELECT ws.id, event, body, 'pending', NOW()
+        FROM webhook_subscribers ws
+        WHERE ws.org_id = target_org
+          AND ws.verified_at IS NOT NULL
+          AND ws.disabled_at IS NULL
+        RETURNING 1
+    )
+    SELECT COUNT(*)::INT FROM inserted;
+$$ LANGUAGE sql VOLATILE;
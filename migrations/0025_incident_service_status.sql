@@ -0,0 +1,14 @@
+-- Each affected service carries its own status within an incident, so one
+-- incident can take the API down while only degrading the dashboard.
+ALTER TABLE incident_services
+ADD COLUMN IF NOT EXISTS service_status VARCHAR(30) NOT NULL DEFAULT 'major_outage'
+    CHECK (service_status IN ('operational', 'degraded_performance', 'partial_outage', 'major_outage', 'under_maintenance'));
+
+UPDATE incident_services isvc
+SET service_status = CASE i.impact
+    WHEN 'minor' THEN 'degraded_performance'
+    WHEN 'none' THEN 'operational'
+    ELSE 'major_outage'
+END
+FROM incidents i
+WHERE i.id = isvc.incident_id;
@@ -0,0 +1,4 @@
+-- Updates can be corrected after publishing; the previous text is kept in
+-- the audit log and the timeline shows that the entry was edited.
+ALTER TABLE incident_updates
+ADD COLUMN IF NOT EXISTS edited_at TIMESTAMPTZ;
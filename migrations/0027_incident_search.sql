@@ -0,0 +1,12 @@
+-- Full-text search over incident titles and update messages.
+ALTER TABLE incidents
+ADD COLUMN IF NOT EXISTS search_vector TSVECTOR
+    GENERATED ALWAYS AS (to_tsvector('english', coalesce(title, ''))) STORED;
+
+ALTER TABLE incident_updates
+ADD COLUMN IF NOT EXISTS search_vector TSVECTOR
+    GENERATED ALWAYS AS (to_tsvector('english', coalesce(message, ''))) STORED;
+
+CREATE INDEX IF NOT EXISTS idx_incidents_search ON incidents USING GIN (search_vector);
+CREATE INDEX IF NOT EXISTS idx_incident_updates_search ON incident_updates USING GIN (search_vector);
+CREATE INDEX IF NOT EXISTS idx_incidents_org_started ON incidents (org_id, started_at DESC, id DESC);
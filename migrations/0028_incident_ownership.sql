@@ -0,0 +1,25 @@
+-- Ownership and acknowledgement for incidents, plus an internal timeline of
+-- workflow events that never appears on the public status page.
+ALTER TABLE incidents
+ADD COLUMN IF NOT EXISTS assignee_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+ADD COLUMN IF NOT EXISTS acknowledged_at TIMESTAMPTZ,
+ADD COLUMN IF NOT EXISTS acknowledged_by UUID REFERENCES users(id) ON DELETE SET NULL,
+ADD COLUMN IF NOT EXISTS escalation_count INT NOT NULL DEFAULT 0,
+ADD COLUMN IF NOT EXISTS last_escalated_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_incidents_unacknowledged
+    ON incidents (started_at)
+    WHERE is_auto = TRUE AND status != 'resolved' AND acknowledged_at IS NULL;
+
+CREATE TABLE IF NOT EXISTS incident_events (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    incident_id UUID NOT NULL REFERENCES incidents(id) ON DELETE CASCADE,
+    event_type VARCHAR(32) NOT NULL
+        CHECK (event_type IN ('acknowledged', 'assigned', 'escalated')),
+    actor_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    details JSONB NOT NULL DEFAULT '{}'::jsonb,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_incident_events_incident
+    ON incident_events (incident_id, created_at DESC);
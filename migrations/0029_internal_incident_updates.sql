@@ -0,0 +1,5 @@
+-- Internal notes share the incident timeline with public updates but are
+-- only visible to organization members.
+ALTER TABLE incident_updates
+ADD COLUMN IF NOT EXISTS visibility VARCHAR(16) NOT NULL DEFAULT 'public'
+    CHECK (visibility IN ('public', 'internal'));
@@ -0,0 +1,30 @@
+-- Damping rules for automated monitoring. Defaults reproduce the previous
+-- behaviour: recover on the first success, no flap detection, no cool-down
+-- and every auto-incident stays in public history.
+CREATE TABLE IF NOT EXISTS monitoring_policies (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE UNIQUE,
+    recovery_threshold INT NOT NULL DEFAULT 1
+        CHECK (recovery_threshold >= 1 AND recovery_threshold <= 10),
+    flap_detection_enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    flap_window_checks INT NOT NULL DEFAULT 10
+        CHECK (flap_window_checks >= 4 AND flap_window_checks <= 50),
+    flap_state_changes INT NOT NULL DEFAULT 4
+        CHECK (flap_state_changes >= 2),
+    auto_incident_cooldown_minutes INT NOT NULL DEFAULT 0
+        CHECK (auto_incident_cooldown_minutes >= 0 AND auto_incident_cooldown_minutes <= 1440),
+    min_public_auto_incident_minutes INT NOT NULL DEFAULT 0
+        CHECK (min_public_auto_incident_minutes >= 0 AND min_public_auto_incident_minutes <= 1440),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (flap_state_changes < flap_window_checks)
+);
+
+-- NULL recovery_threshold falls back to the organization policy.
+ALTER TABLE monitors
+ADD COLUMN IF NOT EXISTS recovery_threshold INT
+    CHECK (recovery_threshold >= 1 AND recovery_threshold <= 10),
+ADD COLUMN IF NOT EXISTS consecutive_successes INT NOT NULL DEFAULT 0;
+
+ALTER TABLE incidents
+ADD COLUMN IF NOT EXISTS hidden_from_public BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,62 @@
+-- Directed dependency graph between services of the same organization.
+-- A service whose upstream (directly or transitively) is in partial or major
+-- outage is shown as degraded due to that upstream.
+CREATE TABLE IF NOT EXISTS service_dependencies (
+    service_id UUID NOT NULL REFERENCES services(id) ON DELETE CASCADE,
+    depends_on_service_id UUID NOT NULL REFERENCES services(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (service_id, depends_on_service_id),
+    CHECK (service_id != depends_on_service_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_service_dependencies_upstream
+    ON service_dependencies (depends_on_service_id);
+
+ALTER TABLE services
+ADD COLUMN IF NOT EXISTS upstream_cause_service_id UUID REFERENCES services(id) ON DELETE SET NULL;
+
+-- Recomputes the nearest upstream outage for every service in an
+-- organization. Only a service's own status counts as an outage, so the
+-- cause always points at the root of the failure rather than at an
+-- intermediate service that is merely degraded by it.
+CREATE OR REPLACE FUNCTION refresh_upstream_impact(target_org UUID)
+RETURNS INT AS $$
+DECLARE
+    changed INT;
+BEGIN
+    WITH RECURSIVE upstream (service_id, upstream_id, depth) AS (
+        SELECT d.service_id, d.depends_on_service_id, 1
+        FROM service_dependencies d
+        JOIN services s ON s.id = d.service_id
+        WHERE s.org_id = target_org
+        UNION
+        SELECT u.service_id, d.depends_on_service_id, u.depth + 1
+        FROM upstream u
+        JOIN service_dependencies d ON d.service_id = u.upstream_id
+        WHERE u.depth < 32
+    ),
+    causes AS (
+        SELECT DISTINCT ON (u.service_id) u.service_id, u.upstream_id
+        FROM upstream u
+        JOIN services up ON up.id = u.upstream_id
+        WHERE up.current_status IN ('partial_outage', 'major_outage')
+          AND u.upstream_id != u.service_id
+        ORDER BY u.service_id, u.depth, up.display_order, up.id
+    ),
+    computed AS (
+        SELECT s.id, c.upstream_id
+        FROM services s
+        LEFT JOIN causes c ON c.service_id = s.id
+        WHERE s.org_id = target_org
+    )
+    UPDATE services s
+    SET upstream_cause_service_id = computed.upstream_id,
+        updated_at = NOW()
+    FROM computed
+    WHERE s.id = computed.id
+      AND s.upstream_cause_service_id IS DISTINCT FROM computed.upstream_id;
+
+    GET DIAGNOSTICS changed = ROW_COUNT;
+    RETURN changed;
+END;
+$$ LANGUAGE plpgsql;
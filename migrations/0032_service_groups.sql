@@ -0,0 +1,71 @@
+-- Service groups become first-class entities. `services.group_name` is kept
+-- in sync with the group's name for clients that still read it.
+CREATE TABLE IF NOT EXISTS service_groups (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    display_order INT NOT NULL DEFAULT 0,
+    collapsed_by_default BOOLEAN NOT NULL DEFAULT FALSE,
+    status_rule VARCHAR(20) NOT NULL DEFAULT 'worst_of'
+        CHECK (status_rule IN ('worst_of', 'majority')),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (org_id, name)
+);
+
+CREATE INDEX IF NOT EXISTS idx_service_groups_org_order
+    ON service_groups (org_id, display_order);
+
+ALTER TABLE services
+ADD COLUMN IF NOT EXISTS group_id UUID REFERENCES service_groups(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_services_group ON services (group_id) WHERE group_id IS NOT NULL;
+
+-- Promote existing free-text group names, ordered by their first service.
+INSERT INTO service_groups (org_id, name, display_order)
+SELECT org_id, name, (ROW_NUMBER() OVER (PARTITION BY org_id ORDER BY first_order, name) - 1)::INT
+FROM (
+    SELECT org_id, TRIM(group_name) AS name, MIN(display_order) AS first_order
+    FROM services
+    WHERE group_name IS NOT NULL AND TRIM(group_name) != ''
+    GROUP BY org_id, TRIM(group_name)
+) existing
+ON CONFLICT (org_id, name) DO NOTHING;
+
+UPDATE services s
+SET group_id = g.id, group_name = g.name
+FROM service_groups g
+WHERE g.org_id = s.org_id
+  AND s.group_id IS NULL
+  AND s.group_name IS NOT NULL
+  AND g.name = TRIM(s.group_name);
+
+-- Subscribers with no rows here receive notifications for every service.
+CREATE TABLE IF NOT EXISTS subscriber_service_groups (
+    subscriber_id UUID NOT NULL REFERENCES subscribers(id) ON DELETE CASCADE,
+    group_id UUID NOT NULL REFERENCES service_groups(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (subscriber_id, group_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_subscriber_service_groups_group
+    ON subscriber_service_groups (group_id);
+
+-- Whether a subscriber should hear about a change touching these services.
+-- Org-wide notices (no services) always go out.
+CREATE OR REPLACE FUNCTION subscriber_wants_services(target_subscriber UUID, service_ids UUID[])
+RETURNS BOOLEAN AS $$
+    SELECT COALESCE(cardinality(service_ids), 0) = 0
+        OR NOT EXISTS (
+            SELECT 1 FROM subscriber_service_groups
+            WHERE subscriber_id = target_subscriber
+        )
+        OR EXISTS (
+            SELECT 1
+            FROM subscriber_service_groups ssg
+            JOIN services s ON s.group_id = ssg.group_id
+            WHERE ssg.subscriber_id = target_subscriber
+              AND s.id = ANY(service_ids)
+        );
+$$ LANGUAGE sql STABLE;
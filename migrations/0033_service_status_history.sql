@@ -0,0 +1,54 @@
+-- Every change to a service's own `current_status` becomes a closed interval;
+-- the current status is the single open interval (ended_at IS NULL).
+CREATE TABLE IF NOT EXISTS service_status_intervals (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    service_id UUID NOT NULL REFERENCES services(id) ON DELETE CASCADE,
+    status VARCHAR(30) NOT NULL,
+    source VARCHAR(20) NOT NULL CHECK (source IN ('manual', 'incident', 'monitor')),
+    started_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    ended_at TIMESTAMPTZ,
+    CHECK (ended_at IS NULL OR ended_at >= started_at)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_service_status_intervals_open
+    ON service_status_intervals (service_id) WHERE ended_at IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_service_status_intervals_service_started
+    ON service_status_intervals (service_id, started_at DESC);
+
+-- History starts now; earlier days fall back to monitor data alone.
+INSERT INTO service_status_intervals (service_id, status, source, started_at)
+SELECT id, current_status, 'manual', NOW()
+FROM services
+ON CONFLICT DO NOTHING;
+
+-- Closes the open interval of every service in the organization whose
+-- status no longer matches, and opens one for the new status. Safe to call
+-- after any write that may have touched `current_status`.
+CREATE OR REPLACE FUNCTION record_service_status_intervals(target_org UUID, change_source VARCHAR)
+RETURNS INT AS $$
+DECLARE
+    opened INT;
+BEGIN
+    UPDATE service_status_intervals ssi
+    SET ended_at = NOW()
+    FROM services s
+    WHERE s.id = ssi.service_id
+      AND s.org_id = target_org
+      AND ssi.ended_at IS NULL
+      AND ssi.status != s.current_status;
+
+    INSERT INTO service_status_intervals (service_id, status, source, started_at)
+    SELECT s.id, s.current_status, change_source, NOW()
+    FROM services s
+    WHERE s.org_id = target_org
+      AND NOT EXISTS (
+          SELECT 1 FROM service_status_intervals open_interval
+          WHERE open_interval.service_id = s.id AND open_interval.ended_at IS NULL
+      )
+    ON CONFLICT (service_id) WHERE ended_at IS NULL DO NOTHING;
+
+    GET DIAGNOSTICS opened = ROW_COUNT;
+    RETURN opened;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,25 @@
+-- Audience restrictions for public status pages. `viewer_session_secret`
+-- signs viewer sessions; rotating it signs everyone out.
+ALTER TABLE organizations
+    ADD COLUMN IF NOT EXISTS page_visibility VARCHAR(20) NOT NULL DEFAULT 'public'
+        CHECK (page_visibility IN ('public', 'password', 'email_domain', 'ip_allowlist')),
+    ADD COLUMN IF NOT EXISTS page_password_hash TEXT,
+    ADD COLUMN IF NOT EXISTS page_allowed_email_domains TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN IF NOT EXISTS page_allowed_cidrs TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN IF NOT EXISTS viewer_session_secret TEXT NOT NULL
+        DEFAULT replace(gen_random_uuid()::text || gen_random_uuid()::text, '-', '');
+
+-- One-time magic links for email-domain restricted pages. Only a hash of the
+-- token is stored.
+CREATE TABLE IF NOT EXISTS viewer_login_links (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    email VARCHAR(255) NOT NULL,
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    consumed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_viewer_login_links_org_created
+    ON viewer_login_links (org_id, created_at DESC);
@@ -0,0 +1,211 @@
+-- Additional, audience-specific status pages (for example an internal
+-- engineering page or a partner page). The organization itself remains the
+-- primary page; these pages carry their own slug, branding, custom domain,
+-- service selection, subscribers and access settings on top of the same
+-- services and incidents. Slugs share one namespace with organization slugs.
+CREATE TABLE IF NOT EXISTS status_pages (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    slug VARCHAR(100) NOT NULL UNIQUE,
+    name VARCHAR(255) NOT NULL,
+    -- NULL branding falls back to the organization's.
+    logo_url TEXT,
+    brand_color VARCHAR(7),
+    custom_domain VARCHAR(255),
+    custom_domain_status VARCHAR(32) NOT NULL DEFAULT 'not_configured'
+        CHECK (custom_domain_status IN ('not_configured', 'pending_verification', 'verified', 'blocked_by_plan')),
+    custom_domain_verified_at TIMESTAMPTZ,
+    page_visibility VARCHAR(20) NOT NULL DEFAULT 'public'
+        CHECK (page_visibility IN ('public', 'password', 'email_domain', 'ip_allowlist')),
+    page_password_hash TEXT,
+    page_allowed_email_domains TEXT[] NOT NULL DEFAULT '{}',
+    page_allowed_cidrs TEXT[] NOT NULL DEFAULT '{}',
+    viewer_session_secret TEXT NOT NULL
+        DEFAULT replace(gen_random_uuid()::text || gen_random_uuid()::text, '-', ''),
+    -- 'plan_limit' while a downgrade leaves the page over the plan's limit.
+    disabled_reason VARCHAR(32),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_status_pages_org ON status_pages (org_id, created_at);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_status_pages_custom_domain
+    ON status_pages (lower(custom_domain))
+    WHERE custom_domain IS NOT NULL;
+
+-- The services a page lists. Pages with no rows list every visible service,
+-- like the primary page; pages with rows list exactly those services, hidden
+-- ones included.
+CREATE TABLE IF NOT EXISTS status_page_services (
+    status_page_id UUID NOT NULL REFERENCES status_pages(id) ON DELETE CASCADE,
+    service_id UUID NOT NULL REFERENCES services(id) ON DELETE CASCADE,
+    PRIMARY KEY (status_page_id, service_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_status_page_services_service
+    ON status_page_services (service_id);
+
+-- Subscribers and sign-in links belong to one page. NULL is the primary page.
+ALTER TABLE subscribers
+    ADD COLUMN IF NOT EXISTS status_page_id UUID REFERENCES status_pages(id) ON DELETE CASCADE;
+ALTER TABLE subscribers DROP CONSTRAINT IF EXISTS subscribers_org_id_email_key;
+ALTER TABLE subscribers
+    ADD CONSTRAINT subscribers_org_page_email_key UNIQUE NULLS NOT DISTINCT (org_id, status_page_id, email);
+
+ALTER TABLE webhook_subscribers
+    ADD COLUMN IF NOT EXISTS status_page_id UUID REFERENCES status_pages(id) ON DELETE CASCADE;
+ALTER TABLE webhook_subscribers DROP CONSTRAINT IF EXISTS webhook_subscribers_org_id_url_key;
+ALTER TABLE webhook_subscribers
+    ADD CONSTRAINT webhook_subscribers_org_page_url_key UNIQUE NULLS NOT DISTINCT (org_id, status_page_id, url);
+
+ALTER TABLE viewer_login_links
+    ADD COLUMN IF NOT EXISTS status_page_id UUID REFERENCES status_pages(id) ON DELETE CASCADE;
+
+-- Every page that is currently reachable, primary pages included, with
+-- branding resolved and the custom domain only once it is verified.
+CREATE OR REPLACE VIEW public_status_pages AS
+SELECT
+    o.id AS org_id,
+    NULL::UUID AS page_id,
+    o.slug,
+    o.name,
+    o.logo_url,
+    o.brand_color,
+    CASE WHEN o.custom_domain_status = 'verified' THEN o.custom_domain END AS verified_custom_domain,
+    o.page_visibility,
+    o.page_password_hash,
+    o.page_allowed_email_domains,
+    o.page_allowed_cidrs,
+    o.viewer_session_secret
+FROM organizations o
+UNION ALL
+SELECT
+    sp.org_id,
+    sp.id AS page_id,
+    sp.slug,
+    sp.name,
+    COALESCE(sp.logo_url, o.logo_url),
+    COALESCE(sp.brand_color, o.brand_color),
+    CASE WHEN sp.custom_domain_status = 'verified' THEN sp.custom_domain END,
+    sp.page_visibility,
+    sp.page_password_hash,
+    sp.page_allowed_email_domains,
+    sp.page_allowed_cidrs,
+    sp.viewer_session_secret
+FROM status_pages sp
+JOIN organizations o ON o.id = sp.org_id
+WHERE sp.disabled_reason IS NULL;
+
+-- The services shown on a page. `target_page` NULL is the primary page.
+CREATE OR REPLACE FUNCTION status_page_service_ids(target_org UUID, target_page UUID)
+RETURNS SETOF UUID AS $$
+    SELECT s.id
+    FROM services s
+    WHERE s.org_id = target_org
+      AND CASE
+            WHEN EXISTS (
+                SELECT 1 FROM status_page_services WHERE status_page_id = target_page
+            ) THEN s.id IN (
+                SELECT service_id FROM status_page_services WHERE status_page_id = target_page
+            )
+            ELSE s.is_visible
+          END;
+$$ LANGUAGE sql STABLE;
+
+-- Whether news about these services belongs on a page. Org-wide notices (no
+-- services), the primary page and pages without a selection cover everything.
+CREATE OR REPLACE FUNCTION status_page_covers_services(target_page UUID, service_ids UUID[])
+RETURNS BOOLEAN AS $$
+    SELECT target_page IS NULL
+        OR COALESCE(cardinality(service_ids), 0) = 0
+        OR NOT EXISTS (
+            SELECT 1 FROM status_page_services WHERE status_page_id = target_page
+        )
+        OR EXISTS (
+            SELECT 1 FROM status_page_services
+            WHERE status_page_id = target_page
+              AND service_id = ANY(service_ids)
+        );
+$$ LANGUAGE sql STABLE;
+
+CREATE OR REPLACE FUNCTION status_page_covers_incident(target_page UUID, target_incident UUID)
+RETURNS BOOLEAN AS $$
+    SELECT status_page_covers_services(
+        target_page,
+        ARRAY(SELECT service_id FROM incident_services WHERE incident_id = target_incident)
+    );
+$$ LANGUAGE sql STABLE;
+
+-- Subscribers now also need their page to be live and to cover the services.
+CREATE OR REPLACE FUNCTION subscriber_wants_services(target_subscriber UUID, service_ids UUID[])
+RETURNS BOOLEAN AS $$
+    SELECT (
+            COALESCE(cardinality(service_ids), 0) = 0
+            OR NOT EXISTS (
+                SELECT 1 FROM subscriber_service_groups
+                WHERE subscriber_id = target_subscriber
+            )
+            OR EXISTS (
+                SELECT 1
+                FROM subscriber_service_groups ssg
+                JOIN services s ON s.group_id = ssg.group_id
+                WHERE ssg.subscriber_id = target_subscriber
+                  AND s.id = ANY(service_ids)
+            )
+        )
+        AND EXISTS (
+            SELECT 1
+            FROM subscribers sub
+            JOIN public_status_pages p
+              ON p.org_id = sub.org_id
+             AND p.page_id IS NOT DISTINCT FROM sub.status_page_id
+            WHERE sub.id = target_subscriber
+              AND status_page_covers_services(sub.status_page_id, service_ids)
+        );
+$$ LANGUAGE sql STABLE;
+
+-- Webhook subscribers of an additional page only receive events about the
+-- services on that page. The event's services come from the payload's
+-- `service_id`, or from the incident named by `incident_id`.
+CREATE OR REPLACE FUNCTION enqueue_webhook_subscriber_deliveries(
+    target_org UUID,
+    event TEXT,
+    body JSONB
+)
+RETURNS INT AS $$
+    WITH event_services AS (
+        SELECT CASE
+            WHEN body->'data' ? 'service_id'
+                THEN ARRAY[(body->'data'->>'service_id')::UUID]
+            WHEN body->'data' ? 'incident_id'
+                THEN ARRAY(
+                    SELECT service_id FROM incident_services
+                    WHERE incident_id = (body->'data'->>'incident_id')::UUID
+                )
+            ELSE ARRAY[]::UUID[]
+        END AS ids
+    ),
+    inserted AS (
+        INSERT INTO webhook_subscriber_deliveries (
+            webhook_subscriber_id,
+            event_type,
+            payload,
+            status,
+            next_retry_at
+        )
+        SELECT ws.id, event, body, 'pending', NOW()
+        FROM webhook_subscribers ws
+        CROSS JOIN event_services es
+        WHERE ws.org_id = target_org
+          AND ws.verified_at IS NOT NULL
+          AND ws.disabled_at IS NULL
+          AND EXISTS (
+              SELECT 1 FROM public_status_pages p
+              WHERE p.org_id = ws.org_id
+                AND p.page_id IS NOT DISTINCT FROM ws.status_page_id
+          )
+          AND status_page_covers_services(ws.status_page_id, es.ids)
+        RETURNING 1
+    )
+    SELECT COUNT(*)::INT FROM inserted;
+$$ LANGUAGE sql VOLATILE;
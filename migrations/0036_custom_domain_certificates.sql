@@ -0,0 +1,118 @@
+-- TLS certificates for verified custom domains, issued and renewed through
+-- ACME with HTTP-01 challenges. Private keys are stored encrypted with
+-- CERTIFICATE_ENCRYPTION_KEY; the edge proxy reads the decrypted material
+-- from the API.
+
+-- DNS-verified domains whose certificate could not be issued, or whose
+-- certificate lapsed because renewals kept failing.
+ALTER TABLE organizations DROP CONSTRAINT IF EXISTS organizations_custom_domain_status_check;
+ALTER TABLE organizations
+    ADD CONSTRAINT organizations_custom_domain_status_check CHECK (custom_domain_status IN (
+        'not_configured', 'pending_verification', 'verified', 'blocked_by_plan',
+        'certificate_failed', 'certificate_expired'
+    ));
+
+ALTER TABLE status_pages DROP CONSTRAINT IF EXISTS status_pages_custom_domain_status_check;
+ALTER TABLE status_pages
+    ADD CONSTRAINT status_pages_custom_domain_status_check CHECK (custom_domain_status IN (
+        'not_configured', 'pending_verification', 'verified', 'blocked_by_plan',
+        'certificate_failed', 'certificate_expired'
+    ));
+
+-- One ACME account per directory, so switching CAs (or pointing a test
+-- environment at Pebble) registers a fresh account.
+CREATE TABLE IF NOT EXISTS acme_accounts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    directory_url TEXT NOT NULL UNIQUE,
+    account_url TEXT NOT NULL,
+    -- AES-256-GCM nonce followed by the ciphertext of the P-256 account key.
+    encrypted_key BYTEA NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS custom_domain_certificates (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    domain VARCHAR(255) NOT NULL UNIQUE,
+    -- PEM chain, leaf first. NULL until the first issuance succeeds.
+    certificate_pem TEXT,
+    encrypted_private_key BYTEA,
+    not_before TIMESTAMPTZ,
+    not_after TIMESTAMPTZ,
+    issued_at TIMESTAMPTZ,
+    renew_after TIMESTAMPTZ,
+    -- Due for an issuance attempt when this passes; pushed forward while an
+    -- attempt is running and backed off after failures.
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_attempt_at TIMESTAMPTZ,
+    consecutive_failures INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_custom_domain_certificates_next_attempt
+    ON custom_domain_certificates(next_attempt_at);
+
+-- Key authorizations served from /.well-known/acme-challenge/{token} while
+-- an order is being validated.
+CREATE TABLE IF NOT EXISTS acme_http_challenges (
+    token VARCHAR(255) PRIMARY KEY,
+    domain VARCHAR(255) NOT NULL,
+    key_authorization TEXT NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Every custom domain that has passed DNS verification and should hold a
+-- certificate, whichever kind of page it belongs to.
+CREATE OR REPLACE VIEW verified_custom_domains AS
+SELECT lower(o.custom_domain) AS domain, o.id AS org_id, NULL::UUID AS page_id
+FROM organizations o
+WHERE o.custom_domain IS NOT NULL
+  AND o.custom_domain_status IN ('verified', 'certificate_failed', 'certificate_expired')
+UNION ALL
+SELECT lower(sp.custom_domain), sp.org_id, sp.id
+FROM status_pages sp
+WHERE sp.custom_domain IS NOT NULL
+  AND sp.disabled_reason IS NULL
+  AND sp.custom_domain_status IN ('verified', 'certificate_failed', 'certificate_expired');
+
+-- Certificate trouble does not change where a verified domain routes.
+CREATE OR REPLACE VIEW public_status_pages AS
+SELECT
+    o.id AS org_id,
+    NULL::UUID AS page_id,
+    o.slug,
+    o.name,
+    o.logo_url,
+    o.brand_color,
+    CASE
+        WHEN o.custom_domain_status IN ('verified', 'certificate_failed', 'certificate_expired')
+            THEN o.custom_domain
+    END AS verified_custom_domain,
+    o.page_visibility,
+    o.page_password_hash,
+    o.page_allowed_email_domains,
+    o.page_allowed_cidrs,
+    o.viewer_session_secret
+FROM organizations o
+UNION ALL
+SELECT
+    sp.org_id,
+    sp.id AS page_id,
+    sp.slug,
+    sp.name,
+    COALESCE(sp.logo_url, o.logo_url),
+    COALESCE(sp.brand_color, o.brand_color),
+    CASE
+        WHEN sp.custom_domain_status IN ('verified', 'certificate_failed', 'certificate_expired')
+            THEN sp.custom_domain
+    END,
+    sp.page_visibility,
+    sp.page_password_hash,
+    sp.page_allowed_email_domains,
+    sp.page_allowed_cidrs,
+    sp.viewer_session_secret
+FROM status_pages sp
+JOIN organizations o ON o.id = sp.org_id
+WHERE sp.disabled_reason IS NULL;
@@ -0,0 +1,105 @@
+-- Verified custom domains are re-resolved in the background so DNS drift is
+-- noticed. A domain that keeps failing moves to 'misconfigured' and keeps
+-- routing until its grace period ends; a passing check restores it.
+ALTER TABLE organizations DROP CONSTRAINT IF EXISTS organizations_custom_domain_status_check;
+ALTER TABLE organizations
+    ADD CONSTRAINT organizations_custom_domain_status_check CHECK (custom_domain_status IN (
+        'not_configured', 'pending_verification', 'verified', 'blocked_by_plan',
+        'certificate_failed', 'certificate_expired', 'misconfigured'
+    ));
+
+ALTER TABLE status_pages DROP CONSTRAINT IF EXISTS status_pages_custom_domain_status_check;
+ALTER TABLE status_pages
+    ADD CONSTRAINT status_pages_custom_domain_status_check CHECK (custom_domain_status IN (
+        'not_configured', 'pending_verification', 'verified', 'blocked_by_plan',
+        'certificate_failed', 'certificate_expired', 'misconfigured'
+    ));
+
+-- The token is published as a TXT record on
+-- _statuspage-verification.<domain> for domains behind proxies that neither
+-- CNAME to nor resolve to the managed target.
+ALTER TABLE organizations
+    ADD COLUMN IF NOT EXISTS custom_domain_verification_token VARCHAR(64) NOT NULL
+        DEFAULT replace(gen_random_uuid()::text, '-', ''),
+    ADD COLUMN IF NOT EXISTS custom_domain_verification_method VARCHAR(20)
+        CHECK (custom_domain_verification_method IN ('a_record', 'cname', 'txt_token')),
+    ADD COLUMN IF NOT EXISTS custom_domain_checked_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS custom_domain_check_failures INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS custom_domain_grace_ends_at TIMESTAMPTZ;
+
+ALTER TABLE status_pages
+    ADD COLUMN IF NOT EXISTS custom_domain_verification_token VARCHAR(64) NOT NULL
+        DEFAULT replace(gen_random_uuid()::text, '-', ''),
+    ADD COLUMN IF NOT EXISTS custom_domain_verification_method VARCHAR(20)
+        CHECK (custom_domain_verification_method IN ('a_record', 'cname', 'txt_token')),
+    ADD COLUMN IF NOT EXISTS custom_domain_checked_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS custom_domain_check_failures INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS custom_domain_grace_ends_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_organizations_custom_domain_checked_at
+    ON organizations(custom_domain_checked_at) WHERE custom_domain IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_status_pages_custom_domain_checked_at
+    ON status_pages(custom_domain_checked_at) WHERE custom_domain IS NOT NULL;
+
+-- Misconfigured domains keep their certificate during the grace period so
+-- a quick DNS fix does not wait on reissuance.
+CREATE OR REPLACE VIEW verified_custom_domains AS
+SELECT lower(o.custom_domain) AS domain, o.id AS org_id, NULL::UUID AS page_id
+FROM organizations o
+WHERE o.custom_domain IS NOT NULL
+  AND (
+      o.custom_domain_status IN ('verified', 'certificate_failed', 'certificate_expired')
+      OR (o.custom_domain_status = 'misconfigured' AND o.custom_domain_grace_ends_at > NOW())
+  )
+UNION ALL
+SELECT lower(sp.custom_domain), sp.org_id, sp.id
+FROM status_pages sp
+WHERE sp.custom_domain IS NOT NULL
+  AND sp.disabled_reason IS NULL
+  AND (
+      sp.custom_domain_status IN ('verified', 'certificate_failed', 'certificate_expired')
+      OR (sp.custom_domain_status = 'misconfigured' AND sp.custom_domain_grace_ends_at > NOW())
+  );
+
+CREATE OR REPLACE VIEW public_status_pages AS
+SELECT
+    o.id AS org_id,
+    NULL::UUID AS page_id,
+    o.slug,
+    o.name,
+    o.logo_url,
+    o.brand_color,
+    CASE
+        WHEN o.custom_domain_status IN ('verified', 'certificate_failed', 'certificate_expired')
+            THEN o.custom_domain
+        WHEN o.custom_domain_status = 'misconfigured' AND o.custom_domain_grace_ends_at > NOW()
+            THEN o.custom_domain
+    END AS verified_custom_domain,
+    o.page_visibility,
+    o.page_password_hash,
+    o.page_allowed_email_domains,
+    o.page_allowed_cidrs,
+    o.viewer_session_secret
+FROM organizations o
+UNION ALL
+SELECT
+    sp.org_id,
+    sp.id AS page_id,
+    sp.slug,
+    sp.name,
+    COALESCE(sp.logo_url, o.logo_url),
+    COALESCE(sp.brand_color, o.brand_color),
+    CASE
+        WHEN sp.custom_domain_status IN ('verified', 'certificate_failed', 'certificate_expired')
+            THEN sp.custom_domain
+        WHEN sp.custom_domain_status = 'misconfigured' AND sp.custom_domain_grace_ends_at > NOW()
+            THEN sp.custom_domain
+    END,
+    sp.page_visibility,
+    sp.page_password_hash,
+    sp.page_allowed_email_domains,
+    sp.page_allowed_cidrs,
+    sp.viewer_session_secret
+FROM status_pages sp
+JOIN organizations o ON o.id = sp.org_id
+WHERE sp.disabled_reason IS NULL;
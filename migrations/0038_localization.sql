@@ -0,0 +1,57 @@
+-- Emails and public responses are rendered in the organization's default
+-- locale unless a subscriber (or a public API caller) asks for another one.
+ALTER TABLE organizations
+    ADD COLUMN IF NOT EXISTS locale VARCHAR(10) NOT NULL DEFAULT 'en'
+        CHECK (locale IN ('en', 'de', 'es', 'fr'));
+
+-- NULL follows the organization default.
+ALTER TABLE subscribers
+    ADD COLUMN IF NOT EXISTS locale VARCHAR(10)
+        CHECK (locale IN ('en', 'de', 'es', 'fr'));
+
+CREATE OR REPLACE VIEW public_status_pages AS
+SELECT
+    o.id AS org_id,
+    NULL::UUID AS page_id,
+    o.slug,
+    o.name,
+    o.logo_url,
+    o.brand_color,
+    CASE
+        WHEN o.custom_domain_status IN ('verified', 'certificate_failed', 'certificate_expired')
+            THEN o.custom_domain
+        WHEN o.custom_domain_status = 'misconfigured' AND o.custom_domain_grace_ends_at > NOW()
+            THEN o.custom_domain
+    END AS verified_custom_domain,
+    o.page_visibility,
+    o.page_password_hash,
+    o.page_allowed_email_domains,
+    o.page_allowed_cidrs,
+    o.viewer_session_secret,
+    o.locale,
+    o.timezone
+FROM organizations o
+UNION ALL
+SELECT
+    sp.org_id,
+    sp.id AS page_id,
+    sp.slug,
+    sp.name,
+    COALESCE(sp.logo_url, o.logo_url),
+    COALESCE(sp.brand_color, o.brand_color),
+    CASE
+        WHEN sp.custom_domain_status IN ('verified', 'certificate_failed', 'certificate_expired')
+            THEN sp.custom_domain
+        WHEN sp.custom_domain_status = 'misconfigured' AND sp.custom_domain_grace_ends_at > NOW()
+            THEN sp.custom_domain
+    END,
+    sp.page_visibility,
+    sp.page_password_hash,
+    sp.page_allowed_email_domains,
+    sp.page_allowed_cidrs,
+    sp.viewer_session_secret,
+    o.locale,
+    o.timezone
+FROM status_pages sp
+JOIN organizations o ON o.id = sp.org_id
+WHERE sp.disabled_reason IS NULL;
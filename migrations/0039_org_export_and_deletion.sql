@@ -0,0 +1,53 @@
+-- Owner-requested data exports. The archive is built in the background and
+-- kept until expires_at, after which only the row remains.
+CREATE TABLE IF NOT EXISTS organization_exports (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    requested_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'running', 'completed', 'failed', 'expired')),
+    archive BYTEA,
+    archive_size_bytes BIGINT,
+    error TEXT,
+    started_at TIMESTAMPTZ,
+    completed_at TIMESTAMPTZ,
+    expires_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_organization_exports_org
+    ON organization_exports(org_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_organization_exports_queue
+    ON organization_exports(created_at) WHERE status IN ('pending', 'running');
+
+-- Scheduled organization deletions. Rows deliberately have no foreign key to
+-- organizations: they outlive the purge as the operator-visible record of
+-- who asked for it, what Stripe said and what was removed.
+CREATE TABLE IF NOT EXISTS organization_deletions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    org_id UUID NOT NULL,
+    org_slug VARCHAR(255) NOT NULL,
+    org_name VARCHAR(255) NOT NULL,
+    requested_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    requested_by_email VARCHAR(255),
+    reason TEXT,
+    status VARCHAR(20) NOT NULL DEFAULT 'scheduled'
+        CHECK (status IN ('scheduled', 'processing', 'canceled', 'completed')),
+    scheduled_for TIMESTAMPTZ NOT NULL,
+    canceled_at TIMESTAMPTZ,
+    canceled_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    stripe_subscription_id VARCHAR(255),
+    stripe_cancellation VARCHAR(20)
+        CHECK (stripe_cancellation IN ('not_subscribed', 'canceled', 'already_canceled')),
+    purge_summary JSONB NOT NULL DEFAULT '{}'::jsonb,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    completed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_organization_deletions_active
+    ON organization_deletions(org_id) WHERE status IN ('scheduled', 'processing');
+CREATE INDEX IF NOT EXISTS idx_organization_deletions_due
+    ON organization_deletions(scheduled_for) WHERE status IN ('scheduled', 'processing');
@@ -0,0 +1,12 @@
+-- Maps records brought over from another status page provider to the rows
+-- created for them, so re-running an import skips what already exists.
+CREATE TABLE IF NOT EXISTS import_records (
+    org_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    provider VARCHAR(50) NOT NULL,
+    kind VARCHAR(30) NOT NULL
+        CHECK (kind IN ('service_group', 'service', 'incident', 'incident_update')),
+    external_id VARCHAR(255) NOT NULL,
+    local_id UUID NOT NULL,
+    imported_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (org_id, provider, kind, external_id)
+);
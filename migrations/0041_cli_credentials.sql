@@ -0,0 +1,33 @@
+-- Device-style login for the command-line client: the CLI starts a request,
+-- a signed-in user approves its short code in the browser, and the CLI
+-- exchanges its device code for a long-lived credential.
+CREATE TABLE IF NOT EXISTS cli_login_requests (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    device_code_hash VARCHAR(64) NOT NULL UNIQUE,
+    user_code VARCHAR(9) NOT NULL UNIQUE,
+    client_name VARCHAR(100) NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'approved', 'denied')),
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    decided_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_cli_login_requests_expires
+    ON cli_login_requests (expires_at);
+
+-- Bearer credentials held by the CLI. Only a hash of the token is stored.
+CREATE TABLE IF NOT EXISTS cli_credentials (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    last_used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_cli_credentials_user
+    ON cli_credentials (user_id, created_at DESC);
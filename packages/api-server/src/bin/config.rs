@@ -0,0 +1,167 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use serde::Deserialize;
+
+use shared::models::config_sync::{ConfigAction, ConfigPlan, ConfigSpec};
+
+const USAGE: &str = "usage: config <plan|apply> <org-slug> <spec.yaml|spec.json> [--prune] [--json]
+
+Diffs the spec against the organization through the API and, for apply,
+makes the changes in one transaction. `${NAME}` in the file is replaced with
+the NAME environment variable, so webhook secrets can stay out of the repo.
+
+Environment:
+  STATUSPAGE_API_URL        API base URL (default http://localhost:4000)
+  STATUSPAGE_TOKEN          CLI credential of an organization admin (see `statuspage login`)
+  STATUSPAGE_SESSION_TOKEN  Session token of an organization admin, if no CLI credential";
+
+#[derive(Deserialize)]
+struct DataResponse<T> {
+    data: T,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    error: ErrorBody,
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    message: String,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    dotenvy::dotenv().ok();
+
+    let mut positional = Vec::new();
+    let mut prune = false;
+    let mut json = false;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--prune" => prune = true,
+            "--json" => json = true,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            flag if flag.starts_with("--") => bail!("unknown option {flag}\n\n{USAGE}"),
+            _ => positional.push(arg),
+        }
+    }
+    let [command, slug, path] = <[String; 3]>::try_from(positional).map_err(|_| {
+        anyhow::anyhow!("expected a command, an organization slug and a file\n\n{USAGE}")
+    })?;
+    if command != "plan" && command != "apply" {
+        bail!("unknown command {command}\n\n{USAGE}");
+    }
+
+    let path = PathBuf::from(path);
+    let raw =
+        std::fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+    let spec = parse_spec(&path, &expand_env(&raw)?)?;
+
+    let base_url =
+        std::env::var("STATUSPAGE_API_URL").unwrap_or_else(|_| "http://localhost:4000".to_string());
+    let request = reqwest::Client::new().post(format!(
+        "{}/api/organizations/{slug}/config/{command}",
+        base_url.trim_end_matches('/')
+    ));
+    let request = match (
+        std::env::var("STATUSPAGE_TOKEN"),
+        std::env::var("STATUSPAGE_SESSION_TOKEN"),
+    ) {
+        (Ok(token), _) => request.bearer_auth(token),
+        (_, Ok(token)) => request.header("cookie", format!("authjs.session-token={token}")),
+        _ => bail!("STATUSPAGE_TOKEN or STATUSPAGE_SESSION_TOKEN must be set"),
+    };
+
+    let response = request
+        .json(&serde_json::json!({ "spec": spec, "prune": prune }))
+        .send()
+        .await?;
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        let message = serde_json::from_str::<ErrorResponse>(&body)
+            .map(|error| error.error.message)
+            .unwrap_or(body);
+        bail!("{command} failed ({status}): {message}");
+    }
+
+    let plan = serde_json::from_str::<DataResponse<ConfigPlan>>(&body)?.data;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&plan)?);
+    } else {
+        print_plan(&plan);
+    }
+
+    Ok(())
+}
+
+fn parse_spec(path: &std::path::Path, raw: &str) -> anyhow::Result<ConfigSpec> {
+    let is_json = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+    if is_json {
+        serde_json::from_str(raw).with_context(|| format!("parsing {}", path.display()))
+    } else {
+        serde_yaml::from_str(raw).with_context(|| format!("parsing {}", path.display()))
+    }
+}
+
+/// Replaces `${NAME}` with the NAME environment variable; unset variables
+/// are an error rather than an empty string.
+fn expand_env(raw: &str) -> anyhow::Result<String> {
+    let mut expanded = String::with_capacity(raw.len());
+    let mut rest = raw;
+    while let Some(start) = rest.find("${") {
+        expanded.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find('}').context("unterminated ${ in spec")?;
+        let name = &after[..end];
+        let value = std::env::var(name)
+            .with_context(|| format!("environment variable {name} is not set"))?;
+        expanded.push_str(&value);
+        rest = &after[end + 1..];
+    }
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
+fn print_plan(plan: &ConfigPlan) {
+    for change in &plan.changes {
+        let (symbol, verb) = match change.action {
+            ConfigAction::Create => ('+', "create"),
+            ConfigAction::Update => ('~', "update"),
+            ConfigAction::Delete => ('-', "delete"),
+        };
+        let resource = serde_json::to_value(change.resource)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default();
+        if change.fields.is_empty() {
+            println!("{symbol} {verb} {resource} {}", change.name);
+        } else {
+            println!(
+                "{symbol} {verb} {resource} {} ({})",
+                change.name,
+                change.fields.join(", ")
+            );
+        }
+    }
+
+    let summary = &plan.summary;
+    let outcome = if plan.applied { "Applied" } else { "Plan" };
+    println!(
+        "{outcome}: {} to create, {} to update, {} to delete.",
+        summary.create, summary.update, summary.delete
+    );
+    if summary.unmanaged > 0 {
+        println!(
+            "{} existing resources are not in the spec; pass --prune to delete them.",
+            summary.unmanaged
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use sqlx::postgres::PgPoolOptions;
+
+use api_server::services::provider_import::{self, ProviderExport, DEFAULT_PROVIDER};
+
+const USAGE: &str =
+    "usage: import <org-slug> <export.json> [--provider <name>] [--apply]\n\nWithout --apply the import is a dry run that only prints its report.";
+
+/// Imports a Statuspage-style JSON export into an existing organization and
+/// prints a JSON report of what was (or would be) created.
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    dotenvy::dotenv().ok();
+
+    let mut positional = Vec::new();
+    let mut provider = DEFAULT_PROVIDER.to_string();
+    let mut apply = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--apply" => apply = true,
+            "--provider" => provider = args.next().context("--provider needs a value")?,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            flag if flag.starts_with("--") => bail!("unknown option {flag}\n\n{USAGE}"),
+            _ => positional.push(arg),
+        }
+    }
+    let [slug, path] = <[String; 2]>::try_from(positional)
+        .map_err(|_| anyhow::anyhow!("expected an organization slug and a file\n\n{USAGE}"))?;
+    let path = PathBuf::from(path);
+
+    let raw =
+        std::fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+    let export: ProviderExport =
+        serde_json::from_str(&raw).with_context(|| format!("parsing {}", path.display()))?;
+
+    let database_url = std::env::var("DATABASE_URL").context("DATABASE_URL must be set")?;
+    let pool = PgPoolOptions::new()
+        .max_connections(2)
+        .acquire_timeout(Duration::from_secs(3))
+        .connect(&database_url)
+        .await?;
+
+    let org = api_server::db::organizations::find_by_slug(&pool, &slug)
+        .await?
+        .with_context(|| format!("organization '{slug}' not found"))?;
+
+    let report = provider_import::import(&pool, &org, &provider, export, apply).await?;
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    if !apply {
+        eprintln!("Dry run only; re-run with --apply to import.");
+    }
+
+    Ok(())
+}
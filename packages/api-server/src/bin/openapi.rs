@@ -0,0 +1,25 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+
+/// Writes the OpenAPI spec generated from the route annotations. Defaults to
+/// the checked-in `openapi/openapi.generated.json`; pass a path to write
+/// elsewhere.
+fn main() -> anyhow::Result<()> {
+    let path = std::env::args_os()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../openapi/openapi.generated.json")
+        });
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("creating {}", parent.display()))?;
+    }
+    std::fs::write(&path, api_server::routes::openapi::spec_json())
+        .with_context(|| format!("writing {}", path.display()))?;
+    println!("Wrote {}", path.display());
+
+    Ok(())
+}
@@ -13,9 +13,21 @@ pub struct Config {
     pub smtp_password: Option<String>,
     pub smtp_secure: bool,
     pub email_from: String,
+    pub email_transport: String,
+    pub email_file_drop_dir: Option<String>,
+    pub email_http_api_url: Option<String>,
+    pub email_http_api_key: Option<String>,
     pub app_base_url: String,
     pub email_dispatch_interval_secs: u64,
     pub email_dispatch_batch_size: i64,
+    pub email_digest_interval_secs: u64,
+    pub sms_http_api_url: Option<String>,
+    pub sms_http_api_key: Option<String>,
+    pub sms_from: Option<String>,
+    pub sms_dispatch_interval_secs: u64,
+    pub sms_dispatch_batch_size: i64,
+    pub incident_escalation_delay_minutes: i64,
+    pub incident_escalation_interval_secs: u64,
     pub stripe_secret_key: Option<String>,
     pub stripe_webhook_secret: Option<String>,
     pub stripe_price_pro: Option<String>,
@@ -25,7 +37,20 @@ pub struct Config {
     pub api_port: u16,
     pub api_host: String,
     pub cors_origin: String,
+    pub trusted_proxy_cidrs: Vec<String>,
     pub statuspage_host: Option<String>,
+    pub acme_directory_url: Option<String>,
+    pub acme_contact_email: Option<String>,
+    pub acme_ca_cert_path: Option<String>,
+    pub certificate_encryption_key: Option<String>,
+    pub certificate_renewal_interval_secs: u64,
+    pub certificate_renew_before_days: i64,
+    pub edge_proxy_token: Option<String>,
+    pub custom_domain_recheck_interval_secs: u64,
+    pub custom_domain_miss_threshold: i32,
+    pub custom_domain_grace_hours: i64,
+    pub org_deletion_cool_off_hours: i64,
+    pub org_export_retention_hours: i64,
     pub run_migrations_on_start: bool,
     pub run_migrations_only: bool,
     pub log_level: String,
@@ -68,6 +93,20 @@ impl Config {
                 .context("SMTP_SECURE must be true or false")?,
             email_from: std::env::var("EMAIL_FROM")
                 .unwrap_or_else(|_| "alerts@statuspage.local".to_string()),
+            email_transport: std::env::var("EMAIL_TRANSPORT")
+                .ok()
+                .map(|value| value.trim().to_ascii_lowercase())
+                .filter(|value| !value.is_empty())
+                .unwrap_or_else(|| "smtp".to_string()),
+            email_file_drop_dir: std::env::var("EMAIL_FILE_DROP_DIR")
+                .ok()
+                .filter(|value| !value.trim().is_empty()),
+            email_http_api_url: std::env::var("EMAIL_HTTP_API_URL")
+                .ok()
+                .filter(|value| !value.trim().is_empty()),
+            email_http_api_key: std::env::var("EMAIL_HTTP_API_KEY")
+                .ok()
+                .filter(|value| !value.trim().is_empty()),
             app_base_url: std::env::var("APP_BASE_URL")
                 .unwrap_or_else(|_| "http://localhost:3000".to_string()),
             email_dispatch_interval_secs: std::env::var("EMAIL_DISPATCH_INTERVAL_SECS")
@@ -78,6 +117,35 @@ impl Config {
                 .unwrap_or_else(|_| "20".to_string())
                 .parse()
                 .context("EMAIL_DISPATCH_BATCH_SIZE must be a number")?,
+            email_digest_interval_secs: std::env::var("EMAIL_DIGEST_INTERVAL_SECS")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .context("EMAIL_DIGEST_INTERVAL_SECS must be a number")?,
+            sms_http_api_url: std::env::var("SMS_HTTP_API_URL")
+                .ok()
+                .filter(|value| !value.trim().is_empty()),
+            sms_http_api_key: std::env::var("SMS_HTTP_API_KEY")
+                .ok()
+                .filter(|value| !value.trim().is_empty()),
+            sms_from: std::env::var("SMS_FROM")
+                .ok()
+                .filter(|value| !value.trim().is_empty()),
+            sms_dispatch_interval_secs: std::env::var("SMS_DISPATCH_INTERVAL_SECS")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()
+                .context("SMS_DISPATCH_INTERVAL_SECS must be a number")?,
+            sms_dispatch_batch_size: std::env::var("SMS_DISPATCH_BATCH_SIZE")
+                .unwrap_or_else(|_| "20".to_string())
+                .parse()
+                .context("SMS_DISPATCH_BATCH_SIZE must be a number")?,
+            incident_escalation_delay_minutes: std::env::var("INCIDENT_ESCALATION_DELAY_MINUTES")
+                .unwrap_or_else(|_| "15".to_string())
+                .parse()
+                .context("INCIDENT_ESCALATION_DELAY_MINUTES must be a number")?,
+            incident_escalation_interval_secs: std::env::var("INCIDENT_ESCALATION_INTERVAL_SECS")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .context("INCIDENT_ESCALATION_INTERVAL_SECS must be a number")?,
             stripe_secret_key: std::env::var("STRIPE_SECRET_KEY")
                 .ok()
                 .filter(|value| !value.trim().is_empty()),
@@ -106,9 +174,61 @@ impl Config {
             api_host: std::env::var("API_HOST").unwrap_or_else(|_| "0.0.0.0".to_string()),
             cors_origin: std::env::var("CORS_ORIGIN")
                 .unwrap_or_else(|_| "http://localhost:3000".to_string()),
+            trusted_proxy_cidrs: std::env::var("TRUSTED_PROXY_CIDRS")
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+                .collect(),
             statuspage_host: std::env::var("STATUSPAGE_HOST")
                 .ok()
                 .filter(|value| !value.trim().is_empty()),
+            acme_directory_url: std::env::var("ACME_DIRECTORY_URL")
+                .ok()
+                .filter(|value| !value.trim().is_empty()),
+            acme_contact_email: std::env::var("ACME_CONTACT_EMAIL")
+                .ok()
+                .filter(|value| !value.trim().is_empty()),
+            acme_ca_cert_path: std::env::var("ACME_CA_CERT_PATH")
+                .ok()
+                .filter(|value| !value.trim().is_empty()),
+            certificate_encryption_key: std::env::var("CERTIFICATE_ENCRYPTION_KEY")
+                .ok()
+                .filter(|value| !value.trim().is_empty()),
+            certificate_renewal_interval_secs: std::env::var("CERTIFICATE_RENEWAL_INTERVAL_SECS")
+                .unwrap_or_else(|_| "300".to_string())
+                .parse()
+                .context("CERTIFICATE_RENEWAL_INTERVAL_SECS must be a number")?,
+            certificate_renew_before_days: std::env::var("CERTIFICATE_RENEW_BEFORE_DAYS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .context("CERTIFICATE_RENEW_BEFORE_DAYS must be a number")?,
+            edge_proxy_token: std::env::var("EDGE_PROXY_TOKEN")
+                .ok()
+                .filter(|value| !value.trim().is_empty()),
+            custom_domain_recheck_interval_secs: std::env::var(
+                "CUSTOM_DOMAIN_RECHECK_INTERVAL_SECS",
+            )
+            .unwrap_or_else(|_| "3600".to_string())
+            .parse()
+            .context("CUSTOM_DOMAIN_RECHECK_INTERVAL_SECS must be a number")?,
+            custom_domain_miss_threshold: std::env::var("CUSTOM_DOMAIN_MISS_THRESHOLD")
+                .unwrap_or_else(|_| "3".to_string())
+                .parse()
+                .context("CUSTOM_DOMAIN_MISS_THRESHOLD must be a number")?,
+            custom_domain_grace_hours: std::env::var("CUSTOM_DOMAIN_GRACE_HOURS")
+                .unwrap_or_else(|_| "72".to_string())
+                .parse()
+                .context("CUSTOM_DOMAIN_GRACE_HOURS must be a number")?,
+            org_deletion_cool_off_hours: std::env::var("ORG_DELETION_COOL_OFF_HOURS")
+                .unwrap_or_else(|_| "168".to_string())
+                .parse()
+                .context("ORG_DELETION_COOL_OFF_HOURS must be a number")?,
+            org_export_retention_hours: std::env::var("ORG_EXPORT_RETENTION_HOURS")
+                .unwrap_or_else(|_| "168".to_string())
+                .parse()
+                .context("ORG_EXPORT_RETENTION_HOURS must be a number")?,
             run_migrations_on_start: std::env::var("RUN_MIGRATIONS_ON_START")
                 .unwrap_or_else(|_| "true".to_string())
                 .parse()
@@ -4,7 +4,7 @@ use uuid::Uuid;
 
 use shared::error::AppError;
 
-#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct AuditLogEntry {
     pub id: Uuid,
     pub org_id: Uuid,
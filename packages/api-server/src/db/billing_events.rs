@@ -3,7 +3,7 @@ use shared::error::AppError;
 use sqlx::PgPool;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, serde::Serialize, sqlx::FromRow)]
+#[derive(Debug, Clone, serde::Serialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct BillingEventEntry {
     pub stripe_event_id: String,
     pub event_type: String,
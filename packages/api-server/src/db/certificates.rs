@@ -0,0 +1,344 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use shared::error::AppError;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct DueCertificate {
+    pub id: Uuid,
+    pub domain: String,
+    pub consecutive_failures: i32,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct StoredCertificate {
+    pub domain: String,
+    pub certificate_pem: String,
+    pub encrypted_private_key: Vec<u8>,
+    pub not_after: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct AcmeAccount {
+    pub account_url: String,
+    pub encrypted_key: Vec<u8>,
+}
+
+pub struct IssuedCertificate<'a> {
+    pub certificate_pem: &'a str,
+    pub encrypted_private_key: &'a [u8],
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    pub renew_after: DateTime<Utc>,
+}
+
+/// Brings the certificate table in line with the verified domains: new
+/// domains become due immediately, released domains lose their certificate,
+/// and domains whose certificate lapsed are marked `certificate_expired`.
+pub async fn sync_domains(pool: &PgPool) -> Result<(), AppError> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO custom_domain_certificates (domain)
+        SELECT DISTINCT domain FROM verified_custom_domains
+        ON CONFLICT (domain) DO NOTHING
+        "#,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        r#"
+        DELETE FROM custom_domain_certificates c
+        WHERE NOT EXISTS (SELECT 1 FROM verified_custom_domains d WHERE d.domain = c.domain)
+        "#,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    for table in ["organizations", "status_pages"] {
+        sqlx::query(&format!(
+            r#"
+            UPDATE {table} t
+            SET custom_domain_status = 'certificate_expired', updated_at = NOW()
+            FROM custom_domain_certificates c
+            WHERE lower(t.custom_domain) = c.domain
+              AND t.custom_domain_status = 'verified'
+              AND c.not_after <= NOW()
+            "#
+        ))
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Makes a domain due right away, for example after its owner re-ran DNS
+/// verification.
+pub async fn request_issuance(pool: &PgPool, domain: &str) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        INSERT INTO custom_domain_certificates (domain)
+        VALUES (lower($1))
+        ON CONFLICT (domain) DO UPDATE SET next_attempt_at = NOW(), updated_at = NOW()
+        "#,
+    )
+    .bind(domain)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Claims domains that need a first certificate or a renewal. Claimed rows
+/// are leased for `lease_minutes` so concurrent API instances skip them.
+pub async fn claim_due(
+    pool: &PgPool,
+    limit: i64,
+    lease_minutes: i64,
+) -> Result<Vec<DueCertificate>, AppError> {
+    let due = sqlx::query_as::<_, DueCertificate>(
+        r#"
+        UPDATE custom_domain_certificates
+        SET
+            next_attempt_at = NOW() + make_interval(mins => $2::int),
+            last_attempt_at = NOW(),
+            updated_at = NOW()
+        WHERE id IN (
+            SELECT id
+            FROM custom_domain_certificates
+            WHERE next_attempt_at <= NOW()
+              AND (certificate_pem IS NULL OR renew_after <= NOW())
+            ORDER BY next_attempt_at
+            LIMIT $1
+            FOR UPDATE SKIP LOCKED
+        )
+        RETURNING id, domain, consecutive_failures
+        "#,
+    )
+    .bind(limit)
+    .bind(lease_minutes)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(due)
+}
+
+/// Stores a newly issued certificate and returns its domain to `verified`
+/// if issuance had been failing.
+pub async fn store_issued(
+    pool: &PgPool,
+    id: Uuid,
+    certificate: &IssuedCertificate<'_>,
+) -> Result<(), AppError> {
+    let mut tx = pool.begin().await?;
+
+    let domain = sqlx::query_scalar::<_, String>(
+        r#"
+        UPDATE custom_domain_certificates
+        SET
+            certificate_pem = $2,
+            encrypted_private_key = $3,
+            not_before = $4,
+            not_after = $5,
+            renew_after = $6,
+            issued_at = NOW(),
+            next_attempt_at = $6,
+            consecutive_failures = 0,
+            last_error = NULL,
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING domain
+        "#,
+    )
+    .bind(id)
+    .bind(certificate.certificate_pem)
+    .bind(certificate.encrypted_private_key)
+    .bind(certificate.not_before)
+    .bind(certificate.not_after)
+    .bind(certificate.renew_after)
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    if let Some(domain) = domain {
+        set_domain_status(
+            &mut tx,
+            &domain,
+            "('certificate_failed', 'certificate_expired')",
+            "verified",
+        )
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Records a failed attempt. A domain without any certificate yet moves to
+/// `certificate_failed`; one with a certificate keeps serving it until it
+/// expires.
+pub async fn record_failure(
+    pool: &PgPool,
+    id: Uuid,
+    error: &str,
+    next_attempt_at: DateTime<Utc>,
+) -> Result<(), AppError> {
+    let mut tx = pool.begin().await?;
+
+    let failed = sqlx::query_as::<_, (String, bool)>(
+        r#"
+        UPDATE custom_domain_certificates
+        SET
+            consecutive_failures = consecutive_failures + 1,
+            last_error = $2,
+            next_attempt_at = $3,
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING domain, certificate_pem IS NULL
+        "#,
+    )
+    .bind(id)
+    .bind(error)
+    .bind(next_attempt_at)
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    if let Some((domain, true)) = failed {
+        set_domain_status(&mut tx, &domain, "('verified')", "certificate_failed").await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+async fn set_domain_status(
+    tx: &mut Transaction<'_, Postgres>,
+    domain: &str,
+    from: &str,
+    to: &str,
+) -> Result<(), AppError> {
+    for table in ["organizations", "status_pages"] {
+        sqlx::query(&format!(
+            r#"
+            UPDATE {table}
+            SET custom_domain_status = $2, updated_at = NOW()
+            WHERE lower(custom_domain) = $1 AND custom_domain_status IN {from}
+            "#
+        ))
+        .bind(domain)
+        .bind(to)
+        .execute(&mut **tx)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// The unexpired certificate for a domain that is still verified.
+pub async fn find_active(
+    pool: &PgPool,
+    domain: &str,
+) -> Result<Option<StoredCertificate>, AppError> {
+    let certificate = sqlx::query_as::<_, StoredCertificate>(
+        r#"
+        SELECT c.domain, c.certificate_pem, c.encrypted_private_key, c.not_after
+        FROM custom_domain_certificates c
+        WHERE c.domain = lower($1)
+          AND c.certificate_pem IS NOT NULL
+          AND c.encrypted_private_key IS NOT NULL
+          AND c.not_after > NOW()
+          AND EXISTS (SELECT 1 FROM verified_custom_domains d WHERE d.domain = c.domain)
+        "#,
+    )
+    .bind(domain)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(certificate)
+}
+
+pub async fn find_acme_account(
+    pool: &PgPool,
+    directory_url: &str,
+) -> Result<Option<AcmeAccount>, AppError> {
+    let account = sqlx::query_as::<_, AcmeAccount>(
+        "SELECT account_url, encrypted_key FROM acme_accounts WHERE directory_url = $1",
+    )
+    .bind(directory_url)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(account)
+}
+
+pub async fn save_acme_account(
+    pool: &PgPool,
+    directory_url: &str,
+    account_url: &str,
+    encrypted_key: &[u8],
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        INSERT INTO acme_accounts (directory_url, account_url, encrypted_key)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (directory_url) DO UPDATE
+        SET account_url = EXCLUDED.account_url, encrypted_key = EXCLUDED.encrypted_key
+        "#,
+    )
+    .bind(directory_url)
+    .bind(account_url)
+    .bind(encrypted_key)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn save_challenge(
+    pool: &PgPool,
+    domain: &str,
+    token: &str,
+    key_authorization: &str,
+    ttl_minutes: i64,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        INSERT INTO acme_http_challenges (token, domain, key_authorization, expires_at)
+        VALUES ($1, lower($2), $3, NOW() + make_interval(mins => $4::int))
+        ON CONFLICT (token) DO UPDATE
+        SET key_authorization = EXCLUDED.key_authorization, expires_at = EXCLUDED.expires_at
+        "#,
+    )
+    .bind(token)
+    .bind(domain)
+    .bind(key_authorization)
+    .bind(ttl_minutes)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete_challenge(pool: &PgPool, token: &str) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM acme_http_challenges WHERE token = $1 OR expires_at <= NOW()")
+        .bind(token)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn find_challenge(pool: &PgPool, token: &str) -> Result<Option<String>, AppError> {
+    let key_authorization = sqlx::query_scalar::<_, String>(
+        "SELECT key_authorization FROM acme_http_challenges WHERE token = $1 AND expires_at > NOW()",
+    )
+    .bind(token)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(key_authorization)
+}
@@ -0,0 +1,196 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use shared::error::AppError;
+use shared::models::cli_auth::{CliCredential, CliLoginDetails};
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct CliLoginRequest {
+    pub id: Uuid,
+    pub user_code: String,
+    pub client_name: String,
+    pub status: String,
+    pub user_id: Option<Uuid>,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<CliLoginRequest> for CliLoginDetails {
+    fn from(request: CliLoginRequest) -> Self {
+        Self {
+            user_code: request.user_code,
+            client_name: request.client_name,
+            created_at: request.created_at,
+            expires_at: request.expires_at,
+        }
+    }
+}
+
+/// Drops requests that can no longer be approved or redeemed, so their user
+/// codes can be handed out again.
+pub async fn delete_stale_login_requests(pool: &PgPool) -> Result<u64, AppError> {
+    let result =
+        sqlx::query("DELETE FROM cli_login_requests WHERE expires_at < NOW() - INTERVAL '1 hour'")
+            .execute(pool)
+            .await?;
+
+    Ok(result.rows_affected())
+}
+
+pub async fn create_login_request(
+    pool: &PgPool,
+    device_code_hash: &str,
+    user_code: &str,
+    client_name: &str,
+    expires_at: DateTime<Utc>,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        INSERT INTO cli_login_requests (device_code_hash, user_code, client_name, expires_at)
+        VALUES ($1, $2, $3, $4)
+        "#,
+    )
+    .bind(device_code_hash)
+    .bind(user_code)
+    .bind(client_name)
+    .bind(expires_at)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn find_pending_by_user_code(
+    pool: &PgPool,
+    user_code: &str,
+) -> Result<Option<CliLoginRequest>, AppError> {
+    let request = sqlx::query_as::<_, CliLoginRequest>(
+        r#"
+        SELECT id, user_code, client_name, status, user_id, expires_at, created_at
+        FROM cli_login_requests
+        WHERE user_code = $1 AND status = 'pending' AND expires_at > NOW()
+        "#,
+    )
+    .bind(user_code)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(request)
+}
+
+/// Approves or denies a pending, unexpired request. Returns false when there
+/// is no such request.
+pub async fn decide_login_request(
+    pool: &PgPool,
+    user_code: &str,
+    user_id: Uuid,
+    approve: bool,
+) -> Result<bool, AppError> {
+    let result = sqlx::query(
+        r#"
+        UPDATE cli_login_requests
+        SET status = CASE WHEN $3 THEN 'approved' ELSE 'denied' END,
+            user_id = $2,
+            decided_at = NOW()
+        WHERE user_code = $1 AND status = 'pending' AND expires_at > NOW()
+        "#,
+    )
+    .bind(user_code)
+    .bind(user_id)
+    .bind(approve)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn find_by_device_code(
+    conn: &mut PgConnection,
+    device_code_hash: &str,
+) -> Result<Option<CliLoginRequest>, AppError> {
+    let request = sqlx::query_as::<_, CliLoginRequest>(
+        r#"
+        SELECT id, user_code, client_name, status, user_id, expires_at, created_at
+        FROM cli_login_requests
+        WHERE device_code_hash = $1
+        FOR UPDATE
+        "#,
+    )
+    .bind(device_code_hash)
+    .fetch_optional(conn)
+    .await?;
+
+    Ok(request)
+}
+
+/// Removes a decided request once the CLI has seen the outcome, so an
+/// approval is redeemed at most once.
+pub async fn delete_login_request(conn: &mut PgConnection, id: Uuid) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM cli_login_requests WHERE id = $1")
+        .bind(id)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn create_credential(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    name: &str,
+    token_hash: &str,
+    expires_at: DateTime<Utc>,
+) -> Result<Uuid, AppError> {
+    let id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO cli_credentials (user_id, name, token_hash, expires_at)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id
+        "#,
+    )
+    .bind(user_id)
+    .bind(name)
+    .bind(token_hash)
+    .bind(expires_at)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(id)
+}
+
+/// Credentials that still work, newest first.
+pub async fn list_active_for_user(
+    pool: &PgPool,
+    user_id: Uuid,
+) -> Result<Vec<CliCredential>, AppError> {
+    let credentials = sqlx::query_as::<_, CliCredential>(
+        r#"
+        SELECT id, name, expires_at, last_used_at, created_at
+        FROM cli_credentials
+        WHERE user_id = $1 AND revoked_at IS NULL AND expires_at > NOW()
+        ORDER BY created_at DESC
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(credentials)
+}
+
+pub async fn revoke(pool: &PgPool, user_id: Uuid, id: Uuid) -> Result<bool, AppError> {
+    let result = sqlx::query(
+        r#"
+        UPDATE cli_credentials
+        SET revoked_at = NOW()
+        WHERE id = $1 AND user_id = $2 AND revoked_at IS NULL
+        "#,
+    )
+    .bind(id)
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
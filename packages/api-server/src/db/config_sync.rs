@@ -0,0 +1,362 @@
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use shared::error::AppError;
+use shared::models::config_sync::{MonitorSpec, ServiceGroupSpec, ServiceSpec, WebhookSpec};
+use shared::models::monitor::{normalize_monitor_config, Monitor};
+use shared::models::service::Service;
+use shared::models::service_group::ServiceGroup;
+
+/// A webhook with its signing secret, which the API never returns but a
+/// spec may change.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ManagedWebhook {
+    pub id: Uuid,
+    pub name: String,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<String>,
+    pub is_enabled: bool,
+}
+
+/// Serializes config applies for one organization until the transaction ends.
+pub async fn lock_org(conn: &mut PgConnection, org_id: Uuid) -> Result<(), AppError> {
+    sqlx::query("SELECT id FROM organizations WHERE id = $1 FOR UPDATE")
+        .bind(org_id)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn list_groups(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+) -> Result<Vec<ServiceGroup>, AppError> {
+    let groups = sqlx::query_as::<_, ServiceGroup>(
+        "SELECT * FROM service_groups WHERE org_id = $1 ORDER BY display_order, name",
+    )
+    .bind(org_id)
+    .fetch_all(conn)
+    .await?;
+
+    Ok(groups)
+}
+
+pub async fn list_services(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+) -> Result<Vec<Service>, AppError> {
+    let services = sqlx::query_as::<_, Service>(
+        "SELECT * FROM services WHERE org_id = $1 ORDER BY display_order, created_at",
+    )
+    .bind(org_id)
+    .fetch_all(conn)
+    .await?;
+
+    Ok(services)
+}
+
+pub async fn list_monitors(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+) -> Result<Vec<Monitor>, AppError> {
+    let monitors = sqlx::query_as::<_, Monitor>(
+        "SELECT * FROM monitors WHERE org_id = $1 ORDER BY created_at, id",
+    )
+    .bind(org_id)
+    .fetch_all(conn)
+    .await?;
+
+    Ok(monitors)
+}
+
+pub async fn list_webhooks(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+) -> Result<Vec<ManagedWebhook>, AppError> {
+    let webhooks = sqlx::query_as::<_, ManagedWebhook>(
+        r#"
+        SELECT id, name, url, secret, event_types, is_enabled
+        FROM webhook_configs
+        WHERE org_id = $1
+        ORDER BY created_at, id
+        "#,
+    )
+    .bind(org_id)
+    .fetch_all(conn)
+    .await?;
+
+    Ok(webhooks)
+}
+
+pub async fn create_group(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    spec: &ServiceGroupSpec,
+) -> Result<Uuid, AppError> {
+    let id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO service_groups (
+            org_id, name, description, display_order, collapsed_by_default, status_rule
+        )
+        VALUES (
+            $1, $2, $3,
+            (SELECT COALESCE(MAX(display_order), -1) + 1 FROM service_groups WHERE org_id = $1),
+            $4, $5
+        )
+        RETURNING id
+        "#,
+    )
+    .bind(org_id)
+    .bind(spec.name.trim())
+    .bind(&spec.description)
+    .bind(spec.collapsed_by_default)
+    .bind(spec.status_rule)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(id)
+}
+
+pub async fn update_group(
+    conn: &mut PgConnection,
+    id: Uuid,
+    spec: &ServiceGroupSpec,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        UPDATE service_groups
+        SET description = $2, collapsed_by_default = $3, status_rule = $4, updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(&spec.description)
+    .bind(spec.collapsed_by_default)
+    .bind(spec.status_rule)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Members of a deleted group become ungrouped, as with the groups API.
+pub async fn delete_group(conn: &mut PgConnection, id: Uuid) -> Result<(), AppError> {
+    sqlx::query(
+        "UPDATE services SET group_id = NULL, group_name = NULL, updated_at = NOW() WHERE group_id = $1",
+    )
+    .bind(id)
+    .execute(&mut *conn)
+    .await?;
+    sqlx::query("DELETE FROM service_groups WHERE id = $1")
+        .bind(id)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn create_service(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    spec: &ServiceSpec,
+    group: Option<(Uuid, &str)>,
+) -> Result<Uuid, AppError> {
+    let id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO services (
+            org_id, name, description, group_id, group_name, is_visible, display_order
+        )
+        VALUES (
+            $1, $2, $3, $4, $5, $6,
+            (SELECT COALESCE(MAX(display_order), -1) + 1 FROM services WHERE org_id = $1)
+        )
+        RETURNING id
+        "#,
+    )
+    .bind(org_id)
+    .bind(spec.name.trim())
+    .bind(&spec.description)
+    .bind(group.map(|(id, _)| id))
+    .bind(group.map(|(_, name)| name))
+    .bind(spec.is_visible)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(id)
+}
+
+pub async fn update_service(
+    conn: &mut PgConnection,
+    id: Uuid,
+    spec: &ServiceSpec,
+    group: Option<(Uuid, &str)>,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        UPDATE services
+        SET description = $2, group_id = $3, group_name = $4, is_visible = $5, updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(&spec.description)
+    .bind(group.map(|(id, _)| id))
+    .bind(group.map(|(_, name)| name))
+    .bind(spec.is_visible)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete_service(conn: &mut PgConnection, id: Uuid) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM services WHERE id = $1")
+        .bind(id)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn create_monitor(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    service_id: Uuid,
+    spec: &MonitorSpec,
+) -> Result<Uuid, AppError> {
+    let id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO monitors (
+            service_id, org_id, monitor_type, config, interval_seconds, timeout_ms,
+            failure_threshold, recovery_threshold, is_active, disabled_reason
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, NULL)
+        RETURNING id
+        "#,
+    )
+    .bind(service_id)
+    .bind(org_id)
+    .bind(spec.monitor_type)
+    .bind(normalize_monitor_config(
+        spec.monitor_type,
+        spec.config.clone(),
+    ))
+    .bind(spec.interval_seconds)
+    .bind(spec.timeout_ms)
+    .bind(spec.failure_threshold)
+    .bind(spec.recovery_threshold)
+    .bind(spec.is_active)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(id)
+}
+
+/// Re-enabling a monitor clears a plan-limit or failure disable, as the
+/// monitors API does.
+pub async fn update_monitor(
+    conn: &mut PgConnection,
+    id: Uuid,
+    spec: &MonitorSpec,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        UPDATE monitors SET
+            config = $2,
+            interval_seconds = $3,
+            timeout_ms = $4,
+            failure_threshold = $5,
+            recovery_threshold = $6,
+            is_active = $7,
+            disabled_reason = CASE WHEN $7 THEN NULL ELSE disabled_reason END,
+            updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(normalize_monitor_config(
+        spec.monitor_type,
+        spec.config.clone(),
+    ))
+    .bind(spec.interval_seconds)
+    .bind(spec.timeout_ms)
+    .bind(spec.failure_threshold)
+    .bind(spec.recovery_threshold)
+    .bind(spec.is_active)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete_monitor(conn: &mut PgConnection, id: Uuid) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM monitors WHERE id = $1")
+        .bind(id)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn create_webhook(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    spec: &WebhookSpec,
+) -> Result<Uuid, AppError> {
+    let id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO webhook_configs (org_id, name, url, secret, event_types, is_enabled, disabled_reason)
+        VALUES ($1, $2, $3, $4, $5, $6, NULL)
+        RETURNING id
+        "#,
+    )
+    .bind(org_id)
+    .bind(spec.name.trim())
+    .bind(&spec.url)
+    .bind(&spec.secret)
+    .bind(&spec.event_types)
+    .bind(spec.is_enabled)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(id)
+}
+
+pub async fn update_webhook(
+    conn: &mut PgConnection,
+    id: Uuid,
+    spec: &WebhookSpec,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        UPDATE webhook_configs SET
+            url = $2,
+            secret = $3,
+            event_types = $4,
+            is_enabled = $5,
+            disabled_reason = CASE WHEN $5 THEN NULL ELSE disabled_reason END,
+            updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(&spec.url)
+    .bind(&spec.secret)
+    .bind(&spec.event_types)
+    .bind(spec.is_enabled)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete_webhook(conn: &mut PgConnection, id: Uuid) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM webhook_configs WHERE id = $1")
+        .bind(id)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use shared::enums::{CustomDomainStatus, CustomDomainVerificationMethod};
+use shared::error::AppError;
+
+/// A verified custom domain due for background re-verification, on either
+/// an organization or one of its status pages.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct DueDomainCheck {
+    pub org_id: Uuid,
+    /// `None` for an organization's primary page.
+    pub page_id: Option<Uuid>,
+    pub domain: String,
+    pub status: CustomDomainStatus,
+    pub verification_token: String,
+}
+
+impl DueDomainCheck {
+    fn table(&self) -> &'static str {
+        if self.page_id.is_some() {
+            "status_pages"
+        } else {
+            "organizations"
+        }
+    }
+
+    fn row_id(&self) -> Uuid {
+        self.page_id.unwrap_or(self.org_id)
+    }
+}
+
+const RECHECKED_STATUSES: &str =
+    "('verified', 'certificate_failed', 'certificate_expired', 'misconfigured')";
+
+/// Claims domains whose last check is older than `interval_secs`. Claiming
+/// stamps `custom_domain_checked_at`, so concurrent API instances skip them.
+pub async fn claim_due(
+    pool: &PgPool,
+    interval_secs: i64,
+    limit: i64,
+) -> Result<Vec<DueDomainCheck>, AppError> {
+    let due = sqlx::query_as::<_, DueDomainCheck>(&format!(
+        r#"
+        WITH due_orgs AS (
+            UPDATE organizations
+            SET custom_domain_checked_at = NOW()
+            WHERE id IN (
+                SELECT id
+                FROM organizations
+                WHERE custom_domain IS NOT NULL
+                  AND custom_domain_status IN {RECHECKED_STATUSES}
+                  AND (
+                      custom_domain_checked_at IS NULL
+                      OR custom_domain_checked_at <= NOW() - make_interval(secs => $1::double precision)
+                  )
+                ORDER BY custom_domain_checked_at NULLS FIRST
+                LIMIT $2
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING id AS org_id, NULL::UUID AS page_id, custom_domain AS domain,
+                      custom_domain_status AS status,
+                      custom_domain_verification_token AS verification_token
+        ),
+        due_pages AS (
+            UPDATE status_pages
+            SET custom_domain_checked_at = NOW()
+            WHERE id IN (
+                SELECT id
+                FROM status_pages
+                WHERE custom_domain IS NOT NULL
+                  AND disabled_reason IS NULL
+                  AND custom_domain_status IN {RECHECKED_STATUSES}
+                  AND (
+                      custom_domain_checked_at IS NULL
+                      OR custom_domain_checked_at <= NOW() - make_interval(secs => $1::double precision)
+                  )
+                ORDER BY custom_domain_checked_at NULLS FIRST
+                LIMIT $2
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING org_id, id AS page_id, custom_domain AS domain,
+                      custom_domain_status AS status,
+                      custom_domain_verification_token AS verification_token
+        )
+        SELECT * FROM due_orgs
+        UNION ALL
+        SELECT * FROM due_pages
+        "#
+    ))
+    .bind(interval_secs)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(due)
+}
+
+/// Records a passing check. Returns whether a `misconfigured` domain was
+/// restored to `verified`.
+pub async fn record_pass(
+    pool: &PgPool,
+    check: &DueDomainCheck,
+    method: CustomDomainVerificationMethod,
+) -> Result<bool, AppError> {
+    let table = check.table();
+    let restored = sqlx::query_scalar::<_, bool>(&format!(
+        r#"
+        UPDATE {table}
+        SET
+            custom_domain_check_failures = 0,
+            custom_domain_grace_ends_at = NULL,
+            custom_domain_verification_method = $3,
+            custom_domain_status = CASE
+                WHEN custom_domain_status = 'misconfigured' THEN 'verified'
+                ELSE custom_domain_status
+            END,
+            updated_at = CASE
+                WHEN custom_domain_status = 'misconfigured' THEN NOW()
+                ELSE updated_at
+            END
+        WHERE id = $1
+          AND lower(custom_domain) = lower($2)
+          AND custom_domain_status IN {RECHECKED_STATUSES}
+        RETURNING $4
+        "#
+    ))
+    .bind(check.row_id())
+    .bind(&check.domain)
+    .bind(method)
+    .bind(check.status == CustomDomainStatus::Misconfigured)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(restored.unwrap_or(false))
+}
+
+/// Records a failed check. After `threshold` consecutive failures the domain
+/// becomes `misconfigured` and keeps routing for `grace_hours`. Returns the
+/// end of the grace period when this failure crossed the threshold.
+pub async fn record_miss(
+    pool: &PgPool,
+    check: &DueDomainCheck,
+    threshold: i32,
+    grace_hours: i64,
+) -> Result<Option<DateTime<Utc>>, AppError> {
+    let table = check.table();
+    let updated = sqlx::query_as::<_, (CustomDomainStatus, Option<DateTime<Utc>>)>(&format!(
+        r#"
+        UPDATE {table}
+        SET
+            custom_domain_check_failures = custom_domain_check_failures + 1,
+            custom_domain_status = CASE
+                WHEN custom_domain_check_failures + 1 >= $3 THEN 'misconfigured'
+                ELSE custom_domain_status
+            END,
+            custom_domain_grace_ends_at = CASE
+                WHEN custom_domain_check_failures + 1 >= $3
+                     AND custom_domain_status <> 'misconfigured'
+                    THEN NOW() + make_interval(hours => $4::int)
+                ELSE custom_domain_grace_ends_at
+            END,
+            updated_at = NOW()
+        WHERE id = $1
+          AND lower(custom_domain) = lower($2)
+          AND custom_domain_status IN {RECHECKED_STATUSES}
+        RETURNING custom_domain_status, custom_domain_grace_ends_at
+        "#
+    ))
+    .bind(check.row_id())
+    .bind(&check.domain)
+    .bind(threshold.max(1))
+    .bind(grace_hours)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(match updated {
+        Some((CustomDomainStatus::Misconfigured, grace_ends_at))
+            if check.status != CustomDomainStatus::Misconfigured =>
+        {
+            grace_ends_at
+        }
+        _ => None,
+    })
+}
@@ -0,0 +1,253 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use shared::enums::{IncidentImpact, IncidentStatus, ServiceStatus};
+use shared::error::AppError;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ImportRecord {
+    pub kind: String,
+    pub external_id: String,
+    pub local_id: Uuid,
+}
+
+/// Records imported earlier from `provider` whose local row still exists.
+/// A record pointing at a deleted row is treated as never imported.
+pub async fn list_live(
+    pool: &PgPool,
+    org_id: Uuid,
+    provider: &str,
+) -> Result<Vec<ImportRecord>, AppError> {
+    let records = sqlx::query_as::<_, ImportRecord>(
+        r#"
+        SELECT r.kind, r.external_id, r.local_id
+        FROM import_records r
+        WHERE r.org_id = $1
+          AND r.provider = $2
+          AND CASE r.kind
+              WHEN 'service_group' THEN EXISTS(SELECT 1 FROM service_groups WHERE id = r.local_id)
+              WHEN 'service' THEN EXISTS(SELECT 1 FROM services WHERE id = r.local_id)
+              WHEN 'incident' THEN EXISTS(SELECT 1 FROM incidents WHERE id = r.local_id)
+              WHEN 'incident_update' THEN EXISTS(SELECT 1 FROM incident_updates WHERE id = r.local_id)
+              ELSE FALSE
+          END
+        "#,
+    )
+    .bind(org_id)
+    .bind(provider)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(records)
+}
+
+pub async fn record(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    provider: &str,
+    kind: &str,
+    external_id: &str,
+    local_id: Uuid,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        INSERT INTO import_records (org_id, provider, kind, external_id, local_id)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (org_id, provider, kind, external_id)
+        DO UPDATE SET local_id = EXCLUDED.local_id, imported_at = NOW()
+        "#,
+    )
+    .bind(org_id)
+    .bind(provider)
+    .bind(kind)
+    .bind(external_id)
+    .bind(local_id)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Reuses a same-named group already on the organization.
+pub async fn upsert_service_group(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    name: &str,
+    display_order: i32,
+) -> Result<Uuid, AppError> {
+    let id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO service_groups (org_id, name, display_order)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (org_id, name) DO UPDATE SET name = EXCLUDED.name
+        RETURNING id
+        "#,
+    )
+    .bind(org_id)
+    .bind(name)
+    .bind(display_order)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(id)
+}
+
+pub struct ImportedService<'a> {
+    pub name: &'a str,
+    pub description: Option<&'a str>,
+    pub status: ServiceStatus,
+    pub group: Option<(Uuid, &'a str)>,
+    pub display_order: i32,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+pub async fn insert_service(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    service: &ImportedService<'_>,
+) -> Result<Uuid, AppError> {
+    let id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO services (
+            org_id, name, description, current_status, group_id, group_name,
+            display_order, created_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, COALESCE($8, NOW()))
+        RETURNING id
+        "#,
+    )
+    .bind(org_id)
+    .bind(service.name)
+    .bind(service.description)
+    .bind(service.status)
+    .bind(service.group.map(|(id, _)| id))
+    .bind(service.group.map(|(_, name)| name))
+    .bind(service.display_order)
+    .bind(service.created_at)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(id)
+}
+
+pub async fn next_service_order(conn: &mut PgConnection, org_id: Uuid) -> Result<i32, AppError> {
+    let next = sqlx::query_scalar::<_, i32>(
+        "SELECT COALESCE(MAX(display_order), -1) + 1 FROM services WHERE org_id = $1",
+    )
+    .bind(org_id)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(next)
+}
+
+pub struct ImportedIncident<'a> {
+    pub title: &'a str,
+    pub status: IncidentStatus,
+    pub impact: IncidentImpact,
+    pub started_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub async fn insert_incident(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    incident: &ImportedIncident<'_>,
+) -> Result<Uuid, AppError> {
+    let id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO incidents (
+            org_id, title, status, impact, started_at, resolved_at, created_at, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING id
+        "#,
+    )
+    .bind(org_id)
+    .bind(incident.title)
+    .bind(incident.status)
+    .bind(incident.impact)
+    .bind(incident.started_at)
+    .bind(incident.resolved_at)
+    .bind(incident.created_at)
+    .bind(incident.updated_at)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(id)
+}
+
+pub async fn link_incident_service(
+    conn: &mut PgConnection,
+    incident_id: Uuid,
+    service_id: Uuid,
+    service_status: ServiceStatus,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        INSERT INTO incident_services (incident_id, service_id, service_status)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (incident_id, service_id) DO NOTHING
+        "#,
+    )
+    .bind(incident_id)
+    .bind(service_id)
+    .bind(service_status)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn insert_incident_update(
+    conn: &mut PgConnection,
+    incident_id: Uuid,
+    status: IncidentStatus,
+    message: &str,
+    created_at: DateTime<Utc>,
+) -> Result<Uuid, AppError> {
+    let id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO incident_updates (incident_id, status, message, created_at)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id
+        "#,
+    )
+    .bind(incident_id)
+    .bind(status)
+    .bind(message)
+    .bind(created_at)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(id)
+}
+
+/// Brings an incident imported earlier up to date after newer updates were
+/// added to it.
+pub async fn sync_incident_status(
+    conn: &mut PgConnection,
+    incident_id: Uuid,
+    status: IncidentStatus,
+    resolved_at: Option<DateTime<Utc>>,
+    updated_at: DateTime<Utc>,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        UPDATE incidents
+        SET status = $2, resolved_at = $3, updated_at = GREATEST(updated_at, $4)
+        WHERE id = $1
+        "#,
+    )
+    .bind(incident_id)
+    .bind(status)
+    .bind(resolved_at)
+    .bind(updated_at)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use shared::error::AppError;
+use shared::models::incident::IncidentEvent;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+pub async fn record(
+    pool: &PgPool,
+    incident_id: Uuid,
+    event_type: &str,
+    actor_user_id: Option<Uuid>,
+    details: serde_json::Value,
+) -> Result<IncidentEvent, AppError> {
+    let event = sqlx::query_as::<_, IncidentEvent>(
+        r#"
+        INSERT INTO incident_events (incident_id, event_type, actor_user_id, details)
+        VALUES ($1, $2, $3, $4)
+        RETURNING *
+        "#,
+    )
+    .bind(incident_id)
+    .bind(event_type)
+    .bind(actor_user_id)
+    .bind(details)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(event)
+}
+
+pub async fn list_by_incident(
+    pool: &PgPool,
+    incident_id: Uuid,
+) -> Result<Vec<IncidentEvent>, AppError> {
+    let events = sqlx::query_as::<_, IncidentEvent>(
+        "SELECT * FROM incident_events WHERE incident_id = $1 ORDER BY created_at DESC",
+    )
+    .bind(incident_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(events)
+}
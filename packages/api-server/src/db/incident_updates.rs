@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use shared::error::AppError;
 use shared::models::incident_update::{CreateIncidentUpdateRequest, IncidentUpdate};
 use sqlx::PgPool;
@@ -11,8 +12,8 @@ pub async fn create(
 ) -> Result<IncidentUpdate, AppError> {
     let update = sqlx::query_as::<_, IncidentUpdate>(
         r#"
-        INSERT INTO incident_updates (incident_id, status, message, created_by)
-        VALUES ($1, $2, $3, $4)
+        INSERT INTO incident_updates (incident_id, status, message, created_by, created_at, visibility)
+        VALUES ($1, $2, $3, $4, COALESCE($5, NOW()), $6)
         RETURNING *
         "#,
     )
@@ -20,6 +21,8 @@ pub async fn create(
     .bind(req.status)
     .bind(&req.message)
     .bind(user_id)
+    .bind(req.created_at)
+    .bind(req.visibility)
     .fetch_one(pool)
     .await?;
 
@@ -40,3 +43,43 @@ pub async fn find_by_incident(
 
     Ok(updates)
 }
+
+pub async fn edit(
+    pool: &PgPool,
+    incident_id: Uuid,
+    update_id: Uuid,
+    message: &str,
+    created_at: DateTime<Utc>,
+) -> Result<IncidentUpdate, AppError> {
+    let update = sqlx::query_as::<_, IncidentUpdate>(
+        r#"
+        UPDATE incident_updates
+        SET message = $3, created_at = $4, edited_at = NOW()
+        WHERE id = $2 AND incident_id = $1
+        RETURNING *
+        "#,
+    )
+    .bind(incident_id)
+    .bind(update_id)
+    .bind(message)
+    .bind(created_at)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Incident update not found".to_string()))?;
+
+    Ok(update)
+}
+
+pub async fn delete(pool: &PgPool, incident_id: Uuid, update_id: Uuid) -> Result<(), AppError> {
+    let result = sqlx::query("DELETE FROM incident_updates WHERE id = $2 AND incident_id = $1")
+        .bind(incident_id)
+        .bind(update_id)
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound("Incident update not found".to_string()));
+    }
+
+    Ok(())
+}
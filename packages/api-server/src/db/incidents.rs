@@ -1,10 +1,11 @@
-use shared::enums::{IncidentImpact, IncidentStatus, ServiceStatus};
+use chrono::{DateTime, Utc};
+use shared::enums::{IncidentImpact, IncidentStatus, StatusChangeSource};
 use shared::error::AppError;
 use shared::models::incident::{
-    AffectedService, CreateIncidentRequest, Incident, IncidentWithDetails,
+    AffectedService, CreateIncidentRequest, Incident, IncidentWithDetails, ServiceStatusChange,
 };
 use shared::models::incident_update::IncidentUpdate;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
 
 pub async fn create(
@@ -18,6 +19,15 @@ pub async fn create(
             "At least one affected service is required".to_string(),
         ));
     }
+    if req
+        .service_statuses
+        .iter()
+        .any(|change| !req.affected_service_ids.contains(&change.service_id))
+    {
+        return Err(AppError::Validation(
+            "Service statuses must refer to affected services".to_string(),
+        ));
+    }
 
     let mut tx = pool.begin().await?;
 
@@ -26,8 +36,8 @@ pub async fn create(
     // 1. Insert incident
     let incident = sqlx::query_as::<_, Incident>(
         r#"
-        INSERT INTO incidents (org_id, title, status, impact, created_by)
-        VALUES ($1, $2, $3, $4, $5)
+        INSERT INTO incidents (org_id, title, status, impact, created_by, started_at)
+        VALUES ($1, $2, $3, $4, $5, COALESCE($6, NOW()))
         RETURNING *
         "#,
     )
@@ -36,46 +46,39 @@ pub async fn create(
     .bind(status)
     .bind(req.impact)
     .bind(user_id)
+    .bind(req.started_at)
     .fetch_one(&mut *tx)
     .await?;
 
-    // 2. Insert initial update
+    // 2. Insert initial update, dated with the incident start
     sqlx::query(
         r#"
-        INSERT INTO incident_updates (incident_id, status, message, created_by)
-        VALUES ($1, $2, $3, $4)
+        INSERT INTO incident_updates (incident_id, status, message, created_by, created_at)
+        VALUES ($1, $2, $3, $4, $5)
         "#,
     )
     .bind(incident.id)
     .bind(status)
     .bind(&req.message)
     .bind(user_id)
+    .bind(incident.started_at)
     .execute(&mut *tx)
     .await?;
 
-    // 3. Link affected services
+    // 3. Link affected services with their per-service status
     for service_id in &req.affected_service_ids {
-        sqlx::query("INSERT INTO incident_services (incident_id, service_id) VALUES ($1, $2)")
-            .bind(incident.id)
-            .bind(service_id)
-            .execute(&mut *tx)
-            .await?;
+        sqlx::query(
+            "INSERT INTO incident_services (incident_id, service_id, service_status) VALUES ($1, $2, $3)",
+        )
+        .bind(incident.id)
+        .bind(service_id)
+        .bind(req.initial_service_status(*service_id))
+        .execute(&mut *tx)
+        .await?;
     }
 
-    // 4. Update affected service statuses based on impact
-    let new_status = req.impact.to_service_status();
-    if new_status != ServiceStatus::Operational {
-        for service_id in &req.affected_service_ids {
-            sqlx::query(
-                "UPDATE services SET current_status = $1, updated_at = NOW() WHERE id = $2 AND org_id = $3",
-            )
-            .bind(new_status)
-            .bind(service_id)
-            .bind(org_id)
-            .execute(&mut *tx)
-            .await?;
-        }
-    }
+    // 4. Update affected service statuses from all active incidents
+    recalculate_service_statuses(&mut tx, org_id, &req.affected_service_ids, &[]).await?;
 
     tx.commit().await?;
     Ok(incident)
@@ -171,20 +174,24 @@ pub async fn find_by_id_with_details(
 
     let affected_services = sqlx::query_as::<_, AffectedService>(
         r#"
-        SELECT s.id as service_id, s.name as service_name
+        SELECT s.id as service_id, s.name as service_name, isvc.service_status as status
         FROM services s
         JOIN incident_services isvc ON isvc.service_id = s.id
         WHERE isvc.incident_id = $1
+        ORDER BY s.display_order, s.name
         "#,
     )
     .bind(incident_id)
     .fetch_all(pool)
     .await?;
 
+    let events = crate::db::incident_events::list_by_incident(pool, incident_id).await?;
+
     Ok(Some(IncidentWithDetails {
         incident,
         updates,
         affected_services,
+        events,
     }))
 }
 
@@ -220,12 +227,118 @@ pub async fn update_status(
     Ok(incident)
 }
 
+/// Marks the incident acknowledged and, if nobody owns it yet, assigns it to
+/// the acknowledging user. Returns `false` when it was already acknowledged.
+pub async fn acknowledge(
+    pool: &PgPool,
+    incident_id: Uuid,
+    org_id: Uuid,
+    user_id: Uuid,
+) -> Result<(Incident, bool), AppError> {
+    let acknowledged = sqlx::query_as::<_, Incident>(
+        r#"
+        UPDATE incidents SET
+            acknowledged_at = NOW(),
+            acknowledged_by = $3,
+            assignee_user_id = COALESCE(assignee_user_id, $3),
+            updated_at = NOW()
+        WHERE id = $1 AND org_id = $2 AND acknowledged_at IS NULL
+        RETURNING *
+        "#,
+    )
+    .bind(incident_id)
+    .bind(org_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    if let Some(incident) = acknowledged {
+        return Ok((incident, true));
+    }
+
+    let incident =
+        sqlx::query_as::<_, Incident>("SELECT * FROM incidents WHERE id = $1 AND org_id = $2")
+            .bind(incident_id)
+            .bind(org_id)
+            .fetch_optional(pool)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Incident not found".to_string()))?;
+
+    Ok((incident, false))
+}
+
+pub async fn set_assignee(
+    pool: &PgPool,
+    incident_id: Uuid,
+    org_id: Uuid,
+    assignee_user_id: Option<Uuid>,
+) -> Result<Incident, AppError> {
+    let incident = sqlx::query_as::<_, Incident>(
+        r#"
+        UPDATE incidents SET assignee_user_id = $3, updated_at = NOW()
+        WHERE id = $1 AND org_id = $2
+        RETURNING *
+        "#,
+    )
+    .bind(incident_id)
+    .bind(org_id)
+    .bind(assignee_user_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Incident not found".to_string()))?;
+
+    Ok(incident)
+}
+
+/// Claims open auto-incidents that have gone unacknowledged for
+/// `delay_minutes` since they started or were last escalated, bumping their
+/// escalation count so each one is escalated once per delay.
+pub async fn claim_due_escalations(
+    pool: &PgPool,
+    delay_minutes: i64,
+    max_escalations: i32,
+    limit: i64,
+) -> Result<Vec<Incident>, AppError> {
+    let incidents = sqlx::query_as::<_, Incident>(
+        r#"
+        WITH due AS (
+            SELECT id
+            FROM incidents
+            WHERE is_auto = TRUE
+              AND status != 'resolved'
+              AND acknowledged_at IS NULL
+              AND escalation_count < $2
+              AND COALESCE(last_escalated_at, started_at)
+                  <= NOW() - make_interval(mins => $1::INT)
+            ORDER BY started_at ASC
+            LIMIT $3
+            FOR UPDATE SKIP LOCKED
+        )
+        UPDATE incidents i SET
+            escalation_count = i.escalation_count + 1,
+            last_escalated_at = NOW()
+        FROM due
+        WHERE i.id = due.id
+        RETURNING i.*
+        "#,
+    )
+    .bind(delay_minutes)
+    .bind(max_escalations)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(incidents)
+}
+
 /// When resolving an incident, recalculate affected service statuses.
+/// `resolved_at` backfills the resolution time; it defaults to now.
 pub async fn resolve_and_recalculate(
     pool: &PgPool,
     incident_id: Uuid,
     org_id: Uuid,
     user_id: Uuid,
+    resolved_at: Option<DateTime<Utc>>,
 ) -> Result<Incident, AppError> {
     let mut tx = pool.begin().await?;
 
@@ -234,7 +347,7 @@ pub async fn resolve_and_recalculate(
         r#"
         UPDATE incidents SET
             status = 'resolved',
-            resolved_at = NOW(),
+            resolved_at = COALESCE($3, NOW()),
             updated_at = NOW()
         WHERE id = $1 AND org_id = $2
         RETURNING *
@@ -242,6 +355,7 @@ pub async fn resolve_and_recalculate(
     )
     .bind(incident_id)
     .bind(org_id)
+    .bind(resolved_at)
     .fetch_optional(&mut *tx)
     .await?
     .ok_or_else(|| AppError::NotFound("Incident not found".to_string()))?;
@@ -249,12 +363,13 @@ pub async fn resolve_and_recalculate(
     // Add resolution update
     sqlx::query(
         r#"
-        INSERT INTO incident_updates (incident_id, status, message, created_by)
-        VALUES ($1, 'resolved', 'This incident has been resolved.', $2)
+        INSERT INTO incident_updates (incident_id, status, message, created_by, created_at)
+        VALUES ($1, 'resolved', 'This incident has been resolved.', $2, $3)
         "#,
     )
     .bind(incident_id)
     .bind(user_id)
+    .bind(incident.resolved_at)
     .execute(&mut *tx)
     .await?;
 
@@ -264,97 +379,291 @@ pub async fn resolve_and_recalculate(
             .bind(incident_id)
             .fetch_all(&mut *tx)
             .await?;
+    let released_ids = claimed_service_ids(&mut tx, incident_id).await?;
 
-    // For each affected service, recalculate status
-    for service_id in service_ids {
-        let worst_impact: Option<IncidentImpact> = sqlx::query_scalar(
-            r#"
-            SELECT i.impact FROM incidents i
-            JOIN incident_services isvc ON isvc.incident_id = i.id
-            WHERE isvc.service_id = $1
-              AND i.id != $2
-              AND i.status != 'resolved'
-            ORDER BY
-                CASE i.impact
-                    WHEN 'critical' THEN 0
-                    WHEN 'major' THEN 1
-                    WHEN 'minor' THEN 2
-                    WHEN 'none' THEN 3
-                END
-            LIMIT 1
-            "#,
-        )
-        .bind(service_id)
-        .bind(incident_id)
-        .fetch_optional(&mut *tx)
-        .await?;
+    recalculate_service_statuses(&mut tx, org_id, &service_ids, &released_ids).await?;
 
-        let new_status = match worst_impact {
-            Some(impact) => impact.to_service_status(),
-            None => ServiceStatus::Operational,
-        };
+    tx.commit().await?;
+    Ok(incident)
+}
 
-        sqlx::query("UPDATE services SET current_status = $1, updated_at = NOW() WHERE id = $2")
-            .bind(new_status)
-            .bind(service_id)
-            .execute(&mut *tx)
+/// Moves an incident to `status` after its timeline changed underneath it,
+/// setting or clearing `resolved_at` and recalculating affected services.
+pub async fn restore_status(
+    pool: &PgPool,
+    incident_id: Uuid,
+    org_id: Uuid,
+    status: IncidentStatus,
+    resolved_at: Option<DateTime<Utc>>,
+) -> Result<Incident, AppError> {
+    let mut tx = pool.begin().await?;
+
+    let incident = sqlx::query_as::<_, Incident>(
+        r#"
+        UPDATE incidents SET status = $3, resolved_at = $4, updated_at = NOW()
+        WHERE id = $1 AND org_id = $2
+        RETURNING *
+        "#,
+    )
+    .bind(incident_id)
+    .bind(org_id)
+    .bind(status)
+    .bind(resolved_at)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Incident not found".to_string()))?;
+
+    let service_ids: Vec<Uuid> =
+        sqlx::query_scalar("SELECT service_id FROM incident_services WHERE incident_id = $1")
+            .bind(incident_id)
+            .fetch_all(&mut *tx)
             .await?;
-    }
+    // Resolving lifts the incident's claims; reopening reinstates them,
+    // which the recalculation picks up on its own.
+    let released_ids = if status == IncidentStatus::Resolved {
+        claimed_service_ids(&mut tx, incident_id).await?
+    } else {
+        Vec::new()
+    };
+
+    recalculate_service_statuses(&mut tx, org_id, &service_ids, &released_ids).await?;
 
     tx.commit().await?;
     Ok(incident)
 }
 
 pub async fn delete(pool: &PgPool, incident_id: Uuid, org_id: Uuid) -> Result<(), AppError> {
+    let mut tx = pool.begin().await?;
+
     // Get affected services before deletion for status recalculation
     let service_ids: Vec<Uuid> =
         sqlx::query_scalar("SELECT service_id FROM incident_services WHERE incident_id = $1")
             .bind(incident_id)
-            .fetch_all(pool)
+            .fetch_all(&mut *tx)
             .await?;
+    let released_ids = claimed_service_ids(&mut tx, incident_id).await?;
 
     let result = sqlx::query("DELETE FROM incidents WHERE id = $1 AND org_id = $2")
         .bind(incident_id)
         .bind(org_id)
-        .execute(pool)
+        .execute(&mut *tx)
         .await?;
 
     if result.rows_affected() == 0 {
         return Err(AppError::NotFound("Incident not found".to_string()));
     }
 
-    // Recalculate service statuses after deletion
-    for service_id in service_ids {
-        let worst_impact: Option<IncidentImpact> = sqlx::query_scalar(
+    recalculate_service_statuses(&mut tx, org_id, &service_ids, &released_ids).await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Applies per-service status changes to an incident, adding services that
+/// were not affected before, and recalculates the services' current status.
+pub async fn set_service_statuses(
+    pool: &PgPool,
+    incident_id: Uuid,
+    org_id: Uuid,
+    changes: &[ServiceStatusChange],
+) -> Result<(), AppError> {
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    let mut tx = pool.begin().await?;
+    let mut service_ids = Vec::with_capacity(changes.len());
+    let released_ids = claimed_service_ids(&mut tx, incident_id).await?;
+
+    for change in changes {
+        let result = sqlx::query(
             r#"
-            SELECT i.impact FROM incidents i
-            JOIN incident_services isvc ON isvc.incident_id = i.id
-            WHERE isvc.service_id = $1 AND i.status != 'resolved'
-            ORDER BY
-                CASE i.impact
-                    WHEN 'critical' THEN 0
-                    WHEN 'major' THEN 1
-                    WHEN 'minor' THEN 2
-                    WHEN 'none' THEN 3
-                END
-            LIMIT 1
+            INSERT INTO incident_services (incident_id, service_id, service_status)
+            SELECT i.id, s.id, $3
+            FROM incidents i
+            JOIN services s ON s.org_id = i.org_id
+            WHERE i.id = $1 AND s.id = $2 AND i.org_id = $4
+            ON CONFLICT (incident_id, service_id)
+            DO UPDATE SET service_status = EXCLUDED.service_status
             "#,
         )
-        .bind(service_id)
-        .fetch_optional(pool)
+        .bind(incident_id)
+        .bind(change.service_id)
+        .bind(change.status)
+        .bind(org_id)
+        .execute(&mut *tx)
         .await?;
 
-        let new_status = match worst_impact {
-            Some(impact) => impact.to_service_status(),
-            None => ServiceStatus::Operational,
-        };
-
-        sqlx::query("UPDATE services SET current_status = $1, updated_at = NOW() WHERE id = $2")
-            .bind(new_status)
-            .bind(service_id)
-            .execute(pool)
-            .await?;
+        if result.rows_affected() == 0 {
+            return Err(AppError::Validation(format!(
+                "Service {} not found",
+                change.service_id
+            )));
+        }
+        service_ids.push(change.service_id);
     }
 
+    recalculate_service_statuses(&mut tx, org_id, &service_ids, &released_ids).await?;
+
+    tx.commit().await?;
     Ok(())
 }
+
+/// Sets each service to the worst status an unresolved incident gives it.
+/// Incidents that leave a service operational don't claim it, so a service
+/// no incident claims keeps its manually set status, unless it is among
+/// `released_ids`, whose claim was just lifted, and goes back to operational.
+async fn recalculate_service_statuses(
+    conn: &mut PgConnection,
+    org_id: Uuid,
+    service_ids: &[Uuid],
+    released_ids: &[Uuid],
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        WITH worst AS (
+            SELECT
+                s.id,
+                (
+                    SELECT isvc.service_status
+                    FROM incident_services isvc
+                    JOIN incidents i ON i.id = isvc.incident_id
+                    WHERE isvc.service_id = s.id
+                      AND i.status != 'resolved'
+                      AND isvc.service_status != 'operational'
+                    ORDER BY
+                        CASE isvc.service_status
+                            WHEN 'major_outage' THEN 0
+                            WHEN 'partial_outage' THEN 1
+                            WHEN 'degraded_performance' THEN 2
+                            ELSE 3
+                        END
+                    LIMIT 1
+                ) AS status
+            FROM services s
+            WHERE s.org_id = $1 AND s.id = ANY($2)
+        )
+        UPDATE services s SET
+            current_status = COALESCE(worst.status, 'operational'),
+            updated_at = NOW()
+        FROM worst
+        WHERE s.id = worst.id
+          AND (worst.status IS NOT NULL OR s.id = ANY($3))
+          AND s.current_status IS DISTINCT FROM COALESCE(worst.status, 'operational')
+        "#,
+    )
+    .bind(org_id)
+    .bind(service_ids)
+    .bind(released_ids)
+    .execute(&mut *conn)
+    .await?;
+
+    super::status_history::record(&mut *conn, org_id, StatusChangeSource::Incident).await?;
+    super::service_dependencies::refresh_upstream_impact(conn, org_id).await?;
+
+    Ok(())
+}
+
+/// Services the incident currently gives a non-operational status.
+async fn claimed_service_ids(
+    conn: &mut PgConnection,
+    incident_id: Uuid,
+) -> Result<Vec<Uuid>, AppError> {
+    let service_ids = sqlx::query_scalar(
+        r#"
+        SELECT service_id FROM incident_services
+        WHERE incident_id = $1 AND service_status != 'operational'
+        "#,
+    )
+    .bind(incident_id)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    Ok(service_ids)
+}
+
+pub struct IncidentSearchFilter<'a> {
+    pub query: Option<&'a str>,
+    pub status: Option<IncidentStatus>,
+    pub impact: Option<IncidentImpact>,
+    pub service_id: Option<Uuid>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub is_auto: Option<bool>,
+    pub oldest_first: bool,
+    /// Members also see internal notes and short auto-incidents that are
+    /// suppressed from public history.
+    pub internal_view: bool,
+    /// Limits results to incidents shown on this status page. `None` is the
+    /// primary page, which shows every incident.
+    pub status_page_id: Option<Uuid>,
+    /// Keyset position `(started_at, id)` of the last incident already seen.
+    pub after: Option<(DateTime<Utc>, Uuid)>,
+    pub limit: i64,
+}
+
+/// Searches an organization's incidents ordered by `(started_at, id)`, so
+/// pages stay stable while new incidents are created.
+pub async fn search(
+    pool: &PgPool,
+    org_id: Uuid,
+    filter: &IncidentSearchFilter<'_>,
+) -> Result<Vec<Incident>, AppError> {
+    let (direction, comparison) = if filter.oldest_first {
+        ("ASC", ">")
+    } else {
+        ("DESC", "<")
+    };
+
+    let incidents = sqlx::query_as::<_, Incident>(&format!(
+        r#"
+        SELECT i.*
+        FROM incidents i
+        WHERE i.org_id = $1
+          AND ($2::VARCHAR IS NULL OR i.status = $2)
+          AND ($3::VARCHAR IS NULL OR i.impact = $3)
+          AND (
+              $4::UUID IS NULL
+              OR EXISTS (
+                  SELECT 1 FROM incident_services isvc
+                  WHERE isvc.incident_id = i.id AND isvc.service_id = $4
+              )
+          )
+          AND ($5::TIMESTAMPTZ IS NULL OR i.resolved_at IS NULL OR i.resolved_at >= $5)
+          AND ($6::TIMESTAMPTZ IS NULL OR i.started_at < $6)
+          AND ($7::BOOLEAN IS NULL OR i.is_auto = $7)
+          AND ($12 OR NOT i.hidden_from_public)
+          AND status_page_covers_incident($13, i.id)
+          AND (
+              $8::TEXT IS NULL
+              OR i.search_vector @@ websearch_to_tsquery('english', $8)
+              OR EXISTS (
+                  SELECT 1 FROM incident_updates iu
+                  WHERE iu.incident_id = i.id
+                    AND ($12 OR iu.visibility = 'public')
+                    AND iu.search_vector @@ websearch_to_tsquery('english', $8)
+              )
+          )
+          AND ($9::TIMESTAMPTZ IS NULL OR (i.started_at, i.id) {comparison} ($9, $10))
+        ORDER BY i.started_at {direction}, i.id {direction}
+        LIMIT $11
+        "#
+    ))
+    .bind(org_id)
+    .bind(filter.status)
+    .bind(filter.impact)
+    .bind(filter.service_id)
+    .bind(filter.from)
+    .bind(filter.to)
+    .bind(filter.is_auto)
+    .bind(filter.query)
+    .bind(filter.after.map(|(started_at, _)| started_at))
+    .bind(filter.after.map(|(_, id)| id))
+    .bind(filter.limit)
+    .bind(filter.internal_view)
+    .bind(filter.status_page_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(incidents)
+}
@@ -1,15 +1,31 @@
 pub mod audit_logs;
 pub mod billing_events;
+pub mod certificates;
+pub mod cli_credentials;
+pub mod config_sync;
+pub mod custom_domains;
+pub mod import_records;
+pub mod incident_events;
 pub mod incident_updates;
 pub mod incidents;
 pub mod invitations;
 pub mod members;
+pub mod monitoring_policies;
 pub mod monitors;
 pub mod notification_logs;
 pub mod notification_preferences;
+pub mod organization_deletions;
+pub mod organization_exports;
 pub mod organizations;
+pub mod page_access;
+pub mod service_dependencies;
+pub mod service_groups;
 pub mod services;
+pub mod sms_logs;
+pub mod status_history;
+pub mod status_pages;
 pub mod subscribers;
 pub mod users;
 pub mod webhook_deliveries;
+pub mod webhook_subscribers;
 pub mod webhooks;
@@ -0,0 +1,60 @@
+use shared::error::AppError;
+use shared::models::monitoring_policy::{MonitoringPolicy, UpdateMonitoringPolicyRequest};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+pub async fn get_or_create(pool: &PgPool, org_id: Uuid) -> Result<MonitoringPolicy, AppError> {
+    sqlx::query(
+        r#"
+        INSERT INTO monitoring_policies (org_id)
+        VALUES ($1)
+        ON CONFLICT (org_id) DO NOTHING
+        "#,
+    )
+    .bind(org_id)
+    .execute(pool)
+    .await?;
+
+    let policy = sqlx::query_as::<_, MonitoringPolicy>(
+        "SELECT * FROM monitoring_policies WHERE org_id = $1",
+    )
+    .bind(org_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(policy)
+}
+
+pub async fn update(
+    pool: &PgPool,
+    org_id: Uuid,
+    req: &UpdateMonitoringPolicyRequest,
+) -> Result<MonitoringPolicy, AppError> {
+    let _ = get_or_create(pool, org_id).await?;
+
+    let policy = sqlx::query_as::<_, MonitoringPolicy>(
+        r#"
+        UPDATE monitoring_policies SET
+            recovery_threshold = COALESCE($2, recovery_threshold),
+            flap_detection_enabled = COALESCE($3, flap_detection_enabled),
+            flap_window_checks = COALESCE($4, flap_window_checks),
+            flap_state_changes = COALESCE($5, flap_state_changes),
+            auto_incident_cooldown_minutes = COALESCE($6, auto_incident_cooldown_minutes),
+            min_public_auto_incident_minutes = COALESCE($7, min_public_auto_incident_minutes),
+            updated_at = NOW()
+        WHERE org_id = $1
+        RETURNING *
+        "#,
+    )
+    .bind(org_id)
+    .bind(req.recovery_threshold)
+    .bind(req.flap_detection_enabled)
+    .bind(req.flap_window_checks)
+    .bind(req.flap_state_changes)
+    .bind(req.auto_incident_cooldown_minutes)
+    .bind(req.min_public_auto_incident_minutes)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(policy)
+}
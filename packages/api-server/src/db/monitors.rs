@@ -34,9 +34,10 @@ pub async fn create(
             interval_seconds,
             timeout_ms,
             failure_threshold,
+            recovery_threshold,
             disabled_reason
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, NULL)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, NULL)
         RETURNING *
         "#,
     )
@@ -50,6 +51,7 @@ pub async fn create(
     .bind(req.interval_seconds.unwrap_or(60))
     .bind(req.timeout_ms.unwrap_or(10000))
     .bind(req.failure_threshold.unwrap_or(3))
+    .bind(req.recovery_threshold)
     .fetch_one(pool)
     .await?;
 
@@ -111,6 +113,10 @@ pub async fn update(
             timeout_ms = COALESCE($5, timeout_ms),
             failure_threshold = COALESCE($6, failure_threshold),
             is_active = COALESCE($7, is_active),
+            recovery_threshold = CASE
+                WHEN $9 THEN NULL
+                ELSE COALESCE($8, recovery_threshold)
+            END,
             disabled_reason = CASE
                 WHEN COALESCE($7, is_active) THEN NULL
                 ELSE disabled_reason
@@ -131,6 +137,8 @@ pub async fn update(
     .bind(req.timeout_ms)
     .bind(req.failure_threshold)
     .bind(req.is_active)
+    .bind(req.recovery_threshold)
+    .bind(req.inherit_recovery_threshold)
     .fetch_optional(pool)
     .await?
     .ok_or_else(|| AppError::NotFound("Monitor not found".to_string()))?;
@@ -3,6 +3,7 @@ use serde::Serialize;
 use sqlx::PgPool;
 use uuid::Uuid;
 
+use shared::enums::{DigestFrequency, Locale};
 use shared::error::AppError;
 
 #[derive(Debug, Clone, sqlx::FromRow)]
@@ -11,11 +12,13 @@ pub struct PendingNotification {
     pub recipient_email: String,
     pub subject: Option<String>,
     pub body_text: String,
+    pub body_html: Option<String>,
+    pub list_unsubscribe_url: Option<String>,
     pub attempt_count: i32,
     pub max_attempts: i32,
 }
 
-#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct NotificationLogEntry {
     pub id: Uuid,
     pub notification_type: String,
@@ -38,15 +41,42 @@ pub struct NotificationFailureUpdate<'a> {
     pub next_retry_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
-pub async fn enqueue(
-    pool: &PgPool,
-    org_id: Uuid,
-    notification_type: &str,
-    recipient_type: &str,
-    recipient_email: &str,
-    subject: &str,
-    body_text: &str,
-) -> Result<(), AppError> {
+pub struct NewNotification<'a> {
+    pub org_id: Uuid,
+    pub notification_type: &'a str,
+    pub recipient_type: &'a str,
+    pub recipient_email: &'a str,
+    pub subject: &'a str,
+    pub body_text: &'a str,
+    pub body_html: Option<&'a str>,
+    pub list_unsubscribe_url: Option<&'a str>,
+    /// Set for subscriber notifications so they can be held for a digest.
+    pub subscriber_id: Option<Uuid>,
+    pub bypass_digest: bool,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct DueDigest {
+    pub subscriber_id: Uuid,
+    pub org_id: Uuid,
+    /// `None` for subscribers of the primary page.
+    pub status_page_id: Option<Uuid>,
+    pub email: String,
+    pub unsubscribe_token: String,
+    pub digest_frequency: DigestFrequency,
+    pub locale: Option<Locale>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct HeldNotification {
+    pub id: Uuid,
+    pub notification_type: String,
+    pub subject: Option<String>,
+    /// `created_at` rendered in the subscriber's timezone.
+    pub occurred_at_local: String,
+}
+
+pub async fn enqueue(pool: &PgPool, notification: NewNotification<'_>) -> Result<(), AppError> {
     sqlx::query(
         r#"
         INSERT INTO notification_logs (
@@ -56,18 +86,34 @@ pub async fn enqueue(
             recipient_email,
             subject,
             body_text,
+            body_html,
+            list_unsubscribe_url,
+            subscriber_id,
+            bypass_digest,
             status,
             next_retry_at
         )
-        VALUES ($1, $2, $3, $4, $5, $6, 'pending', NOW())
+        VALUES (
+            $1, $2, $3, $4, $5, $6, $7, $8, $9, $10,
+            CASE
+                WHEN $9::uuid IS NOT NULL AND NOT $10 AND subscriber_holds_notifications($9)
+                    THEN 'held'
+                ELSE 'pending'
+            END,
+            NOW()
+        )
         "#,
     )
-    .bind(org_id)
-    .bind(notification_type)
-    .bind(recipient_type)
-    .bind(recipient_email)
-    .bind(subject)
-    .bind(body_text)
+    .bind(notification.org_id)
+    .bind(notification.notification_type)
+    .bind(notification.recipient_type)
+    .bind(notification.recipient_email)
+    .bind(notification.subject)
+    .bind(notification.body_text)
+    .bind(notification.body_html)
+    .bind(notification.list_unsubscribe_url)
+    .bind(notification.subscriber_id)
+    .bind(notification.bypass_digest)
     .execute(pool)
     .await?;
 
@@ -100,6 +146,8 @@ pub async fn claim_pending(
             nl.recipient_email,
             nl.subject,
             nl.body_text,
+            nl.body_html,
+            nl.list_unsubscribe_url,
             nl.attempt_count,
             nl.max_attempts
         "#,
@@ -272,3 +320,162 @@ pub async fn retry_failed_by_id(
 
     Ok(entry)
 }
+
+/// Subscribers outside their quiet hours whose oldest held notification has
+/// waited a full digest window (and at least a window since the last digest).
+/// Immediate subscribers only accumulate held rows during quiet hours, so
+/// theirs are flushed as soon as the quiet window ends.
+pub async fn find_due_digests(pool: &PgPool, limit: i64) -> Result<Vec<DueDigest>, AppError> {
+    let due = sqlx::query_as::<_, DueDigest>(
+        r#"
+        SELECT
+            s.id AS subscriber_id,
+            s.org_id,
+            s.status_page_id,
+            s.email,
+            s.unsubscribe_token,
+            s.digest_frequency,
+            s.locale
+        FROM subscribers s
+        JOIN public_status_pages p
+          ON p.org_id = s.org_id
+         AND p.page_id IS NOT DISTINCT FROM s.status_page_id
+        JOIN LATERAL (
+            SELECT MIN(nl.created_at) AS oldest_held_at
+            FROM notification_logs nl
+            WHERE nl.subscriber_id = s.id
+              AND nl.status = 'held'
+        ) held ON held.oldest_held_at IS NOT NULL
+        WHERE s.is_verified = TRUE
+          AND NOT subscriber_in_quiet_hours(s.timezone, s.quiet_hours_start, s.quiet_hours_end, NOW())
+          AND GREATEST(s.last_digest_sent_at, held.oldest_held_at)
+              + CASE s.digest_frequency
+                    WHEN 'hourly' THEN INTERVAL '1 hour'
+                    WHEN 'daily' THEN INTERVAL '1 day'
+                    ELSE INTERVAL '0'
+                END <= NOW()
+        ORDER BY held.oldest_held_at ASC
+        LIMIT $1
+        "#,
+    )
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(due)
+}
+
+pub async fn list_held_for_subscriber(
+    pool: &PgPool,
+    subscriber_id: Uuid,
+) -> Result<Vec<HeldNotification>, AppError> {
+    let held = sqlx::query_as::<_, HeldNotification>(
+        r#"
+        SELECT
+            nl.id,
+            nl.notification_type,
+            nl.subject,
+            to_char(nl.created_at AT TIME ZONE s.timezone, 'YYYY-MM-DD HH24:MI') || ' ' || s.timezone
+                AS occurred_at_local
+        FROM notification_logs nl
+        JOIN subscribers s ON s.id = nl.subscriber_id
+        WHERE nl.subscriber_id = $1
+          AND nl.status = 'held'
+        ORDER BY nl.created_at ASC
+        "#,
+    )
+    .bind(subscriber_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(held)
+}
+
+/// Queues the digest email and folds the held rows into it. Returns `false`
+/// without writing anything if another worker already claimed any of the
+/// held rows.
+pub async fn record_digest(
+    pool: &PgPool,
+    subscriber_id: Uuid,
+    held_ids: &[Uuid],
+    digest: NewNotification<'_>,
+) -> Result<bool, AppError> {
+    let mut tx = pool.begin().await?;
+
+    let digest_id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO notification_logs (
+            org_id,
+            notification_type,
+            recipient_type,
+            recipient_email,
+            subject,
+            body_text,
+            body_html,
+            list_unsubscribe_url,
+            subscriber_id,
+            bypass_digest,
+            status,
+            next_retry_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, TRUE, 'pending', NOW())
+        RETURNING id
+        "#,
+    )
+    .bind(digest.org_id)
+    .bind(digest.notification_type)
+    .bind(digest.recipient_type)
+    .bind(digest.recipient_email)
+    .bind(digest.subject)
+    .bind(digest.body_text)
+    .bind(digest.body_html)
+    .bind(digest.list_unsubscribe_url)
+    .bind(digest.subscriber_id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let folded = sqlx::query(
+        r#"
+        UPDATE notification_logs
+        SET status = 'digested', digest_id = $3
+        WHERE subscriber_id = $1
+          AND id = ANY($2)
+          AND status = 'held'
+        "#,
+    )
+    .bind(subscriber_id)
+    .bind(held_ids)
+    .bind(digest_id)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    if folded != held_ids.len() as u64 {
+        tx.rollback().await?;
+        return Ok(false);
+    }
+
+    sqlx::query("UPDATE subscribers SET last_digest_sent_at = NOW() WHERE id = $1")
+        .bind(subscriber_id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    Ok(true)
+}
+
+/// Held rows whose subscriber has since unsubscribed can never be delivered.
+pub async fn cancel_orphaned_held(pool: &PgPool) -> Result<u64, AppError> {
+    let result = sqlx::query(
+        r#"
+        UPDATE notification_logs
+        SET status = 'canceled', error_message = 'Subscriber no longer exists'
+        WHERE status = 'held'
+          AND subscriber_id IS NULL
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
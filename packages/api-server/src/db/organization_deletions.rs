@@ -0,0 +1,318 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use shared::enums::OrganizationDeletionStatus;
+use shared::error::AppError;
+use shared::models::organization::Organization;
+
+/// A deletion request. `scheduled_for` is when the purge may next run: the
+/// end of the cool-off period at first, then a lease or retry time while
+/// the purge is processing.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct OrganizationDeletion {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub org_slug: String,
+    pub org_name: String,
+    pub requested_by: Option<Uuid>,
+    pub requested_by_email: Option<String>,
+    pub reason: Option<String>,
+    pub status: OrganizationDeletionStatus,
+    pub scheduled_for: DateTime<Utc>,
+    pub canceled_at: Option<DateTime<Utc>>,
+    pub canceled_by: Option<Uuid>,
+    pub stripe_subscription_id: Option<String>,
+    pub stripe_cancellation: Option<String>,
+    pub purge_summary: serde_json::Value,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Rows removed by a purge beyond the organization's cascading deletes.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PurgeSummary {
+    pub services: i64,
+    pub monitors: i64,
+    pub incidents: i64,
+    pub subscribers: u64,
+    pub monitor_checks: u64,
+    pub uptime_days: u64,
+    pub certificates: u64,
+}
+
+const ACTIVE_STATUSES: &str = "('scheduled', 'processing')";
+
+pub async fn schedule(
+    pool: &PgPool,
+    org: &Organization,
+    requested_by: Uuid,
+    requested_by_email: &str,
+    reason: Option<&str>,
+    cool_off_hours: i64,
+) -> Result<OrganizationDeletion, AppError> {
+    sqlx::query_as::<_, OrganizationDeletion>(&format!(
+        r#"
+        INSERT INTO organization_deletions (
+            org_id, org_slug, org_name, requested_by, requested_by_email, reason,
+            stripe_subscription_id, scheduled_for
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, NOW() + make_interval(hours => $8::int))
+        ON CONFLICT (org_id) WHERE status IN {ACTIVE_STATUSES} DO NOTHING
+        RETURNING *
+        "#
+    ))
+    .bind(org.id)
+    .bind(&org.slug)
+    .bind(&org.name)
+    .bind(requested_by)
+    .bind(requested_by_email)
+    .bind(reason)
+    .bind(&org.stripe_subscription_id)
+    .bind(cool_off_hours as i32)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| {
+        AppError::Conflict("Deletion is already scheduled for this organization".to_string())
+    })
+}
+
+pub async fn find_active(
+    pool: &PgPool,
+    org_id: Uuid,
+) -> Result<Option<OrganizationDeletion>, AppError> {
+    let deletion = sqlx::query_as::<_, OrganizationDeletion>(&format!(
+        "SELECT * FROM organization_deletions WHERE org_id = $1 AND status IN {ACTIVE_STATUSES}"
+    ))
+    .bind(org_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(deletion)
+}
+
+/// Cancels a deletion still in its cool-off period. Returns `None` when
+/// there is nothing left to cancel.
+pub async fn cancel(
+    pool: &PgPool,
+    org_id: Uuid,
+    canceled_by: Uuid,
+) -> Result<Option<OrganizationDeletion>, AppError> {
+    let deletion = sqlx::query_as::<_, OrganizationDeletion>(
+        r#"
+        UPDATE organization_deletions
+        SET status = 'canceled', canceled_at = NOW(), canceled_by = $2, updated_at = NOW()
+        WHERE org_id = $1 AND status = 'scheduled'
+        RETURNING *
+        "#,
+    )
+    .bind(org_id)
+    .bind(canceled_by)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(deletion)
+}
+
+pub async fn list_recent(
+    pool: &PgPool,
+    status: Option<OrganizationDeletionStatus>,
+    limit: i64,
+) -> Result<Vec<OrganizationDeletion>, AppError> {
+    let deletions = sqlx::query_as::<_, OrganizationDeletion>(
+        r#"
+        SELECT *
+        FROM organization_deletions
+        WHERE ($1::varchar IS NULL OR status = $1)
+        ORDER BY created_at DESC
+        LIMIT $2
+        "#,
+    )
+    .bind(status)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(deletions)
+}
+
+/// Claims deletions whose cool-off (or retry delay) has passed, moving them
+/// to `processing` and leasing them for `lease_minutes`.
+pub async fn claim_due(
+    pool: &PgPool,
+    limit: i64,
+    lease_minutes: i32,
+) -> Result<Vec<OrganizationDeletion>, AppError> {
+    let claimed = sqlx::query_as::<_, OrganizationDeletion>(&format!(
+        r#"
+        UPDATE organization_deletions
+        SET status = 'processing',
+            attempts = attempts + 1,
+            scheduled_for = NOW() + make_interval(mins => $2::int),
+            updated_at = NOW()
+        WHERE id IN (
+            SELECT id
+            FROM organization_deletions
+            WHERE status IN {ACTIVE_STATUSES} AND scheduled_for <= NOW()
+            ORDER BY scheduled_for
+            LIMIT $1
+            FOR UPDATE SKIP LOCKED
+        )
+        RETURNING *
+        "#
+    ))
+    .bind(limit)
+    .bind(lease_minutes)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(claimed)
+}
+
+pub async fn record_stripe_cancellation(
+    pool: &PgPool,
+    deletion_id: Uuid,
+    cancellation: &str,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        UPDATE organization_deletions
+        SET stripe_cancellation = $2, updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(deletion_id)
+    .bind(cancellation)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn record_failure(
+    pool: &PgPool,
+    deletion_id: Uuid,
+    error: &str,
+    retry_in_minutes: i32,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        UPDATE organization_deletions
+        SET last_error = $2,
+            scheduled_for = NOW() + make_interval(mins => $3::int),
+            updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(deletion_id)
+    .bind(error)
+    .bind(retry_in_minutes)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Deletes the organization in one transaction. Check history, daily
+/// uptime rollups and certificates are keyed by monitor or domain rather
+/// than organization, so they are removed explicitly before the cascade.
+pub async fn purge(
+    pool: &PgPool,
+    deletion_id: Uuid,
+    org_id: Uuid,
+) -> Result<PurgeSummary, AppError> {
+    let mut tx = pool.begin().await?;
+
+    let (services, monitors, incidents) = sqlx::query_as::<_, (i64, i64, i64)>(
+        r#"
+        SELECT
+            (SELECT COUNT(*) FROM services WHERE org_id = $1),
+            (SELECT COUNT(*) FROM monitors WHERE org_id = $1),
+            (SELECT COUNT(*) FROM incidents WHERE org_id = $1)
+        "#,
+    )
+    .bind(org_id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let monitor_checks = sqlx::query(
+        "DELETE FROM monitor_checks WHERE monitor_id IN (SELECT id FROM monitors WHERE org_id = $1)",
+    )
+    .bind(org_id)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    let uptime_days = sqlx::query(
+        "DELETE FROM uptime_daily WHERE monitor_id IN (SELECT id FROM monitors WHERE org_id = $1)",
+    )
+    .bind(org_id)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    let subscribers = sqlx::query("DELETE FROM subscribers WHERE org_id = $1")
+        .bind(org_id)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+    let domains = r#"
+        SELECT lower(custom_domain) FROM organizations
+        WHERE id = $1 AND custom_domain IS NOT NULL
+        UNION
+        SELECT lower(custom_domain) FROM status_pages
+        WHERE org_id = $1 AND custom_domain IS NOT NULL
+    "#;
+    sqlx::query(&format!(
+        "DELETE FROM acme_http_challenges WHERE domain IN ({domains})"
+    ))
+    .bind(org_id)
+    .execute(&mut *tx)
+    .await?;
+    let certificates = sqlx::query(&format!(
+        "DELETE FROM custom_domain_certificates WHERE domain IN ({domains})"
+    ))
+    .bind(org_id)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    sqlx::query("DELETE FROM organizations WHERE id = $1")
+        .bind(org_id)
+        .execute(&mut *tx)
+        .await?;
+
+    let summary = PurgeSummary {
+        services,
+        monitors,
+        incidents,
+        subscribers,
+        monitor_checks,
+        uptime_days,
+        certificates,
+    };
+
+    sqlx::query(
+        r#"
+        UPDATE organization_deletions
+        SET status = 'completed',
+            purge_summary = $2,
+            last_error = NULL,
+            completed_at = NOW(),
+            updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(deletion_id)
+    .bind(sqlx::types::Json(&summary))
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(summary)
+}
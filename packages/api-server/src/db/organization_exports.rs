@@ -0,0 +1,305 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use shared::enums::ExportStatus;
+use shared::error::AppError;
+use shared::models::incident::Incident;
+use shared::models::incident_update::IncidentUpdate;
+
+use crate::db::audit_logs::AuditLogEntry;
+
+/// An export job without its archive bytes.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct OrganizationExport {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub requested_by: Option<Uuid>,
+    pub status: ExportStatus,
+    pub archive_size_bytes: Option<i64>,
+    pub error: Option<String>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ClaimedExport {
+    pub id: Uuid,
+    pub org_id: Uuid,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UptimeHistoryRow {
+    pub monitor_id: Uuid,
+    pub service_name: String,
+    pub date: NaiveDate,
+    pub total_checks: i32,
+    pub successful_checks: i32,
+    pub uptime_percentage: Option<f64>,
+    pub avg_response_time_ms: Option<f64>,
+    pub min_response_time_ms: Option<i32>,
+    pub max_response_time_ms: Option<i32>,
+}
+
+const EXPORT_COLUMNS: &str = "id, org_id, requested_by, status, archive_size_bytes, error, \
+     started_at, completed_at, expires_at, created_at";
+
+/// Exports stuck in `running` this long are assumed to belong to a worker
+/// that died and are picked up again.
+const STALE_RUNNING_MINUTES: i32 = 30;
+
+pub async fn create(
+    pool: &PgPool,
+    org_id: Uuid,
+    requested_by: Uuid,
+) -> Result<OrganizationExport, AppError> {
+    let export = sqlx::query_as::<_, OrganizationExport>(&format!(
+        r#"
+        INSERT INTO organization_exports (org_id, requested_by)
+        VALUES ($1, $2)
+        RETURNING {EXPORT_COLUMNS}
+        "#
+    ))
+    .bind(org_id)
+    .bind(requested_by)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(export)
+}
+
+pub async fn has_unfinished(pool: &PgPool, org_id: Uuid) -> Result<bool, AppError> {
+    let unfinished = sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM organization_exports
+            WHERE org_id = $1 AND status IN ('pending', 'running')
+        )
+        "#,
+    )
+    .bind(org_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(unfinished)
+}
+
+pub async fn list_by_org(
+    pool: &PgPool,
+    org_id: Uuid,
+    limit: i64,
+) -> Result<Vec<OrganizationExport>, AppError> {
+    let exports = sqlx::query_as::<_, OrganizationExport>(&format!(
+        r#"
+        SELECT {EXPORT_COLUMNS}
+        FROM organization_exports
+        WHERE org_id = $1
+        ORDER BY created_at DESC
+        LIMIT $2
+        "#
+    ))
+    .bind(org_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(exports)
+}
+
+pub async fn find_by_id(
+    pool: &PgPool,
+    org_id: Uuid,
+    export_id: Uuid,
+) -> Result<OrganizationExport, AppError> {
+    sqlx::query_as::<_, OrganizationExport>(&format!(
+        "SELECT {EXPORT_COLUMNS} FROM organization_exports WHERE id = $1 AND org_id = $2"
+    ))
+    .bind(export_id)
+    .bind(org_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Export not found".to_string()))
+}
+
+/// Archive bytes of a completed export that has not expired yet.
+pub async fn find_archive(
+    pool: &PgPool,
+    org_id: Uuid,
+    export_id: Uuid,
+) -> Result<Option<Vec<u8>>, AppError> {
+    let archive = sqlx::query_scalar::<_, Option<Vec<u8>>>(
+        r#"
+        SELECT archive
+        FROM organization_exports
+        WHERE id = $1
+          AND org_id = $2
+          AND status = 'completed'
+          AND expires_at > NOW()
+        "#,
+    )
+    .bind(export_id)
+    .bind(org_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(archive.flatten())
+}
+
+/// Claims pending exports, plus running ones whose worker went away.
+pub async fn claim_pending(pool: &PgPool, limit: i64) -> Result<Vec<ClaimedExport>, AppError> {
+    let claimed = sqlx::query_as::<_, ClaimedExport>(
+        r#"
+        UPDATE organization_exports
+        SET status = 'running', started_at = NOW()
+        WHERE id IN (
+            SELECT id
+            FROM organization_exports
+            WHERE status = 'pending'
+               OR (status = 'running' AND started_at <= NOW() - make_interval(mins => $2::int))
+            ORDER BY created_at
+            LIMIT $1
+            FOR UPDATE SKIP LOCKED
+        )
+        RETURNING id, org_id
+        "#,
+    )
+    .bind(limit)
+    .bind(STALE_RUNNING_MINUTES)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(claimed)
+}
+
+pub async fn complete(
+    pool: &PgPool,
+    export_id: Uuid,
+    archive: &[u8],
+    retention_hours: i64,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        UPDATE organization_exports
+        SET status = 'completed',
+            archive = $2,
+            archive_size_bytes = $3,
+            error = NULL,
+            completed_at = NOW(),
+            expires_at = NOW() + make_interval(hours => $4::int)
+        WHERE id = $1
+        "#,
+    )
+    .bind(export_id)
+    .bind(archive)
+    .bind(archive.len() as i64)
+    .bind(retention_hours as i32)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn fail(pool: &PgPool, export_id: Uuid, error: &str) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        UPDATE organization_exports
+        SET status = 'failed', error = $2, completed_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(export_id)
+    .bind(error)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Drops archives past their retention window, keeping the job rows.
+pub async fn expire_archives(pool: &PgPool) -> Result<u64, AppError> {
+    let result = sqlx::query(
+        r#"
+        UPDATE organization_exports
+        SET status = 'expired', archive = NULL
+        WHERE status = 'completed' AND expires_at <= NOW()
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+pub async fn list_incidents(pool: &PgPool, org_id: Uuid) -> Result<Vec<Incident>, AppError> {
+    let incidents = sqlx::query_as::<_, Incident>(
+        "SELECT * FROM incidents WHERE org_id = $1 ORDER BY started_at ASC, created_at ASC",
+    )
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(incidents)
+}
+
+pub async fn list_incident_updates(
+    pool: &PgPool,
+    org_id: Uuid,
+) -> Result<Vec<IncidentUpdate>, AppError> {
+    let updates = sqlx::query_as::<_, IncidentUpdate>(
+        r#"
+        SELECT iu.*
+        FROM incident_updates iu
+        JOIN incidents i ON i.id = iu.incident_id
+        WHERE i.org_id = $1
+        ORDER BY iu.created_at ASC
+        "#,
+    )
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(updates)
+}
+
+pub async fn list_audit_logs(pool: &PgPool, org_id: Uuid) -> Result<Vec<AuditLogEntry>, AppError> {
+    let entries = sqlx::query_as::<_, AuditLogEntry>(
+        r#"
+        SELECT id, org_id, actor_user_id, actor_type, action, target_type, target_id,
+               details, created_at
+        FROM audit_logs
+        WHERE org_id = $1
+        ORDER BY created_at ASC
+        "#,
+    )
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(entries)
+}
+
+pub async fn list_uptime_history(
+    pool: &PgPool,
+    org_id: Uuid,
+) -> Result<Vec<UptimeHistoryRow>, AppError> {
+    let rows = sqlx::query_as::<_, UptimeHistoryRow>(
+        r#"
+        SELECT ud.monitor_id, s.name AS service_name, ud.date, ud.total_checks,
+               ud.successful_checks, ud.uptime_percentage, ud.avg_response_time_ms,
+               ud.min_response_time_ms, ud.max_response_time_ms
+        FROM uptime_daily ud
+        JOIN monitors m ON m.id = ud.monitor_id
+        JOIN services s ON s.id = m.service_id
+        WHERE m.org_id = $1
+        ORDER BY s.name ASC, ud.monitor_id, ud.date ASC
+        "#,
+    )
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
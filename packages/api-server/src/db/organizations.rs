@@ -1,5 +1,8 @@
 use chrono::{DateTime, Utc};
-use shared::enums::{CustomDomainStatus, DowngradeState, OrganizationPlan, SubscriptionStatus};
+use shared::enums::{
+    CustomDomainStatus, CustomDomainVerificationMethod, DowngradeState, OrganizationPlan,
+    SubscriptionStatus,
+};
 use shared::error::AppError;
 use shared::models::organization::{
     CreateOrganizationRequest, Organization, UpdateOrganizationRequest,
@@ -109,6 +112,7 @@ pub async fn update(
             slug = COALESCE($3, slug),
             brand_color = COALESCE($4, brand_color),
             timezone = COALESCE($5, timezone),
+            locale = COALESCE($8, locale),
             logo_url = CASE WHEN $6 IS NOT NULL THEN NULLIF($6, '') ELSE logo_url END,
             custom_domain = CASE WHEN $7 IS NOT NULL THEN NULLIF($7, '') ELSE custom_domain END,
             custom_domain_verified_at = CASE
@@ -119,9 +123,23 @@ pub async fn update(
                 WHEN $7 IS NULL THEN custom_domain_status
                 WHEN NULLIF($7, '') IS NULL THEN 'not_configured'
                 WHEN NULLIF($7, '') IS DISTINCT FROM custom_domain THEN 'pending_verification'
-                WHEN custom_domain_verified_at IS NOT NULL THEN 'verified'
+                WHEN custom_domain_verified_at IS NOT NULL
+                     AND custom_domain_status IN ('not_configured', 'pending_verification')
+                    THEN 'verified'
                 ELSE custom_domain_status
             END,
+            custom_domain_verification_method = CASE
+                WHEN $7 IS NOT NULL AND NULLIF($7, '') IS DISTINCT FROM custom_domain THEN NULL
+                ELSE custom_domain_verification_method
+            END,
+            custom_domain_check_failures = CASE
+                WHEN $7 IS NOT NULL AND NULLIF($7, '') IS DISTINCT FROM custom_domain THEN 0
+                ELSE custom_domain_check_failures
+            END,
+            custom_domain_grace_ends_at = CASE
+                WHEN $7 IS NOT NULL AND NULLIF($7, '') IS DISTINCT FROM custom_domain THEN NULL
+                ELSE custom_domain_grace_ends_at
+            END,
             updated_at = NOW()
         WHERE id = $1
         RETURNING *
@@ -134,6 +152,7 @@ pub async fn update(
     .bind(&req.timezone)
     .bind(&req.logo_url)
     .bind(&req.custom_domain)
+    .bind(req.locale)
     .fetch_one(pool)
     .await?;
 
@@ -252,6 +271,9 @@ pub async fn clear_custom_domain(pool: &PgPool, org_id: Uuid) -> Result<(), AppE
             custom_domain = NULL,
             custom_domain_verified_at = NULL,
             custom_domain_status = 'not_configured',
+            custom_domain_verification_method = NULL,
+            custom_domain_check_failures = 0,
+            custom_domain_grace_ends_at = NULL,
             updated_at = NOW()
         WHERE id = $1 AND custom_domain IS NOT NULL
         "#,
@@ -266,6 +288,7 @@ pub async fn clear_custom_domain(pool: &PgPool, org_id: Uuid) -> Result<(), AppE
 pub async fn mark_custom_domain_verified(
     pool: &PgPool,
     org_id: Uuid,
+    method: CustomDomainVerificationMethod,
 ) -> Result<Organization, AppError> {
     let org = sqlx::query_as::<_, Organization>(
         r#"
@@ -273,24 +296,35 @@ pub async fn mark_custom_domain_verified(
         SET
             custom_domain_verified_at = NOW(),
             custom_domain_status = 'verified',
+            custom_domain_verification_method = $2,
+            custom_domain_checked_at = NOW(),
+            custom_domain_check_failures = 0,
+            custom_domain_grace_ends_at = NULL,
             updated_at = NOW()
         WHERE id = $1
         RETURNING *
         "#,
     )
     .bind(org_id)
+    .bind(method)
     .fetch_one(pool)
     .await?;
 
     Ok(org)
 }
 
+/// Whether an organization or status page already uses `slug`; both are
+/// served under `/s/{slug}`.
 pub async fn slug_exists(pool: &PgPool, slug: &str) -> Result<bool, AppError> {
-    let exists =
-        sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM organizations WHERE slug = $1)")
-            .bind(slug)
-            .fetch_one(pool)
-            .await?;
+    let exists = sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT EXISTS(SELECT 1 FROM organizations WHERE slug = $1)
+            OR EXISTS(SELECT 1 FROM status_pages WHERE slug = $1)
+        "#,
+    )
+    .bind(slug)
+    .fetch_one(pool)
+    .await?;
 
     Ok(exists)
 }
@@ -515,10 +549,13 @@ pub async fn cancel_downgrade(
     Ok(org)
 }
 
+/// Whether an organization or status page other than the excluded one
+/// already claims `custom_domain`.
 pub async fn custom_domain_exists(
     pool: &PgPool,
     custom_domain: &str,
     exclude_org_id: Option<Uuid>,
+    exclude_page_id: Option<Uuid>,
 ) -> Result<bool, AppError> {
     let normalized = custom_domain
         .trim()
@@ -531,11 +568,17 @@ pub async fn custom_domain_exists(
             FROM organizations
             WHERE lower(custom_domain) = $1
               AND ($2::uuid IS NULL OR id != $2)
+        ) OR EXISTS(
+            SELECT 1
+            FROM status_pages
+            WHERE lower(custom_domain) = $1
+              AND ($3::uuid IS NULL OR id != $3)
         )
         "#,
     )
     .bind(normalized)
     .bind(exclude_org_id)
+    .bind(exclude_page_id)
     .fetch_one(pool)
     .await?;
 
@@ -0,0 +1,182 @@
+use chrono::{DateTime, Utc};
+use shared::enums::PageVisibility;
+use shared::error::AppError;
+use shared::models::organization::Organization;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// The columns needed to decide whether a viewer may read a status page.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PageAccess {
+    pub org_id: Uuid,
+    /// `None` for an organization's primary page.
+    pub page_id: Option<Uuid>,
+    pub slug: String,
+    pub page_visibility: PageVisibility,
+    pub page_password_hash: Option<String>,
+    pub page_allowed_email_domains: Vec<String>,
+    pub page_allowed_cidrs: Vec<String>,
+    pub viewer_session_secret: String,
+}
+
+const PAGE_ACCESS_COLUMNS: &str = "org_id, page_id, slug, page_visibility, page_password_hash, \
+     page_allowed_email_domains, page_allowed_cidrs, viewer_session_secret";
+
+pub async fn find_by_slug(pool: &PgPool, slug: &str) -> Result<Option<PageAccess>, AppError> {
+    let access = sqlx::query_as::<_, PageAccess>(&format!(
+        "SELECT {PAGE_ACCESS_COLUMNS} FROM public_status_pages WHERE slug = $1"
+    ))
+    .bind(slug)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(access)
+}
+
+pub async fn find_by_org(pool: &PgPool, org_id: Uuid) -> Result<PageAccess, AppError> {
+    find_by_page(pool, org_id, None).await
+}
+
+/// Access settings of one page; `page_id` of `None` is the primary page.
+pub async fn find_by_page(
+    pool: &PgPool,
+    org_id: Uuid,
+    page_id: Option<Uuid>,
+) -> Result<PageAccess, AppError> {
+    sqlx::query_as::<_, PageAccess>(&format!(
+        r#"
+        SELECT {PAGE_ACCESS_COLUMNS} FROM public_status_pages
+        WHERE org_id = $1 AND page_id IS NOT DISTINCT FROM $2
+        "#
+    ))
+    .bind(org_id)
+    .bind(page_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))
+}
+
+/// Saves new access settings and rotates the session secret, so every
+/// existing viewer session stops working. `password_hash` of `None` keeps
+/// the current password.
+pub async fn update(
+    pool: &PgPool,
+    org_id: Uuid,
+    visibility: PageVisibility,
+    password_hash: Option<&str>,
+    allowed_email_domains: &[String],
+    allowed_cidrs: &[String],
+) -> Result<Organization, AppError> {
+    let org = sqlx::query_as::<_, Organization>(
+        r#"
+        UPDATE organizations SET
+            page_visibility = $2,
+            page_password_hash = COALESCE($3, page_password_hash),
+            page_allowed_email_domains = $4,
+            page_allowed_cidrs = $5,
+            viewer_session_secret = replace(gen_random_uuid()::text || gen_random_uuid()::text, '-', ''),
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING *
+        "#,
+    )
+    .bind(org_id)
+    .bind(visibility)
+    .bind(password_hash)
+    .bind(allowed_email_domains)
+    .bind(allowed_cidrs)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(org)
+}
+
+/// [`update`] for an additional status page.
+pub async fn update_status_page(
+    pool: &PgPool,
+    page_id: Uuid,
+    org_id: Uuid,
+    visibility: PageVisibility,
+    password_hash: Option<&str>,
+    allowed_email_domains: &[String],
+    allowed_cidrs: &[String],
+) -> Result<(), AppError> {
+    let result = sqlx::query(
+        r#"
+        UPDATE status_pages SET
+            page_visibility = $3,
+            page_password_hash = COALESCE($4, page_password_hash),
+            page_allowed_email_domains = $5,
+            page_allowed_cidrs = $6,
+            viewer_session_secret = replace(gen_random_uuid()::text || gen_random_uuid()::text, '-', ''),
+            updated_at = NOW()
+        WHERE id = $1 AND org_id = $2
+        "#,
+    )
+    .bind(page_id)
+    .bind(org_id)
+    .bind(visibility)
+    .bind(password_hash)
+    .bind(allowed_email_domains)
+    .bind(allowed_cidrs)
+    .execute(pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound("Status page not found".to_string()));
+    }
+
+    Ok(())
+}
+
+pub async fn create_login_link(
+    pool: &PgPool,
+    access: &PageAccess,
+    email: &str,
+    token_hash: &str,
+    expires_at: DateTime<Utc>,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        INSERT INTO viewer_login_links (org_id, status_page_id, email, token_hash, expires_at)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+    )
+    .bind(access.org_id)
+    .bind(access.page_id)
+    .bind(email)
+    .bind(token_hash)
+    .bind(expires_at)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Marks an unexpired, unused link as used and returns its email. Each link
+/// works once.
+pub async fn consume_login_link(
+    pool: &PgPool,
+    access: &PageAccess,
+    token_hash: &str,
+) -> Result<Option<String>, AppError> {
+    let email = sqlx::query_scalar::<_, String>(
+        r#"
+        UPDATE viewer_login_links
+        SET consumed_at = NOW()
+        WHERE org_id = $1
+          AND status_page_id IS NOT DISTINCT FROM $2
+          AND token_hash = $3
+          AND consumed_at IS NULL
+          AND expires_at > NOW()
+        RETURNING email
+        "#,
+    )
+    .bind(access.org_id)
+    .bind(access.page_id)
+    .bind(token_hash)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(email)
+}
@@ -0,0 +1,163 @@
+use shared::error::AppError;
+use shared::models::service::{dependency_creates_cycle, ServiceDependency};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+pub async fn list_by_service(
+    pool: &PgPool,
+    service_id: Uuid,
+    org_id: Uuid,
+) -> Result<Vec<ServiceDependency>, AppError> {
+    let dependencies = sqlx::query_as::<_, ServiceDependency>(
+        r#"
+        SELECT
+            d.service_id,
+            d.depends_on_service_id,
+            up.name AS depends_on_service_name,
+            d.created_at
+        FROM service_dependencies d
+        JOIN services s ON s.id = d.service_id
+        JOIN services up ON up.id = d.depends_on_service_id
+        WHERE d.service_id = $1 AND s.org_id = $2
+        ORDER BY up.display_order, up.name
+        "#,
+    )
+    .bind(service_id)
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(dependencies)
+}
+
+pub async fn add(
+    pool: &PgPool,
+    org_id: Uuid,
+    service_id: Uuid,
+    depends_on_service_id: Uuid,
+) -> Result<ServiceDependency, AppError> {
+    let mut tx = pool.begin().await?;
+
+    // Serialize graph edits per organization so two concurrent inserts
+    // cannot each pass the cycle check and close a loop together.
+    sqlx::query("SELECT id FROM organizations WHERE id = $1 FOR UPDATE")
+        .bind(org_id)
+        .execute(&mut *tx)
+        .await?;
+
+    let found: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM services WHERE org_id = $1 AND id IN ($2, $3)")
+            .bind(org_id)
+            .bind(service_id)
+            .bind(depends_on_service_id)
+            .fetch_one(&mut *tx)
+            .await?;
+    let expected = if service_id == depends_on_service_id {
+        1
+    } else {
+        2
+    };
+    if found != expected {
+        return Err(AppError::NotFound("Service not found".to_string()));
+    }
+
+    let edges = sqlx::query_as::<_, (Uuid, Uuid)>(
+        r#"
+        SELECT d.service_id, d.depends_on_service_id
+        FROM service_dependencies d
+        JOIN services s ON s.id = d.service_id
+        WHERE s.org_id = $1
+        "#,
+    )
+    .bind(org_id)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    if edges.contains(&(service_id, depends_on_service_id)) {
+        return Err(AppError::Conflict(
+            "Service already depends on this service".to_string(),
+        ));
+    }
+    if dependency_creates_cycle(&edges, service_id, depends_on_service_id) {
+        return Err(AppError::Validation(
+            "Adding this dependency would create a cycle".to_string(),
+        ));
+    }
+
+    sqlx::query(
+        "INSERT INTO service_dependencies (service_id, depends_on_service_id) VALUES ($1, $2)",
+    )
+    .bind(service_id)
+    .bind(depends_on_service_id)
+    .execute(&mut *tx)
+    .await?;
+
+    refresh_upstream_impact(&mut *tx, org_id).await?;
+
+    let dependency = sqlx::query_as::<_, ServiceDependency>(
+        r#"
+        SELECT
+            d.service_id,
+            d.depends_on_service_id,
+            up.name AS depends_on_service_name,
+            d.created_at
+        FROM service_dependencies d
+        JOIN services up ON up.id = d.depends_on_service_id
+        WHERE d.service_id = $1 AND d.depends_on_service_id = $2
+        "#,
+    )
+    .bind(service_id)
+    .bind(depends_on_service_id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(dependency)
+}
+
+pub async fn remove(
+    pool: &PgPool,
+    org_id: Uuid,
+    service_id: Uuid,
+    depends_on_service_id: Uuid,
+) -> Result<(), AppError> {
+    let mut tx = pool.begin().await?;
+
+    let result = sqlx::query(
+        r#"
+        DELETE FROM service_dependencies d
+        USING services s
+        WHERE s.id = d.service_id
+          AND s.org_id = $1
+          AND d.service_id = $2
+          AND d.depends_on_service_id = $3
+        "#,
+    )
+    .bind(org_id)
+    .bind(service_id)
+    .bind(depends_on_service_id)
+    .execute(&mut *tx)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound("Dependency not found".to_string()));
+    }
+
+    refresh_upstream_impact(&mut *tx, org_id).await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Recomputes which services are degraded by an upstream outage. Call after
+/// any change to a service status or to the dependency graph.
+pub async fn refresh_upstream_impact<'e, E>(executor: E, org_id: Uuid) -> Result<i32, AppError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let changed: i32 = sqlx::query_scalar("SELECT refresh_upstream_impact($1)")
+        .bind(org_id)
+        .fetch_one(executor)
+        .await?;
+
+    Ok(changed)
+}
@@ -0,0 +1,252 @@
+use shared::error::AppError;
+use shared::models::service_group::{
+    CreateServiceGroupRequest, ServiceGroup, UpdateServiceGroupRequest,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+pub async fn find_by_org(pool: &PgPool, org_id: Uuid) -> Result<Vec<ServiceGroup>, AppError> {
+    let groups = sqlx::query_as::<_, ServiceGroup>(
+        "SELECT * FROM service_groups WHERE org_id = $1 ORDER BY display_order, name",
+    )
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(groups)
+}
+
+pub async fn find_by_id(
+    pool: &PgPool,
+    group_id: Uuid,
+    org_id: Uuid,
+) -> Result<Option<ServiceGroup>, AppError> {
+    let group = sqlx::query_as::<_, ServiceGroup>(
+        "SELECT * FROM service_groups WHERE id = $1 AND org_id = $2",
+    )
+    .bind(group_id)
+    .bind(org_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(group)
+}
+
+pub async fn name_exists(
+    pool: &PgPool,
+    org_id: Uuid,
+    name: &str,
+    except_group_id: Option<Uuid>,
+) -> Result<bool, AppError> {
+    let exists: bool = sqlx::query_scalar(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM service_groups
+            WHERE org_id = $1 AND name = $2 AND ($3::UUID IS NULL OR id != $3)
+        )
+        "#,
+    )
+    .bind(org_id)
+    .bind(name)
+    .bind(except_group_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(exists)
+}
+
+pub async fn create(
+    pool: &PgPool,
+    org_id: Uuid,
+    req: &CreateServiceGroupRequest,
+) -> Result<ServiceGroup, AppError> {
+    let group = sqlx::query_as::<_, ServiceGroup>(
+        r#"
+        INSERT INTO service_groups (
+            org_id,
+            name,
+            description,
+            display_order,
+            collapsed_by_default,
+            status_rule
+        )
+        VALUES (
+            $1, $2, $3,
+            (SELECT COALESCE(MAX(display_order), -1) + 1 FROM service_groups WHERE org_id = $1),
+            $4, $5
+        )
+        RETURNING *
+        "#,
+    )
+    .bind(org_id)
+    .bind(req.name.trim())
+    .bind(&req.description)
+    .bind(req.collapsed_by_default.unwrap_or(false))
+    .bind(req.status_rule.unwrap_or_default())
+    .fetch_one(pool)
+    .await?;
+
+    Ok(group)
+}
+
+/// Resolves a legacy free-text `group_name`, creating the group on first use.
+pub async fn find_or_create_by_name(
+    pool: &PgPool,
+    org_id: Uuid,
+    name: &str,
+) -> Result<ServiceGroup, AppError> {
+    let group = sqlx::query_as::<_, ServiceGroup>(
+        r#"
+        INSERT INTO service_groups (org_id, name, display_order)
+        VALUES (
+            $1, $2,
+            (SELECT COALESCE(MAX(display_order), -1) + 1 FROM service_groups WHERE org_id = $1)
+        )
+        ON CONFLICT (org_id, name) DO UPDATE SET name = EXCLUDED.name
+        RETURNING *
+        "#,
+    )
+    .bind(org_id)
+    .bind(name.trim())
+    .fetch_one(pool)
+    .await?;
+
+    Ok(group)
+}
+
+pub async fn update(
+    pool: &PgPool,
+    group_id: Uuid,
+    org_id: Uuid,
+    req: &UpdateServiceGroupRequest,
+) -> Result<ServiceGroup, AppError> {
+    let mut tx = pool.begin().await?;
+
+    let group = sqlx::query_as::<_, ServiceGroup>(
+        r#"
+        UPDATE service_groups SET
+            name = COALESCE($3, name),
+            description = COALESCE($4, description),
+            display_order = COALESCE($5, display_order),
+            collapsed_by_default = COALESCE($6, collapsed_by_default),
+            status_rule = COALESCE($7, status_rule),
+            updated_at = NOW()
+        WHERE id = $1 AND org_id = $2
+        RETURNING *
+        "#,
+    )
+    .bind(group_id)
+    .bind(org_id)
+    .bind(req.name.as_deref().map(str::trim))
+    .bind(&req.description)
+    .bind(req.display_order)
+    .bind(req.collapsed_by_default)
+    .bind(req.status_rule)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Service group not found".to_string()))?;
+
+    // Keep the legacy column readable for older clients
+    sqlx::query(
+        "UPDATE services SET group_name = $2, updated_at = NOW() WHERE group_id = $1 AND group_name IS DISTINCT FROM $2",
+    )
+    .bind(group_id)
+    .bind(&group.name)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(group)
+}
+
+pub async fn delete(pool: &PgPool, group_id: Uuid, org_id: Uuid) -> Result<(), AppError> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        r#"
+        UPDATE services SET group_id = NULL, group_name = NULL, updated_at = NOW()
+        WHERE group_id = $1 AND org_id = $2
+        "#,
+    )
+    .bind(group_id)
+    .bind(org_id)
+    .execute(&mut *tx)
+    .await?;
+
+    let result = sqlx::query("DELETE FROM service_groups WHERE id = $1 AND org_id = $2")
+        .bind(group_id)
+        .bind(org_id)
+        .execute(&mut *tx)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound("Service group not found".to_string()));
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+pub async fn subscriber_group_ids(
+    pool: &PgPool,
+    subscriber_id: Uuid,
+) -> Result<Vec<Uuid>, AppError> {
+    let group_ids = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        SELECT ssg.group_id
+        FROM subscriber_service_groups ssg
+        JOIN service_groups g ON g.id = ssg.group_id
+        WHERE ssg.subscriber_id = $1
+        ORDER BY g.display_order, g.name
+        "#,
+    )
+    .bind(subscriber_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(group_ids)
+}
+
+/// Replaces a subscriber's group subscriptions. An empty list subscribes to
+/// every service again.
+pub async fn set_subscriber_groups(
+    pool: &PgPool,
+    org_id: Uuid,
+    subscriber_id: Uuid,
+    group_ids: &[Uuid],
+) -> Result<(), AppError> {
+    let found: i64 = sqlx::query_scalar(
+        "SELECT COUNT(DISTINCT id) FROM service_groups WHERE org_id = $1 AND id = ANY($2)",
+    )
+    .bind(org_id)
+    .bind(group_ids)
+    .fetch_one(pool)
+    .await?;
+    let mut unique = group_ids.to_vec();
+    unique.sort();
+    unique.dedup();
+    if found != unique.len() as i64 {
+        return Err(AppError::Validation(
+            "One or more service groups were not found".to_string(),
+        ));
+    }
+
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM subscriber_service_groups WHERE subscriber_id = $1")
+        .bind(subscriber_id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(
+        r#"
+        INSERT INTO subscriber_service_groups (subscriber_id, group_id)
+        SELECT $1, UNNEST($2::UUID[])
+        "#,
+    )
+    .bind(subscriber_id)
+    .bind(&unique)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
-use shared::enums::ServiceStatus;
+use shared::enums::{ServiceStatus, StatusChangeSource};
 use shared::error::AppError;
 use shared::models::service::{CreateServiceRequest, Service, UpdateServiceRequest};
+use shared::models::service_group::ServiceGroup;
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -8,6 +9,7 @@ pub async fn create(
     pool: &PgPool,
     org_id: Uuid,
     req: &CreateServiceRequest,
+    group: Option<&ServiceGroup>,
 ) -> Result<Service, AppError> {
     // Auto-set display_order to max + 1
     let max_order: Option<i32> =
@@ -20,20 +22,23 @@ pub async fn create(
 
     let service = sqlx::query_as::<_, Service>(
         r#"
-        INSERT INTO services (org_id, name, description, group_name, is_visible, display_order)
-        VALUES ($1, $2, $3, $4, $5, $6)
+        INSERT INTO services (org_id, name, description, group_id, group_name, is_visible, display_order)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
         RETURNING *
         "#,
     )
     .bind(org_id)
     .bind(&req.name)
     .bind(&req.description)
-    .bind(&req.group_name)
+    .bind(group.map(|group| group.id))
+    .bind(group.map(|group| group.name.as_str()))
     .bind(req.is_visible.unwrap_or(true))
     .bind(display_order)
     .fetch_one(pool)
     .await?;
 
+    super::status_history::record(pool, org_id, StatusChangeSource::Manual).await?;
+
     Ok(service)
 }
 
@@ -68,6 +73,7 @@ pub async fn update(
     service_id: Uuid,
     org_id: Uuid,
     req: &UpdateServiceRequest,
+    group: Option<&ServiceGroup>,
 ) -> Result<Service, AppError> {
     let service = sqlx::query_as::<_, Service>(
         r#"
@@ -75,8 +81,9 @@ pub async fn update(
             name = COALESCE($3, name),
             description = COALESCE($4, description),
             current_status = COALESCE($5, current_status),
-            group_name = COALESCE($6, group_name),
-            is_visible = COALESCE($7, is_visible),
+            group_id = COALESCE($6, group_id),
+            group_name = COALESCE($7, group_name),
+            is_visible = COALESCE($8, is_visible),
             updated_at = NOW()
         WHERE id = $1 AND org_id = $2
         RETURNING *
@@ -87,12 +94,21 @@ pub async fn update(
     .bind(&req.name)
     .bind(&req.description)
     .bind(req.current_status)
-    .bind(&req.group_name)
+    .bind(group.map(|group| group.id))
+    .bind(group.map(|group| group.name.as_str()))
     .bind(req.is_visible)
     .fetch_optional(pool)
     .await?
     .ok_or_else(|| AppError::NotFound("Service not found".to_string()))?;
 
+    if req.current_status.is_some() {
+        super::status_history::record(pool, org_id, StatusChangeSource::Manual).await?;
+        super::service_dependencies::refresh_upstream_impact(pool, org_id).await?;
+        return find_by_id(pool, service_id, org_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Service not found".to_string()));
+    }
+
     Ok(service)
 }
 
@@ -107,6 +123,8 @@ pub async fn delete(pool: &PgPool, service_id: Uuid, org_id: Uuid) -> Result<(),
         return Err(AppError::NotFound("Service not found".to_string()));
     }
 
+    super::service_dependencies::refresh_upstream_impact(pool, org_id).await?;
+
     Ok(())
 }
 
@@ -140,5 +158,11 @@ pub async fn update_status(
         .execute(pool)
         .await?;
 
+    sqlx::query("SELECT record_service_status_intervals(org_id, $2) FROM services WHERE id = $1")
+        .bind(service_id)
+        .bind(StatusChangeSource::Manual)
+        .execute(pool)
+        .await?;
+
     Ok(())
 }
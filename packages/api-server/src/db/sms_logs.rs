@@ -0,0 +1,329 @@
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use shared::error::AppError;
+
+use crate::db::notification_logs::NotificationFailureUpdate;
+
+/// Statuses that count against an organization's monthly SMS quota.
+const BILLABLE_STATUSES: &str = "('pending', 'sending', 'sent', 'failed')";
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PendingSms {
+    pub id: Uuid,
+    pub recipient_phone: String,
+    pub body: String,
+    pub attempt_count: i32,
+    pub max_attempts: i32,
+}
+
+#[derive(Debug, Clone, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct SmsLogEntry {
+    pub id: Uuid,
+    pub subscriber_id: Option<Uuid>,
+    pub message_type: String,
+    pub recipient_phone: String,
+    pub status: String,
+    pub error_message: Option<String>,
+    pub provider_message_id: Option<String>,
+    pub attempt_count: i32,
+    pub max_attempts: i32,
+    pub sent_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub next_retry_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+pub struct NewSms<'a> {
+    pub org_id: Uuid,
+    pub subscriber_id: Option<Uuid>,
+    pub message_type: &'a str,
+    pub recipient_phone: &'a str,
+    pub body: &'a str,
+    pub monthly_quota: i64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, utoipa::ToSchema)]
+pub struct SmsUsage {
+    pub used: i64,
+    pub quota: i64,
+}
+
+/// Queues an SMS, or records it as `quota_exceeded` when the organization has
+/// already used its allowance for the current month. Returns whether the
+/// message was queued for delivery.
+pub async fn enqueue(pool: &PgPool, sms: NewSms<'_>) -> Result<bool, AppError> {
+    let mut tx = pool.begin().await?;
+
+    // Serialize quota checks per organization so concurrent incidents cannot
+    // overshoot the allowance.
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1::text, 0))")
+        .bind(sms.org_id)
+        .execute(&mut *tx)
+        .await?;
+
+    let status = sqlx::query_scalar::<_, String>(&format!(
+        r#"
+        INSERT INTO sms_logs (
+            org_id,
+            subscriber_id,
+            message_type,
+            recipient_phone,
+            body,
+            status,
+            error_message,
+            next_retry_at
+        )
+        SELECT
+            $1, $2, $3, $4, $5,
+            CASE WHEN usage.used >= $6 THEN 'quota_exceeded' ELSE 'pending' END,
+            CASE WHEN usage.used >= $6 THEN 'Monthly SMS quota reached' END,
+            CASE WHEN usage.used >= $6 THEN NULL ELSE NOW() END
+        FROM (
+            SELECT COUNT(*) AS used
+            FROM sms_logs
+            WHERE org_id = $1
+              AND created_at >= date_trunc('month', NOW() AT TIME ZONE 'UTC') AT TIME ZONE 'UTC'
+              AND status IN {BILLABLE_STATUSES}
+        ) usage
+        RETURNING status
+        "#
+    ))
+    .bind(sms.org_id)
+    .bind(sms.subscriber_id)
+    .bind(sms.message_type)
+    .bind(sms.recipient_phone)
+    .bind(sms.body)
+    .bind(sms.monthly_quota)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(status == "pending")
+}
+
+pub async fn monthly_usage(pool: &PgPool, org_id: Uuid, quota: i64) -> Result<SmsUsage, AppError> {
+    let used = sqlx::query_scalar::<_, i64>(&format!(
+        r#"
+        SELECT COUNT(*)
+        FROM sms_logs
+        WHERE org_id = $1
+          AND created_at >= date_trunc('month', NOW() AT TIME ZONE 'UTC') AT TIME ZONE 'UTC'
+          AND status IN {BILLABLE_STATUSES}
+        "#
+    ))
+    .bind(org_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(SmsUsage { used, quota })
+}
+
+pub async fn claim_pending(pool: &PgPool, limit: i64) -> Result<Vec<PendingSms>, AppError> {
+    let messages = sqlx::query_as::<_, PendingSms>(
+        r#"
+        WITH due AS (
+            SELECT id
+            FROM sms_logs
+            WHERE status = 'pending'
+              AND (next_retry_at IS NULL OR next_retry_at <= NOW())
+            ORDER BY created_at ASC
+            LIMIT $1
+            FOR UPDATE SKIP LOCKED
+        )
+        UPDATE sms_logs sl
+        SET
+            status = 'sending',
+            attempt_count = sl.attempt_count + 1
+        FROM due
+        WHERE sl.id = due.id
+        RETURNING
+            sl.id,
+            sl.recipient_phone,
+            sl.body,
+            sl.attempt_count,
+            sl.max_attempts
+        "#,
+    )
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(messages)
+}
+
+pub async fn mark_sent(
+    pool: &PgPool,
+    id: Uuid,
+    provider_message_id: Option<&str>,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        UPDATE sms_logs
+        SET
+            status = 'sent',
+            error_message = NULL,
+            provider_message_id = $2,
+            next_retry_at = NULL,
+            sent_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(provider_message_id)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn mark_failed(
+    pool: &PgPool,
+    id: Uuid,
+    update: NotificationFailureUpdate<'_>,
+) -> Result<(), AppError> {
+    let status = if update.attempt_count >= update.max_attempts {
+        "failed"
+    } else {
+        "pending"
+    };
+
+    sqlx::query(
+        r#"
+        UPDATE sms_logs
+        SET
+            status = $2,
+            error_message = $3,
+            next_retry_at = $4,
+            sent_at = NULL
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(status)
+    .bind(update.error_message)
+    .bind(update.next_retry_at)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn list_recent_by_org(
+    pool: &PgPool,
+    org_id: Uuid,
+    page: i64,
+    per_page: i64,
+    status: Option<&str>,
+) -> Result<(Vec<SmsLogEntry>, i64), AppError> {
+    let total = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(*)
+        FROM sms_logs
+        WHERE org_id = $1
+          AND ($2::text IS NULL OR status = $2)
+        "#,
+    )
+    .bind(org_id)
+    .bind(status)
+    .fetch_one(pool)
+    .await?;
+
+    let offset = (page - 1) * per_page;
+    let entries = sqlx::query_as::<_, SmsLogEntry>(
+        r#"
+        SELECT
+            id,
+            subscriber_id,
+            message_type,
+            recipient_phone,
+            status,
+            error_message,
+            provider_message_id,
+            attempt_count,
+            max_attempts,
+            sent_at,
+            next_retry_at,
+            created_at
+        FROM sms_logs
+        WHERE org_id = $1
+          AND ($4::text IS NULL OR status = $4)
+        ORDER BY created_at DESC
+        LIMIT $2
+        OFFSET $3
+        "#,
+    )
+    .bind(org_id)
+    .bind(per_page)
+    .bind(offset)
+    .bind(status)
+    .fetch_all(pool)
+    .await?;
+
+    Ok((entries, total))
+}
+
+pub async fn retry_failed_by_id(
+    pool: &PgPool,
+    org_id: Uuid,
+    id: Uuid,
+) -> Result<Option<SmsLogEntry>, AppError> {
+    let entry = sqlx::query_as::<_, SmsLogEntry>(
+        r#"
+        UPDATE sms_logs
+        SET
+            status = 'pending',
+            error_message = NULL,
+            next_retry_at = NOW(),
+            sent_at = NULL
+        WHERE id = $1
+          AND org_id = $2
+          AND status = 'failed'
+        RETURNING
+            id,
+            subscriber_id,
+            message_type,
+            recipient_phone,
+            status,
+            error_message,
+            provider_message_id,
+            attempt_count,
+            max_attempts,
+            sent_at,
+            next_retry_at,
+            created_at
+        "#,
+    )
+    .bind(id)
+    .bind(org_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(entry)
+}
+
+/// Cancels queued messages that have not been handed to the provider yet.
+pub async fn cancel_pending_for_org(
+    pool: &PgPool,
+    org_id: Uuid,
+    reason: &str,
+) -> Result<u64, AppError> {
+    let result = sqlx::query(
+        r#"
+        UPDATE sms_logs
+        SET
+            status = 'canceled',
+            error_message = $2,
+            next_retry_at = NULL
+        WHERE org_id = $1
+          AND status = 'pending'
+        "#,
+    )
+    .bind(org_id)
+    .bind(reason)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use shared::enums::StatusChangeSource;
+use shared::error::AppError;
+use shared::models::status_history::ServiceStatusInterval;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Brings the interval log in line with the organization's current service
+/// statuses. Call after any write that may have changed `current_status`.
+pub async fn record<'e, E>(
+    executor: E,
+    org_id: Uuid,
+    source: StatusChangeSource,
+) -> Result<i32, AppError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let opened: i32 = sqlx::query_scalar("SELECT record_service_status_intervals($1, $2)")
+        .bind(org_id)
+        .bind(source)
+        .fetch_one(executor)
+        .await?;
+
+    Ok(opened)
+}
+
+/// Intervals of one service overlapping `[from, to)`, newest first.
+pub async fn list_for_service(
+    pool: &PgPool,
+    service_id: Uuid,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<ServiceStatusInterval>, AppError> {
+    let intervals = sqlx::query_as::<_, ServiceStatusInterval>(
+        r#"
+        SELECT * FROM service_status_intervals
+        WHERE service_id = $1
+          AND started_at < $3
+          AND (ended_at IS NULL OR ended_at > $2)
+        ORDER BY started_at DESC
+        "#,
+    )
+    .bind(service_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(intervals)
+}
+
+/// Intervals of several services that are still open or ended after `from`.
+pub async fn list_since(
+    pool: &PgPool,
+    service_ids: &[Uuid],
+    from: DateTime<Utc>,
+) -> Result<Vec<ServiceStatusInterval>, AppError> {
+    let intervals = sqlx::query_as::<_, ServiceStatusInterval>(
+        r#"
+        SELECT * FROM service_status_intervals
+        WHERE service_id = ANY($1)
+          AND (ended_at IS NULL OR ended_at > $2)
+        ORDER BY service_id, started_at
+        "#,
+    )
+    .bind(service_ids)
+    .bind(from)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(intervals)
+}
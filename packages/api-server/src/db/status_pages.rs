@@ -0,0 +1,444 @@
+use std::collections::{HashMap, HashSet};
+
+use shared::enums::{CustomDomainStatus, CustomDomainVerificationMethod, Locale};
+use shared::error::AppError;
+use shared::models::status_page::StatusPage;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// A reachable status page as visitors see it: an organization's primary
+/// page (`page_id` is `None`) or one of its additional pages, with branding
+/// already falling back to the organization's.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PublicPage {
+    pub org_id: Uuid,
+    pub page_id: Option<Uuid>,
+    pub slug: String,
+    pub name: String,
+    pub logo_url: Option<String>,
+    pub brand_color: String,
+    pub verified_custom_domain: Option<String>,
+    /// The organization's default locale and timezone.
+    pub locale: Locale,
+    pub timezone: String,
+}
+
+const PUBLIC_PAGE_COLUMNS: &str =
+    "org_id, page_id, slug, name, logo_url, brand_color, verified_custom_domain, locale, timezone";
+
+const STATUS_PAGE_COLUMNS: &str = r#"
+    sp.id,
+    sp.org_id,
+    sp.slug,
+    sp.name,
+    sp.logo_url,
+    sp.brand_color,
+    sp.custom_domain,
+    sp.custom_domain_status,
+    sp.custom_domain_verified_at,
+    sp.custom_domain_verification_token,
+    sp.custom_domain_verification_method,
+    sp.custom_domain_checked_at,
+    sp.custom_domain_grace_ends_at,
+    sp.page_visibility,
+    sp.page_allowed_email_domains,
+    sp.page_allowed_cidrs,
+    ARRAY(
+        SELECT sps.service_id FROM status_page_services sps
+        WHERE sps.status_page_id = sp.id
+        ORDER BY sps.service_id
+    ) AS service_ids,
+    sp.disabled_reason,
+    sp.created_at,
+    sp.updated_at
+"#;
+
+pub async fn find_public_by_slug(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<Option<PublicPage>, AppError> {
+    let page = sqlx::query_as::<_, PublicPage>(&format!(
+        "SELECT {PUBLIC_PAGE_COLUMNS} FROM public_status_pages WHERE slug = $1"
+    ))
+    .bind(slug)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(page)
+}
+
+/// The page served on a verified custom domain.
+pub async fn find_public_by_host(
+    pool: &PgPool,
+    host: &str,
+) -> Result<Option<PublicPage>, AppError> {
+    let page = sqlx::query_as::<_, PublicPage>(&format!(
+        "SELECT {PUBLIC_PAGE_COLUMNS} FROM public_status_pages WHERE lower(verified_custom_domain) = $1"
+    ))
+    .bind(host)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(page)
+}
+
+pub async fn find_public(
+    pool: &PgPool,
+    org_id: Uuid,
+    page_id: Option<Uuid>,
+) -> Result<PublicPage, AppError> {
+    sqlx::query_as::<_, PublicPage>(&format!(
+        r#"
+        SELECT {PUBLIC_PAGE_COLUMNS} FROM public_status_pages
+        WHERE org_id = $1 AND page_id IS NOT DISTINCT FROM $2
+        "#
+    ))
+    .bind(org_id)
+    .bind(page_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))
+}
+
+/// Every reachable page of an organization, keyed by `page_id`, for
+/// notifications that link each subscriber back to their own page.
+pub async fn public_pages_by_id(
+    pool: &PgPool,
+    org_id: Uuid,
+) -> Result<HashMap<Option<Uuid>, PublicPage>, AppError> {
+    let pages = sqlx::query_as::<_, PublicPage>(&format!(
+        "SELECT {PUBLIC_PAGE_COLUMNS} FROM public_status_pages WHERE org_id = $1"
+    ))
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(pages.into_iter().map(|page| (page.page_id, page)).collect())
+}
+
+/// The services a page lists; see `status_page_service_ids` in the schema.
+pub async fn service_ids(
+    pool: &PgPool,
+    org_id: Uuid,
+    page_id: Option<Uuid>,
+) -> Result<HashSet<Uuid>, AppError> {
+    let ids = sqlx::query_scalar::<_, Uuid>("SELECT status_page_service_ids($1, $2)")
+        .bind(org_id)
+        .bind(page_id)
+        .fetch_all(pool)
+        .await?;
+
+    Ok(ids.into_iter().collect())
+}
+
+pub async fn find_by_org(pool: &PgPool, org_id: Uuid) -> Result<Vec<StatusPage>, AppError> {
+    let pages = sqlx::query_as::<_, StatusPage>(&format!(
+        "SELECT {STATUS_PAGE_COLUMNS} FROM status_pages sp WHERE sp.org_id = $1 ORDER BY sp.created_at, sp.id"
+    ))
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(pages)
+}
+
+pub async fn find_by_id(
+    pool: &PgPool,
+    page_id: Uuid,
+    org_id: Uuid,
+) -> Result<Option<StatusPage>, AppError> {
+    let page = sqlx::query_as::<_, StatusPage>(&format!(
+        "SELECT {STATUS_PAGE_COLUMNS} FROM status_pages sp WHERE sp.id = $1 AND sp.org_id = $2"
+    ))
+    .bind(page_id)
+    .bind(org_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(page)
+}
+
+/// Additional pages counted against the plan's page limit.
+pub async fn count_active_by_org(pool: &PgPool, org_id: Uuid) -> Result<i64, AppError> {
+    let count = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM status_pages WHERE org_id = $1 AND disabled_reason IS NULL",
+    )
+    .bind(org_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(count)
+}
+
+/// Fields for a new page, already validated and normalized.
+pub struct NewStatusPage<'a> {
+    pub slug: &'a str,
+    pub name: &'a str,
+    pub logo_url: Option<&'a str>,
+    pub brand_color: Option<&'a str>,
+    pub custom_domain: Option<&'a str>,
+}
+
+pub async fn create(
+    pool: &PgPool,
+    org_id: Uuid,
+    page: &NewStatusPage<'_>,
+    service_ids: &[Uuid],
+) -> Result<StatusPage, AppError> {
+    let mut tx = pool.begin().await?;
+    let page_id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO status_pages (
+            org_id, slug, name, logo_url, brand_color, custom_domain, custom_domain_status
+        )
+        VALUES (
+            $1, $2, $3, $4, $5, $6,
+            CASE WHEN $6::text IS NULL THEN 'not_configured' ELSE 'pending_verification' END
+        )
+        RETURNING id
+        "#,
+    )
+    .bind(org_id)
+    .bind(page.slug)
+    .bind(page.name)
+    .bind(page.logo_url)
+    .bind(page.brand_color)
+    .bind(page.custom_domain)
+    .fetch_one(&mut *tx)
+    .await?;
+    replace_services(&mut tx, page_id, service_ids).await?;
+    tx.commit().await?;
+
+    find_by_id(pool, page_id, org_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))
+}
+
+/// Changes to a page. `None` keeps the current value; `Some(None)` clears
+/// an optional field.
+#[derive(Default)]
+pub struct StatusPageChanges<'a> {
+    pub slug: Option<&'a str>,
+    pub name: Option<&'a str>,
+    pub logo_url: Option<Option<&'a str>>,
+    pub brand_color: Option<Option<&'a str>>,
+    pub custom_domain: Option<Option<&'a str>>,
+    pub service_ids: Option<&'a [Uuid]>,
+}
+
+pub async fn update(
+    pool: &PgPool,
+    page_id: Uuid,
+    org_id: Uuid,
+    changes: &StatusPageChanges<'_>,
+) -> Result<StatusPage, AppError> {
+    let mut tx = pool.begin().await?;
+    let updated = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        UPDATE status_pages SET
+            slug = COALESCE($3, slug),
+            name = COALESCE($4, name),
+            logo_url = CASE WHEN $5 THEN $6 ELSE logo_url END,
+            brand_color = CASE WHEN $7 THEN $8 ELSE brand_color END,
+            custom_domain_status = CASE
+                WHEN NOT $9 OR $10::text IS NOT DISTINCT FROM custom_domain THEN custom_domain_status
+                WHEN $10::text IS NULL THEN 'not_configured'
+                ELSE 'pending_verification'
+            END,
+            custom_domain_verified_at = CASE
+                WHEN NOT $9 OR $10::text IS NOT DISTINCT FROM custom_domain THEN custom_domain_verified_at
+            END,
+            custom_domain_verification_method = CASE
+                WHEN NOT $9 OR $10::text IS NOT DISTINCT FROM custom_domain
+                    THEN custom_domain_verification_method
+            END,
+            custom_domain_check_failures = CASE
+                WHEN NOT $9 OR $10::text IS NOT DISTINCT FROM custom_domain
+                    THEN custom_domain_check_failures
+                ELSE 0
+            END,
+            custom_domain_grace_ends_at = CASE
+                WHEN NOT $9 OR $10::text IS NOT DISTINCT FROM custom_domain
+                    THEN custom_domain_grace_ends_at
+            END,
+            custom_domain = CASE WHEN $9 THEN $10 ELSE custom_domain END,
+            updated_at = NOW()
+        WHERE id = $1 AND org_id = $2
+        RETURNING id
+        "#,
+    )
+    .bind(page_id)
+    .bind(org_id)
+    .bind(changes.slug)
+    .bind(changes.name)
+    .bind(changes.logo_url.is_some())
+    .bind(changes.logo_url.flatten())
+    .bind(changes.brand_color.is_some())
+    .bind(changes.brand_color.flatten())
+    .bind(changes.custom_domain.is_some())
+    .bind(changes.custom_domain.flatten())
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))?;
+
+    if let Some(service_ids) = changes.service_ids {
+        replace_services(&mut tx, updated, service_ids).await?;
+    }
+    tx.commit().await?;
+
+    find_by_id(pool, page_id, org_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))
+}
+
+async fn replace_services(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    page_id: Uuid,
+    service_ids: &[Uuid],
+) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM status_page_services WHERE status_page_id = $1")
+        .bind(page_id)
+        .execute(&mut **tx)
+        .await?;
+    sqlx::query(
+        r#"
+        INSERT INTO status_page_services (status_page_id, service_id)
+        SELECT $1, s.id
+        FROM services s
+        JOIN status_pages sp ON sp.id = $1 AND sp.org_id = s.org_id
+        WHERE s.id = ANY($2)
+        "#,
+    )
+    .bind(page_id)
+    .bind(service_ids)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete(pool: &PgPool, page_id: Uuid, org_id: Uuid) -> Result<(), AppError> {
+    let result = sqlx::query("DELETE FROM status_pages WHERE id = $1 AND org_id = $2")
+        .bind(page_id)
+        .bind(org_id)
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound("Status page not found".to_string()));
+    }
+
+    Ok(())
+}
+
+pub async fn mark_custom_domain_verified(
+    pool: &PgPool,
+    page_id: Uuid,
+    org_id: Uuid,
+    method: CustomDomainVerificationMethod,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        UPDATE status_pages
+        SET
+            custom_domain_verified_at = NOW(),
+            custom_domain_status = 'verified',
+            custom_domain_verification_method = $3,
+            custom_domain_checked_at = NOW(),
+            custom_domain_check_failures = 0,
+            custom_domain_grace_ends_at = NULL,
+            updated_at = NOW()
+        WHERE id = $1 AND org_id = $2
+        "#,
+    )
+    .bind(page_id)
+    .bind(org_id)
+    .bind(method)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Moves every page custom domain of an organization from one status to
+/// another, for plan downgrades and their reversal.
+pub async fn move_custom_domain_status(
+    pool: &PgPool,
+    org_id: Uuid,
+    from: CustomDomainStatus,
+    to: CustomDomainStatus,
+) -> Result<u64, AppError> {
+    let result = sqlx::query(
+        r#"
+        UPDATE status_pages
+        SET
+            custom_domain_status = $3,
+            custom_domain_verified_at = CASE WHEN $3 = 'verified' THEN custom_domain_verified_at END,
+            updated_at = NOW()
+        WHERE org_id = $1 AND custom_domain IS NOT NULL AND custom_domain_status = $2
+        "#,
+    )
+    .bind(org_id)
+    .bind(from)
+    .bind(to)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+pub async fn count_custom_domains(pool: &PgPool, org_id: Uuid) -> Result<i64, AppError> {
+    let count = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM status_pages WHERE org_id = $1 AND custom_domain IS NOT NULL",
+    )
+    .bind(org_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(count)
+}
+
+/// Disables the newest additional pages beyond `keep_count`, mirroring
+/// how monitors are limited on downgrade.
+pub async fn disable_excess_for_plan(
+    pool: &PgPool,
+    org_id: Uuid,
+    keep_count: i64,
+) -> Result<Vec<Uuid>, AppError> {
+    let disabled_ids = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        WITH ranked AS (
+            SELECT id,
+                   ROW_NUMBER() OVER (ORDER BY created_at ASC, id ASC) AS position
+            FROM status_pages
+            WHERE org_id = $1
+              AND disabled_reason IS NULL
+        )
+        UPDATE status_pages
+        SET disabled_reason = 'plan_limit', updated_at = NOW()
+        WHERE id IN (SELECT id FROM ranked WHERE position > $2)
+        RETURNING id
+        "#,
+    )
+    .bind(org_id)
+    .bind(keep_count.max(0))
+    .fetch_all(pool)
+    .await?;
+
+    Ok(disabled_ids)
+}
+
+pub async fn restore_plan_limited(pool: &PgPool, org_id: Uuid) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        UPDATE status_pages
+        SET disabled_reason = NULL, updated_at = NOW()
+        WHERE org_id = $1 AND disabled_reason = 'plan_limit'
+        "#,
+    )
+    .bind(org_id)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
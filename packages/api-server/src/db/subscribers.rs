@@ -1,31 +1,85 @@
 use serde::Serialize;
+use shared::enums::{DigestFrequency, SubscriberSource};
 use shared::error::AppError;
-use shared::models::subscriber::Subscriber;
+use shared::models::subscriber::{ResendVerificationFilter, Subscriber, SubscriberPreferences};
 use sqlx::PgPool;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct SubscriberListItem {
     pub id: Uuid,
+    /// `None` for subscribers of the primary page.
+    pub status_page_id: Option<Uuid>,
     pub email: String,
     pub is_verified: bool,
+    pub digest_frequency: DigestFrequency,
+    pub phone_number: Option<String>,
+    pub phone_verified_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub source: SubscriberSource,
     pub verification_sent_at: Option<chrono::DateTime<chrono::Utc>>,
     pub verified_at: Option<chrono::DateTime<chrono::Utc>>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
 
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct SubscriberExportRow {
+    pub email: String,
+    pub is_verified: bool,
+    pub source: SubscriberSource,
+    pub digest_frequency: DigestFrequency,
+    pub timezone: String,
+    pub phone_verified: bool,
+    pub consent_attestation: Option<String>,
+    pub verification_sent_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub verified_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+pub struct ImportedSubscriber<'a> {
+    pub email: &'a str,
+    pub digest_frequency: Option<DigestFrequency>,
+    pub timezone: Option<&'a str>,
+    pub verification_token: &'a str,
+    pub unsubscribe_token: &'a str,
+    /// `Some` imports the subscriber as already verified.
+    pub consent_attestation: Option<&'a str>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ImportOutcome {
+    Created(Subscriber),
+    /// An existing pending subscriber was re-verified or got a fresh token.
+    Updated(Subscriber),
+    /// The subscriber was already verified and was left untouched.
+    Unchanged,
+}
+
+#[derive(sqlx::FromRow)]
+struct ImportedRow {
+    #[sqlx(flatten)]
+    subscriber: Subscriber,
+    inserted: bool,
+}
+
 pub async fn create_or_refresh_pending(
     pool: &PgPool,
     org_id: Uuid,
+    status_page_id: Option<Uuid>,
     email: &str,
     verification_token: &str,
     unsubscribe_token: &str,
+    preferences: &SubscriberPreferences,
 ) -> Result<(Subscriber, bool), AppError> {
     let existing = sqlx::query_as::<_, Subscriber>(
-        "SELECT * FROM subscribers WHERE org_id = $1 AND lower(email) = lower($2)",
+        r#"
+        SELECT * FROM subscribers
+        WHERE org_id = $1 AND status_page_id IS NOT DISTINCT FROM $2 AND lower(email) = lower($3)
+        "#,
     )
     .bind(org_id)
+    .bind(status_page_id)
     .bind(email)
     .fetch_optional(pool)
     .await?;
@@ -42,6 +96,11 @@ pub async fn create_or_refresh_pending(
                 verification_token = $3,
                 verification_sent_at = NOW(),
                 unsubscribe_token = COALESCE(unsubscribe_token, $4),
+                digest_frequency = COALESCE($5, digest_frequency),
+                timezone = COALESCE($6, timezone),
+                quiet_hours_start = COALESCE($7, quiet_hours_start),
+                quiet_hours_end = COALESCE($8, quiet_hours_end),
+                locale = COALESCE($9, locale),
                 updated_at = NOW()
             WHERE id = $1
             RETURNING *
@@ -51,6 +110,11 @@ pub async fn create_or_refresh_pending(
         .bind(org_id)
         .bind(verification_token)
         .bind(unsubscribe_token)
+        .bind(preferences.digest_frequency)
+        .bind(preferences.timezone.as_deref())
+        .bind(preferences.quiet_hours_start)
+        .bind(preferences.quiet_hours_end)
+        .bind(preferences.locale)
         .fetch_one(pool)
         .await?;
 
@@ -61,19 +125,31 @@ pub async fn create_or_refresh_pending(
         r#"
         INSERT INTO subscribers (
             org_id,
+            status_page_id,
             email,
             verification_token,
             verification_sent_at,
-            unsubscribe_token
+            unsubscribe_token,
+            digest_frequency,
+            timezone,
+            quiet_hours_start,
+            quiet_hours_end,
+            locale
         )
-        VALUES ($1, $2, $3, NOW(), $4)
+        VALUES ($1, $2, $3, $4, NOW(), $5, COALESCE($6, 'immediate'), COALESCE($7, 'UTC'), $8, $9, $10)
         RETURNING *
         "#,
     )
     .bind(org_id)
+    .bind(status_page_id)
     .bind(email)
     .bind(verification_token)
     .bind(unsubscribe_token)
+    .bind(preferences.digest_frequency)
+    .bind(preferences.timezone.as_deref())
+    .bind(preferences.quiet_hours_start)
+    .bind(preferences.quiet_hours_end)
+    .bind(preferences.locale)
     .fetch_one(pool)
     .await?;
 
@@ -125,19 +201,267 @@ pub async fn unsubscribe(
     Ok(subscriber)
 }
 
-pub async fn find_verified_by_org(
+pub async fn find_by_unsubscribe_token(
+    pool: &PgPool,
+    org_id: Uuid,
+    token: &str,
+) -> Result<Option<Subscriber>, AppError> {
+    let subscriber = sqlx::query_as::<_, Subscriber>(
+        "SELECT * FROM subscribers WHERE org_id = $1 AND unsubscribe_token = $2",
+    )
+    .bind(org_id)
+    .bind(token)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(subscriber)
+}
+
+/// Replaces the subscriber's delivery preferences. Omitted quiet hours clear
+/// the quiet window; omitted frequency, timezone and locale keep their current
+/// values.
+pub async fn update_preferences(
     pool: &PgPool,
     org_id: Uuid,
+    token: &str,
+    preferences: &SubscriberPreferences,
+) -> Result<Option<Subscriber>, AppError> {
+    let subscriber = sqlx::query_as::<_, Subscriber>(
+        r#"
+        UPDATE subscribers
+        SET
+            digest_frequency = COALESCE($3, digest_frequency),
+            timezone = COALESCE($4, timezone),
+            quiet_hours_start = $5,
+            quiet_hours_end = $6,
+            locale = COALESCE($7, locale),
+            updated_at = NOW()
+        WHERE org_id = $1 AND unsubscribe_token = $2
+        RETURNING *
+        "#,
+    )
+    .bind(org_id)
+    .bind(token)
+    .bind(preferences.digest_frequency)
+    .bind(preferences.timezone.as_deref())
+    .bind(preferences.quiet_hours_start)
+    .bind(preferences.quiet_hours_end)
+    .bind(preferences.locale)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(subscriber)
+}
+
+pub async fn timezone_exists(pool: &PgPool, timezone: &str) -> Result<bool, AppError> {
+    let exists = sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS (SELECT 1 FROM pg_timezone_names WHERE name = $1)",
+    )
+    .bind(timezone)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(exists)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhoneVerificationOutcome {
+    Verified,
+    InvalidCode,
+    Expired,
+    TooManyAttempts,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SmsRecipient {
+    pub subscriber_id: Uuid,
+    pub status_page_id: Option<Uuid>,
+    pub phone_number: String,
+}
+
+/// Stores a new, unverified phone number together with the hash of the code
+/// that was sent to it. Any previously verified number stops receiving SMS.
+pub async fn set_pending_phone(
+    pool: &PgPool,
+    org_id: Uuid,
+    token: &str,
+    phone_number: &str,
+    otp_hash: &str,
+    otp_expires_at: chrono::DateTime<chrono::Utc>,
+) -> Result<Option<Subscriber>, AppError> {
+    let subscriber = sqlx::query_as::<_, Subscriber>(
+        r#"
+        UPDATE subscribers
+        SET
+            phone_number = $3,
+            phone_verified_at = NULL,
+            phone_otp_hash = $4,
+            phone_otp_expires_at = $5,
+            phone_otp_attempts = 0,
+            updated_at = NOW()
+        WHERE org_id = $1 AND unsubscribe_token = $2 AND is_verified = TRUE
+        RETURNING *
+        "#,
+    )
+    .bind(org_id)
+    .bind(token)
+    .bind(phone_number)
+    .bind(otp_hash)
+    .bind(otp_expires_at)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(subscriber)
+}
+
+/// Checks a submitted code against the stored hash. Every check counts as an
+/// attempt, so the code is burned after `max_attempts` wrong guesses.
+pub async fn verify_phone(
+    pool: &PgPool,
+    subscriber_id: Uuid,
+    otp_hash: &str,
+    max_attempts: i32,
+) -> Result<PhoneVerificationOutcome, AppError> {
+    let row = sqlx::query_as::<_, (bool, bool, i32)>(
+        r#"
+        UPDATE subscribers
+        SET
+            phone_otp_attempts = phone_otp_attempts + 1,
+            phone_verified_at = CASE
+                WHEN phone_otp_hash = $2
+                     AND phone_otp_expires_at > NOW()
+                     AND phone_otp_attempts < $3
+                    THEN NOW()
+                ELSE phone_verified_at
+            END,
+            updated_at = NOW()
+        WHERE id = $1 AND phone_otp_hash IS NOT NULL
+        RETURNING
+            phone_verified_at IS NOT NULL,
+            phone_otp_expires_at <= NOW(),
+            phone_otp_attempts
+        "#,
+    )
+    .bind(subscriber_id)
+    .bind(otp_hash)
+    .bind(max_attempts)
+    .fetch_optional(pool)
+    .await?;
+
+    let Some((verified, expired, attempts)) = row else {
+        return Ok(PhoneVerificationOutcome::InvalidCode);
+    };
+
+    if verified {
+        sqlx::query(
+            r#"
+            UPDATE subscribers
+            SET phone_otp_hash = NULL, phone_otp_expires_at = NULL, phone_otp_attempts = 0
+            WHERE id = $1
+            "#,
+        )
+        .bind(subscriber_id)
+        .execute(pool)
+        .await?;
+        return Ok(PhoneVerificationOutcome::Verified);
+    }
+
+    if attempts > max_attempts {
+        return Ok(PhoneVerificationOutcome::TooManyAttempts);
+    }
+    if expired {
+        return Ok(PhoneVerificationOutcome::Expired);
+    }
+
+    Ok(PhoneVerificationOutcome::InvalidCode)
+}
+
+pub async fn remove_phone(
+    pool: &PgPool,
+    org_id: Uuid,
+    token: &str,
+) -> Result<Option<Subscriber>, AppError> {
+    let subscriber = sqlx::query_as::<_, Subscriber>(
+        r#"
+        UPDATE subscribers
+        SET
+            phone_number = NULL,
+            phone_verified_at = NULL,
+            phone_otp_hash = NULL,
+            phone_otp_expires_at = NULL,
+            phone_otp_attempts = 0,
+            updated_at = NOW()
+        WHERE org_id = $1 AND unsubscribe_token = $2
+        RETURNING *
+        "#,
+    )
+    .bind(org_id)
+    .bind(token)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(subscriber)
+}
+
+/// Phone-verified subscribers who follow at least one of `service_ids`
+/// (or every service).
+pub async fn find_sms_recipients(
+    pool: &PgPool,
+    org_id: Uuid,
+    service_ids: &[Uuid],
+) -> Result<Vec<SmsRecipient>, AppError> {
+    let recipients = sqlx::query_as::<_, SmsRecipient>(
+        r#"
+        SELECT id AS subscriber_id, status_page_id, phone_number
+        FROM subscribers
+        WHERE org_id = $1
+          AND is_verified = TRUE
+          AND phone_number IS NOT NULL
+          AND phone_verified_at IS NOT NULL
+          AND subscriber_wants_services(id, $2)
+        ORDER BY created_at ASC
+        "#,
+    )
+    .bind(org_id)
+    .bind(service_ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(recipients)
+}
+
+pub async fn count_sms_recipients(pool: &PgPool, org_id: Uuid) -> Result<i64, AppError> {
+    let count = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(*)
+        FROM subscribers
+        WHERE org_id = $1 AND phone_verified_at IS NOT NULL
+        "#,
+    )
+    .bind(org_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(count)
+}
+
+/// Verified subscribers who follow at least one of `service_ids`. Everyone
+/// follows org-wide notices with no services.
+pub async fn find_verified_for_services(
+    pool: &PgPool,
+    org_id: Uuid,
+    service_ids: &[Uuid],
 ) -> Result<Vec<Subscriber>, AppError> {
     let subscribers = sqlx::query_as::<_, Subscriber>(
         r#"
         SELECT *
         FROM subscribers
-        WHERE org_id = $1 AND is_verified = TRUE
+        WHERE org_id = $1 AND is_verified = TRUE AND subscriber_wants_services(id, $2)
         ORDER BY created_at DESC
         "#,
     )
     .bind(org_id)
+    .bind(service_ids)
     .fetch_all(pool)
     .await?;
 
@@ -149,8 +473,13 @@ pub async fn list_by_org(pool: &PgPool, org_id: Uuid) -> Result<Vec<SubscriberLi
         r#"
         SELECT
             id,
+            status_page_id,
             email,
             is_verified,
+            digest_frequency,
+            phone_number,
+            phone_verified_at,
+            source,
             verification_sent_at,
             verified_at,
             created_at,
@@ -198,6 +527,207 @@ pub async fn refresh_pending_verification_by_id(
     Ok(subscriber)
 }
 
+/// Inserts an imported subscriber of the primary page. Existing verified
+/// subscribers are never modified, and existing pending ones keep their
+/// delivery preferences.
+pub async fn import(
+    pool: &PgPool,
+    org_id: Uuid,
+    imported: ImportedSubscriber<'_>,
+) -> Result<ImportOutcome, AppError> {
+    let row = sqlx::query_as::<_, ImportedRow>(
+        r#"
+        INSERT INTO subscribers (
+            org_id,
+            email,
+            is_verified,
+            verification_token,
+            verification_sent_at,
+            verified_at,
+            unsubscribe_token,
+            digest_frequency,
+            timezone,
+            source,
+            consent_attestation
+        )
+        VALUES (
+            $1,
+            $2,
+            $7::text IS NOT NULL,
+            CASE WHEN $7::text IS NULL THEN $3 END,
+            CASE WHEN $7::text IS NULL THEN NOW() END,
+            CASE WHEN $7::text IS NOT NULL THEN NOW() END,
+            $4,
+            COALESCE($5, 'immediate'),
+            COALESCE($6, 'UTC'),
+            'import',
+            $7
+        )
+        ON CONFLICT (org_id, status_page_id, email) DO UPDATE
+        SET
+            is_verified = EXCLUDED.is_verified,
+            verification_token = EXCLUDED.verification_token,
+            verification_sent_at = COALESCE(EXCLUDED.verification_sent_at, subscribers.verification_sent_at),
+            verified_at = EXCLUDED.verified_at,
+            consent_attestation = EXCLUDED.consent_attestation,
+            updated_at = NOW()
+        WHERE subscribers.is_verified = FALSE
+        RETURNING subscribers.*, (xmax = 0) AS inserted
+        "#,
+    )
+    .bind(org_id)
+    .bind(imported.email)
+    .bind(imported.verification_token)
+    .bind(imported.unsubscribe_token)
+    .bind(imported.digest_frequency)
+    .bind(imported.timezone)
+    .bind(imported.consent_attestation)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(match row {
+        Some(ImportedRow {
+            subscriber,
+            inserted: true,
+        }) => ImportOutcome::Created(subscriber),
+        Some(ImportedRow { subscriber, .. }) => ImportOutcome::Updated(subscriber),
+        None => ImportOutcome::Unchanged,
+    })
+}
+
+/// Primary-page subscribers an import would leave untouched.
+pub async fn list_verified_primary_emails(
+    pool: &PgPool,
+    org_id: Uuid,
+) -> Result<Vec<String>, AppError> {
+    let emails = sqlx::query_scalar::<_, String>(
+        r#"
+        SELECT email
+        FROM subscribers
+        WHERE org_id = $1 AND status_page_id IS NULL AND is_verified = TRUE
+        "#,
+    )
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(emails)
+}
+
+pub async fn list_for_export(
+    pool: &PgPool,
+    org_id: Uuid,
+    is_verified: Option<bool>,
+) -> Result<Vec<SubscriberExportRow>, AppError> {
+    let rows = sqlx::query_as::<_, SubscriberExportRow>(
+        r#"
+        SELECT
+            email,
+            is_verified,
+            source,
+            digest_frequency,
+            timezone,
+            phone_verified_at IS NOT NULL AS phone_verified,
+            consent_attestation,
+            verification_sent_at,
+            verified_at,
+            created_at,
+            updated_at
+        FROM subscribers
+        WHERE org_id = $1
+          AND ($2::boolean IS NULL OR is_verified = $2)
+        ORDER BY created_at ASC, email ASC
+        "#,
+    )
+    .bind(org_id)
+    .bind(is_verified)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Issues fresh verification tokens to pending subscribers matching `filter`,
+/// skipping anyone who was sent a link within the last `cooldown_minutes`.
+pub async fn refresh_pending_verification_matching(
+    pool: &PgPool,
+    org_id: Uuid,
+    filter: &ResendVerificationFilter,
+    cooldown_minutes: i64,
+    limit: i64,
+) -> Result<Vec<Subscriber>, AppError> {
+    let email_domain = filter
+        .email_domain
+        .as_deref()
+        .map(|domain| domain.trim().trim_start_matches('@').to_lowercase())
+        .filter(|domain| !domain.is_empty());
+
+    let subscribers = sqlx::query_as::<_, Subscriber>(
+        r#"
+        WITH matching AS (
+            SELECT id
+            FROM subscribers
+            WHERE org_id = $1
+              AND is_verified = FALSE
+              AND ($2::uuid[] IS NULL OR id = ANY($2))
+              AND ($3::text IS NULL OR source = $3)
+              AND ($4::text IS NULL OR split_part(email, '@', 2) = $4)
+              AND ($5::timestamptz IS NULL OR created_at >= $5)
+              AND ($6::timestamptz IS NULL OR verification_sent_at IS NULL OR verification_sent_at < $6)
+              AND (
+                  verification_sent_at IS NULL
+                  OR verification_sent_at < NOW() - make_interval(mins => $7::int)
+              )
+            ORDER BY created_at ASC
+            LIMIT $8
+            FOR UPDATE SKIP LOCKED
+        )
+        UPDATE subscribers s
+        SET
+            verification_token = gen_random_uuid()::text,
+            verification_sent_at = NOW(),
+            updated_at = NOW()
+        FROM matching
+        WHERE s.id = matching.id
+        RETURNING s.*
+        "#,
+    )
+    .bind(org_id)
+    .bind(filter.ids.as_deref())
+    .bind(filter.source)
+    .bind(email_domain)
+    .bind(filter.created_after)
+    .bind(filter.sent_before)
+    .bind(cooldown_minutes as i32)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(subscribers)
+}
+
+pub async fn delete_many(
+    pool: &PgPool,
+    org_id: Uuid,
+    ids: &[Uuid],
+    emails: &[String],
+) -> Result<u64, AppError> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM subscribers
+        WHERE org_id = $1
+          AND (id = ANY($2) OR lower(email) = ANY($3))
+        "#,
+    )
+    .bind(org_id)
+    .bind(ids)
+    .bind(emails)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
 pub async fn delete_by_id(pool: &PgPool, org_id: Uuid, id: Uuid) -> Result<(), AppError> {
     let result = sqlx::query("DELETE FROM subscribers WHERE org_id = $1 AND id = $2")
         .bind(org_id)
@@ -17,7 +17,7 @@ pub struct PendingWebhookDelivery {
     pub secret: String,
 }
 
-#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct WebhookDeliveryEntry {
     pub id: Uuid,
     pub webhook_config_id: Uuid,
@@ -43,6 +43,10 @@ pub struct DeliveryFailureUpdate<'a> {
     pub next_retry_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+/// Events meant for the organization's own tooling. They go to configured
+/// webhooks only, never to public webhook subscribers.
+pub const INTERNAL_EVENTS: &[&str] = &["incident.escalated"];
+
 pub async fn enqueue_for_event<T: Serialize>(
     pool: &PgPool,
     org_id: Uuid,
@@ -78,17 +82,30 @@ pub async fn enqueue_for_event<T: Serialize>(
                 WHEN $2 = 'incident.updated' THEN np.webhook_on_incident_updated
                 WHEN $2 = 'incident.resolved' THEN np.webhook_on_incident_resolved
                 WHEN $2 = 'service.status_changed' THEN np.webhook_on_service_status_changed
+                WHEN $2 = 'incident.escalated' THEN TRUE
                 ELSE FALSE
               END = TRUE
         "#,
     )
     .bind(org_id)
     .bind(event_type)
-    .bind(payload)
+    .bind(&payload)
     .execute(pool)
     .await?;
 
-    Ok(result.rows_affected())
+    if INTERNAL_EVENTS.contains(&event_type) {
+        return Ok(result.rows_affected());
+    }
+
+    let subscriber_deliveries =
+        sqlx::query_scalar::<_, i32>("SELECT enqueue_webhook_subscriber_deliveries($1, $2, $3)")
+            .bind(org_id)
+            .bind(event_type)
+            .bind(&payload)
+            .fetch_one(pool)
+            .await?;
+
+    Ok(result.rows_affected() + subscriber_deliveries.max(0) as u64)
 }
 
 pub async fn claim_pending(
@@ -0,0 +1,273 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use shared::error::AppError;
+use shared::models::webhook::WebhookSubscriber;
+
+use crate::db::webhook_deliveries::{DeliveryFailureUpdate, PendingWebhookDelivery};
+
+const SUBSCRIBER_COLUMNS: &str = r#"
+    id,
+    org_id,
+    status_page_id,
+    url,
+    verified_at,
+    consecutive_failures,
+    disabled_at,
+    disabled_reason,
+    last_delivered_at,
+    created_at,
+    updated_at
+"#;
+
+/// Stores a subscriber whose endpoint has just echoed our challenge. An
+/// existing subscription for the same URL is re-enabled and gets the new
+/// secret and unsubscribe token. `status_page_id` is `None` for the
+/// primary page.
+pub async fn upsert_verified(
+    pool: &PgPool,
+    org_id: Uuid,
+    status_page_id: Option<Uuid>,
+    url: &str,
+    secret: &str,
+    unsubscribe_token: &str,
+) -> Result<WebhookSubscriber, AppError> {
+    let subscriber = sqlx::query_as::<_, WebhookSubscriber>(&format!(
+        r#"
+        INSERT INTO webhook_subscribers (
+            org_id, status_page_id, url, secret, unsubscribe_token, verified_at
+        )
+        VALUES ($1, $2, $3, $4, $5, NOW())
+        ON CONFLICT (org_id, status_page_id, url) DO UPDATE
+        SET
+            secret = EXCLUDED.secret,
+            unsubscribe_token = EXCLUDED.unsubscribe_token,
+            verified_at = NOW(),
+            consecutive_failures = 0,
+            disabled_at = NULL,
+            disabled_reason = NULL,
+            updated_at = NOW()
+        RETURNING {SUBSCRIBER_COLUMNS}
+        "#
+    ))
+    .bind(org_id)
+    .bind(status_page_id)
+    .bind(url)
+    .bind(secret)
+    .bind(unsubscribe_token)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(subscriber)
+}
+
+pub async fn unsubscribe(
+    pool: &PgPool,
+    org_id: Uuid,
+    token: &str,
+) -> Result<Option<WebhookSubscriber>, AppError> {
+    let subscriber = sqlx::query_as::<_, WebhookSubscriber>(&format!(
+        r#"
+        DELETE FROM webhook_subscribers
+        WHERE org_id = $1 AND unsubscribe_token = $2
+        RETURNING {SUBSCRIBER_COLUMNS}
+        "#
+    ))
+    .bind(org_id)
+    .bind(token)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(subscriber)
+}
+
+pub async fn list_by_org(pool: &PgPool, org_id: Uuid) -> Result<Vec<WebhookSubscriber>, AppError> {
+    let subscribers = sqlx::query_as::<_, WebhookSubscriber>(&format!(
+        r#"
+        SELECT {SUBSCRIBER_COLUMNS}
+        FROM webhook_subscribers
+        WHERE org_id = $1
+        ORDER BY disabled_at IS NOT NULL, created_at DESC
+        "#
+    ))
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(subscribers)
+}
+
+pub async fn delete_by_id(pool: &PgPool, org_id: Uuid, id: Uuid) -> Result<(), AppError> {
+    let result = sqlx::query("DELETE FROM webhook_subscribers WHERE org_id = $1 AND id = $2")
+        .bind(org_id)
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound(
+            "Webhook subscriber not found".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+pub async fn claim_pending(
+    pool: &PgPool,
+    limit: i64,
+) -> Result<Vec<PendingWebhookDelivery>, AppError> {
+    let rows = sqlx::query_as::<_, PendingWebhookDelivery>(
+        r#"
+        WITH due AS (
+            SELECT wsd.id
+            FROM webhook_subscriber_deliveries wsd
+            JOIN webhook_subscribers ws ON ws.id = wsd.webhook_subscriber_id
+            WHERE wsd.status = 'pending'
+              AND ws.disabled_at IS NULL
+              AND (wsd.next_retry_at IS NULL OR wsd.next_retry_at <= NOW())
+            ORDER BY wsd.created_at ASC
+            LIMIT $1
+            FOR UPDATE OF wsd SKIP LOCKED
+        )
+        UPDATE webhook_subscriber_deliveries wsd
+        SET
+            status = 'sending',
+            attempt_count = wsd.attempt_count + 1
+        FROM due, webhook_subscribers ws
+        WHERE wsd.id = due.id
+          AND ws.id = wsd.webhook_subscriber_id
+        RETURNING
+            wsd.id AS delivery_id,
+            wsd.event_type,
+            wsd.payload,
+            wsd.attempt_count,
+            wsd.max_attempts,
+            ws.url,
+            ws.secret
+        "#,
+    )
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn mark_success(
+    pool: &PgPool,
+    delivery_id: Uuid,
+    response_status_code: Option<i32>,
+    response_body: Option<&str>,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        WITH delivered AS (
+            UPDATE webhook_subscriber_deliveries
+            SET
+                status = 'success',
+                response_status_code = $2,
+                response_body = $3,
+                error_message = NULL,
+                next_retry_at = NULL,
+                delivered_at = NOW()
+            WHERE id = $1
+            RETURNING webhook_subscriber_id
+        )
+        UPDATE webhook_subscribers ws
+        SET consecutive_failures = 0, last_delivered_at = NOW()
+        FROM delivered
+        WHERE ws.id = delivered.webhook_subscriber_id
+        "#,
+    )
+    .bind(delivery_id)
+    .bind(response_status_code)
+    .bind(response_body)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Records a failed attempt. Once a delivery exhausts its retries the
+/// subscriber's failure streak grows, and a streak of
+/// `auto_unsubscribe_after` disables the subscriber and cancels its queue.
+/// Returns whether the subscriber was disabled.
+pub async fn mark_failure(
+    pool: &PgPool,
+    delivery_id: Uuid,
+    update: DeliveryFailureUpdate<'_>,
+    auto_unsubscribe_after: i32,
+) -> Result<bool, AppError> {
+    let exhausted = update.attempt_count >= update.max_attempts;
+    let status = if exhausted { "failed" } else { "pending" };
+
+    let mut tx = pool.begin().await?;
+    let subscriber_id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        UPDATE webhook_subscriber_deliveries
+        SET
+            status = $2,
+            response_status_code = $3,
+            response_body = $4,
+            error_message = $5,
+            next_retry_at = $6,
+            delivered_at = NULL
+        WHERE id = $1
+        RETURNING webhook_subscriber_id
+        "#,
+    )
+    .bind(delivery_id)
+    .bind(status)
+    .bind(update.response_status_code)
+    .bind(update.response_body)
+    .bind(update.error_message)
+    .bind(update.next_retry_at)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if !exhausted {
+        tx.commit().await?;
+        return Ok(false);
+    }
+
+    let disabled = sqlx::query_scalar::<_, bool>(
+        r#"
+        UPDATE webhook_subscribers
+        SET
+            consecutive_failures = consecutive_failures + 1,
+            disabled_at = CASE
+                WHEN consecutive_failures + 1 >= $2 THEN NOW()
+                ELSE disabled_at
+            END,
+            disabled_reason = CASE
+                WHEN consecutive_failures + 1 >= $2
+                    THEN 'Automatically unsubscribed after repeated delivery failures'
+                ELSE disabled_reason
+            END,
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING disabled_at IS NOT NULL
+        "#,
+    )
+    .bind(subscriber_id)
+    .bind(auto_unsubscribe_after)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if disabled {
+        sqlx::query(
+            r#"
+            UPDATE webhook_subscriber_deliveries
+            SET status = 'canceled', next_retry_at = NULL, error_message = 'Subscriber disabled'
+            WHERE webhook_subscriber_id = $1 AND status = 'pending'
+            "#,
+        )
+        .bind(subscriber_id)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(disabled)
+}
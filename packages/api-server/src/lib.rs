@@ -0,0 +1,6 @@
+pub mod config;
+pub mod db;
+pub mod middleware;
+pub mod routes;
+pub mod services;
+pub mod state;
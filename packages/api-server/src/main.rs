@@ -1,10 +1,4 @@
-mod config;
-mod db;
-mod middleware;
-mod routes;
-mod services;
-mod state;
-
+use std::net::SocketAddr;
 use std::time::Duration;
 
 use axum::http::{HeaderValue, Method};
@@ -13,13 +7,20 @@ use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
 use tracing_subscriber::EnvFilter;
 
-use crate::config::Config;
-use crate::middleware::request_id::RequestIdLayer;
-use crate::routes::api_router;
-use crate::services::downgrade;
-use crate::services::email_dispatcher;
-use crate::services::webhook_dispatcher;
-use crate::state::AppState;
+use api_server::config::Config;
+use api_server::middleware::request_id::RequestIdLayer;
+use api_server::routes::api_router;
+use api_server::services::certificates;
+use api_server::services::custom_domains;
+use api_server::services::downgrade;
+use api_server::services::email_digest;
+use api_server::services::email_dispatcher;
+use api_server::services::incident_escalation;
+use api_server::services::org_deletion;
+use api_server::services::org_export;
+use api_server::services::sms_dispatcher;
+use api_server::services::webhook_dispatcher;
+use api_server::state::AppState;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -81,7 +82,7 @@ async fn main() -> anyhow::Result<()> {
         )
         .allow_credentials(true);
 
-    let publisher = services::redis_publisher::RedisPublisher::new(redis.clone());
+    let publisher = api_server::services::redis_publisher::RedisPublisher::new(redis.clone());
 
     let state = AppState {
         pool,
@@ -92,7 +93,14 @@ async fn main() -> anyhow::Result<()> {
 
     webhook_dispatcher::spawn(state.pool.clone(), config.clone());
     email_dispatcher::spawn(state.pool.clone(), config.clone());
+    email_digest::spawn(state.pool.clone(), config.clone());
+    sms_dispatcher::spawn(state.pool.clone(), config.clone());
+    incident_escalation::spawn(state.pool.clone(), config.clone());
     downgrade::spawn(state.pool.clone(), config.clone());
+    certificates::spawn(state.pool.clone(), config.clone());
+    custom_domains::spawn(state.pool.clone(), config.clone());
+    org_export::spawn(state.pool.clone(), config.clone());
+    org_deletion::spawn(state.pool.clone(), config.clone());
 
     let app = api_router(state)
         .layer(TraceLayer::new_for_http())
@@ -104,9 +112,12 @@ async fn main() -> anyhow::Result<()> {
 
     let listener = tokio::net::TcpListener::bind(&addr).await?;
 
-    axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
-        .await?;
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal())
+    .await?;
 
     tracing::info!("Server stopped");
     Ok(())
@@ -2,12 +2,24 @@ use axum::extract::FromRequestParts;
 use axum::http::request::Parts;
 use shared::error::AppError;
 use shared::models::user::User;
+use uuid::Uuid;
 
+use crate::services::cli_auth;
 use crate::state::AppState;
 
 #[derive(Debug, Clone)]
 pub struct CurrentUser {
     pub user: User,
+    /// Set when the request authenticated with a command-line client
+    /// credential rather than a browser session.
+    pub cli_credential_id: Option<Uuid>,
+}
+
+#[derive(sqlx::FromRow)]
+struct CliUserRow {
+    #[sqlx(flatten)]
+    user: User,
+    credential_id: Uuid,
 }
 
 impl FromRequestParts<AppState> for CurrentUser {
@@ -17,6 +29,10 @@ impl FromRequestParts<AppState> for CurrentUser {
         parts: &mut Parts,
         state: &AppState,
     ) -> Result<Self, Self::Rejection> {
+        if let Some(token) = extract_cli_token(&parts.headers) {
+            return find_cli_user(state, token).await;
+        }
+
         // Extract session token from cookies
         let cookie_header = parts
             .headers
@@ -44,10 +60,54 @@ impl FromRequestParts<AppState> for CurrentUser {
         })?
         .ok_or(AppError::Unauthorized)?;
 
-        Ok(CurrentUser { user })
+        Ok(CurrentUser {
+            user,
+            cli_credential_id: None,
+        })
     }
 }
 
+/// Resolves a command-line client credential, recording when it was last
+/// used.
+async fn find_cli_user(state: &AppState, token: &str) -> Result<CurrentUser, AppError> {
+    let row = sqlx::query_as::<_, CliUserRow>(
+        r#"
+        WITH credential AS (
+            UPDATE cli_credentials
+            SET last_used_at = NOW()
+            WHERE token_hash = $1 AND revoked_at IS NULL AND expires_at > NOW()
+            RETURNING id, user_id
+        )
+        SELECT u.id, u.name, u.email, u."emailVerified", u.image, u.created_at,
+               c.id AS credential_id
+        FROM users u
+        JOIN credential c ON c.user_id = u.id
+        "#,
+    )
+    .bind(cli_auth::hash_token(token))
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = %e, "Failed to query CLI credential");
+        AppError::Unauthorized
+    })?
+    .ok_or(AppError::Unauthorized)?;
+
+    Ok(CurrentUser {
+        user: row.user,
+        cli_credential_id: Some(row.credential_id),
+    })
+}
+
+fn extract_cli_token(headers: &axum::http::HeaderMap) -> Option<&str> {
+    headers
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(str::trim)
+        .filter(|token| token.starts_with(cli_auth::CLI_TOKEN_PREFIX))
+}
+
 fn extract_session_token(cookie_header: &str) -> Option<String> {
     for cookie in cookie_header.split(';') {
         let cookie = cookie.trim();
@@ -78,6 +138,16 @@ mod tests {
         assert_eq!(extract_session_token(header), Some("xyz789".to_string()));
     }
 
+    #[test]
+    fn test_extract_cli_token_requires_prefix() {
+        let mut headers = axum::http::HeaderMap::new();
+        headers.insert("authorization", "Bearer spcli_abc".parse().unwrap());
+        assert_eq!(extract_cli_token(&headers), Some("spcli_abc"));
+
+        headers.insert("authorization", "Bearer other-token".parse().unwrap());
+        assert_eq!(extract_cli_token(&headers), None);
+    }
+
     #[test]
     fn test_extract_session_token_missing() {
         let header = "other=value; another=thing";
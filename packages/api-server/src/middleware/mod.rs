@@ -1,3 +1,4 @@
 pub mod auth;
 pub mod org_access;
+pub mod page_viewer;
 pub mod request_id;
@@ -1,7 +1,10 @@
 use axum::extract::FromRequestParts;
 use axum::extract::OriginalUri;
 use axum::http::request::Parts;
-use shared::enums::{CustomDomainStatus, DowngradeState, MemberRole, OrganizationPlan};
+use shared::enums::{
+    CustomDomainStatus, CustomDomainVerificationMethod, DowngradeState, Locale, MemberRole,
+    OrganizationPlan, PageVisibility,
+};
 use shared::error::AppError;
 use shared::models::organization::Organization;
 
@@ -67,12 +70,16 @@ impl FromRequestParts<AppState> for OrgAccess {
         let row = sqlx::query_as::<_, OrgMemberRow>(
             r#"
             SELECT o.id, o.name, o.slug, o.plan, o.logo_url, o.brand_color,
-                   o.timezone, o.custom_domain, o.custom_domain_verified_at, o.custom_domain_status, o.stripe_customer_id,
+                   o.timezone, o.locale, o.custom_domain, o.custom_domain_verified_at,
+                   o.custom_domain_status,
+                   o.custom_domain_verification_token, o.custom_domain_verification_method,
+                   o.custom_domain_checked_at, o.custom_domain_grace_ends_at, o.stripe_customer_id,
                    o.stripe_subscription_id, o.subscription_status,
                    o.stripe_price_id, o.current_period_end,
                    o.cancel_at_period_end, o.billing_email, o.trial_ends_at,
                    o.downgrade_target_plan, o.downgrade_started_at, o.downgrade_grace_ends_at,
                    o.downgrade_state, o.downgrade_warning_stage,
+                   o.page_visibility, o.page_allowed_email_domains, o.page_allowed_cidrs,
                    o.created_at, o.updated_at, m.role
             FROM organizations o
             JOIN members m ON m.org_id = o.id
@@ -99,9 +106,14 @@ impl FromRequestParts<AppState> for OrgAccess {
                 logo_url: row.logo_url,
                 brand_color: row.brand_color,
                 timezone: row.timezone,
+                locale: row.locale,
                 custom_domain: row.custom_domain,
                 custom_domain_verified_at: row.custom_domain_verified_at,
                 custom_domain_status: row.custom_domain_status,
+                custom_domain_verification_token: row.custom_domain_verification_token,
+                custom_domain_verification_method: row.custom_domain_verification_method,
+                custom_domain_checked_at: row.custom_domain_checked_at,
+                custom_domain_grace_ends_at: row.custom_domain_grace_ends_at,
                 stripe_customer_id: row.stripe_customer_id,
                 stripe_subscription_id: row.stripe_subscription_id,
                 subscription_status: row.subscription_status,
@@ -115,6 +127,9 @@ impl FromRequestParts<AppState> for OrgAccess {
                 downgrade_grace_ends_at: row.downgrade_grace_ends_at,
                 downgrade_state: row.downgrade_state,
                 downgrade_warning_stage: row.downgrade_warning_stage,
+                page_visibility: row.page_visibility,
+                page_allowed_email_domains: row.page_allowed_email_domains,
+                page_allowed_cidrs: row.page_allowed_cidrs,
                 created_at: row.created_at,
                 updated_at: row.updated_at,
             },
@@ -133,9 +148,14 @@ struct OrgMemberRow {
     logo_url: Option<String>,
     brand_color: String,
     timezone: String,
+    locale: Locale,
     custom_domain: Option<String>,
     custom_domain_verified_at: Option<chrono::DateTime<chrono::Utc>>,
     custom_domain_status: CustomDomainStatus,
+    custom_domain_verification_token: String,
+    custom_domain_verification_method: Option<CustomDomainVerificationMethod>,
+    custom_domain_checked_at: Option<chrono::DateTime<chrono::Utc>>,
+    custom_domain_grace_ends_at: Option<chrono::DateTime<chrono::Utc>>,
     stripe_customer_id: Option<String>,
     stripe_subscription_id: Option<String>,
     subscription_status: shared::enums::SubscriptionStatus,
@@ -149,6 +169,9 @@ struct OrgMemberRow {
     downgrade_grace_ends_at: Option<chrono::DateTime<chrono::Utc>>,
     downgrade_state: DowngradeState,
     downgrade_warning_stage: i32,
+    page_visibility: PageVisibility,
+    page_allowed_email_domains: Vec<String>,
+    page_allowed_cidrs: Vec<String>,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
     role: MemberRole,
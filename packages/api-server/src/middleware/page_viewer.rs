@@ -0,0 +1,87 @@
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr};
+
+use axum::extract::{ConnectInfo, FromRequestParts, RawPathParams};
+use axum::http::request::Parts;
+use chrono::Utc;
+use shared::enums::PageVisibility;
+use shared::error::AppError;
+
+use crate::db;
+use crate::services::viewer_sessions;
+use crate::state::AppState;
+
+/// A request that may read the status page named by the `{slug}` path
+/// parameter. Rejects viewers the page's visibility setting keeps out.
+#[derive(Debug, Clone)]
+pub struct PageViewer {
+    pub org_id: uuid::Uuid,
+    /// `None` for the organization's primary page.
+    pub page_id: Option<uuid::Uuid>,
+    pub visibility: PageVisibility,
+}
+
+impl PageViewer {
+    /// Restricted pages must not be stored by shared caches.
+    pub fn is_restricted(&self) -> bool {
+        self.visibility != PageVisibility::Public
+    }
+}
+
+impl FromRequestParts<AppState> for PageViewer {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let params = RawPathParams::from_request_parts(parts, state)
+            .await
+            .map_err(|_| AppError::Validation("Missing status page slug".to_string()))?;
+        let slug = params
+            .iter()
+            .find(|(name, _)| *name == "slug")
+            .map(|(_, value)| value.to_string())
+            .ok_or_else(|| AppError::Validation("Missing status page slug".to_string()))?;
+
+        let access = db::page_access::find_by_slug(&state.pool, &slug)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))?;
+
+        let Ok(ClientIp(client_ip)) = ClientIp::from_request_parts(parts, state).await;
+        if !viewer_sessions::viewer_allowed(&access, &parts.headers, client_ip, Utc::now()) {
+            return Err(viewer_sessions::access_denied(&access));
+        }
+
+        Ok(PageViewer {
+            org_id: access.org_id,
+            page_id: access.page_id,
+            visibility: access.page_visibility,
+        })
+    }
+}
+
+/// The viewer's address: the connection peer, or the hop recorded by a
+/// proxy in `TRUSTED_PROXY_CIDRS`. `None` when it cannot be trusted.
+#[derive(Debug, Clone, Copy)]
+pub struct ClientIp(pub Option<IpAddr>);
+
+impl FromRequestParts<AppState> for ClientIp {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+
+        Ok(ClientIp(viewer_sessions::client_ip(
+            &parts.headers,
+            peer,
+            &state.config.trusted_proxy_cidrs,
+        )))
+    }
+}
@@ -8,17 +8,20 @@ use chrono::Utc;
 use serde::Serialize;
 use uuid::Uuid;
 
+use shared::enums::OrganizationDeletionStatus;
 use shared::error::AppError;
 use shared::models::invitation::InvitationWithInviter;
 use shared::models::organization::EntitlementViolation;
 
 use crate::db;
+use crate::db::organization_deletions::OrganizationDeletion;
 use crate::state::AppState;
 
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/queue-health", get(queue_health))
         .route("/organizations/search", get(search_organizations))
+        .route("/organization-deletions", get(list_organization_deletions))
         .route("/organizations/{slug}/support", get(organization_support))
         .route(
             "/organizations/{slug}/billing/sync",
@@ -46,12 +49,12 @@ pub fn router() -> Router<AppState> {
         )
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct DataResponse<T: Serialize> {
     data: T,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct QueueHealthResponse {
     pending_email_deliveries: i64,
     failed_email_deliveries: i64,
@@ -63,7 +66,7 @@ struct QueueHealthResponse {
     organizations_in_grace: i64,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct SupportOrganizationResponse {
     organization: SupportOrganization,
     queue_health: OrgQueueHealth,
@@ -76,7 +79,7 @@ struct SupportOrganizationResponse {
     recent_audit_logs: Vec<db::audit_logs::AuditLogEntry>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct SupportOrganization {
     id: Uuid,
     name: String,
@@ -98,7 +101,7 @@ struct SupportOrganization {
     webhook_count: i64,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct OrgQueueHealth {
     pending_email_deliveries: i64,
     failed_email_deliveries: i64,
@@ -106,12 +109,12 @@ struct OrgQueueHealth {
     failed_webhook_deliveries: i64,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct MessageResponse {
     message: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct SupportSearchResult {
     id: Uuid,
     name: String,
@@ -126,11 +129,21 @@ struct SupportSearchResult {
     downgrade_grace_ends_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 struct SearchParams {
     q: String,
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/admin/queue-health",
+    tag = "admin",
+    params(
+        ("x-statuspage-admin-token" = String, Header, description = "Internal operator token"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<QueueHealthResponse>)),
+)]
 async fn queue_health(
     State(state): State<AppState>,
     headers: HeaderMap,
@@ -192,6 +205,16 @@ async fn queue_health(
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/admin/organizations/search",
+    tag = "admin",
+    params(
+        ("x-statuspage-admin-token" = String, Header, description = "Internal operator token"),
+        SearchParams,
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<Vec<SupportSearchResult>>)),
+)]
 async fn search_organizations(
     State(state): State<AppState>,
     headers: HeaderMap,
@@ -220,6 +243,47 @@ async fn search_organizations(
     Ok(Json(DataResponse { data: results }))
 }
 
+#[derive(serde::Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+struct DeletionListParams {
+    status: Option<OrganizationDeletionStatus>,
+}
+
+/// Deletion requests are kept after the organization is gone, so this is
+/// where operators find who deleted what, the Stripe outcome and the purge
+/// counts.
+#[utoipa::path(
+    get,
+    path = "/api/admin/organization-deletions",
+    tag = "admin",
+    params(
+        ("x-statuspage-admin-token" = String, Header, description = "Internal operator token"),
+        DeletionListParams,
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<Vec<OrganizationDeletion>>)),
+)]
+async fn list_organization_deletions(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<DeletionListParams>,
+) -> Result<Json<DataResponse<Vec<OrganizationDeletion>>>, AppError> {
+    require_internal_admin(&state, &headers)?;
+
+    let deletions =
+        db::organization_deletions::list_recent(&state.pool, params.status, 100).await?;
+    Ok(Json(DataResponse { data: deletions }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/organizations/{slug}/support",
+    tag = "admin",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("x-statuspage-admin-token" = String, Header, description = "Internal operator token"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<SupportOrganizationResponse>)),
+)]
 async fn organization_support(
     State(state): State<AppState>,
     headers: HeaderMap,
@@ -339,6 +403,16 @@ async fn organization_support(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/admin/organizations/{slug}/billing/sync",
+    tag = "admin",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("x-statuspage-admin-token" = String, Header, description = "Internal operator token"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<MessageResponse>)),
+)]
 async fn sync_billing_state(
     State(state): State<AppState>,
     headers: HeaderMap,
@@ -375,6 +449,16 @@ async fn sync_billing_state(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/admin/organizations/{slug}/downgrade/enforce",
+    tag = "admin",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("x-statuspage-admin-token" = String, Header, description = "Internal operator token"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<MessageResponse>)),
+)]
 async fn enforce_downgrade(
     State(state): State<AppState>,
     headers: HeaderMap,
@@ -397,8 +481,10 @@ async fn enforce_downgrade(
             target_id: Some(&org.id.to_string()),
             details: serde_json::json!({
                 "disabled_monitor_count": result.disabled_monitor_ids.len(),
+                "disabled_status_page_count": result.disabled_status_page_ids.len(),
                 "blocked_custom_domain": result.blocked_custom_domain,
                 "disabled_webhooks": result.disabled_webhooks,
+                "canceled_sms_count": result.canceled_sms_count,
             }),
         },
     )
@@ -411,6 +497,16 @@ async fn enforce_downgrade(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/admin/organizations/{slug}/downgrade/cancel",
+    tag = "admin",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("x-statuspage-admin-token" = String, Header, description = "Internal operator token"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<MessageResponse>)),
+)]
 async fn cancel_downgrade(
     State(state): State<AppState>,
     headers: HeaderMap,
@@ -455,6 +551,17 @@ async fn cancel_downgrade(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/admin/organizations/{slug}/invitations/{id}/resend",
+    tag = "admin",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+        ("x-statuspage-admin-token" = String, Header, description = "Internal operator token"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<MessageResponse>)),
+)]
 async fn resend_invitation_email(
     State(state): State<AppState>,
     headers: HeaderMap,
@@ -511,6 +618,17 @@ async fn resend_invitation_email(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/admin/organizations/{slug}/retry/email/{id}",
+    tag = "admin",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+        ("x-statuspage-admin-token" = String, Header, description = "Internal operator token"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<MessageResponse>)),
+)]
 async fn retry_email_delivery(
     State(state): State<AppState>,
     headers: HeaderMap,
@@ -552,6 +670,17 @@ async fn retry_email_delivery(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/admin/organizations/{slug}/retry/webhook/{id}",
+    tag = "admin",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+        ("x-statuspage-admin-token" = String, Header, description = "Internal operator token"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<MessageResponse>)),
+)]
 async fn retry_webhook_delivery(
     State(state): State<AppState>,
     headers: HeaderMap,
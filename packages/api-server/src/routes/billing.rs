@@ -14,6 +14,12 @@ pub fn router() -> Router<AppState> {
     Router::new().route("/stripe/webhook", post(handle_stripe_webhook))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/billing/stripe/webhook",
+    tag = "billing",
+    responses((status = 204, description = "No content")),
+)]
 async fn handle_stripe_webhook(
     State(state): State<AppState>,
     headers: HeaderMap,
@@ -0,0 +1,230 @@
+use std::time::Duration as StdDuration;
+
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    routing::{delete, get, post},
+    Json, Router,
+};
+use serde::Serialize;
+use uuid::Uuid;
+
+use shared::error::AppError;
+use shared::models::cli_auth::{
+    CliCredential, CliLoginDetails, CliLoginPollResponse, CliLoginStart, DecideCliLoginRequest,
+    PollCliLoginRequest, StartCliLoginRequest,
+};
+
+use crate::db;
+use crate::middleware::auth::CurrentUser;
+use crate::services::cli_auth;
+use crate::services::rate_limit::{enforce_rate_limit, rate_limit_subject};
+use crate::state::AppState;
+
+const LOGIN_RATE_WINDOW: StdDuration = StdDuration::from_secs(15 * 60);
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/login", post(start_login))
+        .route("/login/poll", post(poll_login))
+        .route("/login/decision", post(decide_login))
+        .route("/login/{user_code}", get(get_login))
+        .route("/credentials", get(list_credentials))
+        .route("/credentials/{id}", delete(revoke_credential))
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+struct DataResponse<T: Serialize> {
+    data: T,
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+struct MessageResponse {
+    message: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/cli/login",
+    tag = "cli",
+    request_body = StartCliLoginRequest,
+    responses((status = 201, description = "Login started; the user approves the code in the browser", body = DataResponse<CliLoginStart>)),
+)]
+async fn start_login(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<StartCliLoginRequest>,
+) -> Result<(StatusCode, Json<DataResponse<CliLoginStart>>), AppError> {
+    enforce_rate_limit(
+        &state.redis,
+        "cli_login_start",
+        &rate_limit_subject(&headers, "local"),
+        10,
+        LOGIN_RATE_WINDOW,
+    )
+    .await?;
+
+    let login = cli_auth::start_login(
+        &state.pool,
+        &state.config.app_base_url,
+        req.client_name.as_deref(),
+    )
+    .await?;
+
+    Ok((StatusCode::CREATED, Json(DataResponse { data: login })))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/cli/login/poll",
+    tag = "cli",
+    request_body = PollCliLoginRequest,
+    responses(
+        (status = 200, description = "Login status, with the credential once approved", body = DataResponse<CliLoginPollResponse>),
+        (status = 404, description = "Unknown or already redeemed login"),
+    ),
+)]
+async fn poll_login(
+    State(state): State<AppState>,
+    Json(req): Json<PollCliLoginRequest>,
+) -> Result<Json<DataResponse<CliLoginPollResponse>>, AppError> {
+    let response = cli_auth::poll_login(&state.pool, req.device_code.trim()).await?;
+    if let Some(credential) = &response.credential {
+        tracing::info!(credential_id = %credential.id, "Issued CLI credential");
+    }
+
+    Ok(Json(DataResponse { data: response }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/cli/login/{user_code}",
+    tag = "cli",
+    params(
+        ("user_code" = String, Path, description = "Code shown by the CLI"),
+    ),
+    responses((status = 200, description = "Pending login awaiting approval", body = DataResponse<CliLoginDetails>)),
+)]
+async fn get_login(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    current_user: CurrentUser,
+    Path(user_code): Path<String>,
+) -> Result<Json<DataResponse<CliLoginDetails>>, AppError> {
+    let user_code = checked_user_code(&state, &headers, &current_user, &user_code).await?;
+    let request = db::cli_credentials::find_pending_by_user_code(&state.pool, &user_code)
+        .await?
+        .ok_or_else(not_found)?;
+
+    Ok(Json(DataResponse {
+        data: request.into(),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/cli/login/decision",
+    tag = "cli",
+    request_body = DecideCliLoginRequest,
+    responses((status = 200, description = "Login approved or denied", body = DataResponse<MessageResponse>)),
+)]
+async fn decide_login(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    current_user: CurrentUser,
+    Json(req): Json<DecideCliLoginRequest>,
+) -> Result<Json<DataResponse<MessageResponse>>, AppError> {
+    let user_code = checked_user_code(&state, &headers, &current_user, &req.user_code).await?;
+    let decided = db::cli_credentials::decide_login_request(
+        &state.pool,
+        &user_code,
+        current_user.user.id,
+        req.approve,
+    )
+    .await?;
+    if !decided {
+        return Err(not_found());
+    }
+
+    let message = if req.approve {
+        "CLI login approved. You can return to your terminal."
+    } else {
+        "CLI login denied."
+    };
+    Ok(Json(DataResponse {
+        data: MessageResponse {
+            message: message.to_string(),
+        },
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/cli/credentials",
+    tag = "cli",
+    responses((status = 200, description = "The current user's active CLI credentials", body = DataResponse<Vec<CliCredential>>)),
+)]
+async fn list_credentials(
+    State(state): State<AppState>,
+    current_user: CurrentUser,
+) -> Result<Json<DataResponse<Vec<CliCredential>>>, AppError> {
+    let credentials =
+        db::cli_credentials::list_active_for_user(&state.pool, current_user.user.id).await?;
+    Ok(Json(DataResponse { data: credentials }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/cli/credentials/{id}",
+    tag = "cli",
+    params(
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 204, description = "No content")),
+)]
+async fn revoke_credential(
+    State(state): State<AppState>,
+    current_user: CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    if !db::cli_credentials::revoke(&state.pool, current_user.user.id, id).await? {
+        return Err(AppError::NotFound("Credential not found".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Logins are approved from a browser session only, so one CLI credential
+/// cannot mint another. Lookups are rate limited so codes cannot be guessed.
+async fn checked_user_code(
+    state: &AppState,
+    headers: &HeaderMap,
+    current_user: &CurrentUser,
+    input: &str,
+) -> Result<String, AppError> {
+    if current_user.cli_credential_id.is_some() {
+        return Err(AppError::Forbidden(
+            "CLI logins must be approved from the web app".to_string(),
+        ));
+    }
+
+    let subject = format!(
+        "{}:{}",
+        rate_limit_subject(headers, "local"),
+        current_user.user.id
+    );
+    enforce_rate_limit(
+        &state.redis,
+        "cli_login_decision",
+        &subject,
+        20,
+        LOGIN_RATE_WINDOW,
+    )
+    .await?;
+
+    cli_auth::normalize_user_code(input).ok_or_else(not_found)
+}
+
+fn not_found() -> AppError {
+    AppError::NotFound("Login request not found or expired".to_string())
+}
@@ -0,0 +1,179 @@
+use axum::{extract::State, routing::post, Json, Router};
+use serde::Serialize;
+
+use shared::enums::OrganizationPlan;
+use shared::error::AppError;
+use shared::models::config_sync::{ConfigPlan, ConfigSpec, ConfigSyncRequest};
+
+use crate::db;
+use crate::middleware::org_access::OrgAccess;
+use crate::services::config_sync::{self, SyncPlan};
+use crate::state::AppState;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/plan", post(plan_config))
+        .route("/apply", post(apply_config))
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+struct DataResponse<T: Serialize> {
+    data: T,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/config/plan",
+    tag = "config",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = ConfigSyncRequest,
+    responses((status = 200, description = "Changes an apply would make; nothing is written", body = DataResponse<ConfigPlan>)),
+)]
+async fn plan_config(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Json(req): Json<ConfigSyncRequest>,
+) -> Result<Json<DataResponse<ConfigPlan>>, AppError> {
+    org_access.require_admin()?;
+
+    let sync = checked_plan(&state, &org_access, &req).await?;
+    Ok(Json(DataResponse { data: sync.plan }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/config/apply",
+    tag = "config",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = ConfigSyncRequest,
+    responses(
+        (status = 200, description = "Changes applied in one transaction", body = DataResponse<ConfigPlan>),
+        (status = 409, description = "The organization changed while applying; plan again"),
+    ),
+)]
+async fn apply_config(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Json(req): Json<ConfigSyncRequest>,
+) -> Result<Json<DataResponse<ConfigPlan>>, AppError> {
+    org_access.require_admin()?;
+
+    let sync = checked_plan(&state, &org_access, &req).await?;
+    if sync.plan.changes.is_empty() {
+        return Ok(Json(DataResponse { data: sync.plan }));
+    }
+
+    let plan = config_sync::apply(&state.pool, org_access.org.id, &req.spec, &sync.plan).await?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "config.apply",
+            target_type: "organization",
+            target_id: Some(&org_access.org.id.to_string()),
+            details: serde_json::json!({
+                "prune": plan.prune,
+                "create": plan.summary.create,
+                "update": plan.summary.update,
+                "delete": plan.summary.delete,
+            }),
+        },
+    )
+    .await?;
+
+    Ok(Json(DataResponse { data: plan }))
+}
+
+async fn checked_plan(
+    state: &AppState,
+    org_access: &OrgAccess,
+    req: &ConfigSyncRequest,
+) -> Result<SyncPlan, AppError> {
+    validate_spec(org_access.org.plan, &req.spec)?;
+    let sync = config_sync::plan(&state.pool, org_access.org.id, &req.spec, req.prune).await?;
+    super::monitors::ensure_monitor_capacity(org_access.org.plan, sync.monitors_after)?;
+    Ok(sync)
+}
+
+/// Runs every resource through the same checks as its own API.
+fn validate_spec(plan: OrganizationPlan, spec: &ConfigSpec) -> Result<(), AppError> {
+    for group in &spec.service_groups {
+        super::service_groups::validate_group_name(&group.name)?;
+    }
+
+    for service in &spec.services {
+        if service.name.trim().is_empty() {
+            return Err(AppError::Validation("Service name is required".to_string()));
+        }
+        for monitor in &service.monitors {
+            super::monitors::validate_monitor_settings(
+                monitor.monitor_type,
+                Some(&monitor.config),
+                Some(monitor.interval_seconds),
+                Some(monitor.timeout_ms),
+                monitor.recovery_threshold,
+            )
+            .map_err(|error| match error {
+                AppError::Validation(message) => {
+                    AppError::Validation(format!("Service '{}': {message}", service.name.trim()))
+                }
+                other => other,
+            })?;
+        }
+    }
+
+    if !spec.webhooks.is_empty() {
+        super::notifications::require_webhook_feature(plan)?;
+    }
+    for webhook in &spec.webhooks {
+        super::notifications::validate_webhook_payload(
+            &webhook.name,
+            &webhook.url,
+            &webhook.secret,
+            &webhook.event_types,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(value: serde_json::Value) -> ConfigSpec {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn validate_spec_applies_monitor_and_webhook_rules() {
+        let valid = spec(serde_json::json!({
+            "services": [{ "name": "API", "monitors": [
+                { "type": "tcp", "config": { "host": "db.internal", "port": 5432 } }
+            ]}]
+        }));
+        assert!(validate_spec(OrganizationPlan::Free, &valid).is_ok());
+
+        let bad_monitor = spec(serde_json::json!({
+            "services": [{ "name": "API", "monitors": [
+                { "type": "tcp", "config": { "host": "db.internal" } }
+            ]}]
+        }));
+        assert!(validate_spec(OrganizationPlan::Pro, &bad_monitor).is_err());
+
+        let webhook = spec(serde_json::json!({
+            "webhooks": [{
+                "name": "Ops", "url": "https://hooks.example.com", "secret": "s3cret-value",
+                "event_types": ["incident.created"]
+            }]
+        }));
+        assert!(validate_spec(OrganizationPlan::Free, &webhook).is_err());
+        assert!(validate_spec(OrganizationPlan::Pro, &webhook).is_ok());
+    }
+}
@@ -0,0 +1,182 @@
+use axum::{extract::State, routing::get, Json, Router};
+use serde::{Deserialize, Serialize};
+
+use shared::error::AppError;
+
+use crate::db;
+use crate::db::organization_deletions::OrganizationDeletion;
+use crate::middleware::org_access::OrgAccess;
+use crate::state::AppState;
+
+const MAX_REASON_CHARS: usize = 2_000;
+
+pub fn router() -> Router<AppState> {
+    Router::new().route(
+        "/",
+        get(get_deletion)
+            .post(schedule_deletion)
+            .delete(cancel_deletion),
+    )
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+struct DataResponse<T: Serialize> {
+    data: T,
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+struct DeletionState {
+    /// The scheduled or processing deletion, if any.
+    deletion: Option<OrganizationDeletion>,
+    cool_off_hours: i64,
+}
+
+#[derive(Deserialize, utoipa::ToSchema)]
+struct ScheduleDeletionRequest {
+    /// Must repeat the organization slug.
+    confirm_slug: String,
+    reason: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/deletion",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "Pending deletion, if one is scheduled", body = DataResponse<DeletionState>)),
+)]
+async fn get_deletion(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+) -> Result<Json<DataResponse<DeletionState>>, AppError> {
+    org_access.require_owner()?;
+
+    let deletion = db::organization_deletions::find_active(&state.pool, org_access.org.id).await?;
+    Ok(Json(DataResponse {
+        data: DeletionState {
+            deletion,
+            cool_off_hours: state.config.org_deletion_cool_off_hours,
+        },
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/deletion",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = ScheduleDeletionRequest,
+    responses(
+        (status = 202, description = "Deletion scheduled after the cool-off period", body = DataResponse<OrganizationDeletion>),
+        (status = 409, description = "Deletion is already scheduled"),
+    ),
+)]
+async fn schedule_deletion(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Json(req): Json<ScheduleDeletionRequest>,
+) -> Result<
+    (
+        axum::http::StatusCode,
+        Json<DataResponse<OrganizationDeletion>>,
+    ),
+    AppError,
+> {
+    org_access.require_owner()?;
+
+    if req.confirm_slug.trim() != org_access.org.slug {
+        return Err(AppError::Validation(
+            "Type the organization slug to confirm deletion".to_string(),
+        ));
+    }
+    let reason = req
+        .reason
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+    if reason.is_some_and(|value| value.chars().count() > MAX_REASON_CHARS) {
+        return Err(AppError::Validation(format!(
+            "Reason must be {MAX_REASON_CHARS} characters or fewer"
+        )));
+    }
+
+    let deletion = db::organization_deletions::schedule(
+        &state.pool,
+        &org_access.org,
+        org_access.user.id,
+        &org_access.user.email,
+        reason,
+        state.config.org_deletion_cool_off_hours,
+    )
+    .await?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "organization.deletion_scheduled",
+            target_type: "organization",
+            target_id: Some(&org_access.org.id.to_string()),
+            details: serde_json::json!({
+                "deletion_id": deletion.id,
+                "scheduled_for": deletion.scheduled_for,
+            }),
+        },
+    )
+    .await?;
+    tracing::info!(
+        org_id = %org_access.org.id,
+        deletion_id = %deletion.id,
+        scheduled_for = %deletion.scheduled_for,
+        "Organization deletion scheduled"
+    );
+
+    Ok((
+        axum::http::StatusCode::ACCEPTED,
+        Json(DataResponse { data: deletion }),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/organizations/{slug}/deletion",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses(
+        (status = 200, description = "Deletion canceled", body = DataResponse<OrganizationDeletion>),
+        (status = 404, description = "No deletion is waiting out its cool-off period"),
+    ),
+)]
+async fn cancel_deletion(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+) -> Result<Json<DataResponse<OrganizationDeletion>>, AppError> {
+    org_access.require_owner()?;
+
+    let deletion =
+        db::organization_deletions::cancel(&state.pool, org_access.org.id, org_access.user.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("No cancelable deletion is scheduled".to_string()))?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "organization.deletion_canceled",
+            target_type: "organization",
+            target_id: Some(&org_access.org.id.to_string()),
+            details: serde_json::json!({ "deletion_id": deletion.id }),
+        },
+    )
+    .await?;
+
+    Ok(Json(DataResponse { data: deletion }))
+}
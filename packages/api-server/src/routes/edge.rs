@@ -0,0 +1,117 @@
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap},
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use subtle::ConstantTimeEq;
+
+use shared::error::AppError;
+
+use crate::db;
+use crate::services::certificates::cipher_from_config;
+use crate::state::AppState;
+
+/// Routes for the edge proxy that terminates TLS on custom domains.
+pub fn router() -> Router<AppState> {
+    Router::new().route("/certificates/{domain}", get(get_certificate))
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+struct DataResponse<T: Serialize> {
+    data: T,
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+struct EdgeCertificate {
+    domain: String,
+    /// PEM chain, leaf first.
+    certificate_pem: String,
+    private_key_pem: String,
+    not_after: DateTime<Utc>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/edge/certificates/{domain}",
+    tag = "edge",
+    params(
+        ("x-statuspage-edge-token" = String, Header, description = "Edge proxy token"),
+        ("domain" = String, Path, description = "Custom domain"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<EdgeCertificate>)),
+)]
+async fn get_certificate(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(domain): Path<String>,
+) -> Result<Json<DataResponse<EdgeCertificate>>, AppError> {
+    require_edge_proxy(&state, &headers)?;
+    let cipher = cipher_from_config(&state.config)?
+        .ok_or_else(|| AppError::Forbidden("Certificate storage is not configured".to_string()))?;
+
+    let domain = domain.trim().trim_end_matches('.').to_ascii_lowercase();
+    let certificate = db::certificates::find_active(&state.pool, &domain)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("No certificate for {domain}")))?;
+    let private_key_pem = String::from_utf8(cipher.decrypt(&certificate.encrypted_private_key)?)
+        .map_err(|_| anyhow::anyhow!("Stored private key is not PEM"))?;
+
+    Ok(Json(DataResponse {
+        data: EdgeCertificate {
+            domain: certificate.domain,
+            certificate_pem: certificate.certificate_pem,
+            private_key_pem,
+            not_after: certificate.not_after,
+        },
+    }))
+}
+
+/// Answers the CA's HTTP-01 validation requests, which the edge proxy
+/// forwards from custom domains.
+#[utoipa::path(
+    get,
+    path = "/.well-known/acme-challenge/{token}",
+    tag = "edge",
+    params(
+        ("token" = String, Path, description = "Challenge token"),
+    ),
+    responses((status = 200, description = "Key authorization", body = String, content_type = "text/plain")),
+)]
+pub(crate) async fn acme_challenge(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let key_authorization = db::certificates::find_challenge(&state.pool, &token)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Unknown challenge".to_string()))?;
+
+    Ok(([(header::CONTENT_TYPE, "text/plain")], key_authorization))
+}
+
+fn require_edge_proxy(state: &AppState, headers: &HeaderMap) -> Result<(), AppError> {
+    let configured = state
+        .config
+        .edge_proxy_token
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| AppError::Forbidden("Edge proxy access is not configured".to_string()))?;
+    let provided = headers
+        .get("x-statuspage-edge-token")
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim);
+
+    // The token releases private keys, so don't leak how much of it matched.
+    let matches =
+        provided.is_some_and(|provided| provided.as_bytes().ct_eq(configured.as_bytes()).into());
+
+    if matches {
+        Ok(())
+    } else {
+        Err(AppError::Unauthorized)
+    }
+}
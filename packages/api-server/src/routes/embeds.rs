@@ -0,0 +1,843 @@
+use std::collections::HashMap;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use shared::enums::{IncidentStatus, ServiceStatus};
+use shared::error::AppError;
+use shared::models::incident::Incident;
+use shared::models::incident_update::IncidentUpdate;
+use shared::models::service::Service;
+use shared::models::service_group::aggregate_group_status;
+use shared::models::statuspage::{
+    page_indicator, StatusIndicator, StatuspageComponent, StatuspageIncident,
+    StatuspageIncidentUpdate, StatuspagePage, StatuspageStatusResponse, StatuspageSummaryResponse,
+};
+
+use crate::db;
+use crate::db::status_pages::PublicPage;
+use crate::middleware::page_viewer::PageViewer;
+use crate::services::email_notifications::public_urls;
+use crate::state::AppState;
+
+/// Embeds are polled by browsers and README renderers; a short shared cache
+/// keeps them cheap without letting an outage go unnoticed for long.
+const EMBED_CACHE_CONTROL: &str = "public, max-age=60, stale-while-revalidate=300";
+const RESTRICTED_EMBED_CACHE_CONTROL: &str = "private, max-age=60";
+const DEFAULT_BADGE_COLOR: &str = "#555555";
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/{slug}/badge.svg", get(org_badge))
+        .route("/{slug}/services/{id}/badge.svg", get(service_badge))
+        .route("/{slug}/api/v2/status.json", get(statuspage_status))
+        .route("/{slug}/api/v2/summary.json", get(statuspage_summary))
+        .route("/{slug}/widget", get(widget))
+        .route("/{slug}/widget.js", get(widget_script))
+}
+
+#[derive(Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+struct BadgeParams {
+    label: Option<String>,
+}
+
+/// Everything an embed needs about a page, limited to what the public
+/// status page already shows.
+struct PageSnapshot {
+    org: PublicPage,
+    url: String,
+    history_url: String,
+    services: Vec<Service>,
+    incidents: Vec<Incident>,
+}
+
+impl PageSnapshot {
+    fn overall_status(&self) -> ServiceStatus {
+        self.services
+            .iter()
+            .map(Service::effective_status)
+            .max_by_key(ServiceStatus::severity)
+            .unwrap_or(ServiceStatus::Operational)
+    }
+
+    fn indicator(&self) -> StatusIndicator {
+        let impacts = self
+            .incidents
+            .iter()
+            .map(|incident| incident.impact)
+            .collect::<Vec<_>>();
+        page_indicator(self.overall_status(), &impacts)
+    }
+
+    fn page(&self) -> StatuspagePage {
+        let updated_at = self
+            .services
+            .iter()
+            .map(|service| service.updated_at)
+            .chain(self.incidents.iter().map(|incident| incident.updated_at))
+            .max()
+            .unwrap_or_else(Utc::now);
+
+        StatuspagePage {
+            id: self.org.page_id.unwrap_or(self.org.org_id),
+            name: self.org.name.clone(),
+            url: self.url.clone(),
+            time_zone: self.org.timezone.clone(),
+            updated_at,
+        }
+    }
+}
+
+async fn load_org(pool: &sqlx::PgPool, slug: &str) -> Result<PublicPage, AppError> {
+    db::status_pages::find_public_by_slug(pool, slug)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))
+}
+
+async fn load_snapshot(state: &AppState, slug: &str) -> Result<PageSnapshot, AppError> {
+    let org = load_org(&state.pool, slug).await?;
+    let urls = public_urls(&org, &state.config.app_base_url);
+    let listed = db::status_pages::service_ids(&state.pool, org.org_id, org.page_id).await?;
+    let services = db::services::find_by_org(&state.pool, org.org_id)
+        .await?
+        .into_iter()
+        .filter(|service| listed.contains(&service.id))
+        .collect();
+    let incidents = sqlx::query_as::<_, Incident>(
+        r#"
+        SELECT * FROM incidents
+        WHERE org_id = $1 AND status != 'resolved' AND NOT hidden_from_public
+          AND status_page_covers_incident($2, id)
+        ORDER BY started_at DESC
+        "#,
+    )
+    .bind(org.org_id)
+    .bind(org.page_id)
+    .fetch_all(&state.pool)
+    .await?;
+
+    Ok(PageSnapshot {
+        org,
+        url: urls.base,
+        history_url: urls.history,
+        services,
+        incidents,
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/public/{slug}/badge.svg",
+    tag = "embeds",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        BadgeParams,
+    ),
+    responses(
+        (status = 200, description = "Overall status badge", content_type = "image/svg+xml", body = String),
+        (status = 304, description = "Not modified"),
+    ),
+)]
+async fn org_badge(
+    State(state): State<AppState>,
+    viewer: PageViewer,
+    Path(slug): Path<String>,
+    Query(params): Query<BadgeParams>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let snapshot = load_snapshot(&state, &slug).await?;
+    let status = snapshot.overall_status();
+    let label = params.label.unwrap_or_else(|| snapshot.org.name.clone());
+
+    let svg = badge_svg(
+        &label,
+        &status.to_string(),
+        brand_color(&snapshot.org.brand_color),
+        status_color(status),
+    );
+    Ok(cached(
+        &headers,
+        &viewer,
+        "image/svg+xml; charset=utf-8",
+        svg,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/public/{slug}/services/{id}/badge.svg",
+    tag = "embeds",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Service id"),
+        BadgeParams,
+    ),
+    responses(
+        (status = 200, description = "Service status badge", content_type = "image/svg+xml", body = String),
+        (status = 304, description = "Not modified"),
+    ),
+)]
+async fn service_badge(
+    State(state): State<AppState>,
+    viewer: PageViewer,
+    Path((slug, id)): Path<(String, Uuid)>,
+    Query(params): Query<BadgeParams>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let org = load_org(&state.pool, &slug).await?;
+    let listed = db::status_pages::service_ids(&state.pool, org.org_id, org.page_id).await?;
+    let service = db::services::find_by_id(&state.pool, id, org.org_id)
+        .await?
+        .filter(|service| listed.contains(&service.id))
+        .ok_or_else(|| AppError::NotFound("Service not found".to_string()))?;
+    let status = service.effective_status();
+    let label = params.label.unwrap_or(service.name);
+
+    let svg = badge_svg(
+        &label,
+        &status.to_string(),
+        brand_color(&org.brand_color),
+        status_color(status),
+    );
+    Ok(cached(
+        &headers,
+        &viewer,
+        "image/svg+xml; charset=utf-8",
+        svg,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/public/{slug}/api/v2/status.json",
+    tag = "embeds",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses(
+        (status = 200, description = "Statuspage-compatible page status", body = StatuspageStatusResponse),
+        (status = 304, description = "Not modified"),
+    ),
+)]
+async fn statuspage_status(
+    State(state): State<AppState>,
+    viewer: PageViewer,
+    Path(slug): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let snapshot = load_snapshot(&state, &slug).await?;
+    let body = StatuspageStatusResponse {
+        page: snapshot.page(),
+        status: snapshot.indicator().into(),
+    };
+
+    Ok(cached_json(&headers, &viewer, &body))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/public/{slug}/api/v2/summary.json",
+    tag = "embeds",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses(
+        (status = 200, description = "Statuspage-compatible page summary", body = StatuspageSummaryResponse),
+        (status = 304, description = "Not modified"),
+    ),
+)]
+async fn statuspage_summary(
+    State(state): State<AppState>,
+    viewer: PageViewer,
+    Path(slug): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let snapshot = load_snapshot(&state, &slug).await?;
+    let groups = db::service_groups::find_by_org(&state.pool, snapshot.org.org_id).await?;
+    let page = snapshot.page();
+
+    let mut components = Vec::new();
+    for group in &groups {
+        let members = snapshot
+            .services
+            .iter()
+            .filter(|service| service.group_id == Some(group.id))
+            .collect::<Vec<_>>();
+        if members.is_empty() {
+            continue;
+        }
+        let statuses = members
+            .iter()
+            .map(|service| service.effective_status())
+            .collect::<Vec<_>>();
+        components.push(StatuspageComponent {
+            id: group.id,
+            name: group.name.clone(),
+            status: aggregate_group_status(group.status_rule, &statuses),
+            created_at: group.created_at,
+            updated_at: group.updated_at,
+            position: group.display_order,
+            description: group.description.clone(),
+            showcase: false,
+            start_date: None,
+            group_id: None,
+            page_id: page.id,
+            group: true,
+            only_show_if_degraded: false,
+            components: Some(members.iter().map(|service| service.id).collect()),
+        });
+    }
+    let grouped = components
+        .iter()
+        .map(|component| component.id)
+        .collect::<Vec<_>>();
+    for service in &snapshot.services {
+        components.push(StatuspageComponent {
+            id: service.id,
+            name: service.name.clone(),
+            status: service.effective_status(),
+            created_at: service.created_at,
+            updated_at: service.updated_at,
+            position: service.display_order,
+            description: service.description.clone(),
+            showcase: true,
+            start_date: Some(service.created_at.date_naive()),
+            group_id: service.group_id.filter(|id| grouped.contains(id)),
+            page_id: page.id,
+            group: false,
+            only_show_if_degraded: false,
+            components: None,
+        });
+    }
+
+    let incidents = statuspage_incidents(&state.pool, &snapshot, &components).await?;
+    let body = StatuspageSummaryResponse {
+        status: snapshot.indicator().into(),
+        page,
+        components,
+        incidents,
+        scheduled_maintenances: Vec::new(),
+    };
+
+    Ok(cached_json(&headers, &viewer, &body))
+}
+
+async fn statuspage_incidents(
+    pool: &sqlx::PgPool,
+    snapshot: &PageSnapshot,
+    components: &[StatuspageComponent],
+) -> Result<Vec<StatuspageIncident>, AppError> {
+    let incident_ids = snapshot
+        .incidents
+        .iter()
+        .map(|incident| incident.id)
+        .collect::<Vec<_>>();
+
+    let mut updates_by_incident: HashMap<Uuid, Vec<IncidentUpdate>> = HashMap::new();
+    for update in sqlx::query_as::<_, IncidentUpdate>(
+        r#"
+        SELECT * FROM incident_updates
+        WHERE incident_id = ANY($1) AND visibility = 'public'
+        ORDER BY created_at DESC
+        "#,
+    )
+    .bind(&incident_ids)
+    .fetch_all(pool)
+    .await?
+    {
+        updates_by_incident
+            .entry(update.incident_id)
+            .or_default()
+            .push(update);
+    }
+
+    let mut services_by_incident: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for (incident_id, service_id) in sqlx::query_as::<_, (Uuid, Uuid)>(
+        "SELECT incident_id, service_id FROM incident_services WHERE incident_id = ANY($1)",
+    )
+    .bind(&incident_ids)
+    .fetch_all(pool)
+    .await?
+    {
+        services_by_incident
+            .entry(incident_id)
+            .or_default()
+            .push(service_id);
+    }
+
+    let page_id = snapshot.page().id;
+    Ok(snapshot
+        .incidents
+        .iter()
+        .map(|incident| {
+            let updates = updates_by_incident.remove(&incident.id).unwrap_or_default();
+            let affected = services_by_incident
+                .get(&incident.id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+
+            StatuspageIncident {
+                id: incident.id,
+                name: incident.title.clone(),
+                status: incident.status,
+                created_at: incident.created_at,
+                updated_at: incident.updated_at,
+                monitoring_at: updates
+                    .iter()
+                    .filter(|update| update.status == IncidentStatus::Monitoring)
+                    .map(|update| update.created_at)
+                    .min(),
+                resolved_at: incident.resolved_at,
+                impact: incident.impact,
+                shortlink: snapshot.history_url.clone(),
+                started_at: incident.started_at,
+                page_id,
+                incident_updates: updates
+                    .into_iter()
+                    .map(|update| StatuspageIncidentUpdate {
+                        id: update.id,
+                        status: update.status,
+                        body: update.message,
+                        incident_id: update.incident_id,
+                        created_at: update.created_at,
+                        updated_at: update.edited_at.unwrap_or(update.created_at),
+                        display_at: update.created_at,
+                    })
+                    .collect(),
+                components: components
+                    .iter()
+                    .filter(|component| !component.group && affected.contains(&component.id))
+                    .cloned()
+                    .collect(),
+            }
+        })
+        .collect())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/public/{slug}/widget",
+    tag = "embeds",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses(
+        (status = 200, description = "Embeddable status widget", content_type = "text/html", body = String),
+        (status = 304, description = "Not modified"),
+    ),
+)]
+async fn widget(
+    State(state): State<AppState>,
+    viewer: PageViewer,
+    Path(slug): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let snapshot = load_snapshot(&state, &slug).await?;
+    let latest_updates = sqlx::query_as::<_, (Uuid, String)>(
+        r#"
+        SELECT DISTINCT ON (incident_id) incident_id, message
+        FROM incident_updates
+        WHERE incident_id = ANY($1) AND visibility = 'public'
+        ORDER BY incident_id, created_at DESC
+        "#,
+    )
+    .bind(
+        snapshot
+            .incidents
+            .iter()
+            .map(|incident| incident.id)
+            .collect::<Vec<_>>(),
+    )
+    .fetch_all(&state.pool)
+    .await?
+    .into_iter()
+    .collect::<HashMap<_, _>>();
+
+    let incidents = snapshot
+        .incidents
+        .iter()
+        .map(|incident| WidgetIncident {
+            title: &incident.title,
+            status: incident.status,
+            started_at: incident.started_at,
+            latest_update: latest_updates.get(&incident.id).map(String::as_str),
+        })
+        .collect::<Vec<_>>();
+    let indicator = snapshot.indicator();
+    let html = widget_html(&WidgetPage {
+        org_name: &snapshot.org.name,
+        brand_color: brand_color(&snapshot.org.brand_color),
+        status_url: &snapshot.url,
+        description: indicator.description(),
+        status_color: indicator_color(indicator),
+        incidents: &incidents,
+    });
+
+    Ok(cached(&headers, &viewer, "text/html; charset=utf-8", html))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/public/{slug}/widget.js",
+    tag = "embeds",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses(
+        (status = 200, description = "Script that mounts the status widget", content_type = "application/javascript", body = String),
+        (status = 304, description = "Not modified"),
+    ),
+)]
+async fn widget_script(
+    State(state): State<AppState>,
+    viewer: PageViewer,
+    Path(slug): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    load_org(&state.pool, &slug).await?;
+
+    Ok(cached(
+        &headers,
+        &viewer,
+        "application/javascript; charset=utf-8",
+        WIDGET_SCRIPT.to_string(),
+    ))
+}
+
+/// Inserts the widget iframe next to the script tag, resolving the widget URL
+/// from the script's own `src` so the snippet works on any API host.
+const WIDGET_SCRIPT: &str = r#"(function () {
+  var script = document.currentScript;
+  if (!script) return;
+  var frame = document.createElement("iframe");
+  frame.src = script.src.replace(/widget\.js(\?.*)?$/, "widget");
+  frame.title = "Status";
+  frame.loading = "lazy";
+  frame.style.border = "0";
+  frame.style.width = script.getAttribute("data-width") || "100%";
+  frame.style.height = script.getAttribute("data-height") || "180px";
+  script.parentNode.insertBefore(frame, script);
+})();
+"#;
+
+struct WidgetIncident<'a> {
+    title: &'a str,
+    status: IncidentStatus,
+    started_at: DateTime<Utc>,
+    latest_update: Option<&'a str>,
+}
+
+struct WidgetPage<'a> {
+    org_name: &'a str,
+    brand_color: &'a str,
+    status_url: &'a str,
+    description: &'a str,
+    status_color: &'a str,
+    incidents: &'a [WidgetIncident<'a>],
+}
+
+fn widget_html(page: &WidgetPage<'_>) -> String {
+    let incidents = if page.incidents.is_empty() {
+        "<p class=\"empty\">No active incidents.</p>".to_string()
+    } else {
+        page.incidents
+            .iter()
+            .map(|incident| {
+                format!(
+                    "<li><strong>{}</strong> <span class=\"status\">{}</span><br><small>Since {}</small>{}</li>",
+                    xml_escape(incident.title),
+                    incident.status,
+                    incident.started_at.format("%b %-d, %H:%M UTC"),
+                    incident
+                        .latest_update
+                        .map(|message| format!("<p>{}</p>", xml_escape(message)))
+                        .unwrap_or_default(),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("")
+    };
+
+    format!(
+        r#"<!doctype html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{org} status</title>
+<style>
+body {{ margin: 0; font: 14px/1.4 -apple-system, BlinkMacSystemFont, "Segoe UI", sans-serif; color: #111827; }}
+.widget {{ border: 1px solid #e5e7eb; border-top: 3px solid {brand}; border-radius: 6px; padding: 12px 14px; }}
+.overall {{ display: flex; align-items: center; gap: 8px; font-weight: 600; }}
+.dot {{ width: 10px; height: 10px; border-radius: 50%; background: {status_color}; }}
+ul {{ list-style: none; margin: 10px 0 0; padding: 0; }}
+li {{ padding: 6px 0; border-top: 1px solid #f3f4f6; }}
+li p {{ margin: 4px 0 0; color: #4b5563; }}
+.status {{ color: #6b7280; text-transform: capitalize; }}
+.empty {{ margin: 8px 0 0; color: #6b7280; }}
+a {{ color: {brand}; font-size: 12px; }}
+</style>
+</head>
+<body>
+<div class="widget">
+<div class="overall"><span class="dot"></span>{description}</div>
+<ul>{incidents}</ul>
+<a href="{url}" target="_blank" rel="noopener">View {org} status page</a>
+</div>
+</body>
+</html>
+"#,
+        org = xml_escape(page.org_name),
+        brand = page.brand_color,
+        status_color = page.status_color,
+        description = page.description,
+        incidents = incidents,
+        url = xml_escape(page.status_url),
+    )
+}
+
+/// Renders a two-part flat badge: the label on the brand color and the
+/// status on its status color.
+fn badge_svg(label: &str, message: &str, label_color: &str, message_color: &str) -> String {
+    let label_width = text_width(label);
+    let message_width = text_width(message);
+    let width = label_width + message_width;
+    let label = xml_escape(label);
+    let message = xml_escape(message);
+
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{label}: {message}"><title>{label}: {message}</title><clipPath id="r"><rect width="{width}" height="20" rx="3" fill="#fff"/></clipPath><g clip-path="url(#r)"><rect width="{label_width}" height="20" fill="{label_color}"/><rect x="{label_width}" width="{message_width}" height="20" fill="{message_color}"/></g><g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11"><text x="{label_x}" y="14">{label}</text><text x="{message_x}" y="14">{message}</text></g></svg>"##,
+        label_x = label_width / 2,
+        message_x = label_width + message_width / 2,
+    )
+}
+
+/// Approximate rendered width of 11px Verdana plus horizontal padding.
+fn text_width(text: &str) -> u32 {
+    text.chars().count() as u32 * 7 + 12
+}
+
+fn status_color(status: ServiceStatus) -> &'static str {
+    match status {
+        ServiceStatus::Operational => "#10B981",
+        ServiceStatus::DegradedPerformance => "#F59E0B",
+        ServiceStatus::PartialOutage => "#F97316",
+        ServiceStatus::MajorOutage => "#EF4444",
+        ServiceStatus::UnderMaintenance => "#3B82F6",
+    }
+}
+
+fn indicator_color(indicator: StatusIndicator) -> &'static str {
+    match indicator {
+        StatusIndicator::None => status_color(ServiceStatus::Operational),
+        StatusIndicator::Maintenance => status_color(ServiceStatus::UnderMaintenance),
+        StatusIndicator::Minor => status_color(ServiceStatus::DegradedPerformance),
+        StatusIndicator::Major => status_color(ServiceStatus::PartialOutage),
+        StatusIndicator::Critical => status_color(ServiceStatus::MajorOutage),
+    }
+}
+
+/// Only plain hex colors are interpolated into SVG and CSS.
+fn brand_color(value: &str) -> &str {
+    let hex = value.strip_prefix('#').unwrap_or_default();
+    if matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        value
+    } else {
+        DEFAULT_BADGE_COLOR
+    }
+}
+
+fn xml_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn cached_json<T: Serialize>(headers: &HeaderMap, viewer: &PageViewer, body: &T) -> Response {
+    match serde_json::to_string(body) {
+        Ok(json) => cached(headers, viewer, "application/json", json),
+        Err(err) => AppError::Internal(err.into()).into_response(),
+    }
+}
+
+/// Wraps an embed body with shared-cache headers and a content ETag,
+/// answering `304 Not Modified` when the client already has it. Embeds are
+/// readable from any origin; restricted pages are only cached by the
+/// viewer's own browser.
+fn cached(
+    headers: &HeaderMap,
+    viewer: &PageViewer,
+    content_type: &'static str,
+    body: String,
+) -> Response {
+    let etag = format!(
+        "\"{}\"",
+        &hex::encode(Sha256::digest(body.as_bytes()))[..32]
+    );
+    let not_modified = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.split(',').any(|tag| tag.trim() == etag));
+
+    let mut response = if not_modified {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        ([(header::CONTENT_TYPE, content_type)], body).into_response()
+    };
+    let response_headers = response.headers_mut();
+    if viewer.is_restricted() {
+        response_headers.insert(
+            header::CACHE_CONTROL,
+            HeaderValue::from_static(RESTRICTED_EMBED_CACHE_CONTROL),
+        );
+        response_headers.insert(
+            header::VARY,
+            HeaderValue::from_static("cookie, x-statuspage-viewer-token, x-forwarded-for"),
+        );
+    } else {
+        response_headers.insert(
+            header::CACHE_CONTROL,
+            HeaderValue::from_static(EMBED_CACHE_CONTROL),
+        );
+    }
+    response_headers.insert(
+        header::ACCESS_CONTROL_ALLOW_ORIGIN,
+        HeaderValue::from_static("*"),
+    );
+    if let Ok(value) = HeaderValue::from_str(&etag) {
+        response_headers.insert(header::ETAG, value);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::enums::PageVisibility;
+
+    #[test]
+    fn badge_escapes_label_and_sizes_segments() {
+        let svg = badge_svg("A&B <api>", "Operational", "#123456", "#10B981");
+
+        assert!(svg.contains("A&amp;B &lt;api&gt;"));
+        assert!(!svg.contains("<api>"));
+        assert!(svg.contains(r##"fill="#123456""##));
+        let width = text_width("A&B <api>") + text_width("Operational");
+        assert!(svg.contains(&format!(r#"width="{width}""#)));
+    }
+
+    #[test]
+    fn brand_color_falls_back_for_anything_but_hex() {
+        assert_eq!(brand_color("#3B82F6"), "#3B82F6");
+        assert_eq!(brand_color("#abc"), "#abc");
+        assert_eq!(brand_color("red\"/><script>"), DEFAULT_BADGE_COLOR);
+        assert_eq!(brand_color("#12345"), DEFAULT_BADGE_COLOR);
+    }
+
+    #[test]
+    fn statuspage_page_reports_the_organization_timezone() {
+        let snapshot = PageSnapshot {
+            org: PublicPage {
+                org_id: Uuid::new_v4(),
+                page_id: None,
+                slug: "acme".to_string(),
+                name: "Acme".to_string(),
+                logo_url: None,
+                brand_color: "#3B82F6".to_string(),
+                verified_custom_domain: None,
+                locale: shared::enums::Locale::En,
+                timezone: "Europe/Berlin".to_string(),
+            },
+            url: "https://status.acme.com".to_string(),
+            history_url: "https://status.acme.com/history".to_string(),
+            services: Vec::new(),
+            incidents: Vec::new(),
+        };
+
+        assert_eq!(snapshot.page().time_zone, "Europe/Berlin");
+    }
+
+    fn viewer(visibility: PageVisibility) -> PageViewer {
+        PageViewer {
+            org_id: Uuid::nil(),
+            page_id: None,
+            visibility,
+        }
+    }
+
+    #[test]
+    fn cached_response_honours_if_none_match() {
+        let viewer = viewer(PageVisibility::Public);
+        let first = cached(
+            &HeaderMap::new(),
+            &viewer,
+            "image/svg+xml",
+            "<svg/>".to_string(),
+        );
+        assert_eq!(first.status(), StatusCode::OK);
+        assert_eq!(first.headers()[header::CACHE_CONTROL], EMBED_CACHE_CONTROL);
+        let etag = first.headers()[header::ETAG].clone();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, etag.clone());
+        let second = cached(&headers, &viewer, "image/svg+xml", "<svg/>".to_string());
+        assert_eq!(second.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(second.headers()[header::ETAG], etag);
+    }
+
+    #[test]
+    fn restricted_pages_are_not_shared_cached() {
+        let response = cached(
+            &HeaderMap::new(),
+            &viewer(PageVisibility::Password),
+            "application/json",
+            "{}".to_string(),
+        );
+        assert_eq!(
+            response.headers()[header::CACHE_CONTROL],
+            RESTRICTED_EMBED_CACHE_CONTROL
+        );
+        assert!(response.headers().contains_key(header::VARY));
+    }
+
+    #[test]
+    fn widget_escapes_incident_content() {
+        let incidents = [WidgetIncident {
+            title: "<b>API</b> errors",
+            status: IncidentStatus::Investigating,
+            started_at: Utc::now(),
+            latest_update: Some("Looking into \"it\""),
+        }];
+        let html = widget_html(&WidgetPage {
+            org_name: "Acme",
+            brand_color: "#3B82F6",
+            status_url: "https://status.acme.test",
+            description: StatusIndicator::Major.description(),
+            status_color: indicator_color(StatusIndicator::Major),
+            incidents: &incidents,
+        });
+
+        assert!(html.contains("&lt;b&gt;API&lt;/b&gt; errors"));
+        assert!(html.contains("Looking into &quot;it&quot;"));
+        assert!(html.contains("Partial System Outage"));
+    }
+}
@@ -0,0 +1,182 @@
+use axum::{
+    extract::{Path, State},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::Serialize;
+use uuid::Uuid;
+
+use shared::error::AppError;
+
+use crate::db;
+use crate::db::organization_exports::OrganizationExport;
+use crate::middleware::org_access::OrgAccess;
+use crate::state::AppState;
+
+const LIST_LIMIT: i64 = 20;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", post(create_export).get(list_exports))
+        .route("/{id}", get(get_export))
+        .route("/{id}/download", get(download_export))
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+struct DataResponse<T: Serialize> {
+    data: T,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/exports",
+    tag = "exports",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses(
+        (status = 202, description = "Export queued; poll it until it completes", body = DataResponse<OrganizationExport>),
+        (status = 409, description = "An export is already pending or running"),
+    ),
+)]
+async fn create_export(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+) -> Result<
+    (
+        axum::http::StatusCode,
+        Json<DataResponse<OrganizationExport>>,
+    ),
+    AppError,
+> {
+    org_access.require_owner()?;
+
+    if db::organization_exports::has_unfinished(&state.pool, org_access.org.id).await? {
+        return Err(AppError::Conflict(
+            "An export is already in progress for this organization".to_string(),
+        ));
+    }
+
+    let export =
+        db::organization_exports::create(&state.pool, org_access.org.id, org_access.user.id)
+            .await?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "organization.export_requested",
+            target_type: "organization_export",
+            target_id: Some(&export.id.to_string()),
+            details: serde_json::json!({}),
+        },
+    )
+    .await?;
+
+    Ok((
+        axum::http::StatusCode::ACCEPTED,
+        Json(DataResponse { data: export }),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/exports",
+    tag = "exports",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "Most recent exports, newest first", body = DataResponse<Vec<OrganizationExport>>)),
+)]
+async fn list_exports(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+) -> Result<Json<DataResponse<Vec<OrganizationExport>>>, AppError> {
+    org_access.require_owner()?;
+
+    let exports =
+        db::organization_exports::list_by_org(&state.pool, org_access.org.id, LIST_LIMIT).await?;
+    Ok(Json(DataResponse { data: exports }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/exports/{id}",
+    tag = "exports",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Export ID"),
+    ),
+    responses((status = 200, description = "Export status", body = DataResponse<OrganizationExport>)),
+)]
+async fn get_export(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+) -> Result<Json<DataResponse<OrganizationExport>>, AppError> {
+    org_access.require_owner()?;
+
+    let export = db::organization_exports::find_by_id(&state.pool, org_access.org.id, id).await?;
+    Ok(Json(DataResponse { data: export }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/exports/{id}/download",
+    tag = "exports",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Export ID"),
+    ),
+    responses(
+        (status = 200, description = "Zip archive with services, redacted monitors, incidents, subscribers, audit logs and uptime history", content_type = "application/zip", body = Vec<u8>),
+        (status = 404, description = "The export is unknown, not finished or expired"),
+    ),
+)]
+async fn download_export(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+) -> Result<axum::response::Response, AppError> {
+    use axum::response::IntoResponse;
+
+    org_access.require_owner()?;
+
+    let archive = db::organization_exports::find_archive(&state.pool, org_access.org.id, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Export archive is not available".to_string()))?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "organization.export_downloaded",
+            target_type: "organization_export",
+            target_id: Some(&id.to_string()),
+            details: serde_json::json!({ "size_bytes": archive.len() }),
+        },
+    )
+    .await?;
+
+    let file_name = format!(
+        "{}-export-{}.zip",
+        org_access.org.slug,
+        chrono::Utc::now().format("%Y%m%d")
+    );
+    Ok((
+        [
+            (
+                axum::http::header::CONTENT_TYPE,
+                "application/zip".to_string(),
+            ),
+            (
+                axum::http::header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{file_name}\""),
+            ),
+        ],
+        archive,
+    )
+        .into_response())
+}
@@ -1,17 +1,21 @@
 use axum::{
     extract::{Path, Query, State},
-    routing::{get, post},
+    routing::{get, patch, post},
     Json, Router,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use shared::enums::IncidentStatus;
+use shared::enums::{IncidentStatus, UpdateVisibility};
 use shared::error::AppError;
 use shared::models::incident::{
-    CreateIncidentRequest, Incident, IncidentWithDetails, UpdateIncidentRequest,
+    AssignIncidentRequest, CreateIncidentRequest, Incident, IncidentSearchParams,
+    IncidentWithDetails, UpdateIncidentRequest,
+};
+use shared::models::incident_update::{
+    CreateIncidentUpdateRequest, EditIncidentUpdateRequest, IncidentUpdate,
 };
-use shared::models::incident_update::{CreateIncidentUpdateRequest, IncidentUpdate};
 
 use crate::db;
 use crate::middleware::org_access::OrgAccess;
@@ -20,40 +24,70 @@ use crate::state::AppState;
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/", post(create_incident).get(list_incidents))
+        .route("/search", get(search_incidents))
         .route(
             "/{id}",
             get(get_incident)
                 .patch(update_incident)
                 .delete(delete_incident),
         )
+        .route("/{id}/acknowledge", post(acknowledge_incident))
+        .route("/{id}/assignee", axum::routing::put(assign_incident))
         .route("/{id}/updates", post(create_update))
+        .route(
+            "/{id}/updates/{update_id}",
+            patch(edit_update).delete(delete_update),
+        )
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct DataResponse<T: Serialize> {
     data: T,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct ListResponse<T: Serialize> {
     data: T,
     pagination: Pagination,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct Pagination {
     page: i64,
     per_page: i64,
     total: i64,
 }
 
-#[derive(Deserialize)]
+#[derive(Serialize, utoipa::ToSchema)]
+struct CursorListResponse<T: Serialize> {
+    data: T,
+    pagination: CursorPagination,
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+struct CursorPagination {
+    next_cursor: Option<String>,
+    limit: i64,
+}
+
+#[derive(Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 struct ListParams {
     status: Option<IncidentStatus>,
     page: Option<i64>,
     per_page: Option<i64>,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/incidents",
+    tag = "incidents",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = CreateIncidentRequest,
+    responses((status = 201, description = "Created", body = DataResponse<Incident>)),
+)]
 async fn create_incident(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -67,6 +101,9 @@ async fn create_incident(
     if req.message.trim().is_empty() {
         return Err(AppError::Validation("Message is required".to_string()));
     }
+    if let Some(started_at) = req.started_at {
+        validate_timeline_position(started_at, None, None, None)?;
+    }
 
     let incident =
         db::incidents::create(&state.pool, org_access.org.id, &req, org_access.user.id).await?;
@@ -99,6 +136,7 @@ async fn create_incident(
             "status": incident.status,
             "impact": incident.impact.as_str(),
             "affected_services": req.affected_service_ids.clone(),
+            "service_statuses": req.initial_service_statuses(),
         }
     });
     if let Err(error) = db::webhook_deliveries::enqueue_for_event(
@@ -115,21 +153,39 @@ async fn create_incident(
         );
     }
 
-    if let Err(error) = crate::services::email_notifications::queue_incident_created(
-        &state.pool,
-        org_access.org.id,
-        &state.config.app_base_url,
-        &org_access.org.slug,
-        &incident.title,
-        incident.impact,
-        &req.affected_service_ids,
-    )
-    .await
-    {
-        tracing::warn!(
-            error = %error,
-            "Failed to queue incident created subscriber emails"
-        );
+    if !req.suppress_notifications {
+        if let Err(error) = crate::services::email_notifications::queue_incident_created(
+            &state.pool,
+            org_access.org.id,
+            &state.config.app_base_url,
+            &incident.title,
+            incident.impact,
+            &req.affected_service_ids,
+        )
+        .await
+        {
+            tracing::warn!(
+                error = %error,
+                "Failed to queue incident created subscriber emails"
+            );
+        }
+
+        if let Err(error) = crate::services::sms_notifications::queue_incident_alert(
+            &state.pool,
+            org_access.org.id,
+            &state.config.app_base_url,
+            &crate::services::sms_notifications::IncidentSms {
+                title: &incident.title,
+                status: incident.status,
+                impact: incident.impact,
+                message: None,
+                affected_services: &req.affected_service_ids,
+            },
+        )
+        .await
+        {
+            tracing::warn!(error = %error, "Failed to queue incident created subscriber SMS");
+        }
     }
 
     Ok((
@@ -138,6 +194,16 @@ async fn create_incident(
     ))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/incidents",
+    tag = "incidents",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ListParams,
+    ),
+    responses((status = 200, description = "OK", body = ListResponse<Vec<Incident>>)),
+)]
 async fn list_incidents(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -165,6 +231,49 @@ async fn list_incidents(
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/incidents/search",
+    tag = "incidents",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        IncidentSearchParams,
+    ),
+    responses((status = 200, description = "OK", body = CursorListResponse<Vec<Incident>>)),
+)]
+async fn search_incidents(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Query(params): Query<IncidentSearchParams>,
+) -> Result<Json<CursorListResponse<Vec<Incident>>>, AppError> {
+    let page = crate::services::incident_search::search(
+        &state.pool,
+        org_access.org.id,
+        None,
+        &params,
+        true,
+    )
+    .await?;
+
+    Ok(Json(CursorListResponse {
+        data: page.incidents,
+        pagination: CursorPagination {
+            next_cursor: page.next_cursor,
+            limit: page.limit,
+        },
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/incidents/{id}",
+    tag = "incidents",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<IncidentWithDetails>)),
+)]
 async fn get_incident(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -177,6 +286,17 @@ async fn get_incident(
     Ok(Json(DataResponse { data: incident }))
 }
 
+#[utoipa::path(
+    patch,
+    path = "/api/organizations/{slug}/incidents/{id}",
+    tag = "incidents",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    request_body = UpdateIncidentRequest,
+    responses((status = 200, description = "OK", body = DataResponse<Incident>)),
+)]
 async fn update_incident(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -185,20 +305,11 @@ async fn update_incident(
 ) -> Result<Json<DataResponse<Incident>>, AppError> {
     org_access.require_admin()?;
 
-    // If status change to resolved, use the resolve-and-recalculate logic
-    if req.status == Some(IncidentStatus::Resolved) {
-        let incident = db::incidents::resolve_and_recalculate(
-            &state.pool,
-            id,
-            org_access.org.id,
-            org_access.user.id,
-        )
-        .await?;
-        return Ok(Json(DataResponse { data: incident }));
-    }
-
     // Validate status transition if status is being changed
-    if let Some(new_status) = req.status {
+    if let Some(new_status) = req
+        .status
+        .filter(|status| *status != IncidentStatus::Resolved)
+    {
         let current = db::incidents::find_by_id_with_details(&state.pool, id, org_access.org.id)
             .await?
             .ok_or_else(|| AppError::NotFound("Incident not found".to_string()))?;
@@ -211,6 +322,22 @@ async fn update_incident(
         }
     }
 
+    db::incidents::set_service_statuses(&state.pool, id, org_access.org.id, &req.service_statuses)
+        .await?;
+
+    // If status change to resolved, use the resolve-and-recalculate logic
+    if req.status == Some(IncidentStatus::Resolved) {
+        let incident = db::incidents::resolve_and_recalculate(
+            &state.pool,
+            id,
+            org_access.org.id,
+            org_access.user.id,
+            None,
+        )
+        .await?;
+        return Ok(Json(DataResponse { data: incident }));
+    }
+
     let incident = db::incidents::update_status(
         &state.pool,
         id,
@@ -224,6 +351,90 @@ async fn update_incident(
     Ok(Json(DataResponse { data: incident }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/incidents/{id}/acknowledge",
+    tag = "incidents",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<Incident>)),
+)]
+async fn acknowledge_incident(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+) -> Result<Json<DataResponse<Incident>>, AppError> {
+    org_access.require_admin()?;
+
+    let (incident, acknowledged) =
+        db::incidents::acknowledge(&state.pool, id, org_access.org.id, org_access.user.id).await?;
+    if acknowledged {
+        db::incident_events::record(
+            &state.pool,
+            id,
+            "acknowledged",
+            Some(org_access.user.id),
+            serde_json::json!({ "assignee_user_id": incident.assignee_user_id }),
+        )
+        .await?;
+    }
+
+    Ok(Json(DataResponse { data: incident }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/organizations/{slug}/incidents/{id}/assignee",
+    tag = "incidents",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    request_body = AssignIncidentRequest,
+    responses((status = 200, description = "OK", body = DataResponse<Incident>)),
+)]
+async fn assign_incident(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+    Json(req): Json<AssignIncidentRequest>,
+) -> Result<Json<DataResponse<Incident>>, AppError> {
+    org_access.require_admin()?;
+
+    if let Some(user_id) = req.user_id {
+        db::members::find_by_user_and_org(&state.pool, user_id, org_access.org.id)
+            .await?
+            .ok_or_else(|| {
+                AppError::Validation("Assignee must be a member of this organization".to_string())
+            })?;
+    }
+
+    let incident =
+        db::incidents::set_assignee(&state.pool, id, org_access.org.id, req.user_id).await?;
+    db::incident_events::record(
+        &state.pool,
+        id,
+        "assigned",
+        Some(org_access.user.id),
+        serde_json::json!({ "assignee_user_id": req.user_id }),
+    )
+    .await?;
+
+    Ok(Json(DataResponse { data: incident }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/organizations/{slug}/incidents/{id}",
+    tag = "incidents",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 204, description = "No content")),
+)]
 async fn delete_incident(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -235,6 +446,17 @@ async fn delete_incident(
     Ok(axum::http::StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/incidents/{id}/updates",
+    tag = "incidents",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    request_body = CreateIncidentUpdateRequest,
+    responses((status = 201, description = "Created", body = DataResponse<IncidentUpdate>)),
+)]
 async fn create_update(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -252,16 +474,44 @@ async fn create_update(
         .await?
         .ok_or_else(|| AppError::NotFound("Incident not found".to_string()))?;
 
-    if !incident.incident.status.can_transition_to(&req.status) {
+    let internal = req.visibility == UpdateVisibility::Internal;
+    if internal {
+        if req.status != incident.incident.status || !req.service_statuses.is_empty() {
+            return Err(AppError::Validation(
+                "Internal notes cannot change the incident or service status".to_string(),
+            ));
+        }
+    } else if !incident.incident.status.can_transition_to(&req.status) {
         return Err(AppError::Validation(format!(
             "Cannot transition from {} to {}",
             incident.incident.status, req.status
         )));
     }
+    if let Some(created_at) = req.created_at {
+        let latest = incident
+            .updates
+            .iter()
+            .map(|update| update.created_at)
+            .max();
+        validate_timeline_position(created_at, Some(incident.incident.started_at), latest, None)?;
+    }
+
+    // Apply per-service status changes before any resolution recalculates them
+    db::incidents::set_service_statuses(&state.pool, id, org_access.org.id, &req.service_statuses)
+        .await?;
 
     // Create the update
     let update = db::incident_updates::create(&state.pool, id, &req, org_access.user.id).await?;
 
+    // Internal notes stay on the dashboard timeline: no status change,
+    // real-time event, webhook, email or SMS
+    if internal {
+        return Ok((
+            axum::http::StatusCode::CREATED,
+            Json(DataResponse { data: update }),
+        ));
+    }
+
     // Also update incident status if changed
     if req.status != incident.incident.status {
         if req.status == IncidentStatus::Resolved {
@@ -270,6 +520,7 @@ async fn create_update(
                 id,
                 org_access.org.id,
                 org_access.user.id,
+                req.created_at,
             )
             .await?;
         } else {
@@ -316,6 +567,7 @@ async fn create_update(
             "update_id": update.id,
             "status": req.status,
             "message": req.message.clone(),
+            "service_statuses": req.service_statuses.clone(),
         }
     });
     if let Err(error) = db::webhook_deliveries::enqueue_for_event(
@@ -333,21 +585,54 @@ async fn create_update(
         );
     }
 
-    if let Err(error) = crate::services::email_notifications::queue_incident_updated(
-        &state.pool,
-        org_access.org.id,
-        &state.config.app_base_url,
-        &org_access.org.slug,
-        req.status,
-        &req.message,
-    )
-    .await
-    {
-        tracing::warn!(
-            error = %error,
-            event_type = webhook_event_type,
-            "Failed to queue incident update subscriber emails"
-        );
+    if !req.suppress_notifications {
+        let mut affected_services: Vec<Uuid> = incident
+            .affected_services
+            .iter()
+            .map(|service| service.service_id)
+            .chain(req.service_statuses.iter().map(|change| change.service_id))
+            .collect();
+        affected_services.sort();
+        affected_services.dedup();
+
+        if let Err(error) = crate::services::email_notifications::queue_incident_updated(
+            &state.pool,
+            org_access.org.id,
+            &state.config.app_base_url,
+            req.status,
+            incident.incident.impact,
+            &req.message,
+            &affected_services,
+        )
+        .await
+        {
+            tracing::warn!(
+                error = %error,
+                event_type = webhook_event_type,
+                "Failed to queue incident update subscriber emails"
+            );
+        }
+
+        if let Err(error) = crate::services::sms_notifications::queue_incident_alert(
+            &state.pool,
+            org_access.org.id,
+            &state.config.app_base_url,
+            &crate::services::sms_notifications::IncidentSms {
+                title: &incident.incident.title,
+                status: req.status,
+                impact: incident.incident.impact,
+                message: Some(&req.message),
+                affected_services: &affected_services,
+            },
+        )
+        .await
+        {
+            tracing::warn!(
+                error = %error,
+                event_type = webhook_event_type,
+                "Failed to queue incident update subscriber SMS"
+            );
+        }
     }
 
     Ok((
@@ -355,3 +640,287 @@ async fn create_update(
         Json(DataResponse { data: update }),
     ))
 }
+
+#[utoipa::path(
+    patch,
+    path = "/api/organizations/{slug}/incidents/{id}/updates/{update_id}",
+    tag = "incidents",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+        ("update_id" = Uuid, Path, description = "Update id"),
+    ),
+    request_body = EditIncidentUpdateRequest,
+    responses((status = 200, description = "OK", body = DataResponse<IncidentUpdate>)),
+)]
+async fn edit_update(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id, update_id)): Path<(String, Uuid, Uuid)>,
+    Json(req): Json<EditIncidentUpdateRequest>,
+) -> Result<Json<DataResponse<IncidentUpdate>>, AppError> {
+    org_access.require_admin()?;
+
+    let incident = db::incidents::find_by_id_with_details(&state.pool, id, org_access.org.id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Incident not found".to_string()))?;
+    // Updates are ordered newest first
+    let index = incident
+        .updates
+        .iter()
+        .position(|update| update.id == update_id)
+        .ok_or_else(|| AppError::NotFound("Incident update not found".to_string()))?;
+    let existing = &incident.updates[index];
+
+    let message = match req.message.as_deref().map(str::trim) {
+        Some("") => return Err(AppError::Validation("Message is required".to_string())),
+        Some(message) => message.to_string(),
+        None => existing.message.clone(),
+    };
+    let created_at = req.created_at.unwrap_or(existing.created_at);
+    if req.created_at.is_some() {
+        validate_timeline_position(
+            created_at,
+            Some(incident.incident.started_at),
+            incident
+                .updates
+                .get(index + 1)
+                .map(|update| update.created_at),
+            index
+                .checked_sub(1)
+                .map(|newer| incident.updates[newer].created_at),
+        )?;
+    }
+
+    let update =
+        db::incident_updates::edit(&state.pool, id, update_id, &message, created_at).await?;
+
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "incident_update.edit",
+            target_type: "incident_update",
+            target_id: Some(&update_id.to_string()),
+            details: serde_json::json!({
+                "incident_id": id,
+                "previous_message": existing.message.clone(),
+                "message": update.message.clone(),
+                "previous_created_at": existing.created_at,
+                "created_at": update.created_at,
+            }),
+        },
+    )
+    .await?;
+
+    Ok(Json(DataResponse { data: update }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/organizations/{slug}/incidents/{id}/updates/{update_id}",
+    tag = "incidents",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+        ("update_id" = Uuid, Path, description = "Update id"),
+    ),
+    responses((status = 204, description = "No content")),
+)]
+async fn delete_update(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id, update_id)): Path<(String, Uuid, Uuid)>,
+) -> Result<axum::http::StatusCode, AppError> {
+    org_access.require_admin()?;
+
+    let incident = db::incidents::find_by_id_with_details(&state.pool, id, org_access.org.id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Incident not found".to_string()))?;
+    let existing = incident
+        .updates
+        .iter()
+        .find(|update| update.id == update_id)
+        .ok_or_else(|| AppError::NotFound("Incident update not found".to_string()))?;
+    let public_updates = incident
+        .updates
+        .iter()
+        .filter(|update| update.visibility == UpdateVisibility::Public)
+        .count();
+    if existing.visibility == UpdateVisibility::Public && public_updates == 1 {
+        return Err(AppError::Validation(
+            "An incident must keep at least one public update".to_string(),
+        ));
+    }
+
+    db::incident_updates::delete(&state.pool, id, update_id).await?;
+
+    // The incident's status follows its newest public update, so removing
+    // that update rolls the status (and resolution) back to the one before.
+    let previous_status = incident.incident.status;
+    let restored_status = match status_after_delete(&incident.updates, update_id) {
+        Some((status, at)) if status != previous_status => {
+            let resolved_at = (status == IncidentStatus::Resolved).then_some(at);
+            db::incidents::restore_status(&state.pool, id, org_access.org.id, status, resolved_at)
+                .await?;
+            Some(status)
+        }
+        _ => None,
+    };
+
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "incident_update.delete",
+            target_type: "incident_update",
+            target_id: Some(&update_id.to_string()),
+            details: serde_json::json!({
+                "incident_id": id,
+                "visibility": existing.visibility,
+                "status": existing.status,
+                "message": existing.message.clone(),
+                "created_at": existing.created_at,
+                "incident_status_change": restored_status.map(|status| serde_json::json!({
+                    "from": previous_status,
+                    "to": status,
+                })),
+            }),
+        },
+    )
+    .await?;
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+/// The status and time of the newest public update left once `deleted` is
+/// removed from the timeline.
+fn status_after_delete(
+    updates: &[IncidentUpdate],
+    deleted: Uuid,
+) -> Option<(IncidentStatus, DateTime<Utc>)> {
+    updates
+        .iter()
+        .filter(|update| update.id != deleted && update.visibility == UpdateVisibility::Public)
+        .max_by_key(|update| update.created_at)
+        .map(|update| (update.status, update.created_at))
+}
+
+/// Backfilled timestamps can't be in the future and must keep the timeline
+/// in order: not before the incident started and between the neighbouring
+/// updates.
+fn validate_timeline_position(
+    timestamp: DateTime<Utc>,
+    started_at: Option<DateTime<Utc>>,
+    previous: Option<DateTime<Utc>>,
+    next: Option<DateTime<Utc>>,
+) -> Result<(), AppError> {
+    if timestamp > Utc::now() {
+        return Err(AppError::Validation(
+            "Timestamps cannot be in the future".to_string(),
+        ));
+    }
+    if started_at.is_some_and(|started_at| timestamp < started_at) {
+        return Err(AppError::Validation(
+            "Updates cannot be dated before the incident started".to_string(),
+        ));
+    }
+    if previous.is_some_and(|previous| timestamp < previous) {
+        return Err(AppError::Validation(
+            "Updates cannot be dated before the previous update".to_string(),
+        ));
+    }
+    if next.is_some_and(|next| timestamp > next) {
+        return Err(AppError::Validation(
+            "Updates cannot be dated after the following update".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    #[test]
+    fn timeline_position_must_be_ordered_and_not_in_future() {
+        let now = Utc::now();
+        let started = now - Duration::hours(6);
+        let previous = now - Duration::hours(4);
+        let next = now - Duration::hours(2);
+
+        assert!(validate_timeline_position(
+            now - Duration::hours(3),
+            Some(started),
+            Some(previous),
+            Some(next)
+        )
+        .is_ok());
+        assert!(validate_timeline_position(now + Duration::hours(1), None, None, None).is_err());
+        assert!(
+            validate_timeline_position(now - Duration::hours(7), Some(started), None, None)
+                .is_err()
+        );
+        assert!(validate_timeline_position(
+            now - Duration::hours(5),
+            Some(started),
+            Some(previous),
+            None
+        )
+        .is_err());
+        assert!(validate_timeline_position(
+            now - Duration::hours(1),
+            Some(started),
+            Some(previous),
+            Some(next)
+        )
+        .is_err());
+    }
+
+    fn update(
+        status: IncidentStatus,
+        hours_ago: i64,
+        visibility: UpdateVisibility,
+    ) -> IncidentUpdate {
+        IncidentUpdate {
+            id: Uuid::new_v4(),
+            incident_id: Uuid::nil(),
+            status,
+            message: status.to_string(),
+            created_by: None,
+            created_at: Utc::now() - Duration::hours(hours_ago),
+            edited_at: None,
+            visibility,
+        }
+    }
+
+    #[test]
+    fn deleting_the_latest_update_rolls_status_back_to_the_previous_public_one() {
+        let investigating = update(IncidentStatus::Investigating, 3, UpdateVisibility::Public);
+        let monitoring = update(IncidentStatus::Monitoring, 2, UpdateVisibility::Public);
+        let note = update(IncidentStatus::Resolved, 1, UpdateVisibility::Internal);
+        let resolved = update(IncidentStatus::Resolved, 0, UpdateVisibility::Public);
+        let updates = vec![
+            resolved.clone(),
+            note,
+            monitoring.clone(),
+            investigating.clone(),
+        ];
+
+        assert_eq!(
+            status_after_delete(&updates, resolved.id),
+            Some((IncidentStatus::Monitoring, monitoring.created_at))
+        );
+        assert_eq!(
+            status_after_delete(&updates, investigating.id),
+            Some((IncidentStatus::Resolved, resolved.created_at))
+        );
+    }
+}
@@ -16,12 +16,12 @@ pub fn router() -> Router<AppState> {
     Router::new().route("/{token}/accept", post(accept_invitation))
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct DataResponse<T: Serialize> {
     data: T,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct InvitationAcceptanceResponse {
     org_slug: String,
     org_name: String,
@@ -29,6 +29,15 @@ struct InvitationAcceptanceResponse {
     message: String,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/invitations/{token}/accept",
+    tag = "invitations",
+    params(
+        ("token" = String, Path, description = "Invitation token"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<InvitationAcceptanceResponse>)),
+)]
 async fn accept_invitation(
     State(state): State<AppState>,
     headers: HeaderMap,
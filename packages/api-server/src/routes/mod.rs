@@ -1,12 +1,22 @@
 pub mod admin;
 pub mod billing;
+pub mod cli;
+pub mod config;
+pub mod deletion;
+pub mod edge;
+pub mod embeds;
+pub mod exports;
 pub mod incidents;
 pub mod invitations;
 pub mod monitors;
 pub mod notifications;
+pub mod openapi;
 pub mod organizations;
 pub mod public;
+pub mod service_groups;
 pub mod services;
+pub mod status_pages;
+pub mod viewer_access;
 
 use axum::{extract::State, http::StatusCode, routing::get, Router};
 use redis::AsyncCommands;
@@ -19,29 +29,52 @@ pub fn api_router(state: AppState) -> Router {
         .route("/health", get(health))
         .route("/ready", get(ready))
         .route("/ops/summary", get(ops_summary))
+        .route("/api/openapi.json", get(openapi::openapi_json))
+        .route(
+            "/.well-known/acme-challenge/{token}",
+            get(edge::acme_challenge),
+        )
         .nest("/api/admin", admin::router())
+        .nest("/api/edge", edge::router())
         .nest("/api/billing", billing::router())
+        .nest("/api/cli", cli::router())
         .nest("/api/invitations", invitations::router())
         .nest("/api/organizations", organizations::router())
+        .nest("/api/organizations/{slug}/config", config::router())
+        .nest("/api/organizations/{slug}/deletion", deletion::router())
+        .nest("/api/organizations/{slug}/exports", exports::router())
         .nest("/api/organizations/{slug}/services", services::router())
+        .nest(
+            "/api/organizations/{slug}/service-groups",
+            service_groups::router(),
+        )
+        .nest(
+            "/api/organizations/{slug}/status-pages",
+            status_pages::router(),
+        )
         .nest("/api/organizations/{slug}/incidents", incidents::router())
         .nest("/api/organizations/{slug}/monitors", monitors::router())
         .nest(
             "/api/organizations/{slug}/notifications",
             notifications::router(),
         )
-        .nest("/api/public", public::router())
+        .nest(
+            "/api/public",
+            public::router()
+                .merge(embeds::router())
+                .merge(viewer_access::router()),
+        )
         .with_state(state)
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct HealthResponse {
     status: &'static str,
     database: &'static str,
     redis: &'static str,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct OpsSummaryResponse {
     status: &'static str,
     database: &'static str,
@@ -57,6 +90,15 @@ struct OpsSummaryResponse {
     failed_webhook_deliveries: i64,
 }
 
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "ops",
+    responses(
+        (status = 200, description = "Database and Redis are reachable", body = HealthResponse),
+        (status = 503, description = "A dependency is unavailable"),
+    ),
+)]
 async fn health(State(state): State<AppState>) -> Result<axum::Json<HealthResponse>, StatusCode> {
     // Check database connection
     let db_status = match sqlx::query("SELECT 1").fetch_one(&state.pool).await {
@@ -94,6 +136,15 @@ async fn health(State(state): State<AppState>) -> Result<axum::Json<HealthRespon
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/ready",
+    tag = "ops",
+    responses(
+        (status = 204, description = "Ready to serve traffic"),
+        (status = 503, description = "A dependency is unavailable"),
+    ),
+)]
 async fn ready(State(state): State<AppState>) -> Result<StatusCode, StatusCode> {
     let db_ok = sqlx::query("SELECT 1").fetch_one(&state.pool).await.is_ok();
     let redis_ok = state
@@ -110,6 +161,15 @@ async fn ready(State(state): State<AppState>) -> Result<StatusCode, StatusCode>
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/ops/summary",
+    tag = "ops",
+    responses(
+        (status = 200, description = "Dependency status and queue counts", body = OpsSummaryResponse),
+        (status = 503, description = "Counts could not be loaded"),
+    ),
+)]
 async fn ops_summary(
     State(state): State<AppState>,
 ) -> Result<axum::Json<OpsSummaryResponse>, StatusCode> {
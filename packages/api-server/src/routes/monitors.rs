@@ -6,9 +6,12 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use shared::enums::OrganizationPlan;
+use shared::enums::{MonitorType, OrganizationPlan};
 use shared::error::AppError;
-use shared::models::monitor::{CreateMonitorRequest, Monitor, MonitorCheck, UpdateMonitorRequest};
+use shared::models::monitor::{
+    parse_monitor_config, CreateMonitorRequest, Monitor, MonitorCheck, UpdateMonitorRequest,
+};
+use shared::models::monitoring_policy::{MonitoringPolicy, UpdateMonitoringPolicyRequest};
 
 use crate::db;
 use crate::middleware::org_access::OrgAccess;
@@ -17,6 +20,7 @@ use crate::state::AppState;
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/", post(create_monitor).get(list_monitors))
+        .route("/policy", get(get_policy).patch(update_policy))
         .route(
             "/{id}",
             get(get_monitor)
@@ -26,39 +30,75 @@ pub fn router() -> Router<AppState> {
         .route("/{id}/checks", get(get_check_history))
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct DataResponse<T: Serialize> {
     data: T,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct ListResponse<T: Serialize> {
     data: T,
     pagination: Pagination,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct Pagination {
     page: i64,
     per_page: i64,
     total: i64,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 struct ListParams {
     page: Option<i64>,
     per_page: Option<i64>,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/monitors",
+    tag = "monitors",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = CreateMonitorRequest,
+    responses((status = 201, description = "Created", body = DataResponse<Monitor>)),
+)]
 async fn create_monitor(
     State(state): State<AppState>,
     org_access: OrgAccess,
     Json(req): Json<CreateMonitorRequest>,
 ) -> Result<(axum::http::StatusCode, Json<DataResponse<Monitor>>), AppError> {
     org_access.require_admin()?;
+    validate_monitor_settings(
+        req.monitor_type,
+        Some(&req.config),
+        req.interval_seconds,
+        req.timeout_ms,
+        req.recovery_threshold,
+    )?;
+
+    let monitor_count = db::monitors::count_by_org(&state.pool, org_access.org.id).await?;
+    ensure_monitor_capacity(org_access.org.plan, monitor_count + 1)?;
+
+    let monitor = db::monitors::create(&state.pool, org_access.org.id, &req).await?;
+    Ok((
+        axum::http::StatusCode::CREATED,
+        Json(DataResponse { data: monitor }),
+    ))
+}
 
-    // Validate interval
-    if let Some(interval) = req.interval_seconds {
+/// Checks shared by the monitors API and config sync. The config must
+/// deserialize the way the monitor engine will read it.
+pub(crate) fn validate_monitor_settings(
+    monitor_type: MonitorType,
+    config: Option<&serde_json::Value>,
+    interval_seconds: Option<i32>,
+    timeout_ms: Option<i32>,
+    recovery_threshold: Option<i32>,
+) -> Result<(), AppError> {
+    if let Some(interval) = interval_seconds {
         if !(30..=300).contains(&interval) {
             return Err(AppError::Validation(
                 "Interval must be between 30 and 300 seconds".to_string(),
@@ -66,8 +106,7 @@ async fn create_monitor(
         }
     }
 
-    // Validate timeout
-    if let Some(timeout) = req.timeout_ms {
+    if let Some(timeout) = timeout_ms {
         if !(1000..=30000).contains(&timeout) {
             return Err(AppError::Validation(
                 "Timeout must be between 1000 and 30000 milliseconds".to_string(),
@@ -75,22 +114,47 @@ async fn create_monitor(
         }
     }
 
-    if let Some(limit) = org_access.org.plan.max_monitors() {
-        let monitor_count = db::monitors::count_by_org(&state.pool, org_access.org.id).await?;
-        if monitor_count >= limit {
+    validate_recovery_threshold(recovery_threshold)?;
+
+    if let Some(config) = config {
+        parse_monitor_config(monitor_type, config.clone()).map_err(|error| {
+            AppError::Validation(format!(
+                "Invalid {} monitor config: {error}",
+                monitor_type.as_str()
+            ))
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Rejects a change that would leave the organization with more active
+/// monitors than its plan allows.
+pub(crate) fn ensure_monitor_capacity(
+    plan: OrganizationPlan,
+    monitors_after: i64,
+) -> Result<(), AppError> {
+    if let Some(limit) = plan.max_monitors() {
+        if monitors_after > limit {
             return Err(AppError::Validation(format!(
                 "{} plan allows up to {} monitors. Upgrade to add more.",
-                plan_name(org_access.org.plan),
+                plan_name(plan),
                 limit
             )));
         }
     }
+    Ok(())
+}
 
-    let monitor = db::monitors::create(&state.pool, org_access.org.id, &req).await?;
-    Ok((
-        axum::http::StatusCode::CREATED,
-        Json(DataResponse { data: monitor }),
-    ))
+fn validate_recovery_threshold(threshold: Option<i32>) -> Result<(), AppError> {
+    if let Some(threshold) = threshold {
+        if !(1..=10).contains(&threshold) {
+            return Err(AppError::Validation(
+                "Recovery threshold must be between 1 and 10 checks".to_string(),
+            ));
+        }
+    }
+    Ok(())
 }
 
 fn plan_name(plan: OrganizationPlan) -> &'static str {
@@ -101,6 +165,15 @@ fn plan_name(plan: OrganizationPlan) -> &'static str {
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/monitors",
+    tag = "monitors",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<Vec<Monitor>>)),
+)]
 async fn list_monitors(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -109,6 +182,16 @@ async fn list_monitors(
     Ok(Json(DataResponse { data: monitors }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/monitors/{id}",
+    tag = "monitors",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<Monitor>)),
+)]
 async fn get_monitor(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -120,6 +203,17 @@ async fn get_monitor(
     Ok(Json(DataResponse { data: monitor }))
 }
 
+#[utoipa::path(
+    patch,
+    path = "/api/organizations/{slug}/monitors/{id}",
+    tag = "monitors",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    request_body = UpdateMonitorRequest,
+    responses((status = 200, description = "OK", body = DataResponse<Monitor>)),
+)]
 async fn update_monitor(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -127,11 +221,31 @@ async fn update_monitor(
     Json(req): Json<UpdateMonitorRequest>,
 ) -> Result<Json<DataResponse<Monitor>>, AppError> {
     org_access.require_admin()?;
+    let existing = db::monitors::find_by_id(&state.pool, id, org_access.org.id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Monitor not found".to_string()))?;
+    validate_monitor_settings(
+        existing.monitor_type,
+        req.config.as_ref(),
+        req.interval_seconds,
+        req.timeout_ms,
+        req.recovery_threshold,
+    )?;
 
     let monitor = db::monitors::update(&state.pool, id, org_access.org.id, &req).await?;
     Ok(Json(DataResponse { data: monitor }))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/organizations/{slug}/monitors/{id}",
+    tag = "monitors",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 204, description = "No content")),
+)]
 async fn delete_monitor(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -143,6 +257,112 @@ async fn delete_monitor(
     Ok(axum::http::StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/monitors/policy",
+    tag = "monitors",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<MonitoringPolicy>)),
+)]
+async fn get_policy(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+) -> Result<Json<DataResponse<MonitoringPolicy>>, AppError> {
+    let policy = db::monitoring_policies::get_or_create(&state.pool, org_access.org.id).await?;
+    Ok(Json(DataResponse { data: policy }))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/organizations/{slug}/monitors/policy",
+    tag = "monitors",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = UpdateMonitoringPolicyRequest,
+    responses((status = 200, description = "OK", body = DataResponse<MonitoringPolicy>)),
+)]
+async fn update_policy(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Json(req): Json<UpdateMonitoringPolicyRequest>,
+) -> Result<Json<DataResponse<MonitoringPolicy>>, AppError> {
+    org_access.require_admin()?;
+    validate_recovery_threshold(req.recovery_threshold)?;
+
+    let current = db::monitoring_policies::get_or_create(&state.pool, org_access.org.id).await?;
+    validate_flap_window(
+        req.flap_window_checks.unwrap_or(current.flap_window_checks),
+        req.flap_state_changes.unwrap_or(current.flap_state_changes),
+    )?;
+    for (label, minutes) in [
+        (
+            "Auto-incident cool-down",
+            req.auto_incident_cooldown_minutes,
+        ),
+        (
+            "Minimum public auto-incident duration",
+            req.min_public_auto_incident_minutes,
+        ),
+    ] {
+        if minutes.is_some_and(|minutes| !(0..=1440).contains(&minutes)) {
+            return Err(AppError::Validation(format!(
+                "{label} must be between 0 and 1440 minutes"
+            )));
+        }
+    }
+
+    let policy = db::monitoring_policies::update(&state.pool, org_access.org.id, &req).await?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "monitoring_policy.update",
+            target_type: "monitoring_policy",
+            target_id: Some(&policy.id.to_string()),
+            details: serde_json::json!({
+                "recovery_threshold": policy.recovery_threshold,
+                "flap_detection_enabled": policy.flap_detection_enabled,
+                "flap_window_checks": policy.flap_window_checks,
+                "flap_state_changes": policy.flap_state_changes,
+                "auto_incident_cooldown_minutes": policy.auto_incident_cooldown_minutes,
+                "min_public_auto_incident_minutes": policy.min_public_auto_incident_minutes,
+            }),
+        },
+    )
+    .await?;
+    Ok(Json(DataResponse { data: policy }))
+}
+
+fn validate_flap_window(window_checks: i32, state_changes: i32) -> Result<(), AppError> {
+    if !(4..=50).contains(&window_checks) {
+        return Err(AppError::Validation(
+            "Flap window must be between 4 and 50 checks".to_string(),
+        ));
+    }
+    if state_changes < 2 || state_changes >= window_checks {
+        return Err(AppError::Validation(
+            "Flap state changes must be at least 2 and smaller than the flap window".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/monitors/{id}/checks",
+    tag = "monitors",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+        ListParams,
+    ),
+    responses((status = 200, description = "OK", body = ListResponse<Vec<MonitorCheck>>)),
+)]
 async fn get_check_history(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -179,4 +399,35 @@ mod tests {
         assert_eq!(plan_name(OrganizationPlan::Pro), "Pro");
         assert_eq!(plan_name(OrganizationPlan::Team), "Team");
     }
+
+    #[test]
+    fn monitor_settings_require_a_valid_config_and_ranges() {
+        let http = serde_json::json!({ "url": "https://example.com" });
+        assert!(
+            validate_monitor_settings(MonitorType::Http, Some(&http), Some(60), None, None).is_ok()
+        );
+        assert!(
+            validate_monitor_settings(MonitorType::Http, Some(&http), Some(10), None, None)
+                .is_err()
+        );
+        assert!(
+            validate_monitor_settings(MonitorType::Tcp, Some(&http), None, None, None).is_err(),
+            "an HTTP config is not a valid TCP config"
+        );
+    }
+
+    #[test]
+    fn monitor_capacity_allows_exactly_the_plan_limit() {
+        assert!(ensure_monitor_capacity(OrganizationPlan::Free, 3).is_ok());
+        assert!(ensure_monitor_capacity(OrganizationPlan::Free, 4).is_err());
+        assert!(ensure_monitor_capacity(OrganizationPlan::Team, 1_000).is_ok());
+    }
+
+    #[test]
+    fn validate_flap_window_requires_changes_inside_window() {
+        assert!(validate_flap_window(10, 4).is_ok());
+        assert!(validate_flap_window(3, 2).is_err());
+        assert!(validate_flap_window(10, 1).is_err());
+        assert!(validate_flap_window(10, 10).is_err());
+    }
 }
@@ -12,19 +12,27 @@ use shared::error::AppError;
 use shared::models::notification_preference::{
     NotificationPreferences, UpdateNotificationPreferencesRequest,
 };
+use shared::models::subscriber::{
+    BulkDeleteSubscribersRequest, ImportSubscribersRequest, ResendVerificationFilter,
+    SubscriberImportMode, SubscriberImportSummary,
+};
 use shared::models::webhook::{
-    CreateWebhookConfigRequest, UpdateWebhookConfigRequest, WebhookConfig,
+    CreateWebhookConfigRequest, UpdateWebhookConfigRequest, WebhookConfig, WebhookSubscriber,
 };
 
 use crate::db;
 use crate::middleware::org_access::OrgAccess;
 use crate::state::AppState;
 
+const MAX_BULK_RESEND: i64 = 500;
+const BULK_RESEND_COOLDOWN_MINUTES: i64 = 10;
+
 const ALLOWED_WEBHOOK_EVENTS: &[&str] = &[
     "incident.created",
     "incident.updated",
     "incident.resolved",
     "service.status_changed",
+    "incident.escalated",
 ];
 
 pub fn router() -> Router<AppState> {
@@ -34,6 +42,19 @@ pub fn router() -> Router<AppState> {
             get(get_preferences).patch(update_preferences),
         )
         .route("/subscribers", get(list_subscribers))
+        .route(
+            "/subscribers/import",
+            axum::routing::post(import_subscribers),
+        )
+        .route("/subscribers/export", get(export_subscribers))
+        .route(
+            "/subscribers/bulk-delete",
+            axum::routing::post(bulk_delete_subscribers),
+        )
+        .route(
+            "/subscribers/resend-verification",
+            axum::routing::post(bulk_resend_subscriber_verification),
+        )
         .route(
             "/subscribers/{id}",
             axum::routing::delete(delete_subscriber),
@@ -47,11 +68,22 @@ pub fn router() -> Router<AppState> {
             "/deliveries/email/{id}/retry",
             axum::routing::post(retry_email_delivery),
         )
+        .route("/deliveries/sms", get(list_sms_deliveries))
+        .route(
+            "/deliveries/sms/{id}/retry",
+            axum::routing::post(retry_sms_delivery),
+        )
+        .route("/sms-usage", get(get_sms_usage))
         .route("/deliveries/webhooks", get(list_webhook_deliveries))
         .route(
             "/deliveries/webhooks/{id}/retry",
             axum::routing::post(retry_webhook_delivery),
         )
+        .route("/webhook-subscribers", get(list_webhook_subscribers))
+        .route(
+            "/webhook-subscribers/{id}",
+            axum::routing::delete(delete_webhook_subscriber),
+        )
         .route("/webhooks", get(list_webhooks).post(create_webhook))
         .route(
             "/webhooks/{id}",
@@ -61,30 +93,31 @@ pub fn router() -> Router<AppState> {
         )
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct DataResponse<T: Serialize> {
     data: T,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct ListResponse<T: Serialize> {
     data: Vec<T>,
     pagination: Pagination,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct Pagination {
     page: i64,
     per_page: i64,
     total: i64,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct MessageResponse {
     message: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 struct ListParams {
     page: Option<i64>,
     per_page: Option<i64>,
@@ -105,6 +138,15 @@ fn normalized_status(status: Option<String>) -> Option<String> {
         .filter(|value| !value.is_empty() && value != "all")
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/notifications/preferences",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<NotificationPreferences>)),
+)]
 async fn get_preferences(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -116,6 +158,16 @@ async fn get_preferences(
     Ok(Json(DataResponse { data: preferences }))
 }
 
+#[utoipa::path(
+    patch,
+    path = "/api/organizations/{slug}/notifications/preferences",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = UpdateNotificationPreferencesRequest,
+    responses((status = 200, description = "OK", body = DataResponse<NotificationPreferences>)),
+)]
 async fn update_preferences(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -136,6 +188,15 @@ async fn update_preferences(
     Ok(Json(DataResponse { data: preferences }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/notifications/webhooks",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<Vec<WebhookConfig>>)),
+)]
 async fn list_webhooks(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -146,6 +207,68 @@ async fn list_webhooks(
     Ok(Json(DataResponse { data: webhooks }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/notifications/webhook-subscribers",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<Vec<WebhookSubscriber>>)),
+)]
+async fn list_webhook_subscribers(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+) -> Result<Json<DataResponse<Vec<WebhookSubscriber>>>, AppError> {
+    org_access.require_admin()?;
+
+    let subscribers = db::webhook_subscribers::list_by_org(&state.pool, org_access.org.id).await?;
+    Ok(Json(DataResponse { data: subscribers }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/organizations/{slug}/notifications/webhook-subscribers/{id}",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 204, description = "No content")),
+)]
+async fn delete_webhook_subscriber(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+) -> Result<axum::http::StatusCode, AppError> {
+    org_access.require_admin()?;
+
+    db::webhook_subscribers::delete_by_id(&state.pool, org_access.org.id, id).await?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "webhook_subscriber.delete",
+            target_type: "webhook_subscriber",
+            target_id: Some(&id.to_string()),
+            details: serde_json::json!({}),
+        },
+    )
+    .await?;
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/notifications/subscribers",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<Vec<db::subscribers::SubscriberListItem>>)),
+)]
 async fn list_subscribers(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -156,6 +279,16 @@ async fn list_subscribers(
     Ok(Json(DataResponse { data: subscribers }))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/organizations/{slug}/notifications/subscribers/{id}",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 204, description = "No content")),
+)]
 async fn delete_subscriber(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -164,9 +297,311 @@ async fn delete_subscriber(
     org_access.require_admin()?;
 
     db::subscribers::delete_by_id(&state.pool, org_access.org.id, id).await?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "subscriber.delete",
+            target_type: "subscriber",
+            target_id: Some(&id.to_string()),
+            details: serde_json::json!({}),
+        },
+    )
+    .await?;
     Ok(axum::http::StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/notifications/subscribers/import",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = ImportSubscribersRequest,
+    responses((status = 200, description = "OK", body = DataResponse<SubscriberImportSummary>)),
+)]
+async fn import_subscribers(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Json(req): Json<ImportSubscribersRequest>,
+) -> Result<Json<DataResponse<SubscriberImportSummary>>, AppError> {
+    org_access.require_admin()?;
+
+    let mode = req.mode;
+    let attestation = req
+        .attestation
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    let summary = crate::services::subscriber_import::import_subscribers(
+        &state.pool,
+        &org_access.org,
+        &state.config.app_base_url,
+        req,
+    )
+    .await?;
+
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "subscriber.import",
+            target_type: "organization",
+            target_id: Some(&org_access.org.id.to_string()),
+            details: serde_json::json!({
+                "mode": mode,
+                "attestation": if mode == SubscriberImportMode::PreVerified { attestation } else { None },
+                "total_rows": summary.total_rows,
+                "created": summary.created,
+                "updated": summary.updated,
+                "unchanged": summary.unchanged,
+                "errors": summary.errors.len(),
+            }),
+        },
+    )
+    .await?;
+
+    Ok(Json(DataResponse { data: summary }))
+}
+
+#[derive(Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+struct ExportParams {
+    format: Option<String>,
+    status: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/notifications/subscribers/export",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ExportParams,
+    ),
+    responses((status = 200, description = "Subscribers as CSV or JSON", content_type = "text/csv", body = String)),
+)]
+async fn export_subscribers(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Query(params): Query<ExportParams>,
+) -> Result<axum::response::Response, AppError> {
+    use axum::response::IntoResponse;
+
+    org_access.require_admin()?;
+
+    let is_verified = match normalized_status(params.status).as_deref() {
+        None => None,
+        Some("verified") => Some(true),
+        Some("pending") => Some(false),
+        Some(other) => {
+            return Err(AppError::Validation(format!(
+                "Unknown status '{other}'; expected verified, pending or all"
+            )))
+        }
+    };
+    let format = params
+        .format
+        .map(|value| value.trim().to_lowercase())
+        .unwrap_or_else(|| "csv".to_string());
+    if format != "csv" && format != "json" {
+        return Err(AppError::Validation(
+            "Export format must be csv or json".to_string(),
+        ));
+    }
+
+    let rows =
+        db::subscribers::list_for_export(&state.pool, org_access.org.id, is_verified).await?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "subscriber.export",
+            target_type: "organization",
+            target_id: Some(&org_access.org.id.to_string()),
+            details: serde_json::json!({
+                "format": format,
+                "row_count": rows.len(),
+                "is_verified": is_verified,
+            }),
+        },
+    )
+    .await?;
+
+    if format == "json" {
+        return Ok(Json(DataResponse { data: rows }).into_response());
+    }
+
+    let file_name = format!(
+        "{}-subscribers-{}.csv",
+        org_access.org.slug,
+        chrono::Utc::now().format("%Y%m%d")
+    );
+    Ok((
+        [
+            (
+                axum::http::header::CONTENT_TYPE,
+                "text/csv; charset=utf-8".to_string(),
+            ),
+            (
+                axum::http::header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{file_name}\""),
+            ),
+        ],
+        crate::services::subscriber_import::export_csv(&rows),
+    )
+        .into_response())
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/notifications/subscribers/bulk-delete",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = BulkDeleteSubscribersRequest,
+    responses((status = 200, description = "OK", body = DataResponse<MessageResponse>)),
+)]
+async fn bulk_delete_subscribers(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Json(req): Json<BulkDeleteSubscribersRequest>,
+) -> Result<Json<DataResponse<MessageResponse>>, AppError> {
+    org_access.require_admin()?;
+
+    let ids = req.ids.unwrap_or_default();
+    let emails = req
+        .emails
+        .unwrap_or_default()
+        .iter()
+        .map(|email| email.trim().to_lowercase())
+        .filter(|email| !email.is_empty())
+        .collect::<Vec<_>>();
+    if ids.is_empty() && emails.is_empty() {
+        return Err(AppError::Validation(
+            "Provide subscriber ids or emails to remove".to_string(),
+        ));
+    }
+    if ids.len() + emails.len() > 1_000 {
+        return Err(AppError::Validation(
+            "Remove at most 1000 subscribers at a time".to_string(),
+        ));
+    }
+
+    let deleted =
+        db::subscribers::delete_many(&state.pool, org_access.org.id, &ids, &emails).await?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "subscriber.bulk_delete",
+            target_type: "organization",
+            target_id: Some(&org_access.org.id.to_string()),
+            details: serde_json::json!({
+                "requested": ids.len() + emails.len(),
+                "deleted": deleted,
+            }),
+        },
+    )
+    .await?;
+
+    Ok(Json(DataResponse {
+        data: MessageResponse {
+            message: format!("Removed {deleted} subscribers."),
+        },
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/notifications/subscribers/resend-verification",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = ResendVerificationFilter,
+    responses((status = 200, description = "OK", body = DataResponse<MessageResponse>)),
+)]
+async fn bulk_resend_subscriber_verification(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Json(filter): Json<ResendVerificationFilter>,
+) -> Result<Json<DataResponse<MessageResponse>>, AppError> {
+    org_access.require_admin()?;
+
+    let subscribers = db::subscribers::refresh_pending_verification_matching(
+        &state.pool,
+        org_access.org.id,
+        &filter,
+        BULK_RESEND_COOLDOWN_MINUTES,
+        MAX_BULK_RESEND,
+    )
+    .await?;
+
+    let pages = db::status_pages::public_pages_by_id(&state.pool, org_access.org.id).await?;
+    for subscriber in &subscribers {
+        let Some(token) = subscriber.verification_token.as_deref() else {
+            continue;
+        };
+        let Some(page) = pages.get(&subscriber.status_page_id) else {
+            continue;
+        };
+        crate::services::email_notifications::queue_subscription_verification(
+            &state.pool,
+            &state.config.app_base_url,
+            page,
+            &subscriber.email,
+            subscriber.locale,
+            token,
+        )
+        .await?;
+    }
+
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "subscriber.bulk_resend_verification",
+            target_type: "organization",
+            target_id: Some(&org_access.org.id.to_string()),
+            details: serde_json::json!({ "queued": subscribers.len() }),
+        },
+    )
+    .await?;
+
+    Ok(Json(DataResponse {
+        data: MessageResponse {
+            message: format!(
+                "Queued verification emails for {} pending subscribers.",
+                subscribers.len()
+            ),
+        },
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/notifications/subscribers/{id}/resend",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<MessageResponse>)),
+)]
 async fn resend_subscriber_verification(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -191,13 +626,15 @@ async fn resend_subscriber_verification(
         .as_deref()
         .unwrap_or(verification_token.as_str());
 
+    let page =
+        db::status_pages::find_public(&state.pool, org_access.org.id, subscriber.status_page_id)
+            .await?;
     crate::services::email_notifications::queue_subscription_verification(
         &state.pool,
-        org_access.org.id,
         &state.config.app_base_url,
-        &org_access.org.slug,
-        &org_access.org.name,
+        &page,
         &subscriber.email,
+        subscriber.locale,
         token,
     )
     .await?;
@@ -212,6 +649,16 @@ async fn resend_subscriber_verification(
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/notifications/deliveries/email",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ListParams,
+    ),
+    responses((status = 200, description = "OK", body = ListResponse<db::notification_logs::NotificationLogEntry>)),
+)]
 async fn list_email_deliveries(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -241,6 +688,16 @@ async fn list_email_deliveries(
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/notifications/deliveries/webhooks",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ListParams,
+    ),
+    responses((status = 200, description = "OK", body = ListResponse<db::webhook_deliveries::WebhookDeliveryEntry>)),
+)]
 async fn list_webhook_deliveries(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -270,6 +727,16 @@ async fn list_webhook_deliveries(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/notifications/deliveries/email/{id}/retry",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<MessageResponse>)),
+)]
 async fn retry_email_delivery(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -293,6 +760,117 @@ async fn retry_email_delivery(
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/notifications/deliveries/sms",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ListParams,
+    ),
+    responses((status = 200, description = "OK", body = ListResponse<db::sms_logs::SmsLogEntry>)),
+)]
+async fn list_sms_deliveries(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Query(params): Query<ListParams>,
+) -> Result<Json<ListResponse<db::sms_logs::SmsLogEntry>>, AppError> {
+    org_access.require_admin()?;
+
+    let page = bounded_page(params.page);
+    let per_page = bounded_per_page(params.per_page, 10);
+    let status = normalized_status(params.status);
+
+    let (entries, total) = db::sms_logs::list_recent_by_org(
+        &state.pool,
+        org_access.org.id,
+        page,
+        per_page,
+        status.as_deref(),
+    )
+    .await?;
+    Ok(Json(ListResponse {
+        data: entries,
+        pagination: Pagination {
+            page,
+            per_page,
+            total,
+        },
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/notifications/deliveries/sms/{id}/retry",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<MessageResponse>)),
+)]
+async fn retry_sms_delivery(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+) -> Result<Json<DataResponse<MessageResponse>>, AppError> {
+    org_access.require_admin()?;
+    if !org_access.org.plan.allows_sms() {
+        return Err(AppError::Forbidden(
+            "SMS alerts require the Pro or Team plan".to_string(),
+        ));
+    }
+
+    let entry = db::sms_logs::retry_failed_by_id(&state.pool, org_access.org.id, id)
+        .await?
+        .ok_or_else(|| {
+            AppError::Validation("Only failed SMS deliveries can be retried".to_string())
+        })?;
+
+    Ok(Json(DataResponse {
+        data: MessageResponse {
+            message: format!(
+                "Queued another SMS delivery attempt for {}.",
+                entry.recipient_phone
+            ),
+        },
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/notifications/sms-usage",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<db::sms_logs::SmsUsage>)),
+)]
+async fn get_sms_usage(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+) -> Result<Json<DataResponse<db::sms_logs::SmsUsage>>, AppError> {
+    org_access.require_admin()?;
+
+    let usage = db::sms_logs::monthly_usage(
+        &state.pool,
+        org_access.org.id,
+        org_access.org.plan.monthly_sms_quota(),
+    )
+    .await?;
+    Ok(Json(DataResponse { data: usage }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/notifications/deliveries/webhooks/{id}/retry",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<MessageResponse>)),
+)]
 async fn retry_webhook_delivery(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -316,6 +894,16 @@ async fn retry_webhook_delivery(
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/notifications/webhooks/{id}",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<WebhookConfig>)),
+)]
 async fn get_webhook(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -332,6 +920,16 @@ async fn get_webhook(
     Ok(Json(DataResponse { data: webhook }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/notifications/webhooks",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = CreateWebhookConfigRequest,
+    responses((status = 201, description = "Created", body = DataResponse<WebhookConfig>)),
+)]
 async fn create_webhook(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -366,6 +964,17 @@ async fn create_webhook(
     ))
 }
 
+#[utoipa::path(
+    patch,
+    path = "/api/organizations/{slug}/notifications/webhooks/{id}",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    request_body = UpdateWebhookConfigRequest,
+    responses((status = 200, description = "OK", body = DataResponse<WebhookConfig>)),
+)]
 async fn update_webhook(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -419,6 +1028,16 @@ async fn update_webhook(
     Ok(Json(DataResponse { data: webhook }))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/organizations/{slug}/notifications/webhooks/{id}",
+    tag = "notifications",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 204, description = "No content")),
+)]
 async fn delete_webhook(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -443,7 +1062,7 @@ async fn delete_webhook(
     Ok(axum::http::StatusCode::NO_CONTENT)
 }
 
-fn validate_webhook_payload(
+pub(crate) fn validate_webhook_payload(
     name: &str,
     url: &str,
     secret: &str,
@@ -493,7 +1112,7 @@ fn validate_webhook_event_types(event_types: &[String]) -> Result<(), AppError>
     Ok(())
 }
 
-fn require_webhook_feature(plan: OrganizationPlan) -> Result<(), AppError> {
+pub(crate) fn require_webhook_feature(plan: OrganizationPlan) -> Result<(), AppError> {
     if !plan.allows_outbound_webhooks() {
         return Err(AppError::Validation(
             "Outbound webhooks are available on Pro and Team plans. Upgrade to add one."
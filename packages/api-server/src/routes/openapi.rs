@@ -0,0 +1,317 @@
+use utoipa::openapi::security::{
+    ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityRequirement, SecurityScheme,
+};
+use utoipa::openapi::{ContentBuilder, Ref, ResponseBuilder};
+use utoipa::{Modify, OpenApi};
+
+/// Cookie set by the web app's Auth.js session; every organization and
+/// invitation route reads it.
+const SESSION_COOKIE: &str = "authjs.session-token";
+const SESSION_SCHEME: &str = "session";
+const SESSION_PREFIXES: [&str; 3] = [
+    "/api/organizations",
+    "/api/invitations",
+    "/api/cli/credentials",
+];
+/// Approving a CLI login needs a browser session; a CLI credential is not
+/// accepted there.
+const SESSION_ONLY_PATHS: [&str; 2] = ["/api/cli/login/decision", "/api/cli/login/{user_code}"];
+/// Long-lived `spcli_` bearer token issued to the command-line client, which
+/// works wherever the session cookie does apart from `SESSION_ONLY_PATHS`.
+const CLI_TOKEN_SCHEME: &str = "cli_token";
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "StatusPage API",
+        description = "REST API for managing and reading StatusPage status pages."
+    ),
+    paths(
+        super::admin::queue_health,
+        super::admin::search_organizations,
+        super::admin::list_organization_deletions,
+        super::admin::organization_support,
+        super::admin::sync_billing_state,
+        super::admin::enforce_downgrade,
+        super::admin::cancel_downgrade,
+        super::admin::resend_invitation_email,
+        super::admin::retry_email_delivery,
+        super::admin::retry_webhook_delivery,
+        super::billing::handle_stripe_webhook,
+        super::cli::start_login,
+        super::cli::poll_login,
+        super::cli::get_login,
+        super::cli::decide_login,
+        super::cli::list_credentials,
+        super::cli::revoke_credential,
+        super::config::plan_config,
+        super::config::apply_config,
+        super::edge::get_certificate,
+        super::edge::acme_challenge,
+        super::deletion::get_deletion,
+        super::deletion::schedule_deletion,
+        super::deletion::cancel_deletion,
+        super::embeds::org_badge,
+        super::embeds::service_badge,
+        super::embeds::statuspage_status,
+        super::embeds::statuspage_summary,
+        super::embeds::widget,
+        super::embeds::widget_script,
+        super::exports::create_export,
+        super::exports::list_exports,
+        super::exports::get_export,
+        super::exports::download_export,
+        super::incidents::create_incident,
+        super::incidents::list_incidents,
+        super::incidents::search_incidents,
+        super::incidents::get_incident,
+        super::incidents::update_incident,
+        super::incidents::acknowledge_incident,
+        super::incidents::assign_incident,
+        super::incidents::delete_incident,
+        super::incidents::create_update,
+        super::incidents::edit_update,
+        super::incidents::delete_update,
+        super::invitations::accept_invitation,
+        super::health,
+        super::ready,
+        super::ops_summary,
+        super::monitors::create_monitor,
+        super::monitors::list_monitors,
+        super::monitors::get_monitor,
+        super::monitors::update_monitor,
+        super::monitors::delete_monitor,
+        super::monitors::get_policy,
+        super::monitors::update_policy,
+        super::monitors::get_check_history,
+        super::notifications::get_preferences,
+        super::notifications::update_preferences,
+        super::notifications::list_webhooks,
+        super::notifications::list_webhook_subscribers,
+        super::notifications::delete_webhook_subscriber,
+        super::notifications::list_subscribers,
+        super::notifications::delete_subscriber,
+        super::notifications::import_subscribers,
+        super::notifications::export_subscribers,
+        super::notifications::bulk_delete_subscribers,
+        super::notifications::bulk_resend_subscriber_verification,
+        super::notifications::resend_subscriber_verification,
+        super::notifications::list_email_deliveries,
+        super::notifications::list_webhook_deliveries,
+        super::notifications::retry_email_delivery,
+        super::notifications::list_sms_deliveries,
+        super::notifications::retry_sms_delivery,
+        super::notifications::get_sms_usage,
+        super::notifications::retry_webhook_delivery,
+        super::notifications::get_webhook,
+        super::notifications::create_webhook,
+        super::notifications::update_webhook,
+        super::notifications::delete_webhook,
+        super::organizations::create_organization,
+        super::organizations::list_organizations,
+        super::organizations::get_organization,
+        super::organizations::update_organization,
+        super::organizations::list_members,
+        super::organizations::list_invitations,
+        super::organizations::get_billing_summary,
+        super::organizations::start_checkout,
+        super::organizations::open_billing_portal,
+        super::organizations::get_entitlements,
+        super::organizations::verify_custom_domain,
+        super::organizations::get_page_access,
+        super::organizations::update_page_access,
+        super::organizations::add_member,
+        super::organizations::create_invitation,
+        super::organizations::update_member,
+        super::organizations::remove_member,
+        super::organizations::delete_invitation,
+        super::organizations::resend_invitation,
+        super::public::resolve_custom_domain,
+        super::public::get_status,
+        super::public::subscribe,
+        super::public::verify_subscriber,
+        super::public::unsubscribe_subscriber,
+        super::public::get_subscriber_preferences,
+        super::public::update_subscriber_preferences,
+        super::public::add_subscriber_phone,
+        super::public::verify_subscriber_phone,
+        super::public::remove_subscriber_phone,
+        super::public::get_incident_history,
+        super::public::search_incidents,
+        super::public::get_uptime,
+        super::public::get_service_status_history,
+        super::service_groups::create_group,
+        super::service_groups::list_groups,
+        super::service_groups::get_group,
+        super::service_groups::update_group,
+        super::service_groups::delete_group,
+        super::services::create_service,
+        super::services::list_services,
+        super::services::get_service,
+        super::services::update_service,
+        super::services::delete_service,
+        super::services::reorder_services,
+        super::services::get_status_history,
+        super::services::list_dependencies,
+        super::services::add_dependency,
+        super::services::remove_dependency,
+        super::status_pages::create_page,
+        super::status_pages::list_pages,
+        super::status_pages::get_page,
+        super::status_pages::update_page,
+        super::status_pages::delete_page,
+        super::status_pages::get_page_access,
+        super::status_pages::update_page_access,
+        super::status_pages::verify_custom_domain,
+        super::viewer_access::get_viewer_access,
+        super::viewer_access::sign_in_with_password,
+        super::viewer_access::request_magic_link,
+        super::viewer_access::verify_magic_link,
+    ),
+    components(schemas(shared::error::ErrorBody, shared::error::ErrorDetail)),
+    modifiers(&SessionAuth, &ErrorResponses),
+)]
+pub struct ApiDoc;
+
+struct SessionAuth;
+
+impl Modify for SessionAuth {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            SESSION_SCHEME,
+            SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::new(SESSION_COOKIE))),
+        );
+
+        components.add_security_scheme(
+            CLI_TOKEN_SCHEME,
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+
+        for (path, item) in openapi.paths.paths.iter_mut() {
+            let schemes: &[&str] = if SESSION_ONLY_PATHS.contains(&path.as_str()) {
+                &[SESSION_SCHEME]
+            } else if SESSION_PREFIXES
+                .iter()
+                .any(|prefix| path.starts_with(prefix))
+            {
+                &[SESSION_SCHEME, CLI_TOKEN_SCHEME]
+            } else {
+                continue;
+            };
+            for operation in operations(item) {
+                operation.security = Some(
+                    schemes
+                        .iter()
+                        .map(|scheme| SecurityRequirement::new(*scheme, Vec::<String>::new()))
+                        .collect(),
+                );
+            }
+        }
+    }
+}
+
+/// Every handler reports failures through `AppError`, so each operation gets
+/// a default response with its JSON error body.
+struct ErrorResponses;
+
+impl Modify for ErrorResponses {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let error = ResponseBuilder::new()
+            .description("Error")
+            .content(
+                "application/json",
+                ContentBuilder::new()
+                    .schema(Some(Ref::from_schema_name("ErrorResponse")))
+                    .build(),
+            )
+            .build();
+
+        for item in openapi.paths.paths.values_mut() {
+            for operation in operations(item) {
+                operation
+                    .responses
+                    .responses
+                    .entry("default".to_string())
+                    .or_insert_with(|| error.clone().into());
+            }
+        }
+    }
+}
+
+fn operations(
+    item: &mut utoipa::openapi::PathItem,
+) -> impl Iterator<Item = &mut utoipa::openapi::path::Operation> {
+    [
+        &mut item.get,
+        &mut item.post,
+        &mut item.put,
+        &mut item.patch,
+        &mut item.delete,
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// The spec as pretty-printed JSON, in the form checked in at
+/// `openapi/openapi.generated.json`.
+pub fn spec_json() -> String {
+    let mut json = ApiDoc::openapi()
+        .to_pretty_json()
+        .expect("OpenAPI spec serializes to JSON");
+    json.push('\n');
+    json
+}
+
+pub async fn openapi_json() -> axum::Json<utoipa::openapi::OpenApi> {
+    axum::Json(ApiDoc::openapi())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_in_spec_matches_handlers() {
+        let checked_in: serde_json::Value =
+            serde_json::from_str(include_str!("../../../../openapi/openapi.generated.json"))
+                .expect("checked-in spec is valid JSON");
+        let generated = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+        assert!(
+            checked_in == generated,
+            "openapi/openapi.generated.json is stale; run `cargo run -p api-server --bin openapi`"
+        );
+    }
+
+    #[test]
+    fn organization_routes_require_a_session() {
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+        assert!(spec["paths"]["/api/organizations"]["get"]["security"].is_array());
+        assert!(spec["paths"]["/api/public/{slug}/status"]["get"]["security"].is_null());
+    }
+
+    #[test]
+    fn cli_token_is_accepted_except_for_login_approval() {
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        let schemes = |path: &str, method: &str| -> Vec<String> {
+            spec["paths"][path][method]["security"]
+                .as_array()
+                .map(|requirements| {
+                    requirements
+                        .iter()
+                        .flat_map(|requirement| requirement.as_object().unwrap().keys().cloned())
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        assert_eq!(
+            schemes("/api/organizations/{slug}/incidents", "post"),
+            [SESSION_SCHEME, CLI_TOKEN_SCHEME]
+        );
+        assert_eq!(schemes("/api/cli/login/decision", "post"), [SESSION_SCHEME]);
+        assert!(schemes("/api/cli/login/poll", "post").is_empty());
+    }
+}
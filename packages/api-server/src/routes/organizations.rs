@@ -6,7 +6,7 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use shared::enums::{MemberRole, OrganizationPlan};
+use shared::enums::{CustomDomainVerificationMethod, MemberRole, OrganizationPlan};
 use shared::error::AppError;
 use shared::models::invitation::{CreateInvitationRequest, InvitationWithInviter};
 use shared::models::member::{CreateMemberRequest, MemberWithUser, UpdateMemberRequest};
@@ -14,6 +14,10 @@ use shared::models::organization::{
     BillingEntitlements, CreateOrganizationRequest, EntitlementViolation, Organization,
     UpdateOrganizationRequest,
 };
+use shared::models::page_access::{
+    normalize_cidrs, normalize_email_domains, validate_page_access, PageAccessSettings,
+    UpdatePageAccessRequest, MIN_PAGE_PASSWORD_LENGTH,
+};
 use shared::validation::{
     slugify, validate_brand_color, validate_custom_domain, validate_org_name, validate_slug,
     validate_timezone,
@@ -22,6 +26,7 @@ use shared::validation::{
 use crate::db;
 use crate::middleware::auth::CurrentUser;
 use crate::middleware::org_access::OrgAccess;
+use crate::services::{custom_domains, viewer_sessions};
 use crate::state::AppState;
 
 pub fn router() -> Router<AppState> {
@@ -33,6 +38,10 @@ pub fn router() -> Router<AppState> {
         .route("/{slug}/billing/portal", post(open_billing_portal))
         .route("/{slug}/entitlements", get(get_entitlements))
         .route("/{slug}/custom-domain/verify", post(verify_custom_domain))
+        .route(
+            "/{slug}/page-access",
+            get(get_page_access).put(update_page_access),
+        )
         .route("/{slug}/members", get(list_members).post(add_member))
         .route(
             "/{slug}/invitations",
@@ -52,12 +61,12 @@ pub fn router() -> Router<AppState> {
         )
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct DataResponse<T: Serialize> {
     data: T,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct BillingSummary {
     billing_enabled: bool,
     portal_enabled: bool,
@@ -79,26 +88,39 @@ struct BillingSummary {
     self_serve_downgrade: bool,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, utoipa::ToSchema)]
 struct CheckoutRequest {
     plan: OrganizationPlan,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct BillingSessionResponse {
     url: String,
 }
 
-#[derive(Serialize)]
-struct CustomDomainVerificationResponse {
+#[derive(Serialize, utoipa::ToSchema)]
+pub(crate) struct CustomDomainVerificationResponse {
     domain: String,
     expected_target: String,
     resolved_addresses: Vec<String>,
     expected_addresses: Vec<String>,
+    cname_targets: Vec<String>,
+    /// Alternative to pointing the domain at `expected_target`, for domains
+    /// behind a proxy: publish `txt_record_value` as a TXT record here.
+    txt_record_name: String,
+    txt_record_value: String,
+    pub(crate) verification_method: Option<CustomDomainVerificationMethod>,
     is_ready: bool,
     message: String,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/organizations",
+    tag = "organizations",
+    request_body = CreateOrganizationRequest,
+    responses((status = 201, description = "Created", body = DataResponse<Organization>)),
+)]
 async fn create_organization(
     State(state): State<AppState>,
     current_user: CurrentUser,
@@ -158,6 +180,12 @@ async fn create_organization(
     ))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations",
+    tag = "organizations",
+    responses((status = 200, description = "OK", body = DataResponse<Vec<Organization>>)),
+)]
 async fn list_organizations(
     State(state): State<AppState>,
     current_user: CurrentUser,
@@ -166,6 +194,15 @@ async fn list_organizations(
     Ok(Json(DataResponse { data: orgs }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<Organization>)),
+)]
 async fn get_organization(
     org_access: OrgAccess,
 ) -> Result<Json<DataResponse<Organization>>, AppError> {
@@ -174,6 +211,16 @@ async fn get_organization(
     }))
 }
 
+#[utoipa::path(
+    patch,
+    path = "/api/organizations/{slug}",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = UpdateOrganizationRequest,
+    responses((status = 200, description = "OK", body = DataResponse<Organization>)),
+)]
 async fn update_organization(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -219,6 +266,7 @@ async fn update_organization(
                 &state.pool,
                 &normalized,
                 Some(org_access.org.id),
+                None,
             )
             .await?
         {
@@ -233,6 +281,142 @@ async fn update_organization(
     Ok(Json(DataResponse { data: org }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/page-access",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<PageAccessSettings>)),
+)]
+async fn get_page_access(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+) -> Result<Json<DataResponse<PageAccessSettings>>, AppError> {
+    org_access.require_admin()?;
+
+    let access = db::page_access::find_by_org(&state.pool, org_access.org.id).await?;
+    Ok(Json(DataResponse {
+        data: PageAccessSettings::from_org(&org_access.org, access.page_password_hash.is_some()),
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/organizations/{slug}/page-access",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = UpdatePageAccessRequest,
+    responses((status = 200, description = "OK", body = DataResponse<PageAccessSettings>)),
+)]
+async fn update_page_access(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Json(req): Json<UpdatePageAccessRequest>,
+) -> Result<Json<DataResponse<PageAccessSettings>>, AppError> {
+    org_access.require_admin()?;
+
+    let current = db::page_access::find_by_org(&state.pool, org_access.org.id).await?;
+    let PageAccessUpdate {
+        allowed_email_domains,
+        allowed_cidrs,
+        password_hash,
+        password_set,
+    } = page_access_update(&req, current)?;
+
+    let org = db::page_access::update(
+        &state.pool,
+        org_access.org.id,
+        req.visibility,
+        password_hash.as_deref(),
+        &allowed_email_domains,
+        &allowed_cidrs,
+    )
+    .await?;
+
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "page_access.update",
+            target_type: "organization",
+            target_id: Some(&org.id.to_string()),
+            details: serde_json::json!({
+                "visibility": req.visibility.as_str(),
+                "password_changed": password_hash.is_some(),
+                "allowed_email_domains": &allowed_email_domains,
+                "allowed_cidrs": &allowed_cidrs,
+            }),
+        },
+    )
+    .await?;
+
+    Ok(Json(DataResponse {
+        data: PageAccessSettings::from_org(&org, password_set),
+    }))
+}
+
+/// Validated access settings ready to store.
+pub(crate) struct PageAccessUpdate {
+    pub(crate) allowed_email_domains: Vec<String>,
+    pub(crate) allowed_cidrs: Vec<String>,
+    /// `None` keeps the current password.
+    pub(crate) password_hash: Option<String>,
+    pub(crate) password_set: bool,
+}
+
+/// Applies a page access request on top of a page's current settings.
+pub(crate) fn page_access_update(
+    req: &UpdatePageAccessRequest,
+    current: db::page_access::PageAccess,
+) -> Result<PageAccessUpdate, AppError> {
+    let allowed_email_domains = match req.allowed_email_domains {
+        Some(ref domains) => normalize_email_domains(domains)?,
+        None => current.page_allowed_email_domains,
+    };
+    let allowed_cidrs = match req.allowed_cidrs {
+        Some(ref cidrs) => normalize_cidrs(cidrs)?,
+        None => current.page_allowed_cidrs,
+    };
+    let password_hash = match req.password.as_deref() {
+        Some(password) if password.chars().count() < MIN_PAGE_PASSWORD_LENGTH => {
+            return Err(AppError::Validation(format!(
+                "Password must be at least {MIN_PAGE_PASSWORD_LENGTH} characters"
+            )))
+        }
+        Some(password) => Some(viewer_sessions::hash_password(password)?),
+        None => None,
+    };
+    let password_set = password_hash.is_some() || current.page_password_hash.is_some();
+    validate_page_access(
+        req.visibility,
+        password_set,
+        &allowed_email_domains,
+        &allowed_cidrs,
+    )?;
+
+    Ok(PageAccessUpdate {
+        allowed_email_domains,
+        allowed_cidrs,
+        password_hash,
+        password_set,
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/members",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<Vec<MemberWithUser>>)),
+)]
 async fn list_members(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -243,6 +427,15 @@ async fn list_members(
     Ok(Json(DataResponse { data: members }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/invitations",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<Vec<InvitationWithInviter>>)),
+)]
 async fn list_invitations(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -253,6 +446,15 @@ async fn list_invitations(
     Ok(Json(DataResponse { data: invitations }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/billing",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<BillingSummary>)),
+)]
 async fn get_billing_summary(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -291,6 +493,16 @@ async fn get_billing_summary(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/billing/checkout",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = CheckoutRequest,
+    responses((status = 200, description = "OK", body = DataResponse<BillingSessionResponse>)),
+)]
 async fn start_checkout(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -336,6 +548,15 @@ async fn start_checkout(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/billing/portal",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<BillingSessionResponse>)),
+)]
 async fn open_billing_portal(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -363,6 +584,15 @@ async fn open_billing_portal(
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/entitlements",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<BillingEntitlements>)),
+)]
 async fn get_entitlements(
     org_access: OrgAccess,
 ) -> Result<Json<DataResponse<BillingEntitlements>>, AppError> {
@@ -371,6 +601,15 @@ async fn get_entitlements(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/custom-domain/verify",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<CustomDomainVerificationResponse>)),
+)]
 async fn verify_custom_domain(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -387,25 +626,17 @@ async fn verify_custom_domain(
         })?;
     require_custom_domain_access(org_access.org.plan, &domain)?;
 
-    let expected_target = managed_host_target(&state.config)?;
-    let resolved_addresses = resolve_host_addresses(&domain).await?;
-    let expected_addresses = resolve_host_addresses(&expected_target).await?;
-    let is_ready = !resolved_addresses.is_empty()
-        && !expected_addresses.is_empty()
-        && resolved_addresses
-            .iter()
-            .any(|address| expected_addresses.contains(address));
-
-    let message = if is_ready {
-        db::organizations::mark_custom_domain_verified(&state.pool, org_access.org.id).await?;
-        "Custom domain resolves to the managed target and is ready to use.".to_string()
-    } else if resolved_addresses.is_empty() {
-        "We could not resolve that domain yet. Add the DNS record and try again.".to_string()
-    } else if expected_addresses.is_empty() {
-        "Managed target lookup is not available in this environment yet.".to_string()
-    } else {
-        "The domain resolves, but not to the current managed target yet.".to_string()
-    };
+    let check = check_custom_domain(
+        &state.config,
+        domain.clone(),
+        &org_access.org.custom_domain_verification_token,
+    )
+    .await?;
+    if let Some(method) = check.verification_method {
+        db::organizations::mark_custom_domain_verified(&state.pool, org_access.org.id, method)
+            .await?;
+        db::certificates::request_issuance(&state.pool, &domain).await?;
+    }
 
     db::audit_logs::record(
         &state.pool,
@@ -416,29 +647,83 @@ async fn verify_custom_domain(
             action: "custom_domain.verify",
             target_type: "organization",
             target_id: Some(&org_access.org.id.to_string()),
-            details: serde_json::json!({
-                "domain": domain.clone(),
-                "expected_target": expected_target.clone(),
-                "resolved_addresses": resolved_addresses.clone(),
-                "expected_addresses": expected_addresses.clone(),
-                "is_ready": is_ready,
-            }),
+            details: check.audit_details(),
         },
     )
     .await?;
 
-    Ok(Json(DataResponse {
-        data: CustomDomainVerificationResponse {
-            domain,
-            expected_target,
-            resolved_addresses,
-            expected_addresses,
-            is_ready,
-            message,
-        },
-    }))
+    Ok(Json(DataResponse { data: check }))
+}
+
+/// Checks whether `domain` points at the managed target by CNAME, by
+/// address, or through its TXT verification token.
+pub(crate) async fn check_custom_domain(
+    config: &crate::config::Config,
+    domain: String,
+    verification_token: &str,
+) -> Result<CustomDomainVerificationResponse, AppError> {
+    let target = custom_domains::ManagedTarget::resolve(config).await?;
+    let check = custom_domains::check_domain(&domain).await;
+    let verification_method = check.matched_method(&target, verification_token);
+    let is_ready = verification_method.is_some();
+
+    let message = match verification_method {
+        Some(CustomDomainVerificationMethod::Cname) => {
+            "Custom domain is a CNAME for the managed target and is ready to use.".to_string()
+        }
+        Some(CustomDomainVerificationMethod::ARecord) => {
+            "Custom domain resolves to the managed target and is ready to use.".to_string()
+        }
+        Some(CustomDomainVerificationMethod::TxtToken) => {
+            "Custom domain publishes its verification token and is ready to use.".to_string()
+        }
+        None if check.resolved_addresses.is_empty() && check.txt_values.is_empty() => {
+            "We could not resolve that domain yet. Add the DNS record and try again.".to_string()
+        }
+        None if target.addresses.is_empty() => {
+            "Managed target lookup is not available in this environment yet. Use the TXT record instead.".to_string()
+        }
+        None => "The domain resolves, but not to the current managed target yet.".to_string(),
+    };
+
+    Ok(CustomDomainVerificationResponse {
+        txt_record_name: custom_domains::txt_record_name(&domain),
+        txt_record_value: verification_token.to_string(),
+        domain,
+        expected_target: target.host,
+        resolved_addresses: check.resolved_addresses,
+        expected_addresses: target.addresses,
+        cname_targets: check.cname_targets,
+        verification_method,
+        is_ready,
+        message,
+    })
+}
+
+impl CustomDomainVerificationResponse {
+    pub(crate) fn audit_details(&self) -> serde_json::Value {
+        serde_json::json!({
+            "domain": &self.domain,
+            "expected_target": &self.expected_target,
+            "resolved_addresses": &self.resolved_addresses,
+            "expected_addresses": &self.expected_addresses,
+            "cname_targets": &self.cname_targets,
+            "verification_method": self.verification_method,
+            "is_ready": self.is_ready,
+        })
+    }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/members",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = CreateMemberRequest,
+    responses((status = 201, description = "Created", body = DataResponse<MemberWithUser>)),
+)]
 async fn add_member(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -485,6 +770,16 @@ async fn add_member(
     ))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/invitations",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = CreateInvitationRequest,
+    responses((status = 201, description = "Created", body = DataResponse<InvitationWithInviter>)),
+)]
 async fn create_invitation(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -573,6 +868,17 @@ async fn create_invitation(
     ))
 }
 
+#[utoipa::path(
+    patch,
+    path = "/api/organizations/{slug}/members/{member_id}",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("member_id" = Uuid, Path, description = "Member id"),
+    ),
+    request_body = UpdateMemberRequest,
+    responses((status = 200, description = "OK", body = DataResponse<MemberWithUser>)),
+)]
 async fn update_member(
     State(state): State<AppState>,
     current_user: CurrentUser,
@@ -607,6 +913,16 @@ async fn update_member(
     Ok(Json(DataResponse { data: member }))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/organizations/{slug}/members/{member_id}",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("member_id" = Uuid, Path, description = "Member id"),
+    ),
+    responses((status = 204, description = "No content")),
+)]
 async fn remove_member(
     State(state): State<AppState>,
     current_user: CurrentUser,
@@ -633,6 +949,16 @@ async fn remove_member(
     Ok(axum::http::StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/organizations/{slug}/invitations/{invitation_id}",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("invitation_id" = Uuid, Path, description = "Invitation id"),
+    ),
+    responses((status = 204, description = "No content")),
+)]
 async fn delete_invitation(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -657,6 +983,16 @@ async fn delete_invitation(
     Ok(axum::http::StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/invitations/{invitation_id}/resend",
+    tag = "organizations",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("invitation_id" = Uuid, Path, description = "Invitation id"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<InvitationWithInviter>)),
+)]
 async fn resend_invitation(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -788,7 +1124,7 @@ fn ensure_upgrade_allowed(
     Ok(())
 }
 
-fn require_custom_domain_access(
+pub(crate) fn require_custom_domain_access(
     plan: OrganizationPlan,
     normalized_custom_domain: &str,
 ) -> Result<(), AppError> {
@@ -802,7 +1138,7 @@ fn require_custom_domain_access(
     Ok(())
 }
 
-fn plan_name(plan: OrganizationPlan) -> &'static str {
+pub(crate) fn plan_name(plan: OrganizationPlan) -> &'static str {
     match plan {
         OrganizationPlan::Free => "Free",
         OrganizationPlan::Pro => "Pro",
@@ -810,47 +1146,6 @@ fn plan_name(plan: OrganizationPlan) -> &'static str {
     }
 }
 
-async fn resolve_host_addresses(host: &str) -> Result<Vec<String>, AppError> {
-    let Ok(addresses) = tokio::net::lookup_host((host, 80)).await else {
-        return Ok(Vec::new());
-    };
-
-    let mut unique = addresses
-        .map(|address| address.ip().to_string())
-        .collect::<Vec<_>>();
-    unique.sort();
-    unique.dedup();
-    Ok(unique)
-}
-
-fn managed_host_target(config: &crate::config::Config) -> Result<String, AppError> {
-    if let Some(host) = config
-        .statuspage_host
-        .as_ref()
-        .filter(|value| !value.is_empty())
-    {
-        return Ok(host.clone());
-    }
-
-    extract_host_from_url(&config.app_base_url).ok_or_else(|| {
-        AppError::Validation(
-            "Set STATUSPAGE_HOST or use a valid APP_BASE_URL before verifying a custom domain."
-                .to_string(),
-        )
-    })
-}
-
-fn extract_host_from_url(url: &str) -> Option<String> {
-    let without_scheme = url.split("://").nth(1).unwrap_or(url);
-    without_scheme
-        .split('/')
-        .next()
-        .and_then(|host| host.split(':').next())
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .map(ToOwned::to_owned)
-}
-
 fn looks_like_email(email: &str) -> bool {
     let email = email.trim();
     let Some(at_index) = email.find('@') else {
@@ -863,6 +1158,7 @@ fn looks_like_email(email: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use shared::enums::Locale;
 
     fn base_org(plan: OrganizationPlan) -> Organization {
         Organization {
@@ -873,9 +1169,14 @@ mod tests {
             logo_url: None,
             brand_color: "#3B82F6".to_string(),
             timezone: "UTC".to_string(),
+            locale: Locale::En,
             custom_domain: None,
             custom_domain_verified_at: None,
             custom_domain_status: shared::enums::CustomDomainStatus::NotConfigured,
+            custom_domain_verification_token: "token".to_string(),
+            custom_domain_verification_method: None,
+            custom_domain_checked_at: None,
+            custom_domain_grace_ends_at: None,
             stripe_customer_id: None,
             stripe_subscription_id: None,
             subscription_status: shared::enums::SubscriptionStatus::Inactive,
@@ -889,6 +1190,9 @@ mod tests {
             downgrade_grace_ends_at: None,
             downgrade_state: shared::enums::DowngradeState::None,
             downgrade_warning_stage: 0,
+            page_visibility: shared::enums::PageVisibility::Public,
+            page_allowed_email_domains: Vec::new(),
+            page_allowed_cidrs: Vec::new(),
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         }
@@ -907,9 +1211,21 @@ mod tests {
             smtp_password: None,
             smtp_secure: false,
             email_from: "alerts@example.com".to_string(),
+            email_transport: "smtp".to_string(),
+            email_file_drop_dir: None,
+            email_http_api_url: None,
+            email_http_api_key: None,
             app_base_url: "https://app.statuspage.test".to_string(),
             email_dispatch_interval_secs: 3,
             email_dispatch_batch_size: 20,
+            email_digest_interval_secs: 60,
+            sms_http_api_url: None,
+            sms_http_api_key: None,
+            sms_from: None,
+            sms_dispatch_interval_secs: 5,
+            sms_dispatch_batch_size: 20,
+            incident_escalation_delay_minutes: 15,
+            incident_escalation_interval_secs: 60,
             stripe_secret_key: Some("sk_test_123".to_string()),
             stripe_webhook_secret: Some("whsec_test".to_string()),
             stripe_price_pro: Some("price_pro".to_string()),
@@ -919,7 +1235,20 @@ mod tests {
             api_port: 4000,
             api_host: "127.0.0.1".to_string(),
             cors_origin: "http://localhost:3000".to_string(),
+            trusted_proxy_cidrs: Vec::new(),
             statuspage_host: Some("statuspage.test".to_string()),
+            acme_directory_url: None,
+            acme_contact_email: None,
+            acme_ca_cert_path: None,
+            certificate_encryption_key: None,
+            certificate_renewal_interval_secs: 300,
+            certificate_renew_before_days: 30,
+            edge_proxy_token: None,
+            custom_domain_recheck_interval_secs: 3600,
+            custom_domain_miss_threshold: 3,
+            custom_domain_grace_hours: 72,
+            org_deletion_cool_off_hours: 168,
+            org_export_retention_hours: 168,
             run_migrations_on_start: false,
             run_migrations_only: false,
             log_level: "info".to_string(),
@@ -952,18 +1281,6 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[test]
-    fn extract_host_from_url_handles_scheme_and_port() {
-        assert_eq!(
-            extract_host_from_url("https://status.example.com:443/app"),
-            Some("status.example.com".to_string())
-        );
-        assert_eq!(
-            extract_host_from_url("localhost:3000"),
-            Some("localhost".to_string())
-        );
-    }
-
     #[test]
     fn looks_like_email_rejects_malformed_values() {
         assert!(looks_like_email("owner@example.com"));
@@ -1,18 +1,35 @@
 use axum::{
     extract::{Path, Query, State},
     http::HeaderMap,
-    routing::{get, post},
+    routing::{delete, get, post, put},
     Json, Router,
 };
 use chrono::{Duration, Utc};
 use serde::{Deserialize, Serialize};
 
-use shared::enums::{IncidentStatus, ServiceStatus};
+use shared::enums::DigestFrequency;
+use shared::enums::{IncidentStatus, Locale, OrganizationPlan, PageVisibility, ServiceStatus};
 use shared::error::AppError;
-use shared::models::incident::Incident;
+use shared::i18n::{self, Label};
+use shared::models::incident::{Incident, IncidentSearchParams};
 use shared::models::incident_update::IncidentUpdate;
-use shared::models::subscriber::SubscribeRequest;
+use shared::models::page_access::ViewerAccessResponse;
+use shared::models::service::effective_status;
+use shared::models::service_group::{aggregate_group_status, aggregate_group_uptime, ServiceGroup};
+use shared::models::status_history::{
+    combined_uptime, summarize_intervals, ServiceStatusInterval, StatusHistoryParams,
+};
+use shared::models::subscriber::{
+    SubscribeRequest, Subscriber, SubscriberPhoneRequest, SubscriberPreferences,
+    VerifySubscriberPhoneRequest,
+};
 
+use crate::db::status_pages::PublicPage;
+use crate::db::subscribers::PhoneVerificationOutcome;
+use crate::middleware::page_viewer::{ClientIp, PageViewer};
+use crate::services::sms_notifications;
+use crate::services::viewer_sessions;
+use crate::services::webhook_subscribers::WebhookSubscription;
 use crate::state::AppState;
 
 pub fn router() -> Router<AppState> {
@@ -20,120 +37,259 @@ pub fn router() -> Router<AppState> {
         .route("/resolve", get(resolve_custom_domain))
         .route("/{slug}/status", get(get_status))
         .route("/{slug}/incidents", get(get_incident_history))
+        .route("/{slug}/incidents/search", get(search_incidents))
         .route("/{slug}/uptime", get(get_uptime))
+        .route(
+            "/{slug}/services/{id}/status-history",
+            get(get_service_status_history),
+        )
         .route("/{slug}/subscribe", post(subscribe))
+        // Token-authenticated subscriber links keep working on restricted
+        // pages, so people can always unsubscribe.
         .route("/{slug}/subscribers/verify", get(verify_subscriber))
         .route(
             "/{slug}/subscribers/unsubscribe",
             get(unsubscribe_subscriber),
         )
+        .route(
+            "/{slug}/subscribers/preferences",
+            get(get_subscriber_preferences),
+        )
+        .route(
+            "/{slug}/subscribers/preferences",
+            put(update_subscriber_preferences),
+        )
+        .route("/{slug}/subscribers/phone", post(add_subscriber_phone))
+        .route("/{slug}/subscribers/phone", delete(remove_subscriber_phone))
+        .route(
+            "/{slug}/subscribers/phone/verify",
+            post(verify_subscriber_phone),
+        )
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct DataResponse<T: Serialize> {
     data: T,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct PublicMessageResponse {
     message: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct ResolveCustomDomainResponse {
     slug: String,
     organization: PublicOrg,
+    /// Whether this viewer may read the page, or must sign in first.
+    access: ViewerAccessResponse,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 struct ResolveHostParams {
     host: String,
 }
 
+/// Overrides the language of labels and formatted timestamps. Without it
+/// the `Accept-Language` header decides, then the organization default.
+#[derive(Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+struct LocaleParams {
+    /// `en`, `de`, `es` or `fr`; regional tags such as `de-AT` also match.
+    locale: Option<String>,
+}
+
+/// The language and timezone one public response is rendered in.
+struct Presentation {
+    locale: Locale,
+    timezone: String,
+}
+
+impl Presentation {
+    fn resolve(
+        page: &PublicPage,
+        params: &LocaleParams,
+        headers: &HeaderMap,
+    ) -> Result<Self, AppError> {
+        let requested = params
+            .locale
+            .as_deref()
+            .map(str::trim)
+            .filter(|locale| !locale.is_empty());
+        let locale = match requested {
+            Some(tag) => Locale::from_tag(tag)
+                .ok_or_else(|| AppError::Validation(format!("Unsupported locale '{tag}'")))?,
+            None => headers
+                .get(axum::http::header::ACCEPT_LANGUAGE)
+                .and_then(|value| value.to_str().ok())
+                .and_then(Locale::negotiate)
+                .unwrap_or(page.locale),
+        };
+
+        Ok(Self {
+            locale,
+            timezone: page.timezone.clone(),
+        })
+    }
+
+    fn timestamp(&self, at: chrono::DateTime<Utc>) -> String {
+        i18n::format_timestamp(at, &self.timezone, self.locale)
+    }
+}
+
 // --- Status endpoint ---
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct StatusResponse {
     organization: PublicOrg,
+    /// Language the labels and display timestamps are rendered in.
+    locale: Locale,
     overall_status: ServiceStatus,
+    overall_status_label: String,
+    groups: Vec<PublicServiceGroup>,
     services: Vec<PublicService>,
     active_incidents: Vec<PublicIncident>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct PublicOrg {
     name: String,
     logo_url: Option<String>,
     brand_color: String,
+    default_locale: Locale,
+    /// Display timestamps are rendered in this timezone.
+    timezone: String,
 }
 
-#[derive(Serialize, sqlx::FromRow)]
+#[derive(Serialize, sqlx::FromRow, utoipa::ToSchema)]
 struct PublicService {
     id: uuid::Uuid,
     name: String,
     current_status: ServiceStatus,
+    #[sqlx(skip)]
+    status_label: String,
+    group_id: Option<uuid::Uuid>,
     group_name: Option<String>,
+    /// Name of the visible upstream service whose outage degrades this one.
+    upstream_cause: Option<String>,
+    #[serde(skip)]
+    upstream_outage: bool,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
+struct PublicServiceGroup {
+    id: uuid::Uuid,
+    name: String,
+    description: Option<String>,
+    collapsed_by_default: bool,
+    status: ServiceStatus,
+    status_label: String,
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
 struct PublicIncident {
     id: uuid::Uuid,
     title: String,
     status: IncidentStatus,
+    status_label: String,
     impact: shared::enums::IncidentImpact,
+    impact_label: String,
     started_at: chrono::DateTime<Utc>,
+    /// `started_at` in the organization timezone.
+    started_at_display: String,
     resolved_at: Option<chrono::DateTime<Utc>>,
-    updates: Vec<IncidentUpdate>,
-    affected_services: Vec<String>,
+    resolved_at_display: Option<String>,
+    updates: Vec<PublicIncidentUpdate>,
+    affected_services: Vec<PublicAffectedService>,
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+struct PublicIncidentUpdate {
+    #[serde(flatten)]
+    update: IncidentUpdate,
+    status_label: String,
+    created_at_display: String,
+}
+
+#[derive(Serialize, sqlx::FromRow, utoipa::ToSchema)]
+struct PublicAffectedService {
+    name: String,
+    status: ServiceStatus,
+    #[sqlx(skip)]
+    status_label: String,
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/public/resolve",
+    tag = "public",
+    params(
+        ResolveHostParams,
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<ResolveCustomDomainResponse>)),
+)]
 async fn resolve_custom_domain(
     State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
     Query(params): Query<ResolveHostParams>,
 ) -> Result<Json<DataResponse<ResolveCustomDomainResponse>>, AppError> {
     let host = normalize_host(&params.host)
         .ok_or_else(|| AppError::Validation("Host is required".to_string()))?;
 
-    let org = sqlx::query_as::<_, OrgRow>(
-        "SELECT id, slug, name, logo_url, brand_color FROM organizations WHERE lower(custom_domain) = $1 AND custom_domain_status = 'verified'",
-    )
-    .bind(&host)
-    .fetch_optional(&state.pool)
-    .await?
-    .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))?;
+    let page = crate::db::status_pages::find_public_by_host(&state.pool, &host)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))?;
+
+    // Branding stays visible so a sign-in page can be rendered, but networks
+    // outside an IP allowlist learn nothing beyond the page existing.
+    let page_access =
+        crate::db::page_access::find_by_page(&state.pool, page.org_id, page.page_id).await?;
+    let authorized = viewer_sessions::viewer_allowed(&page_access, &headers, client_ip, Utc::now());
+    if !authorized && page_access.page_visibility == PageVisibility::IpAllowlist {
+        return Err(viewer_sessions::access_denied(&page_access));
+    }
 
     Ok(Json(DataResponse {
         data: ResolveCustomDomainResponse {
-            slug: org.slug,
-            organization: PublicOrg {
-                name: org.name,
-                logo_url: org.logo_url,
-                brand_color: org.brand_color,
+            slug: page.slug.clone(),
+            organization: PublicOrg::from(page),
+            access: ViewerAccessResponse {
+                visibility: page_access.page_visibility,
+                authorized,
             },
         },
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/public/{slug}/status",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        LocaleParams,
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<StatusResponse>)),
+)]
 async fn get_status(
     State(state): State<AppState>,
+    _viewer: PageViewer,
+    headers: HeaderMap,
     Path(slug): Path<String>,
+    Query(locale): Query<LocaleParams>,
 ) -> Result<Json<DataResponse<StatusResponse>>, AppError> {
-    // Get org
-    let org = sqlx::query_as::<_, OrgRow>(
-        "SELECT id, slug, name, logo_url, brand_color FROM organizations WHERE slug = $1",
-    )
-    .bind(&slug)
-    .fetch_optional(&state.pool)
-    .await?
-    .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))?;
-
-    // Get visible services
-    let services = sqlx::query_as::<_, PublicService>(
-        "SELECT id, name, current_status, group_name FROM services WHERE org_id = $1 AND is_visible = true ORDER BY display_order",
-    )
-    .bind(org.id)
-    .fetch_all(&state.pool)
-    .await?;
+    let page = page_by_slug(&state.pool, &slug).await?;
+    let presentation = Presentation::resolve(&page, &locale, &headers)?;
+    let mut services = visible_services(&state.pool, &page).await?;
+    for service in &mut services {
+        service.status_label = service
+            .current_status
+            .label(presentation.locale)
+            .to_string();
+    }
+    let groups = public_groups(&state.pool, page.org_id, &services, presentation.locale).await?;
 
     // Calculate overall status (worst case)
     let overall_status = services
@@ -145,71 +301,145 @@ async fn get_status(
 
     // Get active incidents
     let active_incidents_raw = sqlx::query_as::<_, Incident>(
-        "SELECT * FROM incidents WHERE org_id = $1 AND status != 'resolved' ORDER BY created_at DESC",
+        r#"
+        SELECT * FROM incidents
+        WHERE org_id = $1 AND status != 'resolved' AND status_page_covers_incident($2, id)
+        ORDER BY created_at DESC
+        "#,
     )
-    .bind(org.id)
+    .bind(page.org_id)
+    .bind(page.page_id)
     .fetch_all(&state.pool)
     .await?;
 
     let mut active_incidents = Vec::new();
     for incident in active_incidents_raw {
-        let updates = sqlx::query_as::<_, IncidentUpdate>(
-            "SELECT * FROM incident_updates WHERE incident_id = $1 ORDER BY created_at DESC",
-        )
-        .bind(incident.id)
-        .fetch_all(&state.pool)
-        .await?;
-
-        let affected: Vec<String> = sqlx::query_scalar(
-            r#"
-            SELECT s.name FROM services s
-            JOIN incident_services isvc ON isvc.service_id = s.id
-            WHERE isvc.incident_id = $1
-            "#,
-        )
-        .bind(incident.id)
-        .fetch_all(&state.pool)
-        .await?;
-
-        active_incidents.push(PublicIncident {
-            id: incident.id,
-            title: incident.title,
-            status: incident.status,
-            impact: incident.impact,
-            started_at: incident.started_at,
-            resolved_at: incident.resolved_at,
-            updates,
-            affected_services: affected,
-        });
+        active_incidents
+            .push(public_incident(&state.pool, page.page_id, incident, &presentation).await?);
     }
 
     Ok(Json(DataResponse {
         data: StatusResponse {
-            organization: PublicOrg {
-                name: org.name,
-                logo_url: org.logo_url,
-                brand_color: org.brand_color,
-            },
+            organization: PublicOrg::from(page),
+            locale: presentation.locale,
+            overall_status_label: overall_status.label(presentation.locale).to_string(),
             overall_status,
+            groups,
             services,
             active_incidents,
         },
     }))
 }
 
+/// The services listed on `page`, in display order.
+async fn visible_services(
+    pool: &sqlx::PgPool,
+    page: &PublicPage,
+) -> Result<Vec<PublicService>, AppError> {
+    let mut services = sqlx::query_as::<_, PublicService>(
+        r#"
+        SELECT
+            s.id,
+            s.name,
+            s.current_status,
+            s.group_id,
+            s.group_name,
+            up.name AS upstream_cause,
+            s.upstream_cause_service_id IS NOT NULL AS upstream_outage
+        FROM services s
+        LEFT JOIN services up
+          ON up.id = s.upstream_cause_service_id
+         AND up.id IN (SELECT status_page_service_ids($1, $2))
+        LEFT JOIN service_groups g ON g.id = s.group_id
+        WHERE s.id IN (SELECT status_page_service_ids($1, $2))
+        ORDER BY g.display_order NULLS LAST, s.display_order
+        "#,
+    )
+    .bind(page.org_id)
+    .bind(page.page_id)
+    .fetch_all(pool)
+    .await?;
+    for service in &mut services {
+        service.current_status = effective_status(service.current_status, service.upstream_outage);
+    }
+
+    Ok(services)
+}
+
+/// Groups with at least one visible member, each with a status aggregated
+/// from its visible members' effective statuses.
+async fn public_groups(
+    pool: &sqlx::PgPool,
+    org_id: uuid::Uuid,
+    services: &[PublicService],
+    locale: Locale,
+) -> Result<Vec<PublicServiceGroup>, AppError> {
+    let groups = crate::db::service_groups::find_by_org(pool, org_id).await?;
+
+    Ok(groups
+        .into_iter()
+        .filter_map(|group| {
+            let members = services
+                .iter()
+                .filter(|service| service.group_id == Some(group.id))
+                .map(|service| service.current_status)
+                .collect::<Vec<_>>();
+            if members.is_empty() {
+                return None;
+            }
+            let status = aggregate_group_status(group.status_rule, &members);
+            Some(PublicServiceGroup {
+                status,
+                status_label: status.label(locale).to_string(),
+                id: group.id,
+                name: group.name,
+                description: group.description,
+                collapsed_by_default: group.collapsed_by_default,
+            })
+        })
+        .collect())
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/public/{slug}/subscribe",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = SubscribeRequest,
+    responses((status = 201, description = "Created", body = DataResponse<SubscribeResponse>)),
+)]
 async fn subscribe(
     State(state): State<AppState>,
+    _viewer: PageViewer,
     headers: HeaderMap,
     Path(slug): Path<String>,
     Json(req): Json<SubscribeRequest>,
 ) -> Result<
     (
         axum::http::StatusCode,
-        Json<DataResponse<PublicMessageResponse>>,
+        Json<DataResponse<SubscribeResponse>>,
     ),
     AppError,
 > {
-    let email = req.email.trim().to_lowercase();
+    let email = req.email.as_deref().map(str::trim).unwrap_or_default();
+    let webhook_url = req
+        .webhook_url
+        .as_deref()
+        .map(str::trim)
+        .unwrap_or_default();
+    match (email.is_empty(), webhook_url.is_empty()) {
+        (true, false) => return subscribe_webhook(state, headers, slug, webhook_url).await,
+        (false, false) => {
+            return Err(AppError::Validation(
+                "Subscribe with either an email address or a webhook URL".to_string(),
+            ))
+        }
+        _ => {}
+    }
+
+    let email = email.to_lowercase();
     let subject = format!(
         "{}:{}",
         crate::services::rate_limit::rate_limit_subject(&headers, "local"),
@@ -223,39 +453,44 @@ async fn subscribe(
         std::time::Duration::from_secs(15 * 60),
     )
     .await?;
-    if !looks_like_email(&email) {
-        return Err(AppError::Validation(
-            "Enter a valid email address".to_string(),
-        ));
-    }
+    let email = shared::validation::normalize_subscriber_email(&email)?;
+    let preferences = normalize_preferences(&state.pool, req.preferences).await?;
 
-    let org = sqlx::query_as::<_, OrgRow>(
-        "SELECT id, slug, name, logo_url, brand_color FROM organizations WHERE slug = $1",
-    )
-    .bind(&slug)
-    .fetch_optional(&state.pool)
-    .await?
-    .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))?;
+    let page = page_by_slug(&state.pool, &slug).await?;
 
     let verification_token = uuid::Uuid::new_v4().to_string();
     let unsubscribe_token = uuid::Uuid::new_v4().to_string();
     let (subscriber, needs_verification) = crate::db::subscribers::create_or_refresh_pending(
         &state.pool,
-        org.id,
+        page.org_id,
+        page.page_id,
         &email,
         &verification_token,
         &unsubscribe_token,
+        &preferences,
     )
     .await?;
 
     if needs_verification {
+        // Only pending subscriptions take groups from this unauthenticated
+        // form; verified subscribers change them through their preferences
+        // link.
+        if let Some(group_ids) = preferences.group_ids.as_deref() {
+            crate::db::service_groups::set_subscriber_groups(
+                &state.pool,
+                page.org_id,
+                subscriber.id,
+                group_ids,
+            )
+            .await?;
+        }
+
         crate::services::email_notifications::queue_subscription_verification(
             &state.pool,
-            org.id,
             &state.config.app_base_url,
-            &slug,
-            &org.name,
+            &page,
             &subscriber.email,
+            subscriber.locale,
             subscriber
                 .verification_token
                 .as_deref()
@@ -273,16 +508,81 @@ async fn subscribe(
     Ok((
         axum::http::StatusCode::CREATED,
         Json(DataResponse {
-            data: PublicMessageResponse { message },
+            data: SubscribeResponse {
+                message,
+                webhook: None,
+            },
         }),
     ))
 }
 
-#[derive(Deserialize)]
+#[derive(Serialize, utoipa::ToSchema)]
+struct SubscribeResponse {
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    webhook: Option<WebhookSubscription>,
+}
+
+async fn subscribe_webhook(
+    state: AppState,
+    headers: HeaderMap,
+    slug: String,
+    webhook_url: &str,
+) -> Result<
+    (
+        axum::http::StatusCode,
+        Json<DataResponse<SubscribeResponse>>,
+    ),
+    AppError,
+> {
+    crate::services::rate_limit::enforce_rate_limit(
+        &state.redis,
+        "public_subscribe_webhook",
+        &crate::services::rate_limit::rate_limit_subject(&headers, "local"),
+        5,
+        std::time::Duration::from_secs(15 * 60),
+    )
+    .await?;
+    let page = page_by_slug(&state.pool, &slug).await?;
+
+    let subscription = crate::services::webhook_subscribers::subscribe(
+        &state.pool,
+        &page,
+        &state.config.app_base_url,
+        webhook_url,
+    )
+    .await?;
+
+    Ok((
+        axum::http::StatusCode::CREATED,
+        Json(DataResponse {
+            data: SubscribeResponse {
+                message: format!(
+                    "{} is now subscribed to updates. Store the signing secret; it will not be shown again.",
+                    subscription.subscriber.url
+                ),
+                webhook: Some(subscription),
+            },
+        }),
+    ))
+}
+
+#[derive(Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 struct TokenParams {
     token: String,
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/public/{slug}/subscribers/verify",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        TokenParams,
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<PublicMessageResponse>)),
+)]
 async fn verify_subscriber(
     State(state): State<AppState>,
     headers: HeaderMap,
@@ -297,12 +597,7 @@ async fn verify_subscriber(
         std::time::Duration::from_secs(15 * 60),
     )
     .await?;
-    let org_id =
-        sqlx::query_scalar::<_, uuid::Uuid>("SELECT id FROM organizations WHERE slug = $1")
-            .bind(&slug)
-            .fetch_optional(&state.pool)
-            .await?
-            .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))?;
+    let org_id = org_id_by_slug(&state.pool, &slug).await?;
 
     let subscriber = crate::db::subscribers::verify(&state.pool, org_id, &params.token)
         .await?
@@ -317,6 +612,16 @@ async fn verify_subscriber(
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/public/{slug}/subscribers/unsubscribe",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        TokenParams,
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<PublicMessageResponse>)),
+)]
 async fn unsubscribe_subscriber(
     State(state): State<AppState>,
     headers: HeaderMap,
@@ -331,60 +636,479 @@ async fn unsubscribe_subscriber(
         std::time::Duration::from_secs(15 * 60),
     )
     .await?;
-    let org_id =
-        sqlx::query_scalar::<_, uuid::Uuid>("SELECT id FROM organizations WHERE slug = $1")
-            .bind(&slug)
-            .fetch_optional(&state.pool)
+    let org_id = org_id_by_slug(&state.pool, &slug).await?;
+
+    let unsubscribed =
+        match crate::db::subscribers::unsubscribe(&state.pool, org_id, &params.token).await? {
+            Some(subscriber) => subscriber.email,
+            None => {
+                crate::db::webhook_subscribers::unsubscribe(&state.pool, org_id, &params.token)
+                    .await?
+                    .ok_or_else(|| {
+                        AppError::Validation("Unsubscribe link is invalid or expired".to_string())
+                    })?
+                    .url
+            }
+        };
+
+    Ok(Json(DataResponse {
+        data: PublicMessageResponse {
+            message: format!("{unsubscribed} has been unsubscribed."),
+        },
+    }))
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+struct SubscriberPreferencesResponse {
+    email: String,
+    digest_frequency: DigestFrequency,
+    timezone: String,
+    /// `None` follows the organization default.
+    locale: Option<Locale>,
+    quiet_hours_start: Option<chrono::NaiveTime>,
+    quiet_hours_end: Option<chrono::NaiveTime>,
+    phone_number: Option<String>,
+    phone_verified: bool,
+    group_ids: Vec<uuid::Uuid>,
+}
+
+async fn preferences_response(
+    pool: &sqlx::PgPool,
+    subscriber: Subscriber,
+) -> Result<SubscriberPreferencesResponse, AppError> {
+    let group_ids = crate::db::service_groups::subscriber_group_ids(pool, subscriber.id).await?;
+    Ok(SubscriberPreferencesResponse {
+        email: subscriber.email,
+        digest_frequency: subscriber.digest_frequency,
+        timezone: subscriber.timezone,
+        locale: subscriber.locale,
+        quiet_hours_start: subscriber.quiet_hours_start,
+        quiet_hours_end: subscriber.quiet_hours_end,
+        phone_verified: subscriber.phone_verified_at.is_some(),
+        phone_number: subscriber.phone_number,
+        group_ids,
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/public/{slug}/subscribers/preferences",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        TokenParams,
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<SubscriberPreferencesResponse>)),
+)]
+async fn get_subscriber_preferences(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(slug): Path<String>,
+    Query(params): Query<TokenParams>,
+) -> Result<Json<DataResponse<SubscriberPreferencesResponse>>, AppError> {
+    crate::services::rate_limit::enforce_rate_limit(
+        &state.redis,
+        "subscriber_preferences",
+        &crate::services::rate_limit::rate_limit_subject(&headers, "local"),
+        30,
+        std::time::Duration::from_secs(15 * 60),
+    )
+    .await?;
+    let org_id = org_id_by_slug(&state.pool, &slug).await?;
+
+    let subscriber =
+        crate::db::subscribers::find_by_unsubscribe_token(&state.pool, org_id, &params.token)
+            .await?
+            .ok_or_else(|| {
+                AppError::Validation("Preferences link is invalid or expired".to_string())
+            })?;
+
+    Ok(Json(DataResponse {
+        data: preferences_response(&state.pool, subscriber).await?,
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/public/{slug}/subscribers/preferences",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        TokenParams,
+    ),
+    request_body = SubscriberPreferences,
+    responses((status = 200, description = "OK", body = DataResponse<SubscriberPreferencesResponse>)),
+)]
+async fn update_subscriber_preferences(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(slug): Path<String>,
+    Query(params): Query<TokenParams>,
+    Json(req): Json<SubscriberPreferences>,
+) -> Result<Json<DataResponse<SubscriberPreferencesResponse>>, AppError> {
+    crate::services::rate_limit::enforce_rate_limit(
+        &state.redis,
+        "subscriber_preferences",
+        &crate::services::rate_limit::rate_limit_subject(&headers, "local"),
+        30,
+        std::time::Duration::from_secs(15 * 60),
+    )
+    .await?;
+    let org_id = org_id_by_slug(&state.pool, &slug).await?;
+    let preferences = normalize_preferences(&state.pool, req).await?;
+
+    let subscriber = crate::db::subscribers::update_preferences(
+        &state.pool,
+        org_id,
+        &params.token,
+        &preferences,
+    )
+    .await?
+    .ok_or_else(|| AppError::Validation("Preferences link is invalid or expired".to_string()))?;
+
+    if let Some(group_ids) = preferences.group_ids.as_deref() {
+        crate::db::service_groups::set_subscriber_groups(
+            &state.pool,
+            org_id,
+            subscriber.id,
+            group_ids,
+        )
+        .await?;
+    }
+
+    Ok(Json(DataResponse {
+        data: preferences_response(&state.pool, subscriber).await?,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/public/{slug}/subscribers/phone",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        TokenParams,
+    ),
+    request_body = SubscriberPhoneRequest,
+    responses((status = 201, description = "Created", body = DataResponse<PublicMessageResponse>)),
+)]
+async fn add_subscriber_phone(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(slug): Path<String>,
+    Query(params): Query<TokenParams>,
+    Json(req): Json<SubscriberPhoneRequest>,
+) -> Result<
+    (
+        axum::http::StatusCode,
+        Json<DataResponse<PublicMessageResponse>>,
+    ),
+    AppError,
+> {
+    crate::services::rate_limit::enforce_rate_limit(
+        &state.redis,
+        "subscriber_phone",
+        &crate::services::rate_limit::rate_limit_subject(&headers, "local"),
+        5,
+        std::time::Duration::from_secs(15 * 60),
+    )
+    .await?;
+    let phone_number = shared::validation::normalize_phone_number(&req.phone_number)?;
+
+    let page = page_by_slug(&state.pool, &slug).await?;
+    let org_id = page.org_id;
+    let plan =
+        sqlx::query_scalar::<_, OrganizationPlan>("SELECT plan FROM organizations WHERE id = $1")
+            .bind(org_id)
+            .fetch_one(&state.pool)
+            .await?;
+
+    if !plan.allows_sms() {
+        return Err(AppError::Validation(
+            "SMS alerts are not available for this status page".to_string(),
+        ));
+    }
+
+    let existing =
+        crate::db::subscribers::find_by_unsubscribe_token(&state.pool, org_id, &params.token)
+            .await?
+            .filter(|subscriber| subscriber.is_verified)
+            .ok_or_else(|| {
+                AppError::Validation("Preferences link is invalid or expired".to_string())
+            })?;
+
+    let code = sms_notifications::generate_phone_otp();
+    let subscriber = crate::db::subscribers::set_pending_phone(
+        &state.pool,
+        org_id,
+        &params.token,
+        &phone_number,
+        &sms_notifications::hash_phone_otp(existing.id, &code),
+        Utc::now() + Duration::minutes(sms_notifications::PHONE_OTP_TTL_MINUTES),
+    )
+    .await?
+    .ok_or_else(|| AppError::Validation("Preferences link is invalid or expired".to_string()))?;
+
+    let queued = sms_notifications::queue_phone_verification(
+        &state.pool,
+        org_id,
+        &page.name,
+        subscriber.id,
+        &phone_number,
+        &code,
+    )
+    .await?;
+    if !queued {
+        return Err(AppError::Validation(
+            "SMS alerts are temporarily unavailable for this status page".to_string(),
+        ));
+    }
+
+    Ok((
+        axum::http::StatusCode::CREATED,
+        Json(DataResponse {
+            data: PublicMessageResponse {
+                message: format!("We sent a verification code to {phone_number}."),
+            },
+        }),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/public/{slug}/subscribers/phone/verify",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        TokenParams,
+    ),
+    request_body = VerifySubscriberPhoneRequest,
+    responses((status = 200, description = "OK", body = DataResponse<PublicMessageResponse>)),
+)]
+async fn verify_subscriber_phone(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(slug): Path<String>,
+    Query(params): Query<TokenParams>,
+    Json(req): Json<VerifySubscriberPhoneRequest>,
+) -> Result<Json<DataResponse<PublicMessageResponse>>, AppError> {
+    crate::services::rate_limit::enforce_rate_limit(
+        &state.redis,
+        "subscriber_phone_verify",
+        &crate::services::rate_limit::rate_limit_subject(&headers, "local"),
+        20,
+        std::time::Duration::from_secs(15 * 60),
+    )
+    .await?;
+    let org_id = org_id_by_slug(&state.pool, &slug).await?;
+
+    let subscriber =
+        crate::db::subscribers::find_by_unsubscribe_token(&state.pool, org_id, &params.token)
             .await?
-            .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))?;
+            .ok_or_else(|| {
+                AppError::Validation("Preferences link is invalid or expired".to_string())
+            })?;
 
-    let subscriber = crate::db::subscribers::unsubscribe(&state.pool, org_id, &params.token)
+    let outcome = crate::db::subscribers::verify_phone(
+        &state.pool,
+        subscriber.id,
+        &sms_notifications::hash_phone_otp(subscriber.id, &req.code),
+        sms_notifications::PHONE_OTP_MAX_ATTEMPTS,
+    )
+    .await?;
+
+    let message = match outcome {
+        PhoneVerificationOutcome::Verified => format!(
+            "{} will now receive SMS alerts for critical incidents.",
+            subscriber.phone_number.unwrap_or_default()
+        ),
+        PhoneVerificationOutcome::InvalidCode => {
+            return Err(AppError::Validation(
+                "Verification code is incorrect".to_string(),
+            ))
+        }
+        PhoneVerificationOutcome::Expired | PhoneVerificationOutcome::TooManyAttempts => {
+            return Err(AppError::Validation(
+                "Verification code has expired; request a new one".to_string(),
+            ))
+        }
+    };
+
+    Ok(Json(DataResponse {
+        data: PublicMessageResponse { message },
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/public/{slug}/subscribers/phone",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        TokenParams,
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<SubscriberPreferencesResponse>)),
+)]
+async fn remove_subscriber_phone(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(slug): Path<String>,
+    Query(params): Query<TokenParams>,
+) -> Result<Json<DataResponse<SubscriberPreferencesResponse>>, AppError> {
+    crate::services::rate_limit::enforce_rate_limit(
+        &state.redis,
+        "subscriber_preferences",
+        &crate::services::rate_limit::rate_limit_subject(&headers, "local"),
+        30,
+        std::time::Duration::from_secs(15 * 60),
+    )
+    .await?;
+    let org_id = org_id_by_slug(&state.pool, &slug).await?;
+
+    let subscriber = crate::db::subscribers::remove_phone(&state.pool, org_id, &params.token)
         .await?
         .ok_or_else(|| {
-            AppError::Validation("Unsubscribe link is invalid or expired".to_string())
+            AppError::Validation("Preferences link is invalid or expired".to_string())
         })?;
 
     Ok(Json(DataResponse {
-        data: PublicMessageResponse {
-            message: format!("{} has been unsubscribed.", subscriber.email),
-        },
+        data: preferences_response(&state.pool, subscriber).await?,
     }))
 }
 
-#[derive(sqlx::FromRow)]
-struct OrgRow {
-    id: uuid::Uuid,
-    slug: String,
-    name: String,
-    logo_url: Option<String>,
-    brand_color: String,
+/// An incident as shown on a page. Pages with their own service selection
+/// only name the affected services they list.
+async fn public_incident(
+    pool: &sqlx::PgPool,
+    page_id: Option<uuid::Uuid>,
+    incident: Incident,
+    presentation: &Presentation,
+) -> Result<PublicIncident, AppError> {
+    let locale = presentation.locale;
+    let updates = sqlx::query_as::<_, IncidentUpdate>(
+        "SELECT * FROM incident_updates WHERE incident_id = $1 AND visibility = 'public' ORDER BY created_at DESC",
+    )
+    .bind(incident.id)
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|update| PublicIncidentUpdate {
+        status_label: update.status.label(locale).to_string(),
+        created_at_display: presentation.timestamp(update.created_at),
+        update,
+    })
+    .collect();
+
+    let mut affected_services = sqlx::query_as::<_, PublicAffectedService>(
+        r#"
+        SELECT s.name, isvc.service_status AS status
+        FROM services s
+        JOIN incident_services isvc ON isvc.service_id = s.id
+        WHERE isvc.incident_id = $1
+          AND status_page_covers_services($2, ARRAY[s.id])
+        ORDER BY s.display_order, s.name
+        "#,
+    )
+    .bind(incident.id)
+    .bind(page_id)
+    .fetch_all(pool)
+    .await?;
+    for service in &mut affected_services {
+        service.status_label = service.status.label(locale).to_string();
+    }
+
+    Ok(PublicIncident {
+        id: incident.id,
+        title: incident.title,
+        status: incident.status,
+        status_label: incident.status.label(locale).to_string(),
+        impact: incident.impact,
+        impact_label: incident.impact.label(locale).to_string(),
+        started_at: incident.started_at,
+        started_at_display: presentation.timestamp(incident.started_at),
+        resolved_at: incident.resolved_at,
+        resolved_at_display: incident.resolved_at.map(|at| presentation.timestamp(at)),
+        updates,
+        affected_services,
+    })
 }
 
-fn worst_status(a: &ServiceStatus, b: &ServiceStatus) -> ServiceStatus {
-    let severity = |s: &ServiceStatus| -> u8 {
-        match s {
-            ServiceStatus::MajorOutage => 4,
-            ServiceStatus::PartialOutage => 3,
-            ServiceStatus::DegradedPerformance => 2,
-            ServiceStatus::UnderMaintenance => 1,
-            ServiceStatus::Operational => 0,
+async fn page_by_slug(pool: &sqlx::PgPool, slug: &str) -> Result<PublicPage, AppError> {
+    crate::db::status_pages::find_public_by_slug(pool, slug)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))
+}
+
+/// The organization behind any page slug, disabled pages included, so
+/// subscriber token links keep working while a page is plan-limited.
+async fn org_id_by_slug(pool: &sqlx::PgPool, slug: &str) -> Result<uuid::Uuid, AppError> {
+    sqlx::query_scalar::<_, uuid::Uuid>(
+        r#"
+        SELECT id FROM organizations WHERE slug = $1
+        UNION ALL
+        SELECT org_id FROM status_pages WHERE slug = $1
+        "#,
+    )
+    .bind(slug)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))
+}
+
+async fn normalize_preferences(
+    pool: &sqlx::PgPool,
+    mut preferences: SubscriberPreferences,
+) -> Result<SubscriberPreferences, AppError> {
+    validate_quiet_hours(&preferences)?;
+
+    preferences.timezone = preferences
+        .timezone
+        .map(|timezone| timezone.trim().to_string())
+        .filter(|timezone| !timezone.is_empty());
+    if let Some(timezone) = preferences.timezone.as_deref() {
+        if !crate::db::subscribers::timezone_exists(pool, timezone).await? {
+            return Err(AppError::Validation(format!(
+                "Unknown timezone '{timezone}'"
+            )));
         }
-    };
-    if severity(a) >= severity(b) {
+    }
+
+    Ok(preferences)
+}
+
+fn validate_quiet_hours(preferences: &SubscriberPreferences) -> Result<(), AppError> {
+    match (preferences.quiet_hours_start, preferences.quiet_hours_end) {
+        (None, None) => Ok(()),
+        (Some(start), Some(end)) if start == end => Err(AppError::Validation(
+            "Quiet hours must start and end at different times".to_string(),
+        )),
+        (Some(_), Some(_)) => Ok(()),
+        _ => Err(AppError::Validation(
+            "Set both quiet_hours_start and quiet_hours_end, or neither".to_string(),
+        )),
+    }
+}
+
+impl From<PublicPage> for PublicOrg {
+    fn from(page: PublicPage) -> Self {
+        PublicOrg {
+            name: page.name,
+            logo_url: page.logo_url,
+            brand_color: page.brand_color,
+            default_locale: page.locale,
+            timezone: page.timezone,
+        }
+    }
+}
+
+fn worst_status(a: &ServiceStatus, b: &ServiceStatus) -> ServiceStatus {
+    if a.severity() >= b.severity() {
         *a
     } else {
         *b
     }
 }
 
-fn looks_like_email(email: &str) -> bool {
-    let trimmed = email.trim();
-    trimmed.contains('@')
-        && trimmed.contains('.')
-        && !trimmed.starts_with('@')
-        && !trimmed.ends_with('@')
-}
-
 fn normalize_host(host: &str) -> Option<String> {
     let candidate = host
         .trim()
@@ -414,37 +1138,48 @@ fn normalize_host(host: &str) -> Option<String> {
 
 // --- Incident history endpoint ---
 
-#[derive(Deserialize)]
+#[derive(Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 struct HistoryParams {
     page: Option<i64>,
     per_page: Option<i64>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct HistoryResponse {
+    locale: Locale,
     incidents: Vec<PublicIncident>,
     pagination: PaginationInfo,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct PaginationInfo {
     page: i64,
     per_page: i64,
     total: i64,
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/public/{slug}/incidents",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        HistoryParams,
+        LocaleParams,
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<HistoryResponse>)),
+)]
 async fn get_incident_history(
     State(state): State<AppState>,
+    _viewer: PageViewer,
+    headers: HeaderMap,
     Path(slug): Path<String>,
     Query(params): Query<HistoryParams>,
+    Query(locale): Query<LocaleParams>,
 ) -> Result<Json<DataResponse<HistoryResponse>>, AppError> {
-    let org = sqlx::query_as::<_, OrgRow>(
-        "SELECT id, slug, name, logo_url, brand_color FROM organizations WHERE slug = $1",
-    )
-    .bind(&slug)
-    .fetch_optional(&state.pool)
-    .await?
-    .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))?;
+    let status_page = page_by_slug(&state.pool, &slug).await?;
+    let presentation = Presentation::resolve(&status_page, &locale, &headers)?;
 
     let page = params.page.unwrap_or(1).max(1);
     let per_page = params.per_page.unwrap_or(20).clamp(1, 100);
@@ -452,10 +1187,15 @@ async fn get_incident_history(
     let ninety_days_ago = Utc::now() - Duration::days(90);
 
     let total: i64 = sqlx::query_scalar::<_, i64>(
-        "SELECT COUNT(*) FROM incidents WHERE org_id = $1 AND status = 'resolved' AND created_at > $2",
+        r#"
+        SELECT COUNT(*) FROM incidents
+        WHERE org_id = $1 AND status = 'resolved' AND NOT hidden_from_public AND created_at > $2
+          AND status_page_covers_incident($3, id)
+        "#,
     )
-    .bind(org.id)
+    .bind(status_page.org_id)
     .bind(ninety_days_ago)
+    .bind(status_page.page_id)
     .fetch_one(&state.pool)
     .await
     .unwrap_or(0);
@@ -463,48 +1203,30 @@ async fn get_incident_history(
     let incidents_raw = sqlx::query_as::<_, Incident>(
         r#"
         SELECT * FROM incidents
-        WHERE org_id = $1 AND status = 'resolved' AND created_at > $2
+        WHERE org_id = $1 AND status = 'resolved' AND NOT hidden_from_public AND created_at > $2
+          AND status_page_covers_incident($5, id)
         ORDER BY created_at DESC
         LIMIT $3 OFFSET $4
         "#,
     )
-    .bind(org.id)
+    .bind(status_page.org_id)
     .bind(ninety_days_ago)
     .bind(per_page)
     .bind(offset)
+    .bind(status_page.page_id)
     .fetch_all(&state.pool)
     .await?;
 
     let mut incidents = Vec::new();
     for incident in incidents_raw {
-        let updates = sqlx::query_as::<_, IncidentUpdate>(
-            "SELECT * FROM incident_updates WHERE incident_id = $1 ORDER BY created_at DESC",
-        )
-        .bind(incident.id)
-        .fetch_all(&state.pool)
-        .await?;
-
-        let affected: Vec<String> = sqlx::query_scalar(
-            "SELECT s.name FROM services s JOIN incident_services isvc ON isvc.service_id = s.id WHERE isvc.incident_id = $1",
-        )
-        .bind(incident.id)
-        .fetch_all(&state.pool)
-        .await?;
-
-        incidents.push(PublicIncident {
-            id: incident.id,
-            title: incident.title,
-            status: incident.status,
-            impact: incident.impact,
-            started_at: incident.started_at,
-            resolved_at: incident.resolved_at,
-            updates,
-            affected_services: affected,
-        });
+        incidents.push(
+            public_incident(&state.pool, status_page.page_id, incident, &presentation).await?,
+        );
     }
 
     Ok(Json(DataResponse {
         data: HistoryResponse {
+            locale: presentation.locale,
             incidents,
             pagination: PaginationInfo {
                 page,
@@ -515,14 +1237,76 @@ async fn get_incident_history(
     }))
 }
 
+#[derive(Serialize, utoipa::ToSchema)]
+struct SearchResponse {
+    locale: Locale,
+    incidents: Vec<PublicIncident>,
+    next_cursor: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/public/{slug}/incidents/search",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        IncidentSearchParams,
+        LocaleParams,
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<SearchResponse>)),
+)]
+async fn search_incidents(
+    State(state): State<AppState>,
+    viewer: PageViewer,
+    headers: HeaderMap,
+    Path(_slug): Path<String>,
+    Query(params): Query<IncidentSearchParams>,
+    Query(locale): Query<LocaleParams>,
+) -> Result<Json<DataResponse<SearchResponse>>, AppError> {
+    let status_page =
+        crate::db::status_pages::find_public(&state.pool, viewer.org_id, viewer.page_id).await?;
+    let presentation = Presentation::resolve(&status_page, &locale, &headers)?;
+    let page = crate::services::incident_search::search(
+        &state.pool,
+        viewer.org_id,
+        viewer.page_id,
+        &params,
+        false,
+    )
+    .await?;
+
+    let mut incidents = Vec::with_capacity(page.incidents.len());
+    for incident in page.incidents {
+        incidents
+            .push(public_incident(&state.pool, viewer.page_id, incident, &presentation).await?);
+    }
+
+    Ok(Json(DataResponse {
+        data: SearchResponse {
+            locale: presentation.locale,
+            incidents,
+            next_cursor: page.next_cursor,
+        },
+    }))
+}
+
 // --- Uptime endpoint ---
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct UptimeResponse {
     services: Vec<ServiceUptime>,
+    groups: Vec<GroupUptime>,
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+struct GroupUptime {
+    group_id: uuid::Uuid,
+    group_name: String,
+    days: Vec<UptimeDay>,
+    overall_uptime: Option<f64>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct ServiceUptime {
     service_id: uuid::Uuid,
     service_name: String,
@@ -530,38 +1314,59 @@ struct ServiceUptime {
     overall_uptime: Option<f64>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct UptimeDay {
     date: chrono::NaiveDate,
     uptime_percentage: Option<f64>,
     avg_response_time_ms: Option<f64>,
+    /// Minutes the service itself spent in a partial or major outage.
+    outage_minutes: i64,
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/public/{slug}/uptime",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<UptimeResponse>)),
+)]
 async fn get_uptime(
     State(state): State<AppState>,
+    _viewer: PageViewer,
     Path(slug): Path<String>,
 ) -> Result<Json<DataResponse<UptimeResponse>>, AppError> {
-    let org = sqlx::query_as::<_, OrgRow>(
-        "SELECT id, slug, name, logo_url, brand_color FROM organizations WHERE slug = $1",
-    )
-    .bind(&slug)
-    .fetch_optional(&state.pool)
-    .await?
-    .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))?;
-
-    let services = sqlx::query_as::<_, PublicService>(
-        "SELECT id, name, current_status, group_name FROM services WHERE org_id = $1 AND is_visible = true ORDER BY display_order",
-    )
-    .bind(org.id)
-    .fetch_all(&state.pool)
-    .await?;
+    let page = page_by_slug(&state.pool, &slug).await?;
+    let services = visible_services(&state.pool, &page).await?;
 
     let today = Utc::now().date_naive();
     let ninety_days_ago = today - Duration::days(89);
 
+    let now = Utc::now();
+    let window_start = ninety_days_ago.and_time(chrono::NaiveTime::MIN).and_utc();
+    let service_ids = services.iter().map(|s| s.id).collect::<Vec<_>>();
+    let mut intervals_by_service: std::collections::HashMap<
+        uuid::Uuid,
+        Vec<ServiceStatusInterval>,
+    > = std::collections::HashMap::new();
+    for interval in
+        crate::db::status_history::list_since(&state.pool, &service_ids, window_start).await?
+    {
+        intervals_by_service
+            .entry(interval.service_id)
+            .or_default()
+            .push(interval);
+    }
+
     let mut service_uptimes = Vec::new();
 
     for service in &services {
+        let intervals = intervals_by_service
+            .get(&service.id)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
         // Try to get uptime data from uptime_daily via monitor
         let daily_data: Vec<DailyRow> = sqlx::query_as(
             r#"
@@ -587,24 +1392,24 @@ async fn get_uptime(
 
         for i in 0..90 {
             let date = ninety_days_ago + Duration::days(i);
-            let day = if let Some(row) = daily_map.get(&date) {
-                if let Some(pct) = row.uptime_percentage {
-                    total_checks_weighted += 1.0;
-                    total_uptime_weighted += pct;
-                }
-                UptimeDay {
-                    date,
-                    uptime_percentage: row.uptime_percentage,
-                    avg_response_time_ms: row.avg_response_time_ms,
-                }
-            } else {
-                UptimeDay {
-                    date,
-                    uptime_percentage: None,
-                    avg_response_time_ms: None,
-                }
-            };
-            days.push(day);
+            let day_start = window_start + Duration::days(i);
+            let summary =
+                summarize_intervals(intervals, day_start, day_start + Duration::days(1), now);
+            let monitor_row = daily_map.get(&date);
+            let uptime_percentage = combined_uptime(
+                summary.uptime_percentage(),
+                monitor_row.and_then(|row| row.uptime_percentage),
+            );
+            if let Some(pct) = uptime_percentage {
+                total_checks_weighted += 1.0;
+                total_uptime_weighted += pct;
+            }
+            days.push(UptimeDay {
+                date,
+                uptime_percentage,
+                avg_response_time_ms: monitor_row.and_then(|row| row.avg_response_time_ms),
+                outage_minutes: summary.outage_minutes(),
+            });
         }
 
         let overall_uptime = if total_checks_weighted > 0.0 {
@@ -621,16 +1426,106 @@ async fn get_uptime(
         });
     }
 
+    let groups = crate::db::service_groups::find_by_org(&state.pool, page.org_id).await?;
+    let group_uptimes = groups
+        .iter()
+        .filter_map(|group| group_uptime(group, &services, &service_uptimes))
+        .collect();
+
     Ok(Json(DataResponse {
         data: UptimeResponse {
             services: service_uptimes,
+            groups: group_uptimes,
         },
     }))
 }
 
+fn group_uptime(
+    group: &ServiceGroup,
+    services: &[PublicService],
+    service_uptimes: &[ServiceUptime],
+) -> Option<GroupUptime> {
+    let members = services
+        .iter()
+        .zip(service_uptimes)
+        .filter(|(service, _)| service.group_id == Some(group.id))
+        .map(|(_, uptime)| uptime)
+        .collect::<Vec<_>>();
+    let first = members.first()?;
+
+    let days = (0..first.days.len())
+        .map(|index| {
+            let uptime = members
+                .iter()
+                .map(|member| member.days[index].uptime_percentage)
+                .collect::<Vec<_>>();
+            let response_times = members
+                .iter()
+                .map(|member| member.days[index].avg_response_time_ms)
+                .collect::<Vec<_>>();
+            UptimeDay {
+                date: first.days[index].date,
+                uptime_percentage: aggregate_group_uptime(&uptime),
+                avg_response_time_ms: aggregate_group_uptime(&response_times),
+                outage_minutes: members
+                    .iter()
+                    .map(|member| member.days[index].outage_minutes)
+                    .max()
+                    .unwrap_or(0),
+            }
+        })
+        .collect::<Vec<_>>();
+    let overall = members
+        .iter()
+        .map(|member| member.overall_uptime)
+        .collect::<Vec<_>>();
+
+    Some(GroupUptime {
+        group_id: group.id,
+        group_name: group.name.clone(),
+        days,
+        overall_uptime: aggregate_group_uptime(&overall),
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/public/{slug}/services/{id}/status-history",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+        StatusHistoryParams,
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<crate::routes::services::StatusHistoryResponse>)),
+)]
+async fn get_service_status_history(
+    State(state): State<AppState>,
+    viewer: PageViewer,
+    Path((_slug, id)): Path<(String, uuid::Uuid)>,
+    Query(params): Query<StatusHistoryParams>,
+) -> Result<Json<DataResponse<crate::routes::services::StatusHistoryResponse>>, AppError> {
+    let visible: bool = sqlx::query_scalar("SELECT $1 IN (SELECT status_page_service_ids($2, $3))")
+        .bind(id)
+        .bind(viewer.org_id)
+        .bind(viewer.page_id)
+        .fetch_one(&state.pool)
+        .await?;
+    if !visible {
+        return Err(AppError::NotFound("Service not found".to_string()));
+    }
+
+    let history = crate::routes::services::status_history(&state.pool, id, &params).await?;
+    Ok(Json(DataResponse { data: history }))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::normalize_host;
+    use super::{
+        normalize_host, validate_quiet_hours, HeaderMap, Locale, LocaleParams, Presentation,
+        PublicPage,
+    };
+    use shared::models::subscriber::SubscriberPreferences;
 
     #[test]
     fn normalize_host_trims_port_and_trailing_dot() {
@@ -647,6 +1542,52 @@ mod tests {
             Some("status.example.com".to_string())
         );
     }
+
+    #[test]
+    fn quiet_hours_require_both_bounds() {
+        let time = |value: &str| Some(value.parse::<chrono::NaiveTime>().unwrap());
+        let preferences = |start, end| SubscriberPreferences {
+            quiet_hours_start: start,
+            quiet_hours_end: end,
+            ..Default::default()
+        };
+
+        assert!(validate_quiet_hours(&preferences(None, None)).is_ok());
+        assert!(validate_quiet_hours(&preferences(time("22:00"), time("07:00"))).is_ok());
+        assert!(validate_quiet_hours(&preferences(time("22:00"), None)).is_err());
+        assert!(validate_quiet_hours(&preferences(time("22:00"), time("22:00"))).is_err());
+    }
+
+    #[test]
+    fn locale_prefers_the_query_then_accept_language_then_the_org_default() {
+        let page = PublicPage {
+            org_id: uuid::Uuid::nil(),
+            page_id: None,
+            slug: "demo".to_string(),
+            name: "Demo".to_string(),
+            logo_url: None,
+            brand_color: "#3B82F6".to_string(),
+            verified_custom_domain: None,
+            locale: Locale::Fr,
+            timezone: "Europe/Paris".to_string(),
+        };
+        let params = |locale: Option<&str>| LocaleParams {
+            locale: locale.map(str::to_string),
+        };
+        let resolve = |locale, headers: &HeaderMap| {
+            Presentation::resolve(&page, &params(locale), headers).map(|p| p.locale)
+        };
+        let mut headers = HeaderMap::new();
+        assert_eq!(resolve(None, &headers).unwrap(), Locale::Fr);
+
+        headers.insert(
+            axum::http::header::ACCEPT_LANGUAGE,
+            "ja, de-CH;q=0.8".parse().unwrap(),
+        );
+        assert_eq!(resolve(None, &headers).unwrap(), Locale::De);
+        assert_eq!(resolve(Some("es-MX"), &headers).unwrap(), Locale::Es);
+        assert!(resolve(Some("xx"), &headers).is_err());
+    }
 }
 
 #[derive(sqlx::FromRow)]
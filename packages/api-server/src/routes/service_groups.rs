@@ -0,0 +1,231 @@
+use axum::{
+    extract::{Path, State},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::Serialize;
+use uuid::Uuid;
+
+use shared::error::AppError;
+use shared::models::service_group::{
+    CreateServiceGroupRequest, ServiceGroup, UpdateServiceGroupRequest,
+};
+
+use crate::db;
+use crate::middleware::org_access::OrgAccess;
+use crate::state::AppState;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", post(create_group).get(list_groups))
+        .route(
+            "/{id}",
+            get(get_group).patch(update_group).delete(delete_group),
+        )
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+struct DataResponse<T: Serialize> {
+    data: T,
+}
+
+pub(crate) fn validate_group_name(name: &str) -> Result<(), AppError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::Validation(
+            "Service group name is required".to_string(),
+        ));
+    }
+    if name.chars().count() > 255 {
+        return Err(AppError::Validation(
+            "Service group name must be 255 characters or fewer".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/service-groups",
+    tag = "service-groups",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = CreateServiceGroupRequest,
+    responses((status = 201, description = "Created", body = DataResponse<ServiceGroup>)),
+)]
+async fn create_group(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Json(req): Json<CreateServiceGroupRequest>,
+) -> Result<(axum::http::StatusCode, Json<DataResponse<ServiceGroup>>), AppError> {
+    org_access.require_admin()?;
+    validate_group_name(&req.name)?;
+
+    if db::service_groups::name_exists(&state.pool, org_access.org.id, req.name.trim(), None)
+        .await?
+    {
+        return Err(AppError::Conflict(format!(
+            "Service group '{}' already exists",
+            req.name.trim()
+        )));
+    }
+
+    let group = db::service_groups::create(&state.pool, org_access.org.id, &req).await?;
+    let id = group.id;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "service_group.create",
+            target_type: "service_group",
+            target_id: Some(&id.to_string()),
+            details: serde_json::json!({ "name": group.name }),
+        },
+    )
+    .await?;
+
+    Ok((
+        axum::http::StatusCode::CREATED,
+        Json(DataResponse { data: group }),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/service-groups",
+    tag = "service-groups",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<Vec<ServiceGroup>>)),
+)]
+async fn list_groups(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+) -> Result<Json<DataResponse<Vec<ServiceGroup>>>, AppError> {
+    let groups = db::service_groups::find_by_org(&state.pool, org_access.org.id).await?;
+    Ok(Json(DataResponse { data: groups }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/service-groups/{id}",
+    tag = "service-groups",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<ServiceGroup>)),
+)]
+async fn get_group(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+) -> Result<Json<DataResponse<ServiceGroup>>, AppError> {
+    let group = db::service_groups::find_by_id(&state.pool, id, org_access.org.id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Service group not found".to_string()))?;
+    Ok(Json(DataResponse { data: group }))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/organizations/{slug}/service-groups/{id}",
+    tag = "service-groups",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    request_body = UpdateServiceGroupRequest,
+    responses((status = 200, description = "OK", body = DataResponse<ServiceGroup>)),
+)]
+async fn update_group(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+    Json(req): Json<UpdateServiceGroupRequest>,
+) -> Result<Json<DataResponse<ServiceGroup>>, AppError> {
+    org_access.require_admin()?;
+
+    if let Some(name) = req.name.as_deref() {
+        validate_group_name(name)?;
+        if db::service_groups::name_exists(&state.pool, org_access.org.id, name.trim(), Some(id))
+            .await?
+        {
+            return Err(AppError::Conflict(format!(
+                "Service group '{}' already exists",
+                name.trim()
+            )));
+        }
+    }
+
+    let group = db::service_groups::update(&state.pool, id, org_access.org.id, &req).await?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "service_group.update",
+            target_type: "service_group",
+            target_id: Some(&id.to_string()),
+            details: serde_json::json!({
+                "name": group.name,
+                "status_rule": group.status_rule,
+            }),
+        },
+    )
+    .await?;
+
+    Ok(Json(DataResponse { data: group }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/organizations/{slug}/service-groups/{id}",
+    tag = "service-groups",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 204, description = "No content")),
+)]
+async fn delete_group(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+) -> Result<axum::http::StatusCode, AppError> {
+    org_access.require_admin()?;
+
+    db::service_groups::delete(&state.pool, id, org_access.org.id).await?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "service_group.delete",
+            target_type: "service_group",
+            target_id: Some(&id.to_string()),
+            details: serde_json::json!({}),
+        },
+    )
+    .await?;
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_group_name;
+
+    #[test]
+    fn group_name_must_be_present_and_bounded() {
+        assert!(validate_group_name("Core API").is_ok());
+        assert!(validate_group_name("   ").is_err());
+        assert!(validate_group_name(&"x".repeat(256)).is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use axum::{
-    extract::{Path, State},
-    routing::{get, patch, post},
+    extract::{Path, Query, State},
+    routing::{delete, get, patch, post},
     Json, Router,
 };
 use serde::Serialize;
@@ -8,7 +8,12 @@ use uuid::Uuid;
 
 use shared::error::AppError;
 use shared::models::service::{
-    CreateServiceRequest, ReorderServicesRequest, Service, UpdateServiceRequest,
+    AddServiceDependencyRequest, CreateServiceRequest, ReorderServicesRequest, Service,
+    ServiceDependency, UpdateServiceRequest,
+};
+use shared::models::service_group::ServiceGroup;
+use shared::models::status_history::{
+    summarize_intervals, ServiceStatusInterval, StatusHistoryParams,
 };
 
 use crate::db;
@@ -25,13 +30,32 @@ pub fn router() -> Router<AppState> {
                 .delete(delete_service),
         )
         .route("/reorder", patch(reorder_services))
+        .route(
+            "/{id}/dependencies",
+            get(list_dependencies).post(add_dependency),
+        )
+        .route(
+            "/{id}/dependencies/{depends_on_id}",
+            delete(remove_dependency),
+        )
+        .route("/{id}/status-history", get(get_status_history))
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 struct DataResponse<T: Serialize> {
     data: T,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/services",
+    tag = "services",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = CreateServiceRequest,
+    responses((status = 201, description = "Created", body = DataResponse<Service>)),
+)]
 async fn create_service(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -43,13 +67,53 @@ async fn create_service(
         return Err(AppError::Validation("Service name is required".to_string()));
     }
 
-    let service = db::services::create(&state.pool, org_access.org.id, &req).await?;
+    let group = resolve_group(
+        &state.pool,
+        org_access.org.id,
+        req.group_id,
+        req.group_name.as_deref(),
+    )
+    .await?;
+    let service =
+        db::services::create(&state.pool, org_access.org.id, &req, group.as_ref()).await?;
     Ok((
         axum::http::StatusCode::CREATED,
         Json(DataResponse { data: service }),
     ))
 }
 
+/// Looks up the group a service is being placed in. `group_id` wins over the
+/// legacy `group_name`, which creates the group if it does not exist yet.
+async fn resolve_group(
+    pool: &sqlx::PgPool,
+    org_id: Uuid,
+    group_id: Option<Uuid>,
+    group_name: Option<&str>,
+) -> Result<Option<ServiceGroup>, AppError> {
+    if let Some(group_id) = group_id {
+        return db::service_groups::find_by_id(pool, group_id, org_id)
+            .await?
+            .map(Some)
+            .ok_or_else(|| AppError::Validation("Service group not found".to_string()));
+    }
+
+    match group_name.map(str::trim).filter(|name| !name.is_empty()) {
+        Some(name) => Ok(Some(
+            db::service_groups::find_or_create_by_name(pool, org_id, name).await?,
+        )),
+        None => Ok(None),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/services",
+    tag = "services",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<Vec<Service>>)),
+)]
 async fn list_services(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -58,6 +122,16 @@ async fn list_services(
     Ok(Json(DataResponse { data: services }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/services/{id}",
+    tag = "services",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<Service>)),
+)]
 async fn get_service(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -69,6 +143,17 @@ async fn get_service(
     Ok(Json(DataResponse { data: service }))
 }
 
+#[utoipa::path(
+    patch,
+    path = "/api/organizations/{slug}/services/{id}",
+    tag = "services",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    request_body = UpdateServiceRequest,
+    responses((status = 200, description = "OK", body = DataResponse<Service>)),
+)]
 async fn update_service(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -82,7 +167,15 @@ async fn update_service(
         .await?
         .ok_or_else(|| AppError::NotFound("Service not found".to_string()))?;
 
-    let service = db::services::update(&state.pool, id, org_access.org.id, &req).await?;
+    let group = resolve_group(
+        &state.pool,
+        org_access.org.id,
+        req.group_id,
+        req.group_name.as_deref(),
+    )
+    .await?;
+    let service =
+        db::services::update(&state.pool, id, org_access.org.id, &req, group.as_ref()).await?;
 
     // If status changed, publish real-time event
     if let Some(new_status) = req.current_status {
@@ -133,7 +226,7 @@ async fn update_service(
                 &state.pool,
                 org_access.org.id,
                 &state.config.app_base_url,
-                &org_access.org.slug,
+                service.id,
                 &service.name,
                 old_service.current_status,
                 new_status,
@@ -151,6 +244,16 @@ async fn update_service(
     Ok(Json(DataResponse { data: service }))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/organizations/{slug}/services/{id}",
+    tag = "services",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 204, description = "No content")),
+)]
 async fn delete_service(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -162,6 +265,16 @@ async fn delete_service(
     Ok(axum::http::StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    patch,
+    path = "/api/organizations/{slug}/services/reorder",
+    tag = "services",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = ReorderServicesRequest,
+    responses((status = 204, description = "No content")),
+)]
 async fn reorder_services(
     State(state): State<AppState>,
     org_access: OrgAccess,
@@ -172,3 +285,227 @@ async fn reorder_services(
     db::services::reorder(&state.pool, org_access.org.id, &req.service_ids).await?;
     Ok(axum::http::StatusCode::NO_CONTENT)
 }
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub(crate) struct StatusHistoryResponse {
+    from: chrono::DateTime<chrono::Utc>,
+    to: chrono::DateTime<chrono::Utc>,
+    uptime_percentage: Option<f64>,
+    outage_minutes: i64,
+    intervals: Vec<ServiceStatusInterval>,
+}
+
+const MAX_STATUS_HISTORY_DAYS: i64 = 366;
+
+/// Resolves the requested window, defaulting to the 30 days before `now`.
+pub(crate) fn history_range(
+    params: &StatusHistoryParams,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<(chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>), AppError> {
+    let to = params.to.unwrap_or(now).min(now);
+    let from = params.from.unwrap_or(to - chrono::Duration::days(30));
+    if from >= to {
+        return Err(AppError::Validation(
+            "`from` must be before `to`".to_string(),
+        ));
+    }
+    if to - from > chrono::Duration::days(MAX_STATUS_HISTORY_DAYS) {
+        return Err(AppError::Validation(format!(
+            "Status history is limited to {MAX_STATUS_HISTORY_DAYS} days per request"
+        )));
+    }
+    Ok((from, to))
+}
+
+pub(crate) async fn status_history(
+    pool: &sqlx::PgPool,
+    service_id: Uuid,
+    params: &StatusHistoryParams,
+) -> Result<StatusHistoryResponse, AppError> {
+    let now = chrono::Utc::now();
+    let (from, to) = history_range(params, now)?;
+    let intervals = db::status_history::list_for_service(pool, service_id, from, to).await?;
+    let summary = summarize_intervals(&intervals, from, to, now);
+
+    Ok(StatusHistoryResponse {
+        from,
+        to,
+        uptime_percentage: summary.uptime_percentage(),
+        outage_minutes: summary.outage_minutes(),
+        intervals,
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/services/{id}/status-history",
+    tag = "services",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+        StatusHistoryParams,
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<StatusHistoryResponse>)),
+)]
+async fn get_status_history(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+    Query(params): Query<StatusHistoryParams>,
+) -> Result<Json<DataResponse<StatusHistoryResponse>>, AppError> {
+    db::services::find_by_id(&state.pool, id, org_access.org.id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Service not found".to_string()))?;
+
+    let history = status_history(&state.pool, id, &params).await?;
+    Ok(Json(DataResponse { data: history }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/services/{id}/dependencies",
+    tag = "services",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<Vec<ServiceDependency>>)),
+)]
+async fn list_dependencies(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+) -> Result<Json<DataResponse<Vec<ServiceDependency>>>, AppError> {
+    db::services::find_by_id(&state.pool, id, org_access.org.id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Service not found".to_string()))?;
+
+    let dependencies =
+        db::service_dependencies::list_by_service(&state.pool, id, org_access.org.id).await?;
+    Ok(Json(DataResponse { data: dependencies }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/services/{id}/dependencies",
+    tag = "services",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    request_body = AddServiceDependencyRequest,
+    responses((status = 201, description = "Created", body = DataResponse<ServiceDependency>)),
+)]
+async fn add_dependency(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+    Json(req): Json<AddServiceDependencyRequest>,
+) -> Result<
+    (
+        axum::http::StatusCode,
+        Json<DataResponse<ServiceDependency>>,
+    ),
+    AppError,
+> {
+    org_access.require_admin()?;
+
+    if req.depends_on_service_id == id {
+        return Err(AppError::Validation(
+            "A service cannot depend on itself".to_string(),
+        ));
+    }
+
+    let dependency = db::service_dependencies::add(
+        &state.pool,
+        org_access.org.id,
+        id,
+        req.depends_on_service_id,
+    )
+    .await?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "service_dependency.create",
+            target_type: "service",
+            target_id: Some(&id.to_string()),
+            details: serde_json::json!({
+                "depends_on_service_id": dependency.depends_on_service_id,
+            }),
+        },
+    )
+    .await?;
+
+    Ok((
+        axum::http::StatusCode::CREATED,
+        Json(DataResponse { data: dependency }),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/organizations/{slug}/services/{id}/dependencies/{depends_on_id}",
+    tag = "services",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+        ("depends_on_id" = Uuid, Path, description = "Depends on id"),
+    ),
+    responses((status = 204, description = "No content")),
+)]
+async fn remove_dependency(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id, depends_on_id)): Path<(String, Uuid, Uuid)>,
+) -> Result<axum::http::StatusCode, AppError> {
+    org_access.require_admin()?;
+
+    db::service_dependencies::remove(&state.pool, org_access.org.id, id, depends_on_id).await?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "service_dependency.delete",
+            target_type: "service",
+            target_id: Some(&id.to_string()),
+            details: serde_json::json!({
+                "depends_on_service_id": depends_on_id,
+            }),
+        },
+    )
+    .await?;
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::history_range;
+    use chrono::{Duration, TimeZone, Utc};
+    use shared::models::status_history::StatusHistoryParams;
+
+    #[test]
+    fn history_range_defaults_to_thirty_days_and_rejects_bad_windows() {
+        let now = Utc.with_ymd_and_hms(2026, 5, 1, 12, 0, 0).unwrap();
+
+        let (from, to) = history_range(&StatusHistoryParams::default(), now).unwrap();
+        assert_eq!((to, to - from), (now, Duration::days(30)));
+
+        let reversed = StatusHistoryParams {
+            from: Some(now),
+            to: Some(now - Duration::hours(1)),
+        };
+        assert!(history_range(&reversed, now).is_err());
+
+        let too_long = StatusHistoryParams {
+            from: Some(now - Duration::days(400)),
+            to: None,
+        };
+        assert!(history_range(&too_long, now).is_err());
+    }
+}
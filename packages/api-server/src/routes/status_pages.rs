@@ -0,0 +1,568 @@
+use std::collections::HashSet;
+
+use axum::{
+    extract::{Path, State},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::Serialize;
+use uuid::Uuid;
+
+use shared::error::AppError;
+use shared::models::page_access::{PageAccessSettings, UpdatePageAccessRequest};
+use shared::models::status_page::{CreateStatusPageRequest, StatusPage, UpdateStatusPageRequest};
+use shared::validation::{slugify, validate_brand_color, validate_custom_domain, validate_slug};
+
+use crate::db;
+use crate::db::status_pages::{NewStatusPage, StatusPageChanges};
+use crate::middleware::org_access::OrgAccess;
+use crate::routes::organizations::{
+    check_custom_domain, page_access_update, plan_name, require_custom_domain_access,
+    CustomDomainVerificationResponse, PageAccessUpdate,
+};
+use crate::state::AppState;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", post(create_page).get(list_pages))
+        .route(
+            "/{id}",
+            get(get_page).patch(update_page).delete(delete_page),
+        )
+        .route("/{id}/access", get(get_page_access).put(update_page_access))
+        .route("/{id}/custom-domain/verify", post(verify_custom_domain))
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+struct DataResponse<T: Serialize> {
+    data: T,
+}
+
+fn validate_page_name(name: &str) -> Result<(), AppError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::Validation(
+            "Status page name is required".to_string(),
+        ));
+    }
+    if name.chars().count() > 255 {
+        return Err(AppError::Validation(
+            "Status page name must be 255 characters or fewer".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Deduplicates the requested services and rejects any the organization
+/// does not own.
+fn validate_service_ids(requested: &[Uuid], known: &HashSet<Uuid>) -> Result<Vec<Uuid>, AppError> {
+    let mut seen = HashSet::new();
+    let mut service_ids = Vec::with_capacity(requested.len());
+    for id in requested {
+        if !known.contains(id) {
+            return Err(AppError::Validation(format!("Service {id} does not exist")));
+        }
+        if seen.insert(*id) {
+            service_ids.push(*id);
+        }
+    }
+    Ok(service_ids)
+}
+
+async fn org_service_ids(state: &AppState, org_id: Uuid) -> Result<HashSet<Uuid>, AppError> {
+    Ok(db::services::find_by_org(&state.pool, org_id)
+        .await?
+        .into_iter()
+        .map(|service| service.id)
+        .collect())
+}
+
+/// Maps an optional patch value to a change where an empty string clears it.
+fn clearable(value: Option<&str>) -> Option<Option<&str>> {
+    value.map(|value| Some(value).filter(|value| !value.is_empty()))
+}
+
+fn normalize_custom_domain(value: &str) -> String {
+    value.trim().trim_end_matches('.').to_ascii_lowercase()
+}
+
+/// Checks a custom domain a page is about to claim. Empty clears it.
+async fn check_page_custom_domain(
+    state: &AppState,
+    org_access: &OrgAccess,
+    domain: &str,
+    page_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    require_custom_domain_access(org_access.org.plan, domain)?;
+    if domain.is_empty() {
+        return Ok(());
+    }
+    validate_custom_domain(domain)?;
+    if db::organizations::custom_domain_exists(&state.pool, domain, None, page_id).await? {
+        return Err(AppError::Conflict(format!(
+            "Custom domain '{domain}' is already in use"
+        )));
+    }
+    Ok(())
+}
+
+async fn find_page(
+    state: &AppState,
+    org_access: &OrgAccess,
+    id: Uuid,
+) -> Result<StatusPage, AppError> {
+    db::status_pages::find_by_id(&state.pool, id, org_access.org.id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/status-pages",
+    tag = "status-pages",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = CreateStatusPageRequest,
+    responses((status = 201, description = "Created", body = DataResponse<StatusPage>)),
+)]
+async fn create_page(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Json(req): Json<CreateStatusPageRequest>,
+) -> Result<(axum::http::StatusCode, Json<DataResponse<StatusPage>>), AppError> {
+    org_access.require_admin()?;
+    validate_page_name(&req.name)?;
+
+    if let Some(limit) = org_access.org.plan.max_status_pages() {
+        let page_count =
+            db::status_pages::count_active_by_org(&state.pool, org_access.org.id).await? + 1;
+        if page_count >= limit {
+            return Err(AppError::Validation(format!(
+                "{} plan allows up to {} status pages. Upgrade to add more.",
+                plan_name(org_access.org.plan),
+                limit
+            )));
+        }
+    }
+
+    let slug = match req.slug.as_deref().map(str::trim) {
+        Some(slug) => slug.to_string(),
+        None => slugify(&req.name),
+    };
+    validate_slug(&slug)?;
+    if db::organizations::slug_exists(&state.pool, &slug).await? {
+        return Err(AppError::Conflict(format!(
+            "Slug '{}' is already taken",
+            slug
+        )));
+    }
+
+    let logo_url = req
+        .logo_url
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+    let brand_color = req
+        .brand_color
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+    if let Some(color) = brand_color {
+        validate_brand_color(color)?;
+    }
+    let custom_domain = req
+        .custom_domain
+        .as_deref()
+        .map(normalize_custom_domain)
+        .filter(|value| !value.is_empty());
+    if let Some(domain) = custom_domain.as_deref() {
+        check_page_custom_domain(&state, &org_access, domain, None).await?;
+    }
+    let service_ids = validate_service_ids(
+        req.service_ids.as_deref().unwrap_or_default(),
+        &org_service_ids(&state, org_access.org.id).await?,
+    )?;
+
+    let page = db::status_pages::create(
+        &state.pool,
+        org_access.org.id,
+        &NewStatusPage {
+            slug: &slug,
+            name: req.name.trim(),
+            logo_url,
+            brand_color,
+            custom_domain: custom_domain.as_deref(),
+        },
+        &service_ids,
+    )
+    .await?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "status_page.create",
+            target_type: "status_page",
+            target_id: Some(&page.id.to_string()),
+            details: serde_json::json!({
+                "name": page.name,
+                "slug": page.slug,
+                "service_ids": page.service_ids,
+            }),
+        },
+    )
+    .await?;
+
+    Ok((
+        axum::http::StatusCode::CREATED,
+        Json(DataResponse { data: page }),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/status-pages",
+    tag = "status-pages",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<Vec<StatusPage>>)),
+)]
+async fn list_pages(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+) -> Result<Json<DataResponse<Vec<StatusPage>>>, AppError> {
+    let pages = db::status_pages::find_by_org(&state.pool, org_access.org.id).await?;
+    Ok(Json(DataResponse { data: pages }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/status-pages/{id}",
+    tag = "status-pages",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<StatusPage>)),
+)]
+async fn get_page(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+) -> Result<Json<DataResponse<StatusPage>>, AppError> {
+    let page = find_page(&state, &org_access, id).await?;
+    Ok(Json(DataResponse { data: page }))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/organizations/{slug}/status-pages/{id}",
+    tag = "status-pages",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    request_body = UpdateStatusPageRequest,
+    responses((status = 200, description = "OK", body = DataResponse<StatusPage>)),
+)]
+async fn update_page(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+    Json(req): Json<UpdateStatusPageRequest>,
+) -> Result<Json<DataResponse<StatusPage>>, AppError> {
+    org_access.require_admin()?;
+    let current = find_page(&state, &org_access, id).await?;
+
+    if let Some(name) = req.name.as_deref() {
+        validate_page_name(name)?;
+    }
+    let slug = req.slug.as_deref().map(str::trim);
+    if let Some(slug) = slug {
+        validate_slug(slug)?;
+        if slug != current.slug && db::organizations::slug_exists(&state.pool, slug).await? {
+            return Err(AppError::Conflict(format!(
+                "Slug '{}' is already taken",
+                slug
+            )));
+        }
+    }
+    let brand_color = req.brand_color.as_deref().map(str::trim);
+    if let Some(color) = brand_color.filter(|value| !value.is_empty()) {
+        validate_brand_color(color)?;
+    }
+    let custom_domain = req.custom_domain.as_deref().map(normalize_custom_domain);
+    if let Some(domain) = custom_domain.as_deref() {
+        check_page_custom_domain(&state, &org_access, domain, Some(id)).await?;
+    }
+    let service_ids = match req.service_ids.as_deref() {
+        Some(requested) => Some(validate_service_ids(
+            requested,
+            &org_service_ids(&state, org_access.org.id).await?,
+        )?),
+        None => None,
+    };
+
+    let page = db::status_pages::update(
+        &state.pool,
+        id,
+        org_access.org.id,
+        &StatusPageChanges {
+            slug,
+            name: req.name.as_deref().map(str::trim),
+            logo_url: clearable(req.logo_url.as_deref().map(str::trim)),
+            brand_color: clearable(brand_color),
+            custom_domain: clearable(custom_domain.as_deref()),
+            service_ids: service_ids.as_deref(),
+        },
+    )
+    .await?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "status_page.update",
+            target_type: "status_page",
+            target_id: Some(&id.to_string()),
+            details: serde_json::json!({
+                "name": page.name,
+                "slug": page.slug,
+                "custom_domain": page.custom_domain,
+                "service_ids": page.service_ids,
+            }),
+        },
+    )
+    .await?;
+
+    Ok(Json(DataResponse { data: page }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/organizations/{slug}/status-pages/{id}",
+    tag = "status-pages",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 204, description = "No content")),
+)]
+async fn delete_page(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+) -> Result<axum::http::StatusCode, AppError> {
+    org_access.require_admin()?;
+
+    let page = find_page(&state, &org_access, id).await?;
+    db::status_pages::delete(&state.pool, id, org_access.org.id).await?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "status_page.delete",
+            target_type: "status_page",
+            target_id: Some(&id.to_string()),
+            details: serde_json::json!({ "name": page.name, "slug": page.slug }),
+        },
+    )
+    .await?;
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{slug}/status-pages/{id}/access",
+    tag = "status-pages",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<PageAccessSettings>)),
+)]
+async fn get_page_access(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+) -> Result<Json<DataResponse<PageAccessSettings>>, AppError> {
+    org_access.require_admin()?;
+
+    let page = find_page(&state, &org_access, id).await?;
+    let password_set = stored_password_set(&state, id).await?;
+    Ok(Json(DataResponse {
+        data: PageAccessSettings::from_status_page(&page, password_set),
+    }))
+}
+
+/// Disabled pages are missing from `public_status_pages`, so the password
+/// flag is read from the table directly.
+async fn stored_password_set(state: &AppState, page_id: Uuid) -> Result<bool, AppError> {
+    let password_set = sqlx::query_scalar::<_, bool>(
+        "SELECT page_password_hash IS NOT NULL FROM status_pages WHERE id = $1",
+    )
+    .bind(page_id)
+    .fetch_one(&state.pool)
+    .await?;
+    Ok(password_set)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/organizations/{slug}/status-pages/{id}/access",
+    tag = "status-pages",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    request_body = UpdatePageAccessRequest,
+    responses((status = 200, description = "OK", body = DataResponse<PageAccessSettings>)),
+)]
+async fn update_page_access(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+    Json(req): Json<UpdatePageAccessRequest>,
+) -> Result<Json<DataResponse<PageAccessSettings>>, AppError> {
+    org_access.require_admin()?;
+
+    let page = find_page(&state, &org_access, id).await?;
+    let current = db::page_access::PageAccess {
+        org_id: page.org_id,
+        page_id: Some(page.id),
+        slug: page.slug.clone(),
+        page_visibility: page.page_visibility,
+        page_password_hash: stored_password_set(&state, id).await?.then(String::new),
+        page_allowed_email_domains: page.page_allowed_email_domains.clone(),
+        page_allowed_cidrs: page.page_allowed_cidrs.clone(),
+        viewer_session_secret: String::new(),
+    };
+    let PageAccessUpdate {
+        allowed_email_domains,
+        allowed_cidrs,
+        password_hash,
+        password_set,
+    } = page_access_update(&req, current)?;
+
+    db::page_access::update_status_page(
+        &state.pool,
+        id,
+        org_access.org.id,
+        req.visibility,
+        password_hash.as_deref(),
+        &allowed_email_domains,
+        &allowed_cidrs,
+    )
+    .await?;
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "status_page.access.update",
+            target_type: "status_page",
+            target_id: Some(&id.to_string()),
+            details: serde_json::json!({
+                "visibility": req.visibility.as_str(),
+                "password_changed": password_hash.is_some(),
+                "allowed_email_domains": &allowed_email_domains,
+                "allowed_cidrs": &allowed_cidrs,
+            }),
+        },
+    )
+    .await?;
+
+    let page = find_page(&state, &org_access, id).await?;
+    Ok(Json(DataResponse {
+        data: PageAccessSettings::from_status_page(&page, password_set),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{slug}/status-pages/{id}/custom-domain/verify",
+    tag = "status-pages",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("id" = Uuid, Path, description = "Id"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<CustomDomainVerificationResponse>)),
+)]
+async fn verify_custom_domain(
+    State(state): State<AppState>,
+    org_access: OrgAccess,
+    Path((_slug, id)): Path<(String, Uuid)>,
+) -> Result<Json<DataResponse<CustomDomainVerificationResponse>>, AppError> {
+    org_access.require_admin()?;
+
+    let page = find_page(&state, &org_access, id).await?;
+    let domain = page
+        .custom_domain
+        .filter(|value| !value.trim().is_empty())
+        .ok_or_else(|| {
+            AppError::Validation("Set a custom domain before running verification".to_string())
+        })?;
+    require_custom_domain_access(org_access.org.plan, &domain)?;
+
+    let check = check_custom_domain(
+        &state.config,
+        domain.clone(),
+        &page.custom_domain_verification_token,
+    )
+    .await?;
+    if let Some(method) = check.verification_method {
+        db::status_pages::mark_custom_domain_verified(&state.pool, id, org_access.org.id, method)
+            .await?;
+        db::certificates::request_issuance(&state.pool, &domain).await?;
+    }
+
+    db::audit_logs::record(
+        &state.pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org_access.org.id,
+            actor_user_id: Some(org_access.user.id),
+            actor_type: "user",
+            action: "status_page.custom_domain.verify",
+            target_type: "status_page",
+            target_id: Some(&id.to_string()),
+            details: check.audit_details(),
+        },
+    )
+    .await?;
+
+    Ok(Json(DataResponse { data: check }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_names_must_be_present_and_bounded() {
+        assert!(validate_page_name("Partners").is_ok());
+        assert!(validate_page_name("   ").is_err());
+        assert!(validate_page_name(&"x".repeat(256)).is_err());
+    }
+
+    #[test]
+    fn service_ids_are_deduplicated_and_must_belong_to_the_org() {
+        let api = Uuid::new_v4();
+        let web = Uuid::new_v4();
+        let known = HashSet::from([api, web]);
+
+        assert_eq!(
+            validate_service_ids(&[api, web, api], &known).unwrap(),
+            vec![api, web]
+        );
+        assert!(validate_service_ids(&[Uuid::new_v4()], &known).is_err());
+        assert!(validate_service_ids(&[], &known).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,313 @@
+use std::net::IpAddr;
+use std::time::Duration as StdDuration;
+
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::Utc;
+use serde::Serialize;
+use uuid::Uuid;
+
+use shared::enums::PageVisibility;
+use shared::error::AppError;
+use shared::models::page_access::{
+    email_domain_allowed, ViewerAccessResponse, ViewerMagicLinkRequest,
+    ViewerMagicLinkVerifyRequest, ViewerPasswordRequest, ViewerSessionResponse,
+};
+
+use crate::db;
+use crate::db::page_access::PageAccess;
+use crate::middleware::page_viewer::ClientIp;
+use crate::services::email_notifications;
+use crate::services::rate_limit::enforce_rate_limit;
+use crate::services::viewer_sessions;
+use crate::state::AppState;
+
+const LOGIN_RATE_WINDOW: StdDuration = StdDuration::from_secs(15 * 60);
+
+/// Attempts allowed per window from one client, and for one page from all
+/// clients together.
+struct LoginLimit {
+    scope: &'static str,
+    per_client: usize,
+    per_page: usize,
+}
+
+const PASSWORD_LIMIT: LoginLimit = LoginLimit {
+    scope: "viewer_password",
+    per_client: 10,
+    per_page: 100,
+};
+const MAGIC_LINK_LIMIT: LoginLimit = LoginLimit {
+    scope: "viewer_magic_link",
+    per_client: 5,
+    per_page: 50,
+};
+const MAGIC_LINK_VERIFY_LIMIT: LoginLimit = LoginLimit {
+    scope: "viewer_magic_link_verify",
+    per_client: 20,
+    per_page: 200,
+};
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/{slug}/access", get(get_viewer_access))
+        .route("/{slug}/access/password", post(sign_in_with_password))
+        .route("/{slug}/access/magic-link", post(request_magic_link))
+        .route("/{slug}/access/magic-link/verify", post(verify_magic_link))
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+struct DataResponse<T: Serialize> {
+    data: T,
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+struct MessageResponse {
+    message: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/public/{slug}/access",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    responses((status = 200, description = "OK", body = DataResponse<ViewerAccessResponse>)),
+)]
+async fn get_viewer_access(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Path(slug): Path<String>,
+) -> Result<Json<DataResponse<ViewerAccessResponse>>, AppError> {
+    let access = load_access(&state, &slug).await?;
+
+    Ok(Json(DataResponse {
+        data: ViewerAccessResponse {
+            visibility: access.page_visibility,
+            authorized: viewer_sessions::viewer_allowed(&access, &headers, client_ip, Utc::now()),
+        },
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/public/{slug}/access/password",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = ViewerPasswordRequest,
+    responses((status = 200, description = "OK", body = DataResponse<ViewerSessionResponse>)),
+)]
+async fn sign_in_with_password(
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(slug): Path<String>,
+    Json(req): Json<ViewerPasswordRequest>,
+) -> Result<Response, AppError> {
+    enforce_login_limit(&state, &PASSWORD_LIMIT, client_ip, &slug, &slug).await?;
+
+    let access = load_access(&state, &slug).await?;
+    let password_matches = access.page_visibility == PageVisibility::Password
+        && access
+            .page_password_hash
+            .as_deref()
+            .is_some_and(|hash| viewer_sessions::verify_password(&req.password, hash));
+    if !password_matches {
+        return Err(AppError::Validation("Incorrect password".to_string()));
+    }
+
+    Ok(session_response(&access, None))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/public/{slug}/access/magic-link",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = ViewerMagicLinkRequest,
+    responses((status = 202, description = "Accepted", body = DataResponse<MessageResponse>)),
+)]
+async fn request_magic_link(
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(slug): Path<String>,
+    Json(req): Json<ViewerMagicLinkRequest>,
+) -> Result<(StatusCode, Json<DataResponse<MessageResponse>>), AppError> {
+    let email = shared::validation::normalize_subscriber_email(&req.email)?;
+    enforce_login_limit(&state, &MAGIC_LINK_LIMIT, client_ip, &email, &slug).await?;
+
+    let access = load_access(&state, &slug).await?;
+    if access.page_visibility != PageVisibility::EmailDomain {
+        return Err(AppError::Validation(
+            "This status page does not use email sign-in".to_string(),
+        ));
+    }
+
+    // The reply is the same either way, so the allowlist cannot be probed.
+    if email_domain_allowed(&email, &access.page_allowed_email_domains) {
+        let token = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+        db::page_access::create_login_link(
+            &state.pool,
+            &access,
+            &email,
+            &viewer_sessions::hash_login_token(&token),
+            Utc::now() + viewer_sessions::LOGIN_LINK_TTL,
+        )
+        .await?;
+        let page =
+            db::status_pages::find_public(&state.pool, access.org_id, access.page_id).await?;
+        email_notifications::queue_viewer_magic_link(
+            &state.pool,
+            &state.config.app_base_url,
+            &page,
+            &email,
+            &token,
+        )
+        .await?;
+    }
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(DataResponse {
+            data: MessageResponse {
+                message: "If that address can view this page, a sign-in link is on its way."
+                    .to_string(),
+            },
+        }),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/public/{slug}/access/magic-link/verify",
+    tag = "public",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+    ),
+    request_body = ViewerMagicLinkVerifyRequest,
+    responses((status = 200, description = "OK", body = DataResponse<ViewerSessionResponse>)),
+)]
+async fn verify_magic_link(
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(slug): Path<String>,
+    Json(req): Json<ViewerMagicLinkVerifyRequest>,
+) -> Result<Response, AppError> {
+    enforce_login_limit(&state, &MAGIC_LINK_VERIFY_LIMIT, client_ip, &slug, &slug).await?;
+
+    let access = load_access(&state, &slug).await?;
+    let invalid_link =
+        || AppError::Validation("This sign-in link is invalid or has expired".to_string());
+    if access.page_visibility != PageVisibility::EmailDomain {
+        return Err(invalid_link());
+    }
+
+    let email = db::page_access::consume_login_link(
+        &state.pool,
+        &access,
+        &viewer_sessions::hash_login_token(req.token.trim()),
+    )
+    .await?
+    .filter(|email| email_domain_allowed(email, &access.page_allowed_email_domains))
+    .ok_or_else(invalid_link)?;
+
+    Ok(session_response(&access, Some(&email)))
+}
+
+/// Counts an attempt against the client's own limit and then against the
+/// page's, so neither forged `X-Forwarded-For` headers nor a pool of real
+/// addresses gets more than `per_page` guesses at one page.
+async fn enforce_login_limit(
+    state: &AppState,
+    limit: &LoginLimit,
+    client_ip: Option<IpAddr>,
+    key: &str,
+    slug: &str,
+) -> Result<(), AppError> {
+    enforce_rate_limit(
+        &state.redis,
+        limit.scope,
+        &client_subject(client_ip, key),
+        limit.per_client,
+        LOGIN_RATE_WINDOW,
+    )
+    .await?;
+    enforce_rate_limit(
+        &state.redis,
+        &format!("{}_page", limit.scope),
+        slug,
+        limit.per_page,
+        LOGIN_RATE_WINDOW,
+    )
+    .await
+}
+
+/// Clients without a trustworthy address share one bucket.
+fn client_subject(client_ip: Option<IpAddr>, key: &str) -> String {
+    match client_ip {
+        Some(ip) => format!("{ip}:{key}"),
+        None => format!("unknown:{key}"),
+    }
+}
+
+async fn load_access(state: &AppState, slug: &str) -> Result<PageAccess, AppError> {
+    db::page_access::find_by_slug(&state.pool, slug)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Status page not found".to_string()))
+}
+
+/// Returns the session in the body for server-rendered clients and as a
+/// cookie for browsers calling the public API directly.
+fn session_response(access: &PageAccess, email: Option<&str>) -> Response {
+    let (token, expires_at) = viewer_sessions::issue_session(access, email, Utc::now());
+    let cookie = viewer_sessions::session_cookie(&access.slug, &token, expires_at);
+
+    (
+        [(header::SET_COOKIE, cookie)],
+        Json(DataResponse {
+            data: ViewerSessionResponse { token, expires_at },
+        }),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn spoofed_forwarded_for_values_share_one_rate_limit_key() {
+        let peer = Some("203.0.113.7".parse().unwrap());
+        let trusted = vec!["172.16.0.0/12".to_string()];
+
+        let subjects = ["10.0.0.1", "10.0.0.2", "198.51.100.9, 10.0.0.3", ""]
+            .into_iter()
+            .map(|forwarded| {
+                let mut headers = HeaderMap::new();
+                headers.insert("x-forwarded-for", HeaderValue::from_str(forwarded).unwrap());
+                headers.insert("x-real-ip", HeaderValue::from_str(forwarded).unwrap());
+                let client_ip = viewer_sessions::client_ip(&headers, peer, &trusted);
+                client_subject(client_ip, "acme")
+            })
+            .collect::<Vec<_>>();
+
+        assert!(subjects.iter().all(|subject| subject == "203.0.113.7:acme"));
+    }
+
+    #[test]
+    fn clients_without_an_address_share_a_bucket() {
+        assert_eq!(client_subject(None, "acme"), "unknown:acme");
+    }
+}
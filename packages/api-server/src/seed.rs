@@ -106,14 +106,25 @@ async fn main() -> anyhow::Result<()> {
     let mut service_ids = Vec::new();
     for (i, (name, desc, group, status)) in services.iter().enumerate() {
         let sid = Uuid::new_v4();
+        let group_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO service_groups (org_id, name, display_order)
+             VALUES ($1, $2, (SELECT COUNT(*)::INT FROM service_groups WHERE org_id = $1))
+             ON CONFLICT (org_id, name) DO UPDATE SET name = EXCLUDED.name
+             RETURNING id",
+        )
+        .bind(org_id)
+        .bind(group)
+        .fetch_one(&pool)
+        .await?;
         sqlx::query(
-            "INSERT INTO services (id, org_id, name, description, group_name, current_status, display_order)
-             VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            "INSERT INTO services (id, org_id, name, description, group_id, group_name, current_status, display_order)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
         )
         .bind(sid)
         .bind(org_id)
         .bind(name)
         .bind(desc)
+        .bind(group_id)
         .bind(group)
         .bind(status.as_str())
         .bind(i as i32)
@@ -123,6 +134,11 @@ async fn main() -> anyhow::Result<()> {
         println!("  Created service: {} ({})", name, status);
     }
 
+    sqlx::query("SELECT record_service_status_intervals($1, 'manual')")
+        .bind(org_id)
+        .execute(&pool)
+        .await?;
+
     // 5. Create a resolved incident (happened 3 days ago, lasted 45 min)
     let resolved_incident_id = Uuid::new_v4();
     let resolved_started = Utc::now() - chrono::Duration::days(3);
@@ -144,11 +160,14 @@ async fn main() -> anyhow::Result<()> {
     .await?;
 
     // Link to API service
-    sqlx::query("INSERT INTO incident_services (incident_id, service_id) VALUES ($1, $2)")
-        .bind(resolved_incident_id)
-        .bind(service_ids[0].0) // API
-        .execute(&pool)
-        .await?;
+    sqlx::query(
+        "INSERT INTO incident_services (incident_id, service_id, service_status) VALUES ($1, $2, $3)",
+    )
+    .bind(resolved_incident_id)
+    .bind(service_ids[0].0) // API
+    .bind(ServiceStatus::DegradedPerformance.as_str())
+    .execute(&pool)
+    .await?;
 
     // Add timeline updates for resolved incident
     let updates = vec![
@@ -208,11 +227,14 @@ async fn main() -> anyhow::Result<()> {
     .await?;
 
     // Link to Email Service
-    sqlx::query("INSERT INTO incident_services (incident_id, service_id) VALUES ($1, $2)")
-        .bind(active_incident_id)
-        .bind(service_ids[4].0) // Email Service
-        .execute(&pool)
-        .await?;
+    sqlx::query(
+        "INSERT INTO incident_services (incident_id, service_id, service_status) VALUES ($1, $2, $3)",
+    )
+    .bind(active_incident_id)
+    .bind(service_ids[4].0) // Email Service
+    .bind(ServiceStatus::DegradedPerformance.as_str())
+    .execute(&pool)
+    .await?;
 
     // Timeline updates for active incident
     sqlx::query(
@@ -0,0 +1,484 @@
+//! A small ACME (RFC 8555) client covering what custom domains need: account
+//! registration, HTTP-01 validated orders and certificate download.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context};
+use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use p256::ecdsa::signature::Signer;
+use p256::ecdsa::{Signature, SigningKey};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+const HTTP_TIMEOUT_SECS: u64 = 30;
+const POLL_ATTEMPTS: usize = 30;
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+const BAD_NONCE: &str = "urn:ietf:params:acme:error:badNonce";
+
+/// Serves HTTP-01 key authorizations at
+/// `/.well-known/acme-challenge/{token}` while an order is validated.
+#[async_trait]
+pub trait Http01Responder: Send + Sync {
+    async fn publish(
+        &self,
+        domain: &str,
+        token: &str,
+        key_authorization: &str,
+    ) -> anyhow::Result<()>;
+
+    async fn withdraw(&self, token: &str) -> anyhow::Result<()>;
+}
+
+#[derive(Debug, Clone)]
+pub struct IssuedCertificate {
+    /// PEM chain as returned by the CA, leaf first.
+    pub certificate_pem: String,
+    pub private_key_pem: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Directory {
+    new_nonce: String,
+    new_account: String,
+    new_order: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Order {
+    status: String,
+    authorizations: Vec<String>,
+    finalize: String,
+    certificate: Option<String>,
+    error: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Authorization {
+    status: String,
+    identifier: Identifier,
+    challenges: Vec<Challenge>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Identifier {
+    value: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Challenge {
+    #[serde(rename = "type")]
+    kind: String,
+    url: String,
+    token: Option<String>,
+    error: Option<Value>,
+}
+
+pub struct AcmeClient {
+    http: reqwest::Client,
+    directory: Directory,
+    key: SigningKey,
+    account_url: Option<String>,
+    nonce: Mutex<Option<String>>,
+}
+
+impl AcmeClient {
+    /// Loads the directory. `ca_cert_pem` adds a trusted root for
+    /// directories served with a private CA, such as Pebble's.
+    pub async fn connect(
+        directory_url: &str,
+        ca_cert_pem: Option<&[u8]>,
+        key: SigningKey,
+    ) -> anyhow::Result<Self> {
+        let mut builder =
+            reqwest::Client::builder().timeout(Duration::from_secs(HTTP_TIMEOUT_SECS));
+        if let Some(pem) = ca_cert_pem {
+            builder = builder.add_root_certificate(
+                reqwest::Certificate::from_pem(pem).context("Invalid ACME CA certificate")?,
+            );
+        }
+        let http = builder
+            .build()
+            .context("Failed to build ACME HTTP client")?;
+
+        let response = http
+            .get(directory_url)
+            .send()
+            .await
+            .context("Failed to load ACME directory")?;
+        if !response.status().is_success() {
+            bail!("ACME directory responded with {}", response.status());
+        }
+        let directory = response
+            .json::<Directory>()
+            .await
+            .context("ACME directory is malformed")?;
+
+        Ok(Self {
+            http,
+            directory,
+            key,
+            account_url: None,
+            nonce: Mutex::new(None),
+        })
+    }
+
+    /// Uses an account registered earlier with the same key.
+    pub fn with_account(mut self, account_url: String) -> Self {
+        self.account_url = Some(account_url);
+        self
+    }
+
+    /// Registers the key, or looks up its existing account, and returns the
+    /// account URL.
+    pub async fn register(&mut self, contact_email: Option<&str>) -> anyhow::Result<String> {
+        let mut payload = json!({ "termsOfServiceAgreed": true });
+        if let Some(email) = contact_email {
+            payload["contact"] = json!([format!("mailto:{email}")]);
+        }
+
+        let new_account = self.directory.new_account.clone();
+        let response = self.post(&new_account, Some(&payload)).await?;
+        let account_url = location(&response)?;
+        self.account_url = Some(account_url.clone());
+        Ok(account_url)
+    }
+
+    /// Orders a certificate for `domain`, answers its HTTP-01 challenges
+    /// through `responder` and downloads the issued chain.
+    pub async fn issue(
+        &self,
+        domain: &str,
+        responder: &dyn Http01Responder,
+    ) -> anyhow::Result<IssuedCertificate> {
+        let response = self
+            .post(
+                &self.directory.new_order,
+                Some(&json!({ "identifiers": [{ "type": "dns", "value": domain }] })),
+            )
+            .await?;
+        let order_url = location(&response)?;
+        let order = response
+            .json::<Order>()
+            .await
+            .context("ACME order is malformed")?;
+
+        let mut published = Vec::new();
+        let result = self
+            .complete_order(domain, &order_url, order, responder, &mut published)
+            .await;
+        for token in published {
+            if let Err(error) = responder.withdraw(&token).await {
+                tracing::warn!(error = %error, domain, "Failed to withdraw ACME challenge");
+            }
+        }
+        result
+    }
+
+    async fn complete_order(
+        &self,
+        domain: &str,
+        order_url: &str,
+        order: Order,
+        responder: &dyn Http01Responder,
+        published: &mut Vec<String>,
+    ) -> anyhow::Result<IssuedCertificate> {
+        for authorization_url in &order.authorizations {
+            let authorization = self
+                .post_as_get(authorization_url)
+                .await?
+                .json::<Authorization>()
+                .await
+                .context("ACME authorization is malformed")?;
+            if authorization.status == "valid" {
+                continue;
+            }
+
+            let challenge = authorization
+                .challenges
+                .iter()
+                .find(|challenge| challenge.kind == "http-01")
+                .ok_or_else(|| anyhow!("The CA offered no http-01 challenge for {domain}"))?;
+            let token = challenge
+                .token
+                .as_deref()
+                .ok_or_else(|| anyhow!("The http-01 challenge for {domain} has no token"))?;
+            let key_authorization = format!("{token}.{}", jwk_thumbprint(&self.key));
+            responder
+                .publish(&authorization.identifier.value, token, &key_authorization)
+                .await?;
+            published.push(token.to_string());
+
+            self.post(&challenge.url, Some(&json!({}))).await?;
+            self.poll_authorization(authorization_url).await?;
+        }
+
+        let key_pair = rcgen::KeyPair::generate().context("Failed to generate certificate key")?;
+        let mut params = rcgen::CertificateParams::new(vec![domain.to_string()])
+            .context("Invalid certificate domain")?;
+        params.distinguished_name = rcgen::DistinguishedName::new();
+        params
+            .distinguished_name
+            .push(rcgen::DnType::CommonName, domain);
+        let csr = params
+            .serialize_request(&key_pair)
+            .context("Failed to build certificate signing request")?;
+
+        self.post(
+            &order.finalize,
+            Some(&json!({ "csr": URL_SAFE_NO_PAD.encode(csr.der()) })),
+        )
+        .await?;
+        let order = self.poll_order(order_url).await?;
+        let certificate_url = order
+            .certificate
+            .ok_or_else(|| anyhow!("The CA returned no certificate for {domain}"))?;
+        let certificate_pem = self
+            .post_as_get(&certificate_url)
+            .await?
+            .text()
+            .await
+            .context("Failed to download certificate")?;
+
+        Ok(IssuedCertificate {
+            certificate_pem,
+            private_key_pem: key_pair.serialize_pem(),
+        })
+    }
+
+    async fn poll_authorization(&self, url: &str) -> anyhow::Result<()> {
+        for _ in 0..POLL_ATTEMPTS {
+            let authorization = self
+                .post_as_get(url)
+                .await?
+                .json::<Authorization>()
+                .await
+                .context("ACME authorization is malformed")?;
+            match authorization.status.as_str() {
+                "valid" => return Ok(()),
+                "pending" | "processing" => tokio::time::sleep(POLL_INTERVAL).await,
+                status => {
+                    let detail = authorization
+                        .challenges
+                        .iter()
+                        .find_map(|challenge| challenge.error.as_ref())
+                        .map(problem_detail)
+                        .unwrap_or_default();
+                    bail!(
+                        "Authorization for {} is {status}: {detail}",
+                        authorization.identifier.value
+                    );
+                }
+            }
+        }
+        bail!("Timed out waiting for the CA to validate the challenge")
+    }
+
+    async fn poll_order(&self, url: &str) -> anyhow::Result<Order> {
+        for _ in 0..POLL_ATTEMPTS {
+            let order = self
+                .post_as_get(url)
+                .await?
+                .json::<Order>()
+                .await
+                .context("ACME order is malformed")?;
+            match order.status.as_str() {
+                "valid" => return Ok(order),
+                "pending" | "ready" | "processing" => tokio::time::sleep(POLL_INTERVAL).await,
+                status => bail!(
+                    "Order is {status}: {}",
+                    order.error.as_ref().map(problem_detail).unwrap_or_default()
+                ),
+            }
+        }
+        bail!("Timed out waiting for the CA to issue the certificate")
+    }
+
+    async fn post_as_get(&self, url: &str) -> anyhow::Result<reqwest::Response> {
+        self.post(url, None).await
+    }
+
+    /// Sends a signed request, retrying once when the CA rejects the nonce.
+    async fn post(&self, url: &str, payload: Option<&Value>) -> anyhow::Result<reqwest::Response> {
+        let mut retried = false;
+        loop {
+            let nonce = self.nonce().await?;
+            let header = match &self.account_url {
+                Some(kid) => json!({ "alg": "ES256", "nonce": nonce, "url": url, "kid": kid }),
+                None => {
+                    json!({ "alg": "ES256", "nonce": nonce, "url": url, "jwk": jwk(&self.key) })
+                }
+            };
+            let body = sign_jws(&self.key, &header, payload)?;
+
+            let response = self
+                .http
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, "application/jose+json")
+                .body(body.to_string())
+                .send()
+                .await
+                .with_context(|| format!("ACME request to {url} failed"))?;
+            self.remember_nonce(&response);
+            if response.status().is_success() {
+                return Ok(response);
+            }
+
+            let status = response.status();
+            let problem = response.json::<Value>().await.unwrap_or_default();
+            if !retried && problem["type"] == BAD_NONCE {
+                retried = true;
+                continue;
+            }
+            bail!(
+                "ACME request to {url} failed with {status}: {}",
+                problem_detail(&problem)
+            );
+        }
+    }
+
+    async fn nonce(&self) -> anyhow::Result<String> {
+        if let Some(nonce) = self.nonce.lock().expect("nonce lock poisoned").take() {
+            return Ok(nonce);
+        }
+
+        let response = self
+            .http
+            .head(&self.directory.new_nonce)
+            .send()
+            .await
+            .context("Failed to fetch ACME nonce")?;
+        replay_nonce(&response).ok_or_else(|| anyhow!("The CA returned no Replay-Nonce"))
+    }
+
+    fn remember_nonce(&self, response: &reqwest::Response) {
+        if let Some(nonce) = replay_nonce(response) {
+            *self.nonce.lock().expect("nonce lock poisoned") = Some(nonce);
+        }
+    }
+}
+
+fn replay_nonce(response: &reqwest::Response) -> Option<String> {
+    response
+        .headers()
+        .get("replay-nonce")
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+fn location(response: &reqwest::Response) -> anyhow::Result<String> {
+    response
+        .headers()
+        .get(reqwest::header::LOCATION)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("The CA response has no Location header"))
+}
+
+fn problem_detail(problem: &Value) -> String {
+    problem["detail"]
+        .as_str()
+        .or_else(|| problem["type"].as_str())
+        .unwrap_or("no detail")
+        .to_string()
+}
+
+fn jwk(key: &SigningKey) -> Value {
+    let (x, y) = public_coordinates(key);
+    json!({ "crv": "P-256", "kty": "EC", "x": x, "y": y })
+}
+
+fn public_coordinates(key: &SigningKey) -> (String, String) {
+    let point = key.verifying_key().to_encoded_point(false);
+    (
+        URL_SAFE_NO_PAD.encode(point.x().expect("uncompressed point has x")),
+        URL_SAFE_NO_PAD.encode(point.y().expect("uncompressed point has y")),
+    )
+}
+
+/// RFC 7638 thumbprint: the required members in lexicographic order with no
+/// whitespace, hashed with SHA-256.
+fn jwk_thumbprint(key: &SigningKey) -> String {
+    let (x, y) = public_coordinates(key);
+    let canonical = format!(r#"{{"crv":"P-256","kty":"EC","x":"{x}","y":"{y}"}}"#);
+    URL_SAFE_NO_PAD.encode(Sha256::digest(canonical.as_bytes()))
+}
+
+/// Flattened JWS serialization. A `None` payload is a POST-as-GET.
+fn sign_jws(key: &SigningKey, header: &Value, payload: Option<&Value>) -> anyhow::Result<Value> {
+    let protected = URL_SAFE_NO_PAD.encode(serde_json::to_vec(header)?);
+    let payload = match payload {
+        Some(payload) => URL_SAFE_NO_PAD.encode(serde_json::to_vec(payload)?),
+        None => String::new(),
+    };
+    let signature: Signature = key.sign(format!("{protected}.{payload}").as_bytes());
+
+    Ok(json!({
+        "protected": protected,
+        "payload": payload,
+        "signature": URL_SAFE_NO_PAD.encode(signature.to_bytes()),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use p256::ecdsa::signature::Verifier;
+
+    use super::*;
+
+    fn test_key() -> SigningKey {
+        SigningKey::from_slice(&[7u8; 32]).unwrap()
+    }
+
+    #[test]
+    fn jws_signature_verifies_against_the_account_key() {
+        let key = test_key();
+        let header = json!({ "alg": "ES256", "nonce": "abc", "url": "https://ca.test/order" });
+        let jws = sign_jws(&key, &header, Some(&json!({ "status": "deactivated" }))).unwrap();
+
+        let protected = jws["protected"].as_str().unwrap();
+        let payload = jws["payload"].as_str().unwrap();
+        let signature = URL_SAFE_NO_PAD
+            .decode(jws["signature"].as_str().unwrap())
+            .unwrap();
+        assert_eq!(signature.len(), 64);
+        key.verifying_key()
+            .verify(
+                format!("{protected}.{payload}").as_bytes(),
+                &Signature::from_slice(&signature).unwrap(),
+            )
+            .unwrap();
+
+        let decoded: Value =
+            serde_json::from_slice(&URL_SAFE_NO_PAD.decode(protected).unwrap()).unwrap();
+        assert_eq!(decoded, header);
+    }
+
+    #[test]
+    fn post_as_get_has_an_empty_payload() {
+        let jws = sign_jws(&test_key(), &json!({ "alg": "ES256" }), None).unwrap();
+        assert_eq!(jws["payload"], "");
+    }
+
+    #[test]
+    fn thumbprint_hashes_the_canonical_jwk() {
+        let key = test_key();
+        let jwk = jwk(&key);
+        let canonical = format!(
+            r#"{{"crv":"P-256","kty":"EC","x":"{}","y":"{}"}}"#,
+            jwk["x"].as_str().unwrap(),
+            jwk["y"].as_str().unwrap()
+        );
+
+        let thumbprint = jwk_thumbprint(&key);
+        assert_eq!(
+            thumbprint,
+            URL_SAFE_NO_PAD.encode(Sha256::digest(canonical.as_bytes()))
+        );
+        assert_eq!(thumbprint.len(), 43);
+    }
+}
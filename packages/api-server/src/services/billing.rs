@@ -322,6 +322,60 @@ pub async fn fetch_subscription_snapshot(
     })
 }
 
+/// Outcome of canceling an organization's Stripe subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubscriptionCancellation {
+    NotSubscribed,
+    Canceled,
+    AlreadyCanceled,
+}
+
+impl SubscriptionCancellation {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::NotSubscribed => "not_subscribed",
+            Self::Canceled => "canceled",
+            Self::AlreadyCanceled => "already_canceled",
+        }
+    }
+}
+
+/// Cancels the subscription immediately rather than at period end, as the
+/// organization is about to disappear. A subscription Stripe no longer
+/// knows about counts as already canceled.
+pub async fn cancel_subscription(
+    config: &Config,
+    org: &Organization,
+) -> Result<SubscriptionCancellation, AppError> {
+    let Some(subscription_id) = org.stripe_subscription_id.as_deref() else {
+        return Ok(SubscriptionCancellation::NotSubscribed);
+    };
+    let secret_key = config
+        .stripe_secret_key
+        .as_deref()
+        .ok_or_else(|| AppError::Validation("Stripe billing is not configured".to_string()))?;
+
+    let response = reqwest::Client::new()
+        .delete(format!("{STRIPE_API_BASE}/subscriptions/{subscription_id}"))
+        .basic_auth(secret_key, Some(""))
+        .send()
+        .await
+        .map_err(|error| AppError::Internal(anyhow::anyhow!("Stripe request failed: {error}")))?;
+
+    let status = response.status();
+    if status == StatusCode::NOT_FOUND {
+        return Ok(SubscriptionCancellation::AlreadyCanceled);
+    }
+    let body = response.text().await.map_err(|error| {
+        AppError::Internal(anyhow::anyhow!("Failed to read Stripe response: {error}"))
+    })?;
+    if !status.is_success() {
+        return Err(AppError::Validation(stripe_error_message(status, &body)));
+    }
+
+    Ok(SubscriptionCancellation::Canceled)
+}
+
 async fn post_form(
     secret_key: &str,
     path: &str,
@@ -492,9 +546,21 @@ mod tests {
             smtp_password: None,
             smtp_secure: false,
             email_from: "alerts@example.com".to_string(),
+            email_transport: "smtp".to_string(),
+            email_file_drop_dir: None,
+            email_http_api_url: None,
+            email_http_api_key: None,
             app_base_url: "https://app.statuspage.test".to_string(),
             email_dispatch_interval_secs: 3,
             email_dispatch_batch_size: 20,
+            email_digest_interval_secs: 60,
+            sms_http_api_url: None,
+            sms_http_api_key: None,
+            sms_from: None,
+            sms_dispatch_interval_secs: 5,
+            sms_dispatch_batch_size: 20,
+            incident_escalation_delay_minutes: 15,
+            incident_escalation_interval_secs: 60,
             stripe_secret_key: Some("sk_test_123".to_string()),
             stripe_webhook_secret: Some("whsec_test".to_string()),
             stripe_price_pro: Some("price_pro".to_string()),
@@ -504,7 +570,20 @@ mod tests {
             api_port: 4000,
             api_host: "127.0.0.1".to_string(),
             cors_origin: "http://localhost:3000".to_string(),
+            trusted_proxy_cidrs: Vec::new(),
             statuspage_host: Some("statuspage.test".to_string()),
+            acme_directory_url: None,
+            acme_contact_email: None,
+            acme_ca_cert_path: None,
+            certificate_encryption_key: None,
+            certificate_renewal_interval_secs: 300,
+            certificate_renew_before_days: 30,
+            edge_proxy_token: None,
+            custom_domain_recheck_interval_secs: 3600,
+            custom_domain_miss_threshold: 3,
+            custom_domain_grace_hours: 72,
+            org_deletion_cool_off_hours: 168,
+            org_export_retention_hours: 168,
             run_migrations_on_start: false,
             run_migrations_only: false,
             log_level: "info".to_string(),
@@ -0,0 +1,200 @@
+use chrono::{Duration, Utc};
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use shared::error::AppError;
+use shared::models::cli_auth::{
+    CliLoginPollResponse, CliLoginStart, CliLoginStatus, IssuedCliCredential,
+};
+
+use crate::db;
+
+/// Every CLI token starts with this, so the auth middleware can tell it
+/// apart from other bearer values and leaked tokens are easy to grep for.
+pub const CLI_TOKEN_PREFIX: &str = "spcli_";
+pub const LOGIN_REQUEST_TTL: Duration = Duration::minutes(10);
+pub const CREDENTIAL_TTL: Duration = Duration::days(365);
+pub const POLL_INTERVAL_SECONDS: u64 = 5;
+
+/// Letters and digits that survive being read aloud or retyped: no 0/O,
+/// 1/I/L or 5/S.
+const USER_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKMNPQRTUVWXYZ2346789";
+const USER_CODE_LENGTH: usize = 8;
+const DEFAULT_CLIENT_NAME: &str = "statuspage CLI";
+
+pub fn generate_token() -> String {
+    format!(
+        "{CLI_TOKEN_PREFIX}{}{}",
+        Uuid::new_v4().simple(),
+        Uuid::new_v4().simple()
+    )
+}
+
+/// Device codes and credentials are stored hashed, like login link tokens.
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+/// Formats random bytes as `XXXX-XXXX`.
+fn user_code_from_bytes(bytes: &[u8]) -> String {
+    let mut code = String::with_capacity(USER_CODE_LENGTH + 1);
+    for (index, byte) in bytes.iter().take(USER_CODE_LENGTH).enumerate() {
+        if index == USER_CODE_LENGTH / 2 {
+            code.push('-');
+        }
+        code.push(USER_CODE_ALPHABET[*byte as usize % USER_CODE_ALPHABET.len()] as char);
+    }
+    code
+}
+
+/// Accepts a user code as typed, in any case and with or without the dash.
+pub fn normalize_user_code(input: &str) -> Option<String> {
+    let characters: Vec<u8> = input
+        .bytes()
+        .filter(|byte| !matches!(byte, b'-' | b' '))
+        .map(|byte| byte.to_ascii_uppercase())
+        .collect();
+    if characters.len() != USER_CODE_LENGTH
+        || !characters
+            .iter()
+            .all(|byte| USER_CODE_ALPHABET.contains(byte))
+    {
+        return None;
+    }
+
+    let (first, second) = characters.split_at(USER_CODE_LENGTH / 2);
+    Some(format!(
+        "{}-{}",
+        String::from_utf8_lossy(first),
+        String::from_utf8_lossy(second)
+    ))
+}
+
+fn client_name(input: Option<&str>) -> String {
+    match input.map(str::trim).filter(|name| !name.is_empty()) {
+        Some(name) => name.chars().take(100).collect(),
+        None => DEFAULT_CLIENT_NAME.to_string(),
+    }
+}
+
+pub async fn start_login(
+    pool: &PgPool,
+    app_base_url: &str,
+    requested_name: Option<&str>,
+) -> Result<CliLoginStart, AppError> {
+    db::cli_credentials::delete_stale_login_requests(pool).await?;
+
+    let device_code = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+    let user_code = user_code_from_bytes(Uuid::new_v4().as_bytes());
+    let expires_at = Utc::now() + LOGIN_REQUEST_TTL;
+    db::cli_credentials::create_login_request(
+        pool,
+        &hash_token(&device_code),
+        &user_code,
+        &client_name(requested_name),
+        expires_at,
+    )
+    .await?;
+
+    Ok(CliLoginStart {
+        verification_url: format!(
+            "{}/cli?code={user_code}",
+            app_base_url.trim_end_matches('/')
+        ),
+        device_code,
+        user_code,
+        expires_at,
+        interval_seconds: POLL_INTERVAL_SECONDS,
+    })
+}
+
+/// Reports a login's progress. The first poll after approval issues the
+/// credential and consumes the request; a denial is likewise reported once.
+pub async fn poll_login(
+    pool: &PgPool,
+    device_code: &str,
+) -> Result<CliLoginPollResponse, AppError> {
+    let mut tx = pool.begin().await?;
+    let request = db::cli_credentials::find_by_device_code(&mut tx, &hash_token(device_code))
+        .await?
+        .ok_or_else(|| AppError::NotFound("Login request not found".to_string()))?;
+
+    let without_credential = |status| CliLoginPollResponse {
+        status,
+        credential: None,
+    };
+    let response = match (request.status.as_str(), request.user_id) {
+        ("approved", Some(user_id)) => {
+            let user = db::users::find_by_id(pool, user_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+            let token = generate_token();
+            let expires_at = Utc::now() + CREDENTIAL_TTL;
+            let id = db::cli_credentials::create_credential(
+                &mut tx,
+                user_id,
+                &request.client_name,
+                &hash_token(&token),
+                expires_at,
+            )
+            .await?;
+            db::cli_credentials::delete_login_request(&mut tx, request.id).await?;
+
+            CliLoginPollResponse {
+                status: CliLoginStatus::Approved,
+                credential: Some(IssuedCliCredential {
+                    id,
+                    token,
+                    expires_at,
+                    user_email: user.email,
+                }),
+            }
+        }
+        ("denied", _) => {
+            db::cli_credentials::delete_login_request(&mut tx, request.id).await?;
+            without_credential(CliLoginStatus::Denied)
+        }
+        _ if request.expires_at <= Utc::now() => without_credential(CliLoginStatus::Expired),
+        _ => without_credential(CliLoginStatus::Pending),
+    };
+    tx.commit().await?;
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_codes_normalize_from_loose_input() {
+        let code = user_code_from_bytes(Uuid::new_v4().as_bytes());
+        assert_eq!(code.len(), 9);
+        assert_eq!(normalize_user_code(&code), Some(code.clone()));
+        assert_eq!(
+            normalize_user_code(&code.to_lowercase().replace('-', " ")),
+            Some(code)
+        );
+
+        assert_eq!(normalize_user_code("WDJB-MJH"), None);
+        assert_eq!(normalize_user_code("WDJB-MJH0"), None);
+    }
+
+    #[test]
+    fn tokens_carry_the_prefix_and_hash_deterministically() {
+        let token = generate_token();
+        assert!(token.starts_with(CLI_TOKEN_PREFIX));
+        assert_eq!(token.len(), CLI_TOKEN_PREFIX.len() + 64);
+        assert_eq!(hash_token(&token), hash_token(&token));
+        assert_ne!(hash_token(&token), hash_token(&generate_token()));
+    }
+
+    #[test]
+    fn client_name_defaults_and_truncates() {
+        assert_eq!(client_name(None), DEFAULT_CLIENT_NAME);
+        assert_eq!(client_name(Some("  ")), DEFAULT_CLIENT_NAME);
+        assert_eq!(client_name(Some(" laptop ")), "laptop");
+        assert_eq!(client_name(Some(&"x".repeat(150))).len(), 100);
+    }
+}
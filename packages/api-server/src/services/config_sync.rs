@@ -0,0 +1,860 @@
+//! Diffs a declarative [`ConfigSpec`] against an organization's service
+//! groups, services, monitors and webhooks, and applies the difference in
+//! one transaction.
+
+use std::collections::{HashMap, HashSet};
+
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use shared::enums::StatusChangeSource;
+use shared::error::AppError;
+use shared::models::config_sync::{
+    ConfigAction, ConfigChange, ConfigPlan, ConfigPlanSummary, ConfigResource, ConfigSpec,
+    MonitorSpec, ServiceGroupSpec, ServiceSpec, WebhookSpec,
+};
+use shared::models::monitor::{normalize_monitor_config, Monitor};
+use shared::models::service::Service;
+use shared::models::service_group::ServiceGroup;
+
+use crate::db;
+use crate::db::config_sync::ManagedWebhook;
+
+/// What an organization currently has, as far as a spec can describe it.
+#[derive(Debug, Default)]
+pub struct CurrentConfig {
+    pub groups: Vec<ServiceGroup>,
+    pub services: Vec<Service>,
+    pub monitors: Vec<Monitor>,
+    pub webhooks: Vec<ManagedWebhook>,
+}
+
+#[derive(Debug, Clone)]
+enum Operation {
+    CreateGroup(ServiceGroupSpec),
+    UpdateGroup(Uuid, ServiceGroupSpec),
+    DeleteGroup(Uuid),
+    CreateService(ServiceSpec),
+    UpdateService(Uuid, ServiceSpec),
+    DeleteService(Uuid),
+    CreateMonitor { service: String, spec: MonitorSpec },
+    UpdateMonitor(Uuid, MonitorSpec),
+    DeleteMonitor(Uuid),
+    CreateWebhook(WebhookSpec),
+    UpdateWebhook(Uuid, WebhookSpec),
+    DeleteWebhook(Uuid),
+}
+
+#[derive(Debug)]
+pub struct SyncPlan {
+    pub plan: ConfigPlan,
+    /// Monitors that count towards the plan limit once the plan is applied.
+    pub monitors_after: i64,
+    operations: Vec<Operation>,
+}
+
+pub async fn load(conn: &mut PgConnection, org_id: Uuid) -> Result<CurrentConfig, AppError> {
+    Ok(CurrentConfig {
+        groups: db::config_sync::list_groups(conn, org_id).await?,
+        services: db::config_sync::list_services(conn, org_id).await?,
+        monitors: db::config_sync::list_monitors(conn, org_id).await?,
+        webhooks: db::config_sync::list_webhooks(conn, org_id).await?,
+    })
+}
+
+pub async fn plan(
+    pool: &PgPool,
+    org_id: Uuid,
+    spec: &ConfigSpec,
+    prune: bool,
+) -> Result<SyncPlan, AppError> {
+    let mut conn = pool.acquire().await?;
+    let current = load(&mut conn, org_id).await?;
+    diff(&current, spec, prune)
+}
+
+/// Applies `expected` if the organization still diffs to exactly those
+/// changes; anything edited since it was planned is a conflict.
+pub async fn apply(
+    pool: &PgPool,
+    org_id: Uuid,
+    spec: &ConfigSpec,
+    expected: &ConfigPlan,
+) -> Result<ConfigPlan, AppError> {
+    let mut tx = pool.begin().await?;
+    db::config_sync::lock_org(&mut tx, org_id).await?;
+    let current = load(&mut tx, org_id).await?;
+    let sync = diff(&current, spec, expected.prune)?;
+    if sync.plan.changes != expected.changes {
+        return Err(AppError::Conflict(
+            "The organization changed while the plan was applied; plan again".to_string(),
+        ));
+    }
+
+    let mut groups: HashMap<String, Uuid> = current
+        .groups
+        .iter()
+        .map(|group| (group.name.clone(), group.id))
+        .collect();
+    let mut services: HashMap<String, Uuid> = current
+        .services
+        .iter()
+        .map(|service| (service.name.clone(), service.id))
+        .collect();
+    let mut services_changed = false;
+
+    for operation in &sync.operations {
+        match operation {
+            Operation::CreateGroup(group) => {
+                let id = db::config_sync::create_group(&mut tx, org_id, group).await?;
+                groups.insert(group.name.clone(), id);
+            }
+            Operation::UpdateGroup(id, group) => {
+                db::config_sync::update_group(&mut tx, *id, group).await?;
+            }
+            Operation::DeleteGroup(id) => {
+                db::config_sync::delete_group(&mut tx, *id).await?;
+                services_changed = true;
+            }
+            Operation::CreateService(service) => {
+                let group = resolve_group(&groups, service);
+                let id = db::config_sync::create_service(&mut tx, org_id, service, group).await?;
+                services.insert(service.name.clone(), id);
+                services_changed = true;
+            }
+            Operation::UpdateService(id, service) => {
+                let group = resolve_group(&groups, service);
+                db::config_sync::update_service(&mut tx, *id, service, group).await?;
+                services_changed = true;
+            }
+            Operation::DeleteService(id) => {
+                db::config_sync::delete_service(&mut tx, *id).await?;
+                services_changed = true;
+            }
+            Operation::CreateMonitor { service, spec } => {
+                let service_id = *services.get(service).ok_or_else(|| {
+                    AppError::Internal(anyhow::anyhow!("Service '{service}' was not created"))
+                })?;
+                db::config_sync::create_monitor(&mut tx, org_id, service_id, spec).await?;
+            }
+            Operation::UpdateMonitor(id, spec) => {
+                db::config_sync::update_monitor(&mut tx, *id, spec).await?;
+            }
+            Operation::DeleteMonitor(id) => {
+                db::config_sync::delete_monitor(&mut tx, *id).await?;
+            }
+            Operation::CreateWebhook(webhook) => {
+                db::config_sync::create_webhook(&mut tx, org_id, webhook).await?;
+            }
+            Operation::UpdateWebhook(id, webhook) => {
+                db::config_sync::update_webhook(&mut tx, *id, webhook).await?;
+            }
+            Operation::DeleteWebhook(id) => {
+                db::config_sync::delete_webhook(&mut tx, *id).await?;
+            }
+        }
+    }
+
+    if services_changed {
+        db::status_history::record(&mut *tx, org_id, StatusChangeSource::Manual).await?;
+        db::service_dependencies::refresh_upstream_impact(&mut *tx, org_id).await?;
+    }
+    tx.commit().await?;
+
+    Ok(ConfigPlan {
+        applied: true,
+        ..sync.plan
+    })
+}
+
+fn resolve_group<'a>(
+    groups: &HashMap<String, Uuid>,
+    service: &'a ServiceSpec,
+) -> Option<(Uuid, &'a str)> {
+    let name = service.group.as_deref()?;
+    groups.get(name).map(|id| (*id, name))
+}
+
+/// Computes the changes that turn `current` into `spec`. Without `prune`,
+/// resources missing from the spec are left alone and only counted.
+pub fn diff(current: &CurrentConfig, spec: &ConfigSpec, prune: bool) -> Result<SyncPlan, AppError> {
+    let spec = normalize_spec(spec);
+    let mut builder = PlanBuilder {
+        prune,
+        changes: Vec::new(),
+        operations: Vec::new(),
+        unmanaged: 0,
+    };
+
+    let declared_groups = unique_names(
+        spec.service_groups.iter().map(|group| group.name.as_str()),
+        "service group",
+    )?;
+    unique_names(
+        spec.services.iter().map(|service| service.name.as_str()),
+        "service",
+    )?;
+    unique_names(
+        spec.webhooks.iter().map(|webhook| webhook.name.as_str()),
+        "webhook",
+    )?;
+    for service in &spec.services {
+        if let Some(group) = service.group.as_deref() {
+            if !declared_groups.contains(group) {
+                return Err(AppError::Validation(format!(
+                    "Service '{}' uses service group '{group}', which the spec does not declare",
+                    service.name
+                )));
+            }
+        }
+    }
+
+    let existing_groups = index_by_name(&current.groups, |group| &group.name, "service group")?;
+    let existing_services = index_by_name(&current.services, |service| &service.name, "service")?;
+    let existing_webhooks = index_by_name(&current.webhooks, |webhook| &webhook.name, "webhook")?;
+    let group_names: HashMap<Uuid, &str> = current
+        .groups
+        .iter()
+        .map(|group| (group.id, group.name.as_str()))
+        .collect();
+
+    for group in &spec.service_groups {
+        match existing_groups.get(group.name.as_str()) {
+            None => builder.push(
+                ConfigAction::Create,
+                ConfigResource::ServiceGroup,
+                &group.name,
+                None,
+                Vec::new(),
+                Operation::CreateGroup(group.clone()),
+            ),
+            Some(existing) => {
+                let fields = changed_fields([
+                    ("description", existing.description != group.description),
+                    (
+                        "collapsed_by_default",
+                        existing.collapsed_by_default != group.collapsed_by_default,
+                    ),
+                    ("status_rule", existing.status_rule != group.status_rule),
+                ]);
+                if !fields.is_empty() {
+                    builder.push(
+                        ConfigAction::Update,
+                        ConfigResource::ServiceGroup,
+                        &group.name,
+                        Some(existing.id),
+                        fields,
+                        Operation::UpdateGroup(existing.id, group.clone()),
+                    );
+                }
+            }
+        }
+    }
+
+    for service in &spec.services {
+        match existing_services.get(service.name.as_str()) {
+            None => builder.push(
+                ConfigAction::Create,
+                ConfigResource::Service,
+                &service.name,
+                None,
+                Vec::new(),
+                Operation::CreateService(service.clone()),
+            ),
+            Some(existing) => {
+                let current_group = existing
+                    .group_id
+                    .and_then(|id| group_names.get(&id).copied());
+                let fields = changed_fields([
+                    ("description", existing.description != service.description),
+                    ("group", current_group != service.group.as_deref()),
+                    ("is_visible", existing.is_visible != service.is_visible),
+                ]);
+                if !fields.is_empty() {
+                    builder.push(
+                        ConfigAction::Update,
+                        ConfigResource::Service,
+                        &service.name,
+                        Some(existing.id),
+                        fields,
+                        Operation::UpdateService(existing.id, service.clone()),
+                    );
+                }
+            }
+        }
+    }
+
+    let mut monitors_by_service: HashMap<Uuid, Vec<&Monitor>> = HashMap::new();
+    for monitor in &current.monitors {
+        monitors_by_service
+            .entry(monitor.service_id)
+            .or_default()
+            .push(monitor);
+    }
+    let mut monitors_after = 0_i64;
+    for service in &spec.services {
+        let existing = existing_services
+            .get(service.name.as_str())
+            .and_then(|existing| monitors_by_service.remove(&existing.id))
+            .unwrap_or_default();
+        monitors_after += diff_monitors(&mut builder, service, existing);
+    }
+
+    for webhook in &spec.webhooks {
+        match existing_webhooks.get(webhook.name.as_str()) {
+            None => builder.push(
+                ConfigAction::Create,
+                ConfigResource::Webhook,
+                &webhook.name,
+                None,
+                Vec::new(),
+                Operation::CreateWebhook(webhook.clone()),
+            ),
+            Some(existing) => {
+                let fields = changed_fields([
+                    ("url", existing.url != webhook.url),
+                    ("secret", existing.secret != webhook.secret),
+                    (
+                        "event_types",
+                        sorted(&existing.event_types) != sorted(&webhook.event_types),
+                    ),
+                    ("is_enabled", existing.is_enabled != webhook.is_enabled),
+                ]);
+                if !fields.is_empty() {
+                    builder.push(
+                        ConfigAction::Update,
+                        ConfigResource::Webhook,
+                        &webhook.name,
+                        Some(existing.id),
+                        fields,
+                        Operation::UpdateWebhook(existing.id, webhook.clone()),
+                    );
+                }
+            }
+        }
+    }
+
+    // Everything below is only deleted when pruning. Monitors of services
+    // that are themselves going away are listed so the plan shows them.
+    let declared_services: HashSet<&str> = spec
+        .services
+        .iter()
+        .map(|service| service.name.as_str())
+        .collect();
+    for service in &current.services {
+        if declared_services.contains(service.name.as_str()) {
+            continue;
+        }
+        let mut seen_per_type: HashMap<&str, usize> = HashMap::new();
+        for monitor in monitors_by_service.remove(&service.id).unwrap_or_default() {
+            let position = seen_per_type
+                .entry(monitor.monitor_type.as_str())
+                .or_default();
+            *position += 1;
+            if !builder.delete(
+                ConfigResource::Monitor,
+                &monitor_label(&service.name, monitor.monitor_type.as_str(), *position),
+                monitor.id,
+                Operation::DeleteMonitor(monitor.id),
+            ) && monitor.disabled_reason.is_none()
+            {
+                monitors_after += 1;
+            }
+        }
+        builder.delete(
+            ConfigResource::Service,
+            &service.name,
+            service.id,
+            Operation::DeleteService(service.id),
+        );
+    }
+    for group in &current.groups {
+        if !declared_groups.contains(group.name.as_str()) {
+            builder.delete(
+                ConfigResource::ServiceGroup,
+                &group.name,
+                group.id,
+                Operation::DeleteGroup(group.id),
+            );
+        }
+    }
+    let declared_webhooks: HashSet<&str> = spec
+        .webhooks
+        .iter()
+        .map(|webhook| webhook.name.as_str())
+        .collect();
+    for webhook in &current.webhooks {
+        if !declared_webhooks.contains(webhook.name.as_str()) {
+            builder.delete(
+                ConfigResource::Webhook,
+                &webhook.name,
+                webhook.id,
+                Operation::DeleteWebhook(webhook.id),
+            );
+        }
+    }
+
+    Ok(builder.finish(monitors_after))
+}
+
+/// Pairs a service's declared monitors with its existing ones of the same
+/// type in creation order. Returns how many of its monitors will count
+/// towards the plan limit.
+fn diff_monitors(builder: &mut PlanBuilder, service: &ServiceSpec, existing: Vec<&Monitor>) -> i64 {
+    let mut counted = 0;
+    let mut existing_by_type: HashMap<&str, Vec<&Monitor>> = HashMap::new();
+    for monitor in existing {
+        existing_by_type
+            .entry(monitor.monitor_type.as_str())
+            .or_default()
+            .push(monitor);
+    }
+    let mut seen_per_type: HashMap<&str, usize> = HashMap::new();
+
+    for spec in &service.monitors {
+        let monitor_type = spec.monitor_type.as_str();
+        let position = seen_per_type.entry(monitor_type).or_default();
+        let label = monitor_label(&service.name, monitor_type, *position + 1);
+        let existing = existing_by_type
+            .get(monitor_type)
+            .and_then(|monitors| monitors.get(*position))
+            .copied();
+        *position += 1;
+
+        let Some(existing) = existing else {
+            builder.push(
+                ConfigAction::Create,
+                ConfigResource::Monitor,
+                &label,
+                None,
+                Vec::new(),
+                Operation::CreateMonitor {
+                    service: service.name.clone(),
+                    spec: spec.clone(),
+                },
+            );
+            counted += 1;
+            continue;
+        };
+
+        let config = normalize_monitor_config(spec.monitor_type, spec.config.clone());
+        let fields = changed_fields([
+            ("config", existing.config != config),
+            (
+                "interval_seconds",
+                existing.interval_seconds != spec.interval_seconds,
+            ),
+            ("timeout_ms", existing.timeout_ms != spec.timeout_ms),
+            (
+                "failure_threshold",
+                existing.failure_threshold != spec.failure_threshold,
+            ),
+            (
+                "recovery_threshold",
+                existing.recovery_threshold != spec.recovery_threshold,
+            ),
+            ("is_active", existing.is_active != spec.is_active),
+        ]);
+        let reenabled = !fields.is_empty() && spec.is_active;
+        if existing.disabled_reason.is_none() || reenabled {
+            counted += 1;
+        }
+        if !fields.is_empty() {
+            builder.push(
+                ConfigAction::Update,
+                ConfigResource::Monitor,
+                &label,
+                Some(existing.id),
+                fields,
+                Operation::UpdateMonitor(existing.id, spec.clone()),
+            );
+        }
+    }
+
+    for (monitor_type, monitors) in existing_by_type {
+        let declared = seen_per_type.get(monitor_type).copied().unwrap_or_default();
+        for (index, monitor) in monitors.into_iter().enumerate().skip(declared) {
+            if !builder.delete(
+                ConfigResource::Monitor,
+                &monitor_label(&service.name, monitor_type, index + 1),
+                monitor.id,
+                Operation::DeleteMonitor(monitor.id),
+            ) && monitor.disabled_reason.is_none()
+            {
+                counted += 1;
+            }
+        }
+    }
+
+    counted
+}
+
+struct PlanBuilder {
+    prune: bool,
+    changes: Vec<ConfigChange>,
+    operations: Vec<Operation>,
+    unmanaged: usize,
+}
+
+impl PlanBuilder {
+    fn push(
+        &mut self,
+        action: ConfigAction,
+        resource: ConfigResource,
+        name: &str,
+        id: Option<Uuid>,
+        fields: Vec<String>,
+        operation: Operation,
+    ) {
+        self.changes.push(ConfigChange {
+            action,
+            resource,
+            name: name.to_string(),
+            id,
+            fields,
+        });
+        self.operations.push(operation);
+    }
+
+    /// Plans a delete when pruning and reports whether it did.
+    fn delete(
+        &mut self,
+        resource: ConfigResource,
+        name: &str,
+        id: Uuid,
+        operation: Operation,
+    ) -> bool {
+        if !self.prune {
+            self.unmanaged += 1;
+            return false;
+        }
+        self.push(
+            ConfigAction::Delete,
+            resource,
+            name,
+            Some(id),
+            Vec::new(),
+            operation,
+        );
+        true
+    }
+
+    fn finish(self, monitors_after: i64) -> SyncPlan {
+        let count = |action| {
+            self.changes
+                .iter()
+                .filter(|change| change.action == action)
+                .count()
+        };
+        let summary = ConfigPlanSummary {
+            create: count(ConfigAction::Create),
+            update: count(ConfigAction::Update),
+            delete: count(ConfigAction::Delete),
+            unmanaged: self.unmanaged,
+        };
+
+        SyncPlan {
+            plan: ConfigPlan {
+                applied: false,
+                prune: self.prune,
+                summary,
+                changes: self.changes,
+            },
+            monitors_after,
+            operations: self.operations,
+        }
+    }
+}
+
+fn monitor_label(service: &str, monitor_type: &str, position: usize) -> String {
+    format!("{service} / {monitor_type} #{position}")
+}
+
+fn changed_fields<const N: usize>(fields: [(&str, bool); N]) -> Vec<String> {
+    fields
+        .into_iter()
+        .filter(|(_, changed)| *changed)
+        .map(|(field, _)| field.to_string())
+        .collect()
+}
+
+fn sorted(values: &[String]) -> Vec<&str> {
+    let mut values: Vec<&str> = values.iter().map(String::as_str).collect();
+    values.sort_unstable();
+    values
+}
+
+fn unique_names<'a>(
+    names: impl Iterator<Item = &'a str>,
+    resource: &str,
+) -> Result<HashSet<&'a str>, AppError> {
+    let mut seen = HashSet::new();
+    for name in names {
+        if !seen.insert(name) {
+            return Err(AppError::Validation(format!(
+                "The spec declares {resource} '{name}' more than once"
+            )));
+        }
+    }
+    Ok(seen)
+}
+
+/// Specs match existing resources by name, so names must not be ambiguous
+/// on the organization either.
+fn index_by_name<'a, T>(
+    items: &'a [T],
+    name: impl Fn(&T) -> &String,
+    resource: &str,
+) -> Result<HashMap<&'a str, &'a T>, AppError> {
+    let mut index = HashMap::new();
+    for item in items {
+        if index.insert(name(item).as_str(), item).is_some() {
+            return Err(AppError::Conflict(format!(
+                "More than one {resource} is named '{}'; rename one before syncing",
+                name(item)
+            )));
+        }
+    }
+    Ok(index)
+}
+
+fn normalize_spec(spec: &ConfigSpec) -> ConfigSpec {
+    fn text(value: &Option<String>) -> Option<String> {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(ToOwned::to_owned)
+    }
+
+    let mut spec = spec.clone();
+    for group in &mut spec.service_groups {
+        group.name = group.name.trim().to_string();
+        group.description = text(&group.description);
+    }
+    for service in &mut spec.services {
+        service.name = service.name.trim().to_string();
+        service.description = text(&service.description);
+        service.group = text(&service.group);
+    }
+    for webhook in &mut spec.webhooks {
+        webhook.name = webhook.name.trim().to_string();
+    }
+    spec
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use serde_json::json;
+    use shared::enums::{GroupStatusRule, MonitorType, ServiceStatus};
+
+    use super::*;
+
+    fn group(name: &str) -> ServiceGroup {
+        ServiceGroup {
+            id: Uuid::new_v4(),
+            org_id: Uuid::nil(),
+            name: name.to_string(),
+            description: None,
+            display_order: 0,
+            collapsed_by_default: false,
+            status_rule: GroupStatusRule::WorstOf,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn service(name: &str, group: Option<&ServiceGroup>) -> Service {
+        Service {
+            id: Uuid::new_v4(),
+            org_id: Uuid::nil(),
+            name: name.to_string(),
+            description: None,
+            current_status: ServiceStatus::Operational,
+            display_order: 0,
+            group_name: group.map(|group| group.name.clone()),
+            is_visible: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            upstream_cause_service_id: None,
+            group_id: group.map(|group| group.id),
+        }
+    }
+
+    fn monitor(service: &Service, url: &str) -> Monitor {
+        Monitor {
+            id: Uuid::new_v4(),
+            service_id: service.id,
+            org_id: Uuid::nil(),
+            monitor_type: MonitorType::Http,
+            config: json!({ "type": "http", "url": url }),
+            interval_seconds: 60,
+            timeout_ms: 10_000,
+            failure_threshold: 3,
+            is_active: true,
+            disabled_reason: None,
+            consecutive_failures: 0,
+            recovery_threshold: None,
+            consecutive_successes: 0,
+            last_checked_at: None,
+            last_response_time_ms: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn spec(value: serde_json::Value) -> ConfigSpec {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn current() -> CurrentConfig {
+        let core = group("Core");
+        let api = service("API", Some(&core));
+        let legacy = service("Legacy", None);
+        CurrentConfig {
+            monitors: vec![
+                monitor(&api, "https://api.example.com/health"),
+                monitor(&legacy, "https://legacy.example.com"),
+            ],
+            groups: vec![core],
+            services: vec![api, legacy],
+            webhooks: Vec::new(),
+        }
+    }
+
+    fn summary(plan: &SyncPlan) -> (usize, usize, usize, usize) {
+        let summary = &plan.plan.summary;
+        (
+            summary.create,
+            summary.update,
+            summary.delete,
+            summary.unmanaged,
+        )
+    }
+
+    #[test]
+    fn matching_spec_plans_no_changes() {
+        let plan = diff(
+            &current(),
+            &spec(json!({
+                "service_groups": [{ "name": "Core" }],
+                "services": [
+                    { "name": "API", "group": "Core", "monitors": [
+                        { "type": "http", "config": { "url": "https://api.example.com/health" } }
+                    ]},
+                    { "name": "Legacy", "monitors": [
+                        { "type": "http", "config": { "url": "https://legacy.example.com" } }
+                    ]}
+                ]
+            })),
+            true,
+        )
+        .unwrap();
+
+        assert!(plan.plan.changes.is_empty());
+        assert_eq!(plan.monitors_after, 2);
+    }
+
+    #[test]
+    fn plans_updates_creates_and_leaves_unmanaged_without_prune() {
+        let plan = diff(
+            &current(),
+            &spec(json!({
+                "service_groups": [{ "name": "Core", "status_rule": "majority" }],
+                "services": [{ "name": "API", "monitors": [
+                    { "type": "http", "config": { "url": "https://api.example.com/v2" },
+                      "interval_seconds": 30 },
+                    { "type": "tcp", "config": { "host": "db", "port": 5432 } }
+                ]}]
+            })),
+            false,
+        )
+        .unwrap();
+
+        let changes: Vec<_> = plan
+            .plan
+            .changes
+            .iter()
+            .map(|change| (change.action, change.name.as_str(), change.fields.clone()))
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                (
+                    ConfigAction::Update,
+                    "Core",
+                    vec!["status_rule".to_string()]
+                ),
+                (ConfigAction::Update, "API", vec!["group".to_string()]),
+                (
+                    ConfigAction::Update,
+                    "API / http #1",
+                    vec!["config".to_string(), "interval_seconds".to_string()]
+                ),
+                (ConfigAction::Create, "API / tcp #1", Vec::new()),
+            ]
+        );
+        // The Legacy service and its monitor stay and still count.
+        assert_eq!(summary(&plan), (1, 3, 0, 2));
+        assert_eq!(plan.monitors_after, 3);
+    }
+
+    #[test]
+    fn prune_deletes_undeclared_resources() {
+        let plan = diff(
+            &current(),
+            &spec(json!({ "services": [{ "name": "API" }] })),
+            true,
+        )
+        .unwrap();
+
+        let deletes: Vec<_> = plan
+            .plan
+            .changes
+            .iter()
+            .filter(|change| change.action == ConfigAction::Delete)
+            .map(|change| (change.resource, change.name.as_str()))
+            .collect();
+        assert_eq!(
+            deletes,
+            [
+                (ConfigResource::Monitor, "API / http #1"),
+                (ConfigResource::Monitor, "Legacy / http #1"),
+                (ConfigResource::Service, "Legacy"),
+                (ConfigResource::ServiceGroup, "Core"),
+            ]
+        );
+        assert_eq!(plan.monitors_after, 0);
+    }
+
+    #[test]
+    fn rejects_duplicate_and_undeclared_names() {
+        let duplicate = diff(
+            &current(),
+            &spec(json!({ "services": [{ "name": "API" }, { "name": " API " }] })),
+            false,
+        );
+        assert!(matches!(duplicate, Err(AppError::Validation(_))));
+
+        let undeclared_group = diff(
+            &current(),
+            &spec(json!({ "services": [{ "name": "API", "group": "Core" }] })),
+            false,
+        );
+        assert!(matches!(undeclared_group, Err(AppError::Validation(_))));
+
+        let mut ambiguous = current();
+        ambiguous.services.push(service("API", None));
+        let result = diff(&ambiguous, &ConfigSpec::default(), false);
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+    }
+
+    #[test]
+    fn spec_rejects_unknown_fields() {
+        let result = serde_json::from_value::<ConfigSpec>(json!({
+            "services": [{ "name": "API", "visible": false }]
+        }));
+        assert!(result.is_err());
+    }
+}
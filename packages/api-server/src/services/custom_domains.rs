@@ -0,0 +1,430 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use hickory_resolver::proto::rr::{RData, RecordType};
+use hickory_resolver::TokioAsyncResolver;
+use sqlx::PgPool;
+
+use shared::email::{self, EmailBranding};
+use shared::enums::{CustomDomainVerificationMethod, MemberRole};
+use shared::error::AppError;
+use shared::i18n;
+
+use crate::config::Config;
+use crate::db;
+use crate::db::custom_domains::DueDomainCheck;
+use crate::services::email_notifications::rendered_notification;
+
+/// Label under which a domain publishes its verification token.
+pub const TXT_RECORD_LABEL: &str = "_statuspage-verification";
+
+const RECHECK_BATCH_SIZE: i64 = 50;
+const POLL_INTERVAL_SECS: u64 = 60;
+
+/// The host custom domains should point at, with the addresses it
+/// currently resolves to.
+pub struct ManagedTarget {
+    pub host: String,
+    pub addresses: Vec<String>,
+}
+
+impl ManagedTarget {
+    pub async fn resolve(config: &Config) -> Result<Self, AppError> {
+        let host = managed_host_target(config)?;
+        let addresses = resolve_host_addresses(&host).await;
+        Ok(Self { host, addresses })
+    }
+}
+
+/// What DNS currently says about a custom domain.
+#[derive(Debug, Clone, Default)]
+pub struct DomainCheck {
+    pub resolved_addresses: Vec<String>,
+    /// CNAME chain of the domain, normalized without the trailing dot.
+    pub cname_targets: Vec<String>,
+    /// TXT values published on `_statuspage-verification.<domain>`.
+    pub txt_values: Vec<String>,
+}
+
+impl DomainCheck {
+    /// The first method that proves the domain points at `target`. A CNAME
+    /// is preferred over an address match since it survives address
+    /// changes on the managed target; the TXT token covers proxied domains.
+    pub fn matched_method(
+        &self,
+        target: &ManagedTarget,
+        token: &str,
+    ) -> Option<CustomDomainVerificationMethod> {
+        let target_host = normalize_host(&target.host);
+        if self.cname_targets.contains(&target_host) {
+            return Some(CustomDomainVerificationMethod::Cname);
+        }
+        if self
+            .resolved_addresses
+            .iter()
+            .any(|address| target.addresses.contains(address))
+        {
+            return Some(CustomDomainVerificationMethod::ARecord);
+        }
+        if !token.is_empty() && self.txt_values.iter().any(|value| value.trim() == token) {
+            return Some(CustomDomainVerificationMethod::TxtToken);
+        }
+        None
+    }
+}
+
+/// Looks up the address, CNAME and TXT-token records of `domain`. Lookup
+/// failures come back as empty record sets.
+pub async fn check_domain(domain: &str) -> DomainCheck {
+    let resolved_addresses = resolve_host_addresses(domain).await;
+    let Ok(resolver) = TokioAsyncResolver::tokio_from_system_conf() else {
+        return DomainCheck {
+            resolved_addresses,
+            ..DomainCheck::default()
+        };
+    };
+
+    let cname_targets = match resolver.lookup(domain, RecordType::CNAME).await {
+        Ok(lookup) => lookup
+            .record_iter()
+            .filter_map(|record| match record.data() {
+                Some(RData::CNAME(name)) => Some(normalize_host(&name.0.to_ascii())),
+                _ => None,
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+
+    let txt_values = match resolver.txt_lookup(txt_record_name(domain)).await {
+        Ok(lookup) => lookup
+            .iter()
+            .map(|txt| {
+                txt.txt_data()
+                    .iter()
+                    .map(|part| String::from_utf8_lossy(part))
+                    .collect::<String>()
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+
+    DomainCheck {
+        resolved_addresses,
+        cname_targets,
+        txt_values,
+    }
+}
+
+pub fn txt_record_name(domain: &str) -> String {
+    format!("{TXT_RECORD_LABEL}.{}", normalize_host(domain))
+}
+
+pub fn spawn(pool: PgPool, config: Config) {
+    if config.custom_domain_recheck_interval_secs == 0 {
+        tracing::info!(
+            "CUSTOM_DOMAIN_RECHECK_INTERVAL_SECS is 0; custom domain re-verification disabled"
+        );
+        return;
+    }
+
+    tokio::spawn(async move {
+        tracing::info!(
+            interval_secs = config.custom_domain_recheck_interval_secs,
+            "Custom domain re-verification started"
+        );
+
+        loop {
+            if let Err(error) = recheck_due_domains(&pool, &config).await {
+                tracing::error!(error = %error, "custom domain re-verification failed");
+            }
+            tokio::time::sleep(Duration::from_secs(POLL_INTERVAL_SECS)).await;
+        }
+    });
+}
+
+/// Re-resolves verified domains whose last check is older than the
+/// configured interval and returns how many were checked.
+pub async fn recheck_due_domains(pool: &PgPool, config: &Config) -> Result<usize, AppError> {
+    let target = ManagedTarget::resolve(config).await?;
+    let interval_secs =
+        i64::try_from(config.custom_domain_recheck_interval_secs).unwrap_or(i64::MAX);
+    let due = db::custom_domains::claim_due(pool, interval_secs, RECHECK_BATCH_SIZE).await?;
+
+    for check in &due {
+        let result = async {
+            let method = check_domain(&check.domain)
+                .await
+                .matched_method(&target, &check.verification_token);
+            match method {
+                Some(method) => {
+                    if db::custom_domains::record_pass(pool, check, method).await? {
+                        tracing::info!(domain = %check.domain, "custom domain recovered");
+                        db::certificates::request_issuance(pool, &check.domain).await?;
+                        notify_owners(pool, config, check, DomainAlert::Recovered).await?;
+                    }
+                }
+                // A managed target that does not resolve from here says
+                // nothing about the customer's DNS, so it is not a miss.
+                None if target.addresses.is_empty() => {
+                    tracing::warn!(
+                        target = %target.host,
+                        domain = %check.domain,
+                        "managed target does not resolve; skipping custom domain check"
+                    );
+                }
+                None => {
+                    let misconfigured = db::custom_domains::record_miss(
+                        pool,
+                        check,
+                        config.custom_domain_miss_threshold,
+                        config.custom_domain_grace_hours,
+                    )
+                    .await?;
+                    if let Some(grace_ends_at) = misconfigured {
+                        tracing::warn!(domain = %check.domain, "custom domain misconfigured");
+                        notify_owners(
+                            pool,
+                            config,
+                            check,
+                            DomainAlert::Misconfigured { grace_ends_at },
+                        )
+                        .await?;
+                    }
+                }
+            }
+            Ok::<_, AppError>(())
+        }
+        .await;
+
+        if let Err(error) = result {
+            tracing::error!(domain = %check.domain, error = %error, "custom domain check failed");
+        }
+    }
+
+    Ok(due.len())
+}
+
+enum DomainAlert {
+    Misconfigured { grace_ends_at: DateTime<Utc> },
+    Recovered,
+}
+
+async fn notify_owners(
+    pool: &PgPool,
+    config: &Config,
+    check: &DueDomainCheck,
+    alert: DomainAlert,
+) -> Result<(), AppError> {
+    let Some(org) = db::organizations::find_by_id(pool, check.org_id).await? else {
+        return Ok(());
+    };
+    let page_name = match check.page_id {
+        Some(page_id) => db::status_pages::find_by_id(pool, page_id, org.id)
+            .await?
+            .map(|page| page.name)
+            .unwrap_or_else(|| org.name.clone()),
+        None => org.name.clone(),
+    };
+    let target = managed_host_target(config)?;
+    let settings_url = format!(
+        "{}/dashboard/{}/settings",
+        config.app_base_url.trim_end_matches('/'),
+        org.slug
+    );
+
+    let branding = EmailBranding {
+        org_name: org.name.clone(),
+        logo_url: org.logo_url.clone(),
+        brand_color: org.brand_color.clone(),
+        locale: org.locale,
+    };
+
+    let (notification_type, rendered) = match alert {
+        DomainAlert::Misconfigured { grace_ends_at } => (
+            "custom_domain_misconfigured",
+            email::custom_domain_misconfigured(
+                &branding,
+                &email::CustomDomainMisconfiguredEmail {
+                    domain: &check.domain,
+                    page_name: &page_name,
+                    target: &target,
+                    txt_record_name: &txt_record_name(&check.domain),
+                    failed_checks: config.custom_domain_miss_threshold.max(1),
+                    grace_ends_at: &i18n::format_timestamp(
+                        grace_ends_at,
+                        &org.timezone,
+                        org.locale,
+                    ),
+                    settings_url: &settings_url,
+                },
+            ),
+        ),
+        DomainAlert::Recovered => (
+            "custom_domain_recovered",
+            email::custom_domain_recovered(
+                &branding,
+                &email::CustomDomainRecoveredEmail {
+                    domain: &check.domain,
+                    page_name: &page_name,
+                    target: &target,
+                    settings_url: &settings_url,
+                },
+            ),
+        ),
+    };
+
+    let owners = db::members::find_by_org(pool, org.id)
+        .await?
+        .into_iter()
+        .filter(|member| member.role == MemberRole::Owner);
+    for owner in owners {
+        db::notification_logs::enqueue(
+            pool,
+            rendered_notification(
+                org.id,
+                notification_type,
+                "member",
+                &owner.user_email,
+                &rendered,
+            ),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+async fn resolve_host_addresses(host: &str) -> Vec<String> {
+    let Ok(addresses) = tokio::net::lookup_host((host, 80)).await else {
+        return Vec::new();
+    };
+
+    let mut unique = addresses
+        .map(|address| address.ip().to_string())
+        .collect::<Vec<_>>();
+    unique.sort();
+    unique.dedup();
+    unique
+}
+
+fn managed_host_target(config: &Config) -> Result<String, AppError> {
+    if let Some(host) = config
+        .statuspage_host
+        .as_ref()
+        .filter(|value| !value.is_empty())
+    {
+        return Ok(host.clone());
+    }
+
+    extract_host_from_url(&config.app_base_url).ok_or_else(|| {
+        AppError::Validation(
+            "Set STATUSPAGE_HOST or use a valid APP_BASE_URL before verifying a custom domain."
+                .to_string(),
+        )
+    })
+}
+
+fn extract_host_from_url(url: &str) -> Option<String> {
+    let without_scheme = url.split("://").nth(1).unwrap_or(url);
+    without_scheme
+        .split('/')
+        .next()
+        .and_then(|host| host.split(':').next())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(ToOwned::to_owned)
+}
+
+fn normalize_host(host: &str) -> String {
+    host.trim().trim_end_matches('.').to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target() -> ManagedTarget {
+        ManagedTarget {
+            host: "pages.statuspage.test".to_string(),
+            addresses: vec!["203.0.113.10".to_string()],
+        }
+    }
+
+    #[test]
+    fn extract_host_from_url_handles_scheme_and_port() {
+        assert_eq!(
+            extract_host_from_url("https://status.example.com:443/app"),
+            Some("status.example.com".to_string())
+        );
+        assert_eq!(
+            extract_host_from_url("localhost:3000"),
+            Some("localhost".to_string())
+        );
+    }
+
+    #[test]
+    fn cname_to_the_managed_target_wins_over_an_address_match() {
+        let check = DomainCheck {
+            resolved_addresses: vec!["203.0.113.10".to_string()],
+            cname_targets: vec![normalize_host("Pages.StatusPage.test.")],
+            txt_values: Vec::new(),
+        };
+
+        assert_eq!(
+            check.matched_method(&target(), "token"),
+            Some(CustomDomainVerificationMethod::Cname)
+        );
+    }
+
+    #[test]
+    fn shared_address_matches_as_a_record() {
+        let check = DomainCheck {
+            resolved_addresses: vec!["198.51.100.1".to_string(), "203.0.113.10".to_string()],
+            ..DomainCheck::default()
+        };
+
+        assert_eq!(
+            check.matched_method(&target(), "token"),
+            Some(CustomDomainVerificationMethod::ARecord)
+        );
+    }
+
+    #[test]
+    fn txt_token_covers_proxied_domains() {
+        let check = DomainCheck {
+            resolved_addresses: vec!["198.51.100.1".to_string()],
+            cname_targets: vec!["proxy.cdn.test".to_string()],
+            txt_values: vec!["unrelated".to_string(), " abc123 ".to_string()],
+        };
+
+        assert_eq!(
+            check.matched_method(&target(), "abc123"),
+            Some(CustomDomainVerificationMethod::TxtToken)
+        );
+        assert_eq!(check.matched_method(&target(), "other"), None);
+    }
+
+    #[test]
+    fn unresolvable_domain_has_no_method() {
+        assert_eq!(
+            DomainCheck::default().matched_method(&target(), "abc123"),
+            None
+        );
+        assert_eq!(
+            DomainCheck {
+                txt_values: vec![String::new()],
+                ..DomainCheck::default()
+            }
+            .matched_method(&target(), ""),
+            None
+        );
+    }
+
+    #[test]
+    fn txt_record_name_uses_the_verification_label() {
+        assert_eq!(
+            txt_record_name("Status.Example.com."),
+            "_statuspage-verification.status.example.com"
+        );
+    }
+}
@@ -14,8 +14,10 @@ const DOWNGRADE_GRACE_DAYS: i64 = 14;
 #[derive(Debug, Clone)]
 pub struct EnforcementResult {
     pub disabled_monitor_ids: Vec<uuid::Uuid>,
+    pub disabled_status_page_ids: Vec<uuid::Uuid>,
     pub blocked_custom_domain: bool,
     pub disabled_webhooks: bool,
+    pub canceled_sms_count: u64,
 }
 
 pub fn downgrade_lifecycle_for_plan_change(
@@ -85,6 +87,15 @@ pub async fn entitlement_violations(
     .bind(org.id)
     .fetch_one(pool)
     .await?;
+    let sms_recipient_count = db::subscribers::count_sms_recipients(pool, org.id).await?;
+    // The organization's own page counts towards the status page limit.
+    let status_page_count = db::status_pages::count_active_by_org(pool, org.id).await? + 1;
+    let custom_domain_count = db::status_pages::count_custom_domains(pool, org.id).await?
+        + i64::from(
+            org.custom_domain
+                .as_ref()
+                .is_some_and(|value| !value.trim().is_empty()),
+        );
 
     let mut violations = Vec::new();
 
@@ -102,16 +113,25 @@ pub async fn entitlement_violations(
         }
     }
 
-    if !target_plan.allows_custom_domain()
-        && org
-            .custom_domain
-            .as_ref()
-            .is_some_and(|value| !value.trim().is_empty())
-    {
+    if let Some(max_status_pages) = target_plan.max_status_pages() {
+        if status_page_count > max_status_pages {
+            violations.push(EntitlementViolation {
+                code: "status_page_limit".to_string(),
+                message: format!(
+                    "This organization has {} status pages, but the {} plan allows {}.",
+                    status_page_count, target_plan, max_status_pages
+                ),
+                current_count: Some(status_page_count),
+                allowed_count: Some(max_status_pages),
+            });
+        }
+    }
+
+    if !target_plan.allows_custom_domain() && custom_domain_count > 0 {
         violations.push(EntitlementViolation {
             code: "custom_domain".to_string(),
             message: "Custom domains are not included on the target plan.".to_string(),
-            current_count: Some(1),
+            current_count: Some(custom_domain_count),
             allowed_count: Some(0),
         });
     }
@@ -128,6 +148,18 @@ pub async fn entitlement_violations(
         });
     }
 
+    if !target_plan.allows_sms() && sms_recipient_count > 0 {
+        violations.push(EntitlementViolation {
+            code: "sms_notifications".to_string(),
+            message: format!(
+                "{} subscribers receive SMS alerts, but the target plan does not include SMS.",
+                sms_recipient_count
+            ),
+            current_count: Some(sms_recipient_count),
+            allowed_count: Some(0),
+        });
+    }
+
     Ok(violations)
 }
 
@@ -136,12 +168,18 @@ pub fn required_actions(violations: &[EntitlementViolation]) -> Vec<String> {
         .iter()
         .map(|violation| match violation.code.as_str() {
             "monitor_limit" => "Reduce active monitors before the grace period ends.".to_string(),
+            "status_page_limit" => {
+                "Delete extra status pages before the grace period ends; the newest ones are disabled otherwise.".to_string()
+            }
             "custom_domain" => {
                 "Remove the custom domain or upgrade back to a paid plan.".to_string()
             }
             "outbound_webhooks" => {
                 "Disable outbound webhooks or upgrade back to a paid plan.".to_string()
             }
+            "sms_notifications" => {
+                "SMS alerts stop when the downgrade takes effect; let phone subscribers know or upgrade back to a paid plan.".to_string()
+            }
             _ => violation.message.clone(),
         })
         .collect()
@@ -172,7 +210,23 @@ pub async fn enforce_now(pool: &PgPool, org: &Organization) -> Result<Enforcemen
         }
     };
 
+    // The primary page always stays, so one fewer additional page is kept.
+    let disabled_status_page_ids = match target_plan.max_status_pages() {
+        Some(limit) => db::status_pages::disable_excess_for_plan(pool, org.id, limit - 1).await?,
+        None => {
+            db::status_pages::restore_plan_limited(pool, org.id).await?;
+            Vec::new()
+        }
+    };
+
     let blocked_custom_domain = if target_plan.allows_custom_domain() {
+        db::status_pages::move_custom_domain_status(
+            pool,
+            org.id,
+            CustomDomainStatus::BlockedByPlan,
+            CustomDomainStatus::PendingVerification,
+        )
+        .await?;
         if org.custom_domain.is_some()
             && org.custom_domain_status == CustomDomainStatus::BlockedByPlan
         {
@@ -184,16 +238,32 @@ pub async fn enforce_now(pool: &PgPool, org: &Organization) -> Result<Enforcemen
             .await?;
         }
         false
-    } else if org.custom_domain.is_some() {
-        db::organizations::set_custom_domain_status(
-            pool,
-            org.id,
-            CustomDomainStatus::BlockedByPlan,
-        )
-        .await?;
-        true
     } else {
-        false
+        let mut blocked_pages = 0;
+        for status in [
+            CustomDomainStatus::Verified,
+            CustomDomainStatus::PendingVerification,
+            CustomDomainStatus::CertificateFailed,
+            CustomDomainStatus::CertificateExpired,
+            CustomDomainStatus::Misconfigured,
+        ] {
+            blocked_pages += db::status_pages::move_custom_domain_status(
+                pool,
+                org.id,
+                status,
+                CustomDomainStatus::BlockedByPlan,
+            )
+            .await?;
+        }
+        if org.custom_domain.is_some() {
+            db::organizations::set_custom_domain_status(
+                pool,
+                org.id,
+                CustomDomainStatus::BlockedByPlan,
+            )
+            .await?;
+        }
+        org.custom_domain.is_some() || blocked_pages > 0
     };
 
     let disabled_webhooks = if target_plan.allows_outbound_webhooks() {
@@ -204,12 +274,25 @@ pub async fn enforce_now(pool: &PgPool, org: &Organization) -> Result<Enforcemen
         true
     };
 
+    let canceled_sms_count = if target_plan.allows_sms() {
+        0
+    } else {
+        db::sms_logs::cancel_pending_for_org(
+            pool,
+            org.id,
+            "SMS alerts are not included on the current plan",
+        )
+        .await?
+    };
+
     db::organizations::complete_downgrade_enforcement(pool, org.id, target_plan).await?;
 
     Ok(EnforcementResult {
         disabled_monitor_ids,
+        disabled_status_page_ids,
         blocked_custom_domain,
         disabled_webhooks,
+        canceled_sms_count,
     })
 }
 
@@ -307,12 +390,18 @@ async fn maybe_queue_due_warning(
         );
         db::notification_logs::enqueue(
             pool,
-            org.id,
-            "downgrade_warning",
-            "billing_admin",
-            &recipient,
-            &subject,
-            &body,
+            db::notification_logs::NewNotification {
+                org_id: org.id,
+                notification_type: "downgrade_warning",
+                recipient_type: "billing_admin",
+                recipient_email: &recipient,
+                subject: &subject,
+                body_text: &body,
+                body_html: None,
+                list_unsubscribe_url: None,
+                subscriber_id: None,
+                bypass_digest: false,
+            },
         )
         .await?;
     }
@@ -341,7 +430,7 @@ fn next_warning_stage(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use shared::enums::{CustomDomainStatus, SubscriptionStatus};
+    use shared::enums::{CustomDomainStatus, Locale, SubscriptionStatus};
 
     fn org(plan: OrganizationPlan) -> Organization {
         Organization {
@@ -352,9 +441,14 @@ mod tests {
             logo_url: None,
             brand_color: "#000000".to_string(),
             timezone: "UTC".to_string(),
+            locale: Locale::En,
             custom_domain: None,
             custom_domain_verified_at: None,
             custom_domain_status: CustomDomainStatus::NotConfigured,
+            custom_domain_verification_token: "token".to_string(),
+            custom_domain_verification_method: None,
+            custom_domain_checked_at: None,
+            custom_domain_grace_ends_at: None,
             stripe_customer_id: None,
             stripe_subscription_id: None,
             subscription_status: SubscriptionStatus::Active,
@@ -368,6 +462,9 @@ mod tests {
             downgrade_grace_ends_at: None,
             downgrade_state: DowngradeState::None,
             downgrade_warning_stage: 0,
+            page_visibility: shared::enums::PageVisibility::Public,
+            page_allowed_email_domains: Vec::new(),
+            page_allowed_cidrs: Vec::new(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -430,10 +527,17 @@ mod tests {
                 current_count: Some(1),
                 allowed_count: Some(0),
             },
+            EntitlementViolation {
+                code: "status_page_limit".to_string(),
+                message: "x".to_string(),
+                current_count: Some(2),
+                allowed_count: Some(1),
+            },
         ]);
 
-        assert_eq!(actions.len(), 2);
+        assert_eq!(actions.len(), 3);
         assert!(actions[0].contains("Reduce active monitors"));
         assert!(actions[1].contains("Remove the custom domain"));
+        assert!(actions[2].contains("Delete extra status pages"));
     }
 }
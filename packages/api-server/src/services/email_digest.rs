@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use sqlx::PgPool;
+
+use shared::error::AppError;
+
+use crate::config::Config;
+use crate::db::notification_logs;
+use crate::services::email_notifications;
+
+pub fn spawn(pool: PgPool, config: Config) {
+    tokio::spawn(async move {
+        let interval = Duration::from_secs(config.email_digest_interval_secs.max(15));
+        loop {
+            if let Err(error) = process_due_digests(&pool, &config).await {
+                tracing::error!(error = %error, "subscriber digest processing failed");
+            }
+            tokio::time::sleep(interval).await;
+        }
+    });
+}
+
+pub async fn process_due_digests(pool: &PgPool, config: &Config) -> Result<usize, AppError> {
+    let canceled = notification_logs::cancel_orphaned_held(pool).await?;
+    if canceled > 0 {
+        tracing::info!(
+            canceled,
+            "Canceled held notifications for removed subscribers"
+        );
+    }
+
+    let due = notification_logs::find_due_digests(pool, config.email_dispatch_batch_size).await?;
+    let mut queued = 0;
+    for digest in due {
+        match email_notifications::queue_subscriber_digest(pool, &config.app_base_url, &digest)
+            .await
+        {
+            Ok(true) => queued += 1,
+            Ok(false) => {}
+            Err(error) => {
+                tracing::warn!(
+                    error = %error,
+                    subscriber_id = %digest.subscriber_id,
+                    "Failed to queue subscriber digest"
+                );
+            }
+        }
+    }
+
+    Ok(queued)
+}
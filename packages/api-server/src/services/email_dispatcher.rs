@@ -1,28 +1,29 @@
 use std::time::Duration;
 
 use lettre::message::Mailbox;
-use lettre::transport::smtp::authentication::Credentials;
-use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
 use sqlx::PgPool;
 
 use crate::config::Config;
 use crate::db::notification_logs::{self, NotificationFailureUpdate};
+use crate::services::email_transport::{self, OutgoingEmail};
 
 pub fn spawn(pool: PgPool, config: Config) {
-    let Some(smtp_host) = config.smtp_host.clone() else {
-        tracing::info!("SMTP is not configured; email dispatcher disabled");
-        return;
+    let transport = match email_transport::from_config(&config) {
+        Ok(Some(transport)) => transport,
+        Ok(None) => {
+            tracing::info!(
+                transport = %config.email_transport,
+                "Email transport is not configured; email dispatcher disabled"
+            );
+            return;
+        }
+        Err(error) => {
+            tracing::error!(error = %error, "Failed to configure email transport");
+            return;
+        }
     };
 
     tokio::spawn(async move {
-        let mailer = match build_mailer(&config, &smtp_host) {
-            Ok(mailer) => mailer,
-            Err(error) => {
-                tracing::error!(error = %error, "Failed to configure SMTP mailer");
-                return;
-            }
-        };
-
         let from = match config.email_from.parse::<Mailbox>() {
             Ok(from) => from,
             Err(error) => {
@@ -31,6 +32,8 @@ pub fn spawn(pool: PgPool, config: Config) {
             }
         };
 
+        tracing::info!(transport = transport.name(), "Email dispatcher started");
+
         let mut ticker =
             tokio::time::interval(Duration::from_secs(config.email_dispatch_interval_secs));
         ticker.tick().await;
@@ -66,33 +69,17 @@ pub fn spawn(pool: PgPool, config: Config) {
                             }
                         };
 
-                        let message = match Message::builder()
-                            .from(from.clone())
-                            .to(recipient)
-                            .subject(subject)
-                            .body(notification.body_text.clone())
-                        {
-                            Ok(message) => message,
-                            Err(error) => {
-                                let _ = notification_logs::mark_failed(
-                                    &pool,
-                                    notification.id,
-                                    NotificationFailureUpdate {
-                                        attempt_count: notification.attempt_count,
-                                        max_attempts: notification.max_attempts,
-                                        error_message: Some(&format!(
-                                            "Failed to build email message: {error}"
-                                        )),
-                                        next_retry_at: None,
-                                    },
-                                )
-                                .await;
-                                continue;
-                            }
+                        let email = OutgoingEmail {
+                            from: from.clone(),
+                            to: recipient,
+                            subject,
+                            text: notification.body_text.clone(),
+                            html: notification.body_html.clone(),
+                            list_unsubscribe_url: notification.list_unsubscribe_url.clone(),
                         };
 
-                        match mailer.send(message).await {
-                            Ok(_) => {
+                        match transport.send(&email).await {
+                            Ok(()) => {
                                 if let Err(error) =
                                     notification_logs::mark_sent(&pool, notification.id).await
                                 {
@@ -137,28 +124,6 @@ pub fn spawn(pool: PgPool, config: Config) {
     });
 }
 
-fn build_mailer(
-    config: &Config,
-    smtp_host: &str,
-) -> anyhow::Result<AsyncSmtpTransport<Tokio1Executor>> {
-    let builder = if config.smtp_secure {
-        AsyncSmtpTransport::<Tokio1Executor>::relay(smtp_host)?
-    } else {
-        AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(smtp_host)
-    };
-
-    let builder = builder.port(config.smtp_port);
-
-    let builder = match (&config.smtp_username, &config.smtp_password) {
-        (Some(username), Some(password)) => {
-            builder.credentials(Credentials::new(username.clone(), password.clone()))
-        }
-        _ => builder,
-    };
-
-    Ok(builder.build())
-}
-
 fn next_retry_at(attempt_count: i32, max_attempts: i32) -> Option<chrono::DateTime<chrono::Utc>> {
     notification_logs::next_retry_at(attempt_count, max_attempts)
 }
@@ -1,40 +1,41 @@
-use shared::enums::{IncidentImpact, IncidentStatus, ServiceStatus};
+use shared::email::{self, EmailBranding, RenderedEmail};
+use shared::enums::{IncidentImpact, IncidentStatus, Locale, ServiceStatus};
 use shared::error::AppError;
 
 use crate::db;
+use crate::db::notification_logs::{DueDigest, NewNotification};
+use crate::db::status_pages::PublicPage;
 
-struct PublicUrls {
-    base: String,
-    history: String,
+pub(crate) struct PublicUrls {
+    pub(crate) base: String,
+    pub(crate) history: String,
     verify_prefix: String,
-    unsubscribe_prefix: String,
+    pub(crate) unsubscribe_prefix: String,
 }
 
+/// Sends the confirmation link for a subscription to `page`, in the
+/// subscriber's chosen language when they picked one.
 pub async fn queue_subscription_verification(
     pool: &sqlx::PgPool,
-    org_id: uuid::Uuid,
     app_base_url: &str,
-    org_slug: &str,
-    org_name: &str,
+    page: &PublicPage,
     email: &str,
+    locale: Option<Locale>,
     verification_token: &str,
 ) -> Result<(), AppError> {
-    let public_urls = public_urls(pool, org_id, app_base_url, org_slug).await?;
+    let public_urls = public_urls(page, app_base_url);
     let verify_link = format!("{}{}", public_urls.verify_prefix, verification_token);
-
-    let subject = format!("Confirm your subscription to {org_name}");
-    let body = format!(
-        "You requested updates for {org_name}.\n\nConfirm your subscription:\n{verify_link}\n\nIf you did not request this, you can ignore this email."
-    );
+    let rendered = email::subscription_verification(&page_branding(page, locale), &verify_link);
 
     db::notification_logs::enqueue(
         pool,
-        org_id,
-        "subscriber_verification",
-        "subscriber",
-        email,
-        &subject,
-        &body,
+        rendered_notification(
+            page.org_id,
+            "subscriber_verification",
+            "subscriber",
+            email,
+            &rendered,
+        ),
     )
     .await
 }
@@ -43,7 +44,6 @@ pub async fn queue_incident_created(
     pool: &sqlx::PgPool,
     org_id: uuid::Uuid,
     app_base_url: &str,
-    org_slug: &str,
     title: &str,
     impact: IncidentImpact,
     affected_services: &[uuid::Uuid],
@@ -52,34 +52,45 @@ pub async fn queue_incident_created(
         return Ok(());
     }
 
-    let public_urls = public_urls(pool, org_id, app_base_url, org_slug).await?;
-    let subscribers = db::subscribers::find_verified_by_org(pool, org_id).await?;
+    let pages = db::status_pages::public_pages_by_id(pool, org_id).await?;
+    let subscribers =
+        db::subscribers::find_verified_for_services(pool, org_id, affected_services).await?;
     let services = service_names(pool, affected_services).await?;
 
     for subscriber in subscribers {
+        let Some(page) = pages.get(&subscriber.status_page_id) else {
+            continue;
+        };
+        let public_urls = public_urls(page, app_base_url);
+        let branding = page_branding(page, subscriber.locale);
         let unsubscribe_link = format!(
             "{}{}",
             public_urls.unsubscribe_prefix, subscriber.unsubscribe_token
         );
-        let subject = format!("New incident: {title}");
-        let affected = if services.is_empty() {
-            "n/a".to_string()
-        } else {
-            services.join(", ")
-        };
-        let body = format!(
-            "A new incident has been posted.\n\nTitle: {title}\nImpact: {}\nAffected services: {affected}\n\nFollow updates at:\n{}\n\nUnsubscribe:\n{unsubscribe_link}",
-            impact.as_str(),
-            public_urls.history,
+        let rendered = email::incident_created(
+            &branding,
+            &email::IncidentCreatedEmail {
+                title,
+                impact,
+                affected_services: &services,
+                history_url: &public_urls.history,
+                unsubscribe_url: &unsubscribe_link,
+            },
         );
         db::notification_logs::enqueue(
             pool,
-            org_id,
-            "incident_created",
-            "subscriber",
-            &subscriber.email,
-            &subject,
-            &body,
+            NewNotification {
+                list_unsubscribe_url: Some(&unsubscribe_link),
+                subscriber_id: Some(subscriber.id),
+                bypass_digest: impact.bypasses_digest(),
+                ..rendered_notification(
+                    org_id,
+                    "incident_created",
+                    "subscriber",
+                    &subscriber.email,
+                    &rendered,
+                )
+            },
         )
         .await?;
     }
@@ -87,13 +98,15 @@ pub async fn queue_incident_created(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn queue_incident_updated(
     pool: &sqlx::PgPool,
     org_id: uuid::Uuid,
     app_base_url: &str,
-    org_slug: &str,
     status: IncidentStatus,
+    impact: IncidentImpact,
     message: &str,
+    affected_services: &[uuid::Uuid],
 ) -> Result<(), AppError> {
     let event_key = if status == IncidentStatus::Resolved {
         "incident.resolved"
@@ -104,37 +117,48 @@ pub async fn queue_incident_updated(
         return Ok(());
     }
 
-    let public_urls = public_urls(pool, org_id, app_base_url, org_slug).await?;
-    let subscribers = db::subscribers::find_verified_by_org(pool, org_id).await?;
+    let pages = db::status_pages::public_pages_by_id(pool, org_id).await?;
+    let subscribers =
+        db::subscribers::find_verified_for_services(pool, org_id, affected_services).await?;
+    let notification_type = if status == IncidentStatus::Resolved {
+        "incident_resolved"
+    } else {
+        "incident_updated"
+    };
     for subscriber in subscribers {
+        let Some(page) = pages.get(&subscriber.status_page_id) else {
+            continue;
+        };
+        let public_urls = public_urls(page, app_base_url);
+        let branding = page_branding(page, subscriber.locale);
         let unsubscribe_link = format!(
             "{}{}",
             public_urls.unsubscribe_prefix, subscriber.unsubscribe_token
         );
-        let subject = if status == IncidentStatus::Resolved {
-            "Incident resolved".to_string()
-        } else {
-            format!("Incident update: {}", status.as_str())
-        };
-        let notification_type = if status == IncidentStatus::Resolved {
-            "incident_resolved"
-        } else {
-            "incident_updated"
-        };
-        let body = format!(
-            "An incident update has been posted.\n\nStatus: {}\nMessage: {}\n\nFollow updates at:\n{}\n\nUnsubscribe:\n{unsubscribe_link}",
-            status.as_str(),
-            message,
-            public_urls.history,
+        let rendered = email::incident_updated(
+            &branding,
+            &email::IncidentUpdatedEmail {
+                title: None,
+                status,
+                message,
+                history_url: &public_urls.history,
+                unsubscribe_url: &unsubscribe_link,
+            },
         );
         db::notification_logs::enqueue(
             pool,
-            org_id,
-            notification_type,
-            "subscriber",
-            &subscriber.email,
-            &subject,
-            &body,
+            NewNotification {
+                list_unsubscribe_url: Some(&unsubscribe_link),
+                subscriber_id: Some(subscriber.id),
+                bypass_digest: impact.bypasses_digest(),
+                ..rendered_notification(
+                    org_id,
+                    notification_type,
+                    "subscriber",
+                    &subscriber.email,
+                    &rendered,
+                )
+            },
         )
         .await?;
     }
@@ -142,11 +166,12 @@ pub async fn queue_incident_updated(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn queue_service_status_changed(
     pool: &sqlx::PgPool,
     org_id: uuid::Uuid,
     app_base_url: &str,
-    org_slug: &str,
+    service_id: uuid::Uuid,
     service_name: &str,
     old_status: ServiceStatus,
     new_status: ServiceStatus,
@@ -155,28 +180,43 @@ pub async fn queue_service_status_changed(
         return Ok(());
     }
 
-    let public_urls = public_urls(pool, org_id, app_base_url, org_slug).await?;
-    let subscribers = db::subscribers::find_verified_by_org(pool, org_id).await?;
+    let pages = db::status_pages::public_pages_by_id(pool, org_id).await?;
+    let subscribers =
+        db::subscribers::find_verified_for_services(pool, org_id, &[service_id]).await?;
     for subscriber in subscribers {
+        let Some(page) = pages.get(&subscriber.status_page_id) else {
+            continue;
+        };
+        let public_urls = public_urls(page, app_base_url);
+        let branding = page_branding(page, subscriber.locale);
         let unsubscribe_link = format!(
             "{}{}",
             public_urls.unsubscribe_prefix, subscriber.unsubscribe_token
         );
-        let subject = format!("Service status changed: {service_name}");
-        let body = format!(
-            "{service_name} changed from {} to {}.\n\nSee the latest status at:\n{}\n\nUnsubscribe:\n{unsubscribe_link}",
-            old_status.as_str(),
-            new_status.as_str(),
-            public_urls.base,
+        let rendered = email::service_status_changed(
+            &branding,
+            &email::ServiceStatusChangedEmail {
+                service_name,
+                old_status,
+                new_status,
+                status_url: &public_urls.base,
+                unsubscribe_url: &unsubscribe_link,
+            },
         );
         db::notification_logs::enqueue(
             pool,
-            org_id,
-            "service_status_changed",
-            "subscriber",
-            &subscriber.email,
-            &subject,
-            &body,
+            NewNotification {
+                list_unsubscribe_url: Some(&unsubscribe_link),
+                subscriber_id: Some(subscriber.id),
+                bypass_digest: false,
+                ..rendered_notification(
+                    org_id,
+                    "service_status_changed",
+                    "subscriber",
+                    &subscriber.email,
+                    &rendered,
+                )
+            },
         )
         .await?;
     }
@@ -184,6 +224,57 @@ pub async fn queue_service_status_changed(
     Ok(())
 }
 
+/// Emails every owner and admin about an auto-incident nobody has
+/// acknowledged yet.
+pub async fn queue_incident_escalation(
+    pool: &sqlx::PgPool,
+    app_base_url: &str,
+    org_slug: &str,
+    incident: &shared::models::incident::Incident,
+) -> Result<usize, AppError> {
+    let admins = db::members::find_by_org(pool, incident.org_id)
+        .await?
+        .into_iter()
+        .filter(|member| member.role != shared::enums::MemberRole::Member)
+        .collect::<Vec<_>>();
+    if admins.is_empty() {
+        return Ok(0);
+    }
+
+    let branding = branding(pool, incident.org_id).await?;
+    let incident_url = format!(
+        "{}/dashboard/{org_slug}/incidents/{}",
+        app_base_url.trim_end_matches('/'),
+        incident.id
+    );
+    let rendered = email::incident_escalation(
+        &branding,
+        &email::IncidentEscalationEmail {
+            title: &incident.title,
+            impact: incident.impact,
+            unacknowledged_minutes: (chrono::Utc::now() - incident.started_at).num_minutes(),
+            escalation: incident.escalation_count,
+            incident_url: &incident_url,
+        },
+    );
+
+    for admin in &admins {
+        db::notification_logs::enqueue(
+            pool,
+            rendered_notification(
+                incident.org_id,
+                "incident_escalation",
+                "member",
+                &admin.user_email,
+                &rendered,
+            ),
+        )
+        .await?;
+    }
+
+    Ok(admins.len())
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn queue_invitation_email(
     pool: &sqlx::PgPool,
@@ -196,23 +287,129 @@ pub async fn queue_invitation_email(
     token: &str,
 ) -> Result<(), AppError> {
     let invite_link = format!("{}/invite/{}", app_base_url.trim_end_matches('/'), token);
-    let subject = format!("Join {org_name} on StatusPage");
-    let body = format!(
-        "You were invited to join {org_name} as a {role}.\n\nAccept the invitation:\n{invite_link}\n\nSign in with the GitHub account that matches this email address."
+    let mut branding = branding(pool, org_id).await?;
+    branding.org_name = org_name.to_string();
+    let rendered = email::invitation(&branding, role, &invite_link);
+
+    let recipient_type = format!("invitation:{invitation_id}");
+    db::notification_logs::enqueue(
+        pool,
+        rendered_notification(
+            org_id,
+            "invitation_email",
+            &recipient_type,
+            email,
+            &rendered,
+        ),
+    )
+    .await
+}
+
+pub async fn queue_viewer_magic_link(
+    pool: &sqlx::PgPool,
+    app_base_url: &str,
+    page: &PublicPage,
+    email: &str,
+    token: &str,
+) -> Result<(), AppError> {
+    let login_link = format!(
+        "{}/s/{}/access/verify?token={token}",
+        app_base_url.trim_end_matches('/'),
+        page.slug
     );
+    let rendered = email::viewer_magic_link(&page_branding(page, None), &login_link);
 
     db::notification_logs::enqueue(
         pool,
-        org_id,
-        "invitation_email",
-        &format!("invitation:{invitation_id}"),
-        email,
-        &subject,
-        &body,
+        rendered_notification(page.org_id, "viewer_magic_link", "viewer", email, &rendered),
+    )
+    .await
+}
+
+/// Folds a subscriber's held notifications into a single digest email.
+/// Returns `false` when there was nothing to fold or another worker won.
+pub async fn queue_subscriber_digest(
+    pool: &sqlx::PgPool,
+    app_base_url: &str,
+    due: &DueDigest,
+) -> Result<bool, AppError> {
+    let held = db::notification_logs::list_held_for_subscriber(pool, due.subscriber_id).await?;
+    if held.is_empty() {
+        return Ok(false);
+    }
+
+    let page = db::status_pages::find_public(pool, due.org_id, due.status_page_id).await?;
+    let public_urls = public_urls(&page, app_base_url);
+    let branding = page_branding(&page, due.locale);
+    let unsubscribe_link = format!(
+        "{}{}",
+        public_urls.unsubscribe_prefix, due.unsubscribe_token
+    );
+    let items = held
+        .iter()
+        .map(|notification| email::DigestItem {
+            subject: notification
+                .subject
+                .as_deref()
+                .unwrap_or(notification.notification_type.as_str()),
+            occurred_at: &notification.occurred_at_local,
+        })
+        .collect::<Vec<_>>();
+    let rendered = email::subscriber_digest(
+        &branding,
+        &email::SubscriberDigestEmail {
+            frequency: due.digest_frequency,
+            items: &items,
+            history_url: &public_urls.history,
+            unsubscribe_url: &unsubscribe_link,
+        },
+    );
+    let held_ids = held
+        .iter()
+        .map(|notification| notification.id)
+        .collect::<Vec<_>>();
+
+    db::notification_logs::record_digest(
+        pool,
+        due.subscriber_id,
+        &held_ids,
+        NewNotification {
+            list_unsubscribe_url: Some(&unsubscribe_link),
+            subscriber_id: Some(due.subscriber_id),
+            bypass_digest: true,
+            ..rendered_notification(
+                due.org_id,
+                "subscriber_digest",
+                "subscriber",
+                &due.email,
+                &rendered,
+            )
+        },
     )
     .await
 }
 
+pub(crate) fn rendered_notification<'a>(
+    org_id: uuid::Uuid,
+    notification_type: &'a str,
+    recipient_type: &'a str,
+    recipient_email: &'a str,
+    rendered: &'a RenderedEmail,
+) -> NewNotification<'a> {
+    NewNotification {
+        org_id,
+        notification_type,
+        recipient_type,
+        recipient_email,
+        subject: &rendered.subject,
+        body_text: &rendered.text,
+        body_html: Some(&rendered.html),
+        list_unsubscribe_url: None,
+        subscriber_id: None,
+        bypass_digest: false,
+    }
+}
+
 async fn email_event_enabled(
     pool: &sqlx::PgPool,
     org_id: uuid::Uuid,
@@ -247,32 +444,52 @@ async fn service_names(
     Ok(services)
 }
 
-async fn public_urls(
-    pool: &sqlx::PgPool,
-    org_id: uuid::Uuid,
-    app_base_url: &str,
-    org_slug: &str,
-) -> Result<PublicUrls, AppError> {
-    let custom_domain = sqlx::query_scalar::<_, Option<String>>(
-        "SELECT custom_domain FROM organizations WHERE id = $1 AND custom_domain_status = 'verified'",
-    )
-    .bind(org_id)
-    .fetch_optional(pool)
-    .await?
-    .flatten()
-    .map(|domain| domain.trim().trim_end_matches('/').to_lowercase())
-    .filter(|domain| !domain.is_empty());
+async fn branding(pool: &sqlx::PgPool, org_id: uuid::Uuid) -> Result<EmailBranding, AppError> {
+    let (org_name, logo_url, brand_color, locale) =
+        sqlx::query_as::<_, (String, Option<String>, String, Locale)>(
+            "SELECT name, logo_url, brand_color, locale FROM organizations WHERE id = $1",
+        )
+        .bind(org_id)
+        .fetch_one(pool)
+        .await?;
+
+    Ok(EmailBranding {
+        org_name,
+        logo_url,
+        brand_color,
+        locale,
+    })
+}
+
+/// `locale` is the recipient's own choice, if any; otherwise the
+/// organization default applies.
+fn page_branding(page: &PublicPage, locale: Option<Locale>) -> EmailBranding {
+    EmailBranding {
+        org_name: page.name.clone(),
+        logo_url: page.logo_url.clone(),
+        brand_color: page.brand_color.clone(),
+        locale: locale.unwrap_or(page.locale),
+    }
+}
+
+/// Links into `page`, on its verified custom domain when it has one.
+pub(crate) fn public_urls(page: &PublicPage, app_base_url: &str) -> PublicUrls {
+    let custom_domain = page
+        .verified_custom_domain
+        .as_deref()
+        .map(|domain| domain.trim().trim_end_matches('/').to_lowercase())
+        .filter(|domain| !domain.is_empty());
 
     let base = if let Some(domain) = custom_domain {
         format!("https://{domain}")
     } else {
-        format!("{}/s/{org_slug}", app_base_url.trim_end_matches('/'))
+        format!("{}/s/{}", app_base_url.trim_end_matches('/'), page.slug)
     };
 
-    Ok(PublicUrls {
+    PublicUrls {
         history: format!("{base}/history"),
         verify_prefix: format!("{base}/verify?token="),
         unsubscribe_prefix: format!("{base}/unsubscribe?token="),
         base,
-    })
+    }
 }
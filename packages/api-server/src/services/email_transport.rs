@@ -0,0 +1,359 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use async_trait::async_trait;
+use lettre::message::header::{Header, HeaderName, HeaderValue};
+use lettre::message::{Mailbox, MultiPart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use serde::Serialize;
+
+use crate::config::Config;
+
+const HTTP_API_TIMEOUT_SECS: u64 = 10;
+
+#[derive(Debug, Clone)]
+pub struct OutgoingEmail {
+    pub from: Mailbox,
+    pub to: Mailbox,
+    pub subject: String,
+    pub text: String,
+    pub html: Option<String>,
+    pub list_unsubscribe_url: Option<String>,
+}
+
+#[async_trait]
+pub trait EmailTransport: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    async fn send(&self, email: &OutgoingEmail) -> anyhow::Result<()>;
+}
+
+/// Builds the transport selected by `EMAIL_TRANSPORT`. Returns `None` when the
+/// selected transport has not been configured, which disables email delivery.
+pub fn from_config(config: &Config) -> anyhow::Result<Option<Box<dyn EmailTransport>>> {
+    match config.email_transport.as_str() {
+        "smtp" => {
+            let Some(smtp_host) = config.smtp_host.as_deref() else {
+                return Ok(None);
+            };
+            Ok(Some(Box::new(SmtpEmailTransport::new(config, smtp_host)?)))
+        }
+        "file" => {
+            let Some(dir) = config.email_file_drop_dir.as_deref() else {
+                return Ok(None);
+            };
+            Ok(Some(Box::new(FileDropEmailTransport::new(dir))))
+        }
+        "http" => {
+            let Some(url) = config.email_http_api_url.as_deref() else {
+                return Ok(None);
+            };
+            Ok(Some(Box::new(HttpApiEmailTransport::new(
+                url,
+                config.email_http_api_key.clone(),
+            )?)))
+        }
+        other => bail!("Unknown EMAIL_TRANSPORT '{other}'; expected smtp, file or http"),
+    }
+}
+
+pub struct SmtpEmailTransport {
+    mailer: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpEmailTransport {
+    pub fn new(config: &Config, smtp_host: &str) -> anyhow::Result<Self> {
+        let builder = if config.smtp_secure {
+            AsyncSmtpTransport::<Tokio1Executor>::relay(smtp_host)?
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(smtp_host)
+        };
+
+        let builder = builder.port(config.smtp_port);
+
+        let builder = match (&config.smtp_username, &config.smtp_password) {
+            (Some(username), Some(password)) => {
+                builder.credentials(Credentials::new(username.clone(), password.clone()))
+            }
+            _ => builder,
+        };
+
+        Ok(Self {
+            mailer: builder.build(),
+        })
+    }
+}
+
+#[async_trait]
+impl EmailTransport for SmtpEmailTransport {
+    fn name(&self) -> &'static str {
+        "smtp"
+    }
+
+    async fn send(&self, email: &OutgoingEmail) -> anyhow::Result<()> {
+        let message = build_message(email)?;
+        self.mailer.send(message).await?;
+        Ok(())
+    }
+}
+
+/// Writes each message as an `.eml` file instead of delivering it. Intended
+/// for local development and smoke tests.
+pub struct FileDropEmailTransport {
+    dir: PathBuf,
+}
+
+impl FileDropEmailTransport {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+#[async_trait]
+impl EmailTransport for FileDropEmailTransport {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    async fn send(&self, email: &OutgoingEmail) -> anyhow::Result<()> {
+        let message = build_message(email)?;
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+
+        let file_name = format!(
+            "{}-{}.eml",
+            chrono::Utc::now().format("%Y%m%dT%H%M%S%.3fZ"),
+            uuid::Uuid::new_v4()
+        );
+        let path = self.dir.join(file_name);
+        tokio::fs::write(&path, message.formatted())
+            .await
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        Ok(())
+    }
+}
+
+/// Posts messages as JSON to a transactional email provider's HTTP API.
+pub struct HttpApiEmailTransport {
+    client: reqwest::Client,
+    url: String,
+    api_key: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct HttpApiPayload<'a> {
+    from: String,
+    to: Vec<String>,
+    subject: &'a str,
+    text: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    html: Option<&'a str>,
+    headers: serde_json::Map<String, serde_json::Value>,
+}
+
+impl HttpApiEmailTransport {
+    pub fn new(url: &str, api_key: Option<String>) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(HTTP_API_TIMEOUT_SECS))
+            .build()
+            .context("Failed to build email HTTP client")?;
+
+        Ok(Self {
+            client,
+            url: url.to_string(),
+            api_key,
+        })
+    }
+}
+
+#[async_trait]
+impl EmailTransport for HttpApiEmailTransport {
+    fn name(&self) -> &'static str {
+        "http"
+    }
+
+    async fn send(&self, email: &OutgoingEmail) -> anyhow::Result<()> {
+        let mut headers = serde_json::Map::new();
+        if let Some(url) = &email.list_unsubscribe_url {
+            headers.insert(
+                ListUnsubscribe::name().to_string(),
+                serde_json::Value::String(format!("<{url}>")),
+            );
+        }
+
+        let payload = HttpApiPayload {
+            from: email.from.to_string(),
+            to: vec![email.to.to_string()],
+            subject: &email.subject,
+            text: &email.text,
+            html: email.html.as_deref(),
+            headers,
+        };
+
+        let mut request = self.client.post(&self.url).json(&payload);
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            bail!(
+                "Email API responded with {status}: {}",
+                body.chars().take(500).collect::<String>()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ListUnsubscribe(String);
+
+impl Header for ListUnsubscribe {
+    fn name() -> HeaderName {
+        HeaderName::new_from_ascii_str("List-Unsubscribe")
+    }
+
+    fn parse(s: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(Self(s.to_string()))
+    }
+
+    fn display(&self) -> HeaderValue {
+        HeaderValue::new(Self::name(), format!("<{}>", self.0))
+    }
+}
+
+fn build_message(email: &OutgoingEmail) -> anyhow::Result<Message> {
+    let mut builder = Message::builder()
+        .from(email.from.clone())
+        .to(email.to.clone())
+        .subject(email.subject.clone());
+
+    if let Some(url) = &email.list_unsubscribe_url {
+        builder = builder.header(ListUnsubscribe(url.clone()));
+    }
+
+    let message = match &email.html {
+        Some(html) => builder.multipart(MultiPart::alternative_plain_html(
+            email.text.clone(),
+            html.clone(),
+        ))?,
+        None => builder.body(email.text.clone())?,
+    };
+
+    Ok(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::post;
+    use axum::{Json, Router};
+
+    use super::*;
+
+    fn outgoing() -> OutgoingEmail {
+        OutgoingEmail {
+            from: "alerts@example.com".parse().unwrap(),
+            to: "subscriber@example.com".parse().unwrap(),
+            subject: "New incident: Elevated errors".to_string(),
+            text: "A new incident has been posted.".to_string(),
+            html: Some("<p>A new incident has been posted.</p>".to_string()),
+            list_unsubscribe_url: Some(
+                "https://status.example.com/unsubscribe?token=abc".to_string(),
+            ),
+        }
+    }
+
+    async fn spawn_mock(
+        status: StatusCode,
+    ) -> (String, Arc<Mutex<Option<(HeaderMap, serde_json::Value)>>>) {
+        let captured = Arc::new(Mutex::new(None));
+        let sink = captured.clone();
+        let app = Router::new().route(
+            "/send",
+            post(
+                move |headers: HeaderMap, Json(body): Json<serde_json::Value>| {
+                    let sink = sink.clone();
+                    async move {
+                        *sink.lock().unwrap() = Some((headers, body));
+                        status
+                    }
+                },
+            ),
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        (format!("http://{address}/send"), captured)
+    }
+
+    #[test]
+    fn message_is_multipart_with_list_unsubscribe_header() {
+        let formatted = String::from_utf8(build_message(&outgoing()).unwrap().formatted()).unwrap();
+
+        assert!(formatted
+            .contains("List-Unsubscribe: <https://status.example.com/unsubscribe?token=abc>"));
+        assert!(formatted.contains("multipart/alternative"));
+        assert!(formatted.contains("text/html"));
+    }
+
+    #[tokio::test]
+    async fn http_api_transport_posts_json_to_provider() {
+        let (url, captured) = spawn_mock(StatusCode::ACCEPTED).await;
+        let transport = HttpApiEmailTransport::new(&url, Some("test-key".to_string())).unwrap();
+
+        transport.send(&outgoing()).await.unwrap();
+
+        let (headers, body) = captured.lock().unwrap().take().expect("request captured");
+        assert_eq!(headers["authorization"], "Bearer test-key");
+        assert_eq!(body["to"][0], "subscriber@example.com");
+        assert_eq!(body["subject"], "New incident: Elevated errors");
+        assert_eq!(body["html"], "<p>A new incident has been posted.</p>");
+        assert_eq!(
+            body["headers"]["List-Unsubscribe"],
+            "<https://status.example.com/unsubscribe?token=abc>"
+        );
+    }
+
+    #[tokio::test]
+    async fn http_api_transport_surfaces_provider_errors() {
+        let (url, _captured) = spawn_mock(StatusCode::SERVICE_UNAVAILABLE).await;
+        let transport = HttpApiEmailTransport::new(&url, None).unwrap();
+
+        let error = transport.send(&outgoing()).await.unwrap_err();
+        assert!(error.to_string().contains("503"));
+    }
+
+    #[tokio::test]
+    async fn file_drop_transport_writes_eml_file() {
+        let dir = std::env::temp_dir().join(format!("statuspage-mail-{}", uuid::Uuid::new_v4()));
+        let transport = FileDropEmailTransport::new(&dir);
+
+        transport.send(&outgoing()).await.unwrap();
+
+        let entries = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entries[0].extension().and_then(|ext| ext.to_str()),
+            Some("eml")
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use sqlx::PgPool;
+
+use shared::error::AppError;
+use shared::models::incident::Incident;
+
+use crate::config::Config;
+use crate::db;
+use crate::services::email_notifications;
+
+/// Admins are re-notified once per delay, up to this many times.
+const MAX_ESCALATIONS: i32 = 3;
+const ESCALATION_BATCH_SIZE: i64 = 50;
+
+pub fn spawn(pool: PgPool, config: Config) {
+    if config.incident_escalation_delay_minutes <= 0 {
+        tracing::info!("Incident escalation disabled");
+        return;
+    }
+
+    tokio::spawn(async move {
+        let interval = Duration::from_secs(config.incident_escalation_interval_secs.max(15));
+        loop {
+            if let Err(error) = escalate_due_incidents(&pool, &config).await {
+                tracing::error!(error = %error, "incident escalation failed");
+            }
+            tokio::time::sleep(interval).await;
+        }
+    });
+}
+
+pub async fn escalate_due_incidents(pool: &PgPool, config: &Config) -> Result<usize, AppError> {
+    let incidents = db::incidents::claim_due_escalations(
+        pool,
+        config.incident_escalation_delay_minutes,
+        MAX_ESCALATIONS,
+        ESCALATION_BATCH_SIZE,
+    )
+    .await?;
+
+    let escalated = incidents.len();
+    for incident in incidents {
+        if let Err(error) = escalate(pool, config, &incident).await {
+            tracing::warn!(
+                error = %error,
+                incident_id = %incident.id,
+                "Failed to notify admins about unacknowledged incident"
+            );
+        }
+    }
+
+    Ok(escalated)
+}
+
+async fn escalate(pool: &PgPool, config: &Config, incident: &Incident) -> Result<(), AppError> {
+    let org_slug = sqlx::query_scalar::<_, String>("SELECT slug FROM organizations WHERE id = $1")
+        .bind(incident.org_id)
+        .fetch_one(pool)
+        .await?;
+
+    let notified_admins = email_notifications::queue_incident_escalation(
+        pool,
+        &config.app_base_url,
+        &org_slug,
+        incident,
+    )
+    .await?;
+
+    let payload = serde_json::json!({
+        "event_type": "incident.escalated",
+        "org_id": incident.org_id,
+        "occurred_at": chrono::Utc::now(),
+        "data": {
+            "incident_id": incident.id,
+            "title": incident.title.clone(),
+            "status": incident.status,
+            "impact": incident.impact.as_str(),
+            "started_at": incident.started_at,
+            "escalation": incident.escalation_count,
+        }
+    });
+    db::webhook_deliveries::enqueue_for_event(
+        pool,
+        incident.org_id,
+        "incident.escalated",
+        &payload,
+    )
+    .await?;
+
+    db::incident_events::record(
+        pool,
+        incident.id,
+        "escalated",
+        None,
+        serde_json::json!({
+            "escalation": incident.escalation_count,
+            "notified_admins": notified_admins,
+        }),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use chrono::{DateTime, Utc};
+use shared::error::AppError;
+use shared::models::incident::{Incident, IncidentSearchParams, IncidentSortOrder};
+use uuid::Uuid;
+
+use crate::db;
+use crate::db::incidents::IncidentSearchFilter;
+
+const DEFAULT_LIMIT: i64 = 20;
+const MAX_LIMIT: i64 = 100;
+const MAX_QUERY_CHARS: usize = 200;
+
+pub struct IncidentSearchPage {
+    pub incidents: Vec<Incident>,
+    pub next_cursor: Option<String>,
+    pub limit: i64,
+}
+
+pub async fn search(
+    pool: &sqlx::PgPool,
+    org_id: Uuid,
+    status_page_id: Option<Uuid>,
+    params: &IncidentSearchParams,
+    internal_view: bool,
+) -> Result<IncidentSearchPage, AppError> {
+    let query = params
+        .q
+        .as_deref()
+        .map(str::trim)
+        .filter(|query| !query.is_empty());
+    if query.is_some_and(|query| query.chars().count() > MAX_QUERY_CHARS) {
+        return Err(AppError::Validation(format!(
+            "Search text must be {MAX_QUERY_CHARS} characters or fewer"
+        )));
+    }
+    if let (Some(from), Some(to)) = (params.from, params.to) {
+        if from > to {
+            return Err(AppError::Validation(
+                "'from' must be before 'to'".to_string(),
+            ));
+        }
+    }
+
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let after = params.cursor.as_deref().map(decode_cursor).transpose()?;
+
+    let mut incidents = db::incidents::search(
+        pool,
+        org_id,
+        &IncidentSearchFilter {
+            query,
+            status: params.status,
+            impact: params.impact,
+            service_id: params.service_id,
+            from: params.from,
+            to: params.to,
+            is_auto: params.is_auto,
+            oldest_first: params.sort == IncidentSortOrder::Oldest,
+            internal_view,
+            status_page_id,
+            after,
+            limit: limit + 1,
+        },
+    )
+    .await?;
+
+    // The extra row only tells us whether another page exists
+    let next_cursor = if incidents.len() as i64 > limit {
+        incidents.truncate(limit as usize);
+        incidents
+            .last()
+            .map(|incident| encode_cursor(incident.started_at, incident.id))
+    } else {
+        None
+    };
+
+    Ok(IncidentSearchPage {
+        incidents,
+        next_cursor,
+        limit,
+    })
+}
+
+fn encode_cursor(started_at: DateTime<Utc>, id: Uuid) -> String {
+    hex::encode(format!("{}:{id}", started_at.timestamp_micros()))
+}
+
+fn decode_cursor(cursor: &str) -> Result<(DateTime<Utc>, Uuid), AppError> {
+    let invalid = || AppError::Validation("Invalid cursor".to_string());
+
+    let decoded = hex::decode(cursor.trim()).map_err(|_| invalid())?;
+    let decoded = String::from_utf8(decoded).map_err(|_| invalid())?;
+    let (micros, id) = decoded.split_once(':').ok_or_else(invalid)?;
+    let started_at = micros
+        .parse::<i64>()
+        .ok()
+        .and_then(DateTime::from_timestamp_micros)
+        .ok_or_else(invalid)?;
+    let id = id.parse::<Uuid>().map_err(|_| invalid())?;
+
+    Ok((started_at, id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_round_trips_with_microsecond_precision() {
+        let started_at = DateTime::from_timestamp_micros(1_760_000_000_123_456).unwrap();
+        let id = Uuid::new_v4();
+
+        let cursor = encode_cursor(started_at, id);
+
+        assert_eq!(decode_cursor(&cursor).unwrap(), (started_at, id));
+    }
+
+    #[test]
+    fn malformed_cursors_are_rejected() {
+        assert!(decode_cursor("not-hex").is_err());
+        assert!(decode_cursor(&hex::encode("123")).is_err());
+        assert!(decode_cursor(&hex::encode("abc:not-a-uuid")).is_err());
+    }
+}
@@ -1,7 +1,25 @@
+pub mod acme;
 pub mod billing;
+pub mod certificates;
+pub mod cli_auth;
+pub mod config_sync;
+pub mod custom_domains;
 pub mod downgrade;
+pub mod email_digest;
 pub mod email_dispatcher;
 pub mod email_notifications;
+pub mod email_transport;
+pub mod incident_escalation;
+pub mod incident_search;
+pub mod org_deletion;
+pub mod org_export;
+pub mod provider_import;
 pub mod rate_limit;
 pub mod redis_publisher;
+pub mod sms_dispatcher;
+pub mod sms_notifications;
+pub mod sms_provider;
+pub mod subscriber_import;
+pub mod viewer_sessions;
 pub mod webhook_dispatcher;
+pub mod webhook_subscribers;
@@ -0,0 +1,117 @@
+use std::time::Duration;
+
+use sqlx::PgPool;
+
+use shared::error::AppError;
+
+use crate::config::Config;
+use crate::db;
+use crate::db::organization_deletions::OrganizationDeletion;
+use crate::services::billing;
+
+const DELETION_BATCH_SIZE: i64 = 5;
+const POLL_INTERVAL_SECS: u64 = 60;
+/// How long a claimed deletion is left alone before another instance may
+/// pick it up again.
+const LEASE_MINUTES: i32 = 30;
+const MAX_RETRY_DELAY_MINUTES: i32 = 360;
+
+pub fn spawn(pool: PgPool, config: Config) {
+    tokio::spawn(async move {
+        tracing::info!(
+            cool_off_hours = config.org_deletion_cool_off_hours,
+            "Organization deletion worker started"
+        );
+
+        loop {
+            if let Err(error) = process_due(&pool, &config).await {
+                tracing::error!(error = %error, "Organization deletion run failed");
+            }
+            tokio::time::sleep(Duration::from_secs(POLL_INTERVAL_SECS)).await;
+        }
+    });
+}
+
+async fn process_due(pool: &PgPool, config: &Config) -> Result<(), AppError> {
+    let due =
+        db::organization_deletions::claim_due(pool, DELETION_BATCH_SIZE, LEASE_MINUTES).await?;
+
+    for deletion in due {
+        if let Err(error) = delete_organization(pool, config, &deletion).await {
+            let retry_in = retry_delay_minutes(deletion.attempts);
+            tracing::error!(
+                deletion_id = %deletion.id,
+                org_id = %deletion.org_id,
+                attempts = deletion.attempts,
+                retry_in_minutes = retry_in,
+                error = %error,
+                "Organization deletion failed"
+            );
+            db::organization_deletions::record_failure(
+                pool,
+                deletion.id,
+                &error.to_string(),
+                retry_in,
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Cancels billing first so a failed purge never leaves a paying
+/// subscription behind for an organization that is half gone. Retries
+/// skip the Stripe call once its outcome is recorded.
+async fn delete_organization(
+    pool: &PgPool,
+    config: &Config,
+    deletion: &OrganizationDeletion,
+) -> Result<(), AppError> {
+    if deletion.stripe_cancellation.is_none() {
+        if let Some(org) = db::organizations::find_by_id(pool, deletion.org_id).await? {
+            let cancellation = billing::cancel_subscription(config, &org).await?;
+            db::organization_deletions::record_stripe_cancellation(
+                pool,
+                deletion.id,
+                cancellation.as_str(),
+            )
+            .await?;
+        }
+    }
+
+    let summary = db::organization_deletions::purge(pool, deletion.id, deletion.org_id).await?;
+    tracing::info!(
+        deletion_id = %deletion.id,
+        org_id = %deletion.org_id,
+        org_slug = %deletion.org_slug,
+        subscribers = summary.subscribers,
+        monitor_checks = summary.monitor_checks,
+        uptime_days = summary.uptime_days,
+        "Organization deleted"
+    );
+
+    Ok(())
+}
+
+/// Backs off from 5 minutes, doubling per attempt up to six hours.
+fn retry_delay_minutes(attempts: i32) -> i32 {
+    let exponent = attempts.clamp(1, 8) - 1;
+    (5 * 2_i32.pow(exponent as u32)).min(MAX_RETRY_DELAY_MINUTES)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delay_doubles_and_caps() {
+        assert_eq!(retry_delay_minutes(0), 5);
+        assert_eq!(retry_delay_minutes(1), 5);
+        assert_eq!(retry_delay_minutes(2), 10);
+        assert_eq!(retry_delay_minutes(4), 40);
+        assert_eq!(retry_delay_minutes(7), 320);
+        assert_eq!(retry_delay_minutes(8), MAX_RETRY_DELAY_MINUTES);
+        assert_eq!(retry_delay_minutes(50), MAX_RETRY_DELAY_MINUTES);
+    }
+}
@@ -0,0 +1,826 @@
+//! Imports components, component groups, incidents and subscribers from a
+//! Statuspage-style JSON export. Every imported record is remembered in
+//! `import_records`, so running the same export again only adds what is new.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use shared::enums::{IncidentImpact, IncidentStatus, ServiceStatus, StatusChangeSource};
+use shared::error::AppError;
+use shared::models::organization::Organization;
+
+use crate::db;
+use crate::db::import_records::{ImportedIncident, ImportedService};
+use crate::db::subscribers::ImportedSubscriber;
+
+pub const DEFAULT_PROVIDER: &str = "statuspage";
+
+const KIND_GROUP: &str = "service_group";
+const KIND_SERVICE: &str = "service";
+const KIND_INCIDENT: &str = "incident";
+const KIND_UPDATE: &str = "incident_update";
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ProviderExport {
+    #[serde(default)]
+    pub components: Vec<SourceComponent>,
+    #[serde(default)]
+    pub component_groups: Vec<SourceComponentGroup>,
+    #[serde(default)]
+    pub incidents: Vec<SourceIncident>,
+    #[serde(default)]
+    pub subscribers: Vec<SourceSubscriber>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceComponent {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    pub position: Option<i32>,
+    pub group_id: Option<String>,
+    /// Statuspage lists groups among the components with this flag set.
+    #[serde(default)]
+    pub group: bool,
+    #[serde(default)]
+    pub components: Vec<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceComponentGroup {
+    pub id: String,
+    pub name: String,
+    pub position: Option<i32>,
+    #[serde(default)]
+    pub components: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceIncident {
+    pub id: String,
+    pub name: String,
+    pub status: String,
+    pub impact: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub incident_updates: Vec<SourceIncidentUpdate>,
+    #[serde(default)]
+    pub components: Vec<SourceComponentRef>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceComponentRef {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceIncidentUpdate {
+    pub id: String,
+    pub status: String,
+    #[serde(default)]
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    /// When the update was shown on the page, if backdated.
+    pub display_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub affected_components: Vec<SourceAffectedComponent>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceAffectedComponent {
+    pub code: String,
+    pub new_status: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceSubscriber {
+    pub email: Option<String>,
+    pub mode: Option<String>,
+    pub quarantined_at: Option<DateTime<Utc>>,
+}
+
+/// What an import did, or would do on a dry run.
+#[derive(Debug, Default, Serialize)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub service_groups: ImportCounts,
+    pub services: ImportCounts,
+    pub incidents: ImportCounts,
+    pub incident_updates: ImportCounts,
+    pub subscribers: ImportCounts,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, PartialEq, Eq)]
+pub struct ImportCounts {
+    /// New records (created, or to be created on a dry run).
+    pub created: usize,
+    /// Records a previous run already imported.
+    pub existing: usize,
+    /// Source records that cannot be represented and were left out.
+    pub skipped: usize,
+}
+
+#[derive(Debug)]
+struct PlannedGroup {
+    external_id: String,
+    name: String,
+    position: i32,
+}
+
+#[derive(Debug)]
+struct PlannedService {
+    external_id: String,
+    name: String,
+    description: Option<String>,
+    status: ServiceStatus,
+    group_id: Option<String>,
+    created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug)]
+struct PlannedUpdate {
+    external_id: String,
+    status: IncidentStatus,
+    message: String,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+struct PlannedIncident {
+    external_id: String,
+    title: String,
+    status: IncidentStatus,
+    impact: IncidentImpact,
+    started_at: DateTime<Utc>,
+    resolved_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    /// Affected component ids with the worst status each reached.
+    components: Vec<(String, ServiceStatus)>,
+    /// Oldest first.
+    updates: Vec<PlannedUpdate>,
+}
+
+#[derive(Debug, Default)]
+struct ImportPlan {
+    groups: Vec<PlannedGroup>,
+    services: Vec<PlannedService>,
+    incidents: Vec<PlannedIncident>,
+    subscriber_emails: Vec<String>,
+    skipped_incidents: usize,
+    skipped_subscribers: usize,
+    warnings: Vec<String>,
+}
+
+type ImportedIds = HashMap<(String, String), Uuid>;
+
+/// Runs an import into `org`. Nothing is written unless `apply` is set; the
+/// report is the same either way.
+pub async fn import(
+    pool: &PgPool,
+    org: &Organization,
+    provider: &str,
+    export: ProviderExport,
+    apply: bool,
+) -> Result<ImportReport, AppError> {
+    let plan = build_plan(export);
+    let mut imported: ImportedIds = db::import_records::list_live(pool, org.id, provider)
+        .await?
+        .into_iter()
+        .map(|record| ((record.kind, record.external_id), record.local_id))
+        .collect();
+    let existing_emails: HashSet<String> =
+        db::subscribers::list_verified_primary_emails(pool, org.id)
+            .await?
+            .into_iter()
+            .collect();
+
+    let mut report = count_plan(&plan, &imported, &existing_emails);
+    report.dry_run = !apply;
+    if !apply {
+        return Ok(report);
+    }
+
+    let mut tx = pool.begin().await?;
+
+    let mut group_names = HashMap::new();
+    for group in &plan.groups {
+        group_names.insert(group.external_id.clone(), group.name.clone());
+        if imported.contains_key(&key(KIND_GROUP, &group.external_id)) {
+            continue;
+        }
+        let id =
+            db::import_records::upsert_service_group(&mut tx, org.id, &group.name, group.position)
+                .await?;
+        db::import_records::record(
+            &mut tx,
+            org.id,
+            provider,
+            KIND_GROUP,
+            &group.external_id,
+            id,
+        )
+        .await?;
+        imported.insert(key(KIND_GROUP, &group.external_id), id);
+    }
+
+    let mut next_order = db::import_records::next_service_order(&mut tx, org.id).await?;
+    for service in &plan.services {
+        if imported.contains_key(&key(KIND_SERVICE, &service.external_id)) {
+            continue;
+        }
+        let group = service.group_id.as_ref().and_then(|group_id| {
+            let id = imported.get(&key(KIND_GROUP, group_id))?;
+            Some((*id, group_names.get(group_id)?.as_str()))
+        });
+        let id = db::import_records::insert_service(
+            &mut tx,
+            org.id,
+            &ImportedService {
+                name: &service.name,
+                description: service.description.as_deref(),
+                status: service.status,
+                group,
+                display_order: next_order,
+                created_at: service.created_at,
+            },
+        )
+        .await?;
+        next_order += 1;
+        db::import_records::record(
+            &mut tx,
+            org.id,
+            provider,
+            KIND_SERVICE,
+            &service.external_id,
+            id,
+        )
+        .await?;
+        imported.insert(key(KIND_SERVICE, &service.external_id), id);
+    }
+
+    for incident in &plan.incidents {
+        let incident_id = match imported.get(&key(KIND_INCIDENT, &incident.external_id)) {
+            Some(id) => *id,
+            None => {
+                let id = db::import_records::insert_incident(
+                    &mut tx,
+                    org.id,
+                    &ImportedIncident {
+                        title: &incident.title,
+                        status: incident.status,
+                        impact: incident.impact,
+                        started_at: incident.started_at,
+                        resolved_at: incident.resolved_at,
+                        created_at: incident.created_at,
+                        updated_at: incident.updated_at,
+                    },
+                )
+                .await?;
+                db::import_records::record(
+                    &mut tx,
+                    org.id,
+                    provider,
+                    KIND_INCIDENT,
+                    &incident.external_id,
+                    id,
+                )
+                .await?;
+                imported.insert(key(KIND_INCIDENT, &incident.external_id), id);
+                id
+            }
+        };
+
+        for (component_id, status) in &incident.components {
+            if let Some(service_id) = imported.get(&key(KIND_SERVICE, component_id)) {
+                db::import_records::link_incident_service(
+                    &mut tx,
+                    incident_id,
+                    *service_id,
+                    *status,
+                )
+                .await?;
+            }
+        }
+
+        let mut added_updates = false;
+        for update in &incident.updates {
+            if imported.contains_key(&key(KIND_UPDATE, &update.external_id)) {
+                continue;
+            }
+            let id = db::import_records::insert_incident_update(
+                &mut tx,
+                incident_id,
+                update.status,
+                &update.message,
+                update.created_at,
+            )
+            .await?;
+            db::import_records::record(
+                &mut tx,
+                org.id,
+                provider,
+                KIND_UPDATE,
+                &update.external_id,
+                id,
+            )
+            .await?;
+            imported.insert(key(KIND_UPDATE, &update.external_id), id);
+            added_updates = true;
+        }
+        if added_updates {
+            db::import_records::sync_incident_status(
+                &mut tx,
+                incident_id,
+                incident.status,
+                incident.resolved_at,
+                incident.updated_at,
+            )
+            .await?;
+        }
+    }
+
+    db::status_history::record(&mut *tx, org.id, StatusChangeSource::Manual).await?;
+    tx.commit().await?;
+
+    // Subscribers were confirmed on the source page, so they join verified;
+    // the subscriber import already skips addresses that are verified here.
+    let attestation = format!("Confirmed subscriber migrated from a {provider} export");
+    for email in &plan.subscriber_emails {
+        let verification_token = Uuid::new_v4().to_string();
+        let unsubscribe_token = Uuid::new_v4().to_string();
+        db::subscribers::import(
+            pool,
+            org.id,
+            ImportedSubscriber {
+                email,
+                digest_frequency: None,
+                timezone: None,
+                verification_token: &verification_token,
+                unsubscribe_token: &unsubscribe_token,
+                consent_attestation: Some(&attestation),
+            },
+        )
+        .await?;
+    }
+
+    db::audit_logs::record(
+        pool,
+        db::audit_logs::NewAuditLog {
+            org_id: org.id,
+            actor_user_id: None,
+            actor_type: "system",
+            action: "organization.imported",
+            target_type: "organization",
+            target_id: Some(&org.id.to_string()),
+            details: serde_json::json!({
+                "provider": provider,
+                "service_groups": report.service_groups.created,
+                "services": report.services.created,
+                "incidents": report.incidents.created,
+                "incident_updates": report.incident_updates.created,
+                "subscribers": report.subscribers.created,
+            }),
+        },
+    )
+    .await?;
+
+    Ok(report)
+}
+
+fn key(kind: &str, external_id: &str) -> (String, String) {
+    (kind.to_string(), external_id.to_string())
+}
+
+fn count_plan(
+    plan: &ImportPlan,
+    imported: &ImportedIds,
+    existing_emails: &HashSet<String>,
+) -> ImportReport {
+    fn tally<'a>(
+        kind: &str,
+        ids: impl Iterator<Item = &'a str>,
+        imported: &ImportedIds,
+    ) -> ImportCounts {
+        let mut counts = ImportCounts::default();
+        for id in ids {
+            if imported.contains_key(&key(kind, id)) {
+                counts.existing += 1;
+            } else {
+                counts.created += 1;
+            }
+        }
+        counts
+    }
+
+    let mut subscribers = ImportCounts {
+        skipped: plan.skipped_subscribers,
+        ..ImportCounts::default()
+    };
+    for email in &plan.subscriber_emails {
+        if existing_emails.contains(email) {
+            subscribers.existing += 1;
+        } else {
+            subscribers.created += 1;
+        }
+    }
+
+    let mut incidents = tally(
+        KIND_INCIDENT,
+        plan.incidents.iter().map(|i| i.external_id.as_str()),
+        imported,
+    );
+    incidents.skipped = plan.skipped_incidents;
+
+    ImportReport {
+        dry_run: true,
+        service_groups: tally(
+            KIND_GROUP,
+            plan.groups.iter().map(|g| g.external_id.as_str()),
+            imported,
+        ),
+        services: tally(
+            KIND_SERVICE,
+            plan.services.iter().map(|s| s.external_id.as_str()),
+            imported,
+        ),
+        incidents,
+        incident_updates: tally(
+            KIND_UPDATE,
+            plan.incidents
+                .iter()
+                .flat_map(|i| i.updates.iter().map(|u| u.external_id.as_str())),
+            imported,
+        ),
+        subscribers,
+        warnings: plan.warnings.clone(),
+    }
+}
+
+fn build_plan(export: ProviderExport) -> ImportPlan {
+    let mut plan = ImportPlan::default();
+
+    // Groups arrive either as their own list or as flagged components; a
+    // component's group comes from its `group_id` or the group's member list.
+    let mut member_groups: HashMap<String, String> = HashMap::new();
+    let mut group_ids = HashSet::new();
+    let flagged_groups = export.components.iter().filter(|c| c.group).map(|c| {
+        (
+            c.id.clone(),
+            c.name.clone(),
+            c.position,
+            c.components.clone(),
+        )
+    });
+    let listed_groups = export.component_groups.iter().map(|g| {
+        (
+            g.id.clone(),
+            g.name.clone(),
+            g.position,
+            g.components.clone(),
+        )
+    });
+    for (index, (id, name, position, members)) in listed_groups.chain(flagged_groups).enumerate() {
+        if !group_ids.insert(id.clone()) {
+            continue;
+        }
+        for member in members {
+            member_groups.insert(member, id.clone());
+        }
+        plan.groups.push(PlannedGroup {
+            external_id: id,
+            name: name.trim().to_string(),
+            position: position.unwrap_or(index as i32),
+        });
+    }
+    plan.groups.sort_by_key(|group| group.position);
+
+    let mut components: Vec<&SourceComponent> =
+        export.components.iter().filter(|c| !c.group).collect();
+    components.sort_by_key(|component| component.position.unwrap_or(i32::MAX));
+    let mut component_ids = HashSet::new();
+    for component in components {
+        let status = match component.status.as_deref() {
+            None => ServiceStatus::Operational,
+            Some(raw) => parse_service_status(raw).unwrap_or_else(|| {
+                plan.warnings.push(format!(
+                    "Component '{}' has unknown status '{raw}'; imported as operational",
+                    component.name
+                ));
+                ServiceStatus::Operational
+            }),
+        };
+        let group_id = component
+            .group_id
+            .clone()
+            .or_else(|| member_groups.get(&component.id).cloned())
+            .filter(|group_id| group_ids.contains(group_id));
+        component_ids.insert(component.id.clone());
+        plan.services.push(PlannedService {
+            external_id: component.id.clone(),
+            name: component.name.trim().to_string(),
+            description: component
+                .description
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(ToOwned::to_owned),
+            status,
+            group_id,
+            created_at: component.created_at,
+        });
+    }
+
+    for incident in export.incidents {
+        if let Some(planned) = plan_incident(incident, &component_ids, &mut plan.warnings) {
+            plan.incidents.push(planned);
+        } else {
+            plan.skipped_incidents += 1;
+        }
+    }
+    plan.incidents.sort_by_key(|incident| incident.started_at);
+
+    let mut seen_emails = HashSet::new();
+    for subscriber in export.subscribers {
+        let email = subscriber
+            .email
+            .as_deref()
+            .and_then(|email| shared::validation::normalize_subscriber_email(email).ok());
+        let is_email_mode = subscriber
+            .mode
+            .as_deref()
+            .is_none_or(|mode| mode == "email");
+        match email {
+            Some(email) if is_email_mode && subscriber.quarantined_at.is_none() => {
+                if seen_emails.insert(email.clone()) {
+                    plan.subscriber_emails.push(email);
+                }
+            }
+            _ => plan.skipped_subscribers += 1,
+        }
+    }
+    if plan.skipped_subscribers > 0 {
+        plan.warnings.push(format!(
+            "Skipped {} subscribers: only unquarantined email subscribers are imported",
+            plan.skipped_subscribers
+        ));
+    }
+
+    plan
+}
+
+fn plan_incident(
+    incident: SourceIncident,
+    component_ids: &HashSet<String>,
+    warnings: &mut Vec<String>,
+) -> Option<PlannedIncident> {
+    let Some(status) = parse_incident_status(&incident.status) else {
+        warnings.push(format!(
+            "Incident '{}' has status '{}' (scheduled maintenance is not supported); skipped",
+            incident.name, incident.status
+        ));
+        return None;
+    };
+    let impact = match incident.impact.as_deref() {
+        None | Some("none") => IncidentImpact::None,
+        Some("minor") => IncidentImpact::Minor,
+        Some("major") => IncidentImpact::Major,
+        Some("critical") => IncidentImpact::Critical,
+        Some(other) => {
+            warnings.push(format!(
+                "Incident '{}' has unknown impact '{other}'; imported as none",
+                incident.name
+            ));
+            IncidentImpact::None
+        }
+    };
+
+    let mut updates = Vec::new();
+    let mut component_statuses: HashMap<String, ServiceStatus> = HashMap::new();
+    for component in &incident.components {
+        component_statuses.insert(component.id.clone(), impact.to_service_status());
+    }
+    for update in incident.incident_updates {
+        for affected in &update.affected_components {
+            let reached = affected
+                .new_status
+                .as_deref()
+                .and_then(parse_service_status)
+                .unwrap_or(impact.to_service_status());
+            let entry = component_statuses
+                .entry(affected.code.clone())
+                .or_insert(reached);
+            if reached.severity() > entry.severity() {
+                *entry = reached;
+            }
+        }
+        let update_status = parse_incident_status(&update.status).unwrap_or(status);
+        updates.push(PlannedUpdate {
+            external_id: update.id,
+            status: update_status,
+            message: update.body,
+            created_at: update.display_at.unwrap_or(update.created_at),
+        });
+    }
+    updates.sort_by_key(|update| update.created_at);
+
+    let mut components: Vec<(String, ServiceStatus)> = component_statuses
+        .into_iter()
+        .filter(|(id, _)| component_ids.contains(id))
+        .collect();
+    components.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let last_update_at = updates.last().map(|update| update.created_at);
+    let resolved_at = match status {
+        IncidentStatus::Resolved => incident.resolved_at.or(last_update_at),
+        _ => None,
+    };
+
+    Some(PlannedIncident {
+        external_id: incident.id,
+        title: incident.name.trim().to_string(),
+        status,
+        impact,
+        started_at: incident.started_at.unwrap_or(incident.created_at),
+        resolved_at,
+        created_at: incident.created_at,
+        updated_at: incident
+            .updated_at
+            .or(last_update_at)
+            .unwrap_or(incident.created_at),
+        components,
+        updates,
+    })
+}
+
+fn parse_service_status(raw: &str) -> Option<ServiceStatus> {
+    serde_json::from_value(serde_json::Value::String(raw.to_string())).ok()
+}
+
+/// Statuspage's postmortem is a resolved incident; maintenance statuses
+/// have no equivalent.
+fn parse_incident_status(raw: &str) -> Option<IncidentStatus> {
+    match raw {
+        "investigating" => Some(IncidentStatus::Investigating),
+        "identified" => Some(IncidentStatus::Identified),
+        "monitoring" => Some(IncidentStatus::Monitoring),
+        "resolved" | "postmortem" => Some(IncidentStatus::Resolved),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn export() -> ProviderExport {
+        serde_json::from_str(
+            r#"{
+                "components": [
+                    {"id": "grp1", "name": "Core", "group": true, "position": 1,
+                     "components": ["cmp1"]},
+                    {"id": "cmp2", "name": "Dashboard", "status": "degraded_performance",
+                     "position": 3},
+                    {"id": "cmp1", "name": " API ", "status": "operational", "position": 2,
+                     "created_at": "2023-04-01T10:00:00.000-07:00"}
+                ],
+                "incidents": [
+                    {
+                        "id": "inc1",
+                        "name": "API errors",
+                        "status": "postmortem",
+                        "impact": "major",
+                        "created_at": "2024-01-02T09:00:00Z",
+                        "resolved_at": "2024-01-02T11:00:00Z",
+                        "components": [{"id": "cmp1"}],
+                        "incident_updates": [
+                            {"id": "upd2", "status": "resolved", "body": "Fixed",
+                             "created_at": "2024-01-02T11:00:00Z",
+                             "affected_components": [
+                                {"code": "cmp1", "new_status": "operational"}
+                             ]},
+                            {"id": "upd1", "status": "investigating", "body": "Looking",
+                             "created_at": "2024-01-02T09:05:00Z",
+                             "display_at": "2024-01-02T09:00:00Z",
+                             "affected_components": [
+                                {"code": "cmp1", "new_status": "partial_outage"},
+                                {"code": "gone", "new_status": "major_outage"}
+                             ]}
+                        ]
+                    },
+                    {"id": "mnt1", "name": "Upgrade", "status": "scheduled",
+                     "impact": "maintenance", "created_at": "2024-02-01T00:00:00Z"}
+                ],
+                "subscribers": [
+                    {"email": "Ops@Example.com", "mode": "email"},
+                    {"email": "ops@example.com"},
+                    {"email": "pager@example.com", "quarantined_at": "2024-01-01T00:00:00Z"},
+                    {"mode": "sms"}
+                ]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn plan_maps_groups_components_and_statuses() {
+        let plan = build_plan(export());
+
+        assert_eq!(plan.groups.len(), 1);
+        assert_eq!(plan.groups[0].name, "Core");
+        let names: Vec<_> = plan.services.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["API", "Dashboard"]);
+        assert_eq!(plan.services[0].group_id.as_deref(), Some("grp1"));
+        assert_eq!(plan.services[1].group_id, None);
+        assert_eq!(plan.services[1].status, ServiceStatus::DegradedPerformance);
+        assert_eq!(
+            plan.services[0].created_at.unwrap().to_rfc3339(),
+            "2023-04-01T17:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn plan_keeps_original_incident_timeline() {
+        let plan = build_plan(export());
+
+        assert_eq!(plan.incidents.len(), 1);
+        assert_eq!(plan.skipped_incidents, 1);
+        let incident = &plan.incidents[0];
+        assert_eq!(incident.status, IncidentStatus::Resolved);
+        assert_eq!(incident.impact, IncidentImpact::Major);
+        let updates: Vec<_> = incident
+            .updates
+            .iter()
+            .map(|u| (u.external_id.as_str(), u.created_at.to_rfc3339()))
+            .collect();
+        assert_eq!(
+            updates,
+            [
+                ("upd1", "2024-01-02T09:00:00+00:00".to_string()),
+                ("upd2", "2024-01-02T11:00:00+00:00".to_string()),
+            ]
+        );
+        // The worst status a component reached sticks; unknown codes drop.
+        assert_eq!(
+            incident.components,
+            [("cmp1".to_string(), ServiceStatus::MajorOutage)]
+        );
+        assert!(plan
+            .warnings
+            .iter()
+            .any(|warning| warning.contains("'Upgrade'")));
+    }
+
+    #[test]
+    fn plan_imports_each_email_subscriber_once() {
+        let plan = build_plan(export());
+
+        assert_eq!(plan.subscriber_emails, ["ops@example.com"]);
+        assert_eq!(plan.skipped_subscribers, 2);
+    }
+
+    #[test]
+    fn counts_separate_new_from_previously_imported() {
+        let plan = build_plan(export());
+        let imported: ImportedIds = [
+            (key(KIND_SERVICE, "cmp1"), Uuid::new_v4()),
+            (key(KIND_INCIDENT, "inc1"), Uuid::new_v4()),
+            (key(KIND_UPDATE, "upd1"), Uuid::new_v4()),
+        ]
+        .into_iter()
+        .collect();
+        let emails = HashSet::from(["ops@example.com".to_string()]);
+
+        let report = count_plan(&plan, &imported, &emails);
+
+        assert_eq!(
+            report.services,
+            ImportCounts {
+                created: 1,
+                existing: 1,
+                skipped: 0
+            }
+        );
+        assert_eq!(
+            report.incidents,
+            ImportCounts {
+                created: 0,
+                existing: 1,
+                skipped: 1
+            }
+        );
+        assert_eq!(report.incident_updates.created, 1);
+        assert_eq!(report.subscribers.existing, 1);
+        assert_eq!(report.subscribers.skipped, 2);
+    }
+}
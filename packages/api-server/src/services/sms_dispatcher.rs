@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use sqlx::PgPool;
+
+use crate::config::Config;
+use crate::db::notification_logs::{self, NotificationFailureUpdate};
+use crate::db::sms_logs;
+use crate::services::sms_provider::{self, OutgoingSms};
+
+pub fn spawn(pool: PgPool, config: Config) {
+    let provider = match sms_provider::from_config(&config) {
+        Ok(Some(provider)) => provider,
+        Ok(None) => {
+            tracing::info!("SMS provider is not configured; SMS dispatcher disabled");
+            return;
+        }
+        Err(error) => {
+            tracing::error!(error = %error, "Failed to configure SMS provider");
+            return;
+        }
+    };
+
+    tokio::spawn(async move {
+        tracing::info!(provider = provider.name(), "SMS dispatcher started");
+
+        let mut ticker = tokio::time::interval(Duration::from_secs(
+            config.sms_dispatch_interval_secs.max(1),
+        ));
+        ticker.tick().await;
+
+        loop {
+            ticker.tick().await;
+
+            let messages =
+                match sms_logs::claim_pending(&pool, config.sms_dispatch_batch_size).await {
+                    Ok(messages) => messages,
+                    Err(error) => {
+                        tracing::warn!(error = %error, "Failed to claim pending SMS messages");
+                        continue;
+                    }
+                };
+
+            for message in messages {
+                let sms = OutgoingSms {
+                    to: message.recipient_phone.clone(),
+                    body: message.body.clone(),
+                };
+
+                match provider.send(&sms).await {
+                    Ok(provider_message_id) => {
+                        if let Err(error) =
+                            sms_logs::mark_sent(&pool, message.id, provider_message_id.as_deref())
+                                .await
+                        {
+                            tracing::warn!(
+                                error = %error,
+                                sms_id = %message.id,
+                                "Failed to mark SMS as sent"
+                            );
+                        }
+                    }
+                    Err(error) => {
+                        if let Err(mark_error) = sms_logs::mark_failed(
+                            &pool,
+                            message.id,
+                            NotificationFailureUpdate {
+                                attempt_count: message.attempt_count,
+                                max_attempts: message.max_attempts,
+                                error_message: Some(&error.to_string()),
+                                next_retry_at: notification_logs::next_retry_at(
+                                    message.attempt_count,
+                                    message.max_attempts,
+                                ),
+                            },
+                        )
+                        .await
+                        {
+                            tracing::warn!(
+                                error = %mark_error,
+                                sms_id = %message.id,
+                                "Failed to record SMS delivery failure"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,195 @@
+use sha2::{Digest, Sha256};
+use shared::enums::{IncidentImpact, IncidentStatus, OrganizationPlan};
+use shared::error::AppError;
+use uuid::Uuid;
+
+use crate::db;
+use crate::db::sms_logs::NewSms;
+use crate::services::email_notifications::public_urls;
+
+/// Two concatenated SMS segments; longer messages get expensive quickly.
+const MAX_SMS_CHARS: usize = 320;
+pub const PHONE_OTP_TTL_MINUTES: i64 = 10;
+pub const PHONE_OTP_MAX_ATTEMPTS: i32 = 5;
+
+pub struct IncidentSms<'a> {
+    pub title: &'a str,
+    pub status: IncidentStatus,
+    pub impact: IncidentImpact,
+    pub message: Option<&'a str>,
+    pub affected_services: &'a [Uuid],
+}
+
+pub fn generate_phone_otp() -> String {
+    format!("{:06}", Uuid::new_v4().as_u128() % 1_000_000)
+}
+
+/// Codes are only six digits, so they are salted with the subscriber id to
+/// keep the stored hash from being a lookup table entry.
+pub fn hash_phone_otp(subscriber_id: Uuid, code: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(subscriber_id.as_bytes());
+    hasher.update(code.trim().as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+/// Queues the verification code for a newly added phone number. Returns
+/// `false` when the organization is out of SMS quota for the month.
+pub async fn queue_phone_verification(
+    pool: &sqlx::PgPool,
+    org_id: Uuid,
+    org_name: &str,
+    subscriber_id: Uuid,
+    phone_number: &str,
+    code: &str,
+) -> Result<bool, AppError> {
+    let plan = org_plan(pool, org_id).await?;
+    let body = format!(
+        "{org_name} status alerts: your verification code is {code}. It expires in {PHONE_OTP_TTL_MINUTES} minutes."
+    );
+
+    db::sms_logs::enqueue(
+        pool,
+        NewSms {
+            org_id,
+            subscriber_id: Some(subscriber_id),
+            message_type: "phone_verification",
+            recipient_phone: phone_number,
+            body: &body,
+            monthly_quota: plan.monthly_sms_quota(),
+        },
+    )
+    .await
+}
+
+/// Texts phone-verified subscribers about critical incidents. Other impacts
+/// are left to email so SMS stays reserved for outages.
+pub async fn queue_incident_alert(
+    pool: &sqlx::PgPool,
+    org_id: Uuid,
+    app_base_url: &str,
+    incident: &IncidentSms<'_>,
+) -> Result<(), AppError> {
+    if incident.impact != IncidentImpact::Critical {
+        return Ok(());
+    }
+
+    let plan = org_plan(pool, org_id).await?;
+    if !plan.allows_sms() {
+        return Ok(());
+    }
+
+    let recipients =
+        db::subscribers::find_sms_recipients(pool, org_id, incident.affected_services).await?;
+    if recipients.is_empty() {
+        return Ok(());
+    }
+
+    let pages = db::status_pages::public_pages_by_id(pool, org_id).await?;
+    let message_type = if incident.message.is_none() {
+        "incident_created"
+    } else if incident.status == IncidentStatus::Resolved {
+        "incident_resolved"
+    } else {
+        "incident_updated"
+    };
+
+    for recipient in recipients {
+        let Some(page) = pages.get(&recipient.status_page_id) else {
+            continue;
+        };
+        let body = incident_alert_body(&page.name, incident, &public_urls(page, app_base_url).base);
+        let queued = db::sms_logs::enqueue(
+            pool,
+            NewSms {
+                org_id,
+                subscriber_id: Some(recipient.subscriber_id),
+                message_type,
+                recipient_phone: &recipient.phone_number,
+                body: &body,
+                monthly_quota: plan.monthly_sms_quota(),
+            },
+        )
+        .await?;
+
+        if !queued {
+            tracing::warn!(%org_id, "Monthly SMS quota reached; skipping remaining incident SMS");
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+async fn org_plan(pool: &sqlx::PgPool, org_id: Uuid) -> Result<OrganizationPlan, AppError> {
+    let plan =
+        sqlx::query_scalar::<_, OrganizationPlan>("SELECT plan FROM organizations WHERE id = $1")
+            .bind(org_id)
+            .fetch_one(pool)
+            .await?;
+
+    Ok(plan)
+}
+
+fn incident_alert_body(org_name: &str, incident: &IncidentSms<'_>, status_url: &str) -> String {
+    let text = match incident.message {
+        None => format!("{org_name}: Critical incident - {}", incident.title),
+        Some(message) => format!(
+            "{org_name}: {} - {}. {}",
+            incident.status,
+            incident.title,
+            message.trim()
+        ),
+    };
+
+    // Keep the link intact and trim the prose in front of it instead.
+    let budget = MAX_SMS_CHARS.saturating_sub(status_url.chars().count() + 1);
+    let text = if text.chars().count() > budget {
+        let mut truncated = text
+            .chars()
+            .take(budget.saturating_sub(3))
+            .collect::<String>();
+        truncated.push_str("...");
+        truncated
+    } else {
+        text
+    };
+
+    format!("{text} {status_url}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn incident_alert_body_keeps_link_within_two_segments() {
+        let long_message = "Database failover in progress. ".repeat(20);
+        let body = incident_alert_body(
+            "Acme",
+            &IncidentSms {
+                title: "API outage",
+                status: IncidentStatus::Identified,
+                impact: IncidentImpact::Critical,
+                message: Some(&long_message),
+                affected_services: &[],
+            },
+            "https://status.acme.test",
+        );
+
+        assert!(body.chars().count() <= MAX_SMS_CHARS);
+        assert!(body.starts_with("Acme: Identified - API outage."));
+        assert!(body.ends_with("... https://status.acme.test"));
+    }
+
+    #[test]
+    fn phone_otp_is_six_digits_and_hash_is_salted() {
+        let code = generate_phone_otp();
+        assert_eq!(code.len(), 6);
+        assert!(code.chars().all(|c| c.is_ascii_digit()));
+
+        let first = hash_phone_otp(Uuid::new_v4(), "123456");
+        let second = hash_phone_otp(Uuid::new_v4(), "123456");
+        assert_ne!(first, second);
+    }
+}
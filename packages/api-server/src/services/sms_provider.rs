@@ -0,0 +1,182 @@
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+
+const HTTP_API_TIMEOUT_SECS: u64 = 10;
+
+#[derive(Debug, Clone)]
+pub struct OutgoingSms {
+    pub to: String,
+    pub body: String,
+}
+
+#[async_trait]
+pub trait SmsProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Sends the message and returns the provider's message id, if any.
+    async fn send(&self, sms: &OutgoingSms) -> anyhow::Result<Option<String>>;
+}
+
+/// Builds the configured SMS provider. Returns `None` when `SMS_HTTP_API_URL`
+/// is unset, which disables SMS delivery.
+pub fn from_config(config: &Config) -> anyhow::Result<Option<Box<dyn SmsProvider>>> {
+    let Some(url) = config.sms_http_api_url.as_deref() else {
+        return Ok(None);
+    };
+
+    Ok(Some(Box::new(HttpSmsProvider::new(
+        url,
+        config.sms_http_api_key.clone(),
+        config.sms_from.clone(),
+    )?)))
+}
+
+/// Posts messages as JSON to an SMS gateway. Most providers either accept this
+/// shape directly or can be fronted by a small relay that does.
+pub struct HttpSmsProvider {
+    client: reqwest::Client,
+    url: String,
+    api_key: Option<String>,
+    from: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct HttpSmsPayload<'a> {
+    to: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<&'a str>,
+    body: &'a str,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct HttpSmsResponse {
+    id: Option<String>,
+}
+
+impl HttpSmsProvider {
+    pub fn new(url: &str, api_key: Option<String>, from: Option<String>) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(HTTP_API_TIMEOUT_SECS))
+            .build()
+            .context("Failed to build SMS HTTP client")?;
+
+        Ok(Self {
+            client,
+            url: url.to_string(),
+            api_key,
+            from,
+        })
+    }
+}
+
+#[async_trait]
+impl SmsProvider for HttpSmsProvider {
+    fn name(&self) -> &'static str {
+        "http"
+    }
+
+    async fn send(&self, sms: &OutgoingSms) -> anyhow::Result<Option<String>> {
+        let payload = HttpSmsPayload {
+            to: &sms.to,
+            from: self.from.as_deref(),
+            body: &sms.body,
+        };
+
+        let mut request = self.client.post(&self.url).json(&payload);
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        if !status.is_success() {
+            bail!(
+                "SMS API responded with {status}: {}",
+                body.chars().take(500).collect::<String>()
+            );
+        }
+
+        let parsed = serde_json::from_str::<HttpSmsResponse>(&body).unwrap_or_default();
+        Ok(parsed.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::post;
+    use axum::{Json, Router};
+
+    use super::*;
+
+    async fn spawn_mock(
+        status: StatusCode,
+    ) -> (String, Arc<Mutex<Option<(HeaderMap, serde_json::Value)>>>) {
+        let captured = Arc::new(Mutex::new(None));
+        let sink = captured.clone();
+        let app = Router::new().route(
+            "/messages",
+            post(
+                move |headers: HeaderMap, Json(body): Json<serde_json::Value>| {
+                    let sink = sink.clone();
+                    async move {
+                        *sink.lock().unwrap() = Some((headers, body));
+                        (status, Json(serde_json::json!({ "id": "msg_123" })))
+                    }
+                },
+            ),
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        (format!("http://{address}/messages"), captured)
+    }
+
+    fn outgoing() -> OutgoingSms {
+        OutgoingSms {
+            to: "+14155550100".to_string(),
+            body: "Acme: Critical incident - API outage".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn http_provider_posts_json_and_returns_message_id() {
+        let (url, captured) = spawn_mock(StatusCode::OK).await;
+        let provider = HttpSmsProvider::new(
+            &url,
+            Some("sms-key".to_string()),
+            Some("+15550001111".to_string()),
+        )
+        .unwrap();
+
+        let id = provider.send(&outgoing()).await.unwrap();
+
+        assert_eq!(id.as_deref(), Some("msg_123"));
+        let (headers, body) = captured.lock().unwrap().take().expect("request captured");
+        assert_eq!(headers["authorization"], "Bearer sms-key");
+        assert_eq!(body["to"], "+14155550100");
+        assert_eq!(body["from"], "+15550001111");
+        assert_eq!(body["body"], "Acme: Critical incident - API outage");
+    }
+
+    #[tokio::test]
+    async fn http_provider_surfaces_gateway_errors() {
+        let (url, _captured) = spawn_mock(StatusCode::TOO_MANY_REQUESTS).await;
+        let provider = HttpSmsProvider::new(&url, None, None).unwrap();
+
+        let error = provider.send(&outgoing()).await.unwrap_err();
+        assert!(error.to_string().contains("429"));
+    }
+}
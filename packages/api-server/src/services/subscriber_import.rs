@@ -0,0 +1,445 @@
+use std::collections::HashSet;
+
+use shared::enums::DigestFrequency;
+use shared::error::AppError;
+use shared::models::organization::Organization;
+use shared::models::subscriber::{
+    ImportSubscriberRow, ImportSubscribersRequest, SubscriberImportError, SubscriberImportMode,
+    SubscriberImportSummary,
+};
+
+use crate::db;
+use crate::db::subscribers::{ImportOutcome, ImportedSubscriber, SubscriberExportRow};
+
+pub const MAX_IMPORT_ROWS: usize = 5_000;
+const MAX_ATTESTATION_CHARS: usize = 2_000;
+
+pub async fn import_subscribers(
+    pool: &sqlx::PgPool,
+    org: &Organization,
+    app_base_url: &str,
+    req: ImportSubscribersRequest,
+) -> Result<SubscriberImportSummary, AppError> {
+    let attestation = match req.mode {
+        SubscriberImportMode::SendVerification => None,
+        SubscriberImportMode::PreVerified => {
+            let attestation = req
+                .attestation
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .ok_or_else(|| {
+                    AppError::Validation(
+                        "Pre-verified imports require an attestation describing how consent was collected"
+                            .to_string(),
+                    )
+                })?;
+            if attestation.chars().count() > MAX_ATTESTATION_CHARS {
+                return Err(AppError::Validation(format!(
+                    "Attestation must be {MAX_ATTESTATION_CHARS} characters or fewer"
+                )));
+            }
+            Some(attestation.to_string())
+        }
+    };
+
+    let rows = match (req.csv, req.subscribers) {
+        (Some(csv), None) => parse_import_csv(&csv)?,
+        (None, Some(rows)) => rows
+            .into_iter()
+            .enumerate()
+            .map(|(index, row)| (index + 1, Ok(row)))
+            .collect(),
+        _ => {
+            return Err(AppError::Validation(
+                "Provide either csv or subscribers".to_string(),
+            ))
+        }
+    };
+    if rows.is_empty() {
+        return Err(AppError::Validation(
+            "The import contains no subscribers".to_string(),
+        ));
+    }
+    if rows.len() > MAX_IMPORT_ROWS {
+        return Err(AppError::Validation(format!(
+            "Imports are limited to {MAX_IMPORT_ROWS} subscribers at a time"
+        )));
+    }
+
+    let mut summary = SubscriberImportSummary {
+        total_rows: rows.len(),
+        ..Default::default()
+    };
+    let mut seen = HashSet::new();
+    // Imports always join the primary page.
+    let page = crate::db::status_pages::find_public(pool, org.id, None).await?;
+
+    for (row_number, row) in rows {
+        let row = match row {
+            Ok(row) => row,
+            Err(message) => {
+                summary.errors.push(SubscriberImportError {
+                    row: row_number,
+                    email: None,
+                    message,
+                });
+                continue;
+            }
+        };
+
+        let email = match shared::validation::normalize_subscriber_email(&row.email) {
+            Ok(email) => email,
+            Err(error) => {
+                summary
+                    .errors
+                    .push(row_error(row_number, &row.email, error));
+                continue;
+            }
+        };
+        if !seen.insert(email.clone()) {
+            summary.unchanged += 1;
+            continue;
+        }
+
+        let timezone = row
+            .timezone
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty());
+        if let Some(timezone) = timezone {
+            if !db::subscribers::timezone_exists(pool, timezone).await? {
+                summary.errors.push(row_error(
+                    row_number,
+                    &email,
+                    AppError::Validation(format!("Unknown timezone '{timezone}'")),
+                ));
+                continue;
+            }
+        }
+
+        let verification_token = uuid::Uuid::new_v4().to_string();
+        let unsubscribe_token = uuid::Uuid::new_v4().to_string();
+        let outcome = db::subscribers::import(
+            pool,
+            org.id,
+            ImportedSubscriber {
+                email: &email,
+                digest_frequency: row.digest_frequency,
+                timezone,
+                verification_token: &verification_token,
+                unsubscribe_token: &unsubscribe_token,
+                consent_attestation: attestation.as_deref(),
+            },
+        )
+        .await?;
+
+        let subscriber = match outcome {
+            ImportOutcome::Created(subscriber) => {
+                summary.created += 1;
+                subscriber
+            }
+            ImportOutcome::Updated(subscriber) => {
+                summary.updated += 1;
+                subscriber
+            }
+            ImportOutcome::Unchanged => {
+                summary.unchanged += 1;
+                continue;
+            }
+        };
+
+        if let Some(token) = subscriber.verification_token.as_deref() {
+            crate::services::email_notifications::queue_subscription_verification(
+                pool,
+                app_base_url,
+                &page,
+                &subscriber.email,
+                subscriber.locale,
+                token,
+            )
+            .await?;
+            summary.verification_emails_queued += 1;
+        }
+    }
+
+    Ok(summary)
+}
+
+fn row_error(row: usize, email: &str, error: AppError) -> SubscriberImportError {
+    let message = match error {
+        AppError::Validation(message) => message,
+        other => other.to_string(),
+    };
+    SubscriberImportError {
+        row,
+        email: Some(email.trim().to_string()),
+        message,
+    }
+}
+
+/// A subscriber row paired with the number reported for it in errors.
+pub type NumberedImportRow = (usize, Result<ImportSubscriberRow, String>);
+
+/// Parses an import CSV. The header must include `email`; `digest_frequency`
+/// and `timezone` are optional and unknown columns are ignored. Rows that
+/// cannot be mapped are returned as errors so the rest of the file still
+/// imports. Each row carries the file line it starts on.
+pub fn parse_import_csv(input: &str) -> Result<Vec<NumberedImportRow>, AppError> {
+    let mut records = parse_csv_records(input)?.into_iter();
+    let Some((_, header)) = records.next() else {
+        return Ok(Vec::new());
+    };
+
+    let column = |name: &str| {
+        header.iter().position(|value| {
+            value
+                .trim()
+                .trim_start_matches('\u{feff}')
+                .eq_ignore_ascii_case(name)
+        })
+    };
+    let email_column = column("email").ok_or_else(|| {
+        AppError::Validation("CSV header must include an email column".to_string())
+    })?;
+    let frequency_column = column("digest_frequency");
+    let timezone_column = column("timezone");
+
+    let rows = records
+        .map(|(line, record)| {
+            let row = parse_import_row(&record, email_column, frequency_column, timezone_column);
+            (line, row)
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+fn parse_import_row(
+    record: &[String],
+    email_column: usize,
+    frequency_column: Option<usize>,
+    timezone_column: Option<usize>,
+) -> Result<ImportSubscriberRow, String> {
+    let field = |index: Option<usize>| {
+        index
+            .and_then(|index| record.get(index))
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    };
+
+    let email = field(Some(email_column))
+        .ok_or_else(|| "Email is required".to_string())?
+        .to_string();
+    let digest_frequency = field(frequency_column)
+        .map(|value| {
+            serde_json::from_value::<DigestFrequency>(serde_json::Value::String(
+                value.to_lowercase(),
+            ))
+            .map_err(|_| {
+                format!("Unknown digest_frequency '{value}'; expected immediate, hourly or daily")
+            })
+        })
+        .transpose()?;
+
+    Ok(ImportSubscriberRow {
+        email,
+        digest_frequency,
+        timezone: field(timezone_column).map(str::to_string),
+    })
+}
+
+/// Splits CSV into records, each with the 1-based line it starts on. Blank
+/// records are dropped, but still count towards later line numbers.
+fn parse_csv_records(input: &str) -> Result<Vec<(usize, Vec<String>)>, AppError> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => {
+                    if c == '\n' || (c == '\r' && chars.peek() != Some(&'\n')) {
+                        line += 1;
+                    }
+                    field.push(c);
+                }
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                record.push(std::mem::take(&mut field));
+                push_record(&mut records, record_line, std::mem::take(&mut record));
+                line += 1;
+                record_line = line;
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(AppError::Validation(
+            "CSV has an unterminated quoted field".to_string(),
+        ));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        push_record(&mut records, record_line, record);
+    }
+
+    Ok(records)
+}
+
+fn push_record(records: &mut Vec<(usize, Vec<String>)>, line: usize, record: Vec<String>) {
+    if record.iter().any(|value| !value.trim().is_empty()) {
+        records.push((line, record));
+    }
+}
+
+pub fn export_csv(rows: &[SubscriberExportRow]) -> String {
+    let mut out = String::from(
+        "email,status,source,digest_frequency,timezone,phone_verified,consent_attestation,verification_sent_at,verified_at,created_at,updated_at\n",
+    );
+
+    for row in rows {
+        let fields = [
+            row.email.clone(),
+            if row.is_verified {
+                "verified"
+            } else {
+                "pending"
+            }
+            .to_string(),
+            row.source.as_str().to_string(),
+            row.digest_frequency.as_str().to_string(),
+            row.timezone.clone(),
+            row.phone_verified.to_string(),
+            row.consent_attestation.clone().unwrap_or_default(),
+            format_timestamp(row.verification_sent_at),
+            format_timestamp(row.verified_at),
+            format_timestamp(Some(row.created_at)),
+            format_timestamp(Some(row.updated_at)),
+        ];
+        let line = fields
+            .iter()
+            .map(|value| csv_field(value))
+            .collect::<Vec<_>>()
+            .join(",");
+        out.push_str(&line);
+        out.push('\n');
+    }
+
+    out
+}
+
+fn format_timestamp(value: Option<chrono::DateTime<chrono::Utc>>) -> String {
+    value
+        .map(|value| value.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+        .unwrap_or_default()
+}
+
+/// Quotes a CSV field when needed and defuses values a spreadsheet would
+/// otherwise evaluate as a formula.
+pub(crate) fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@']) {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    };
+
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::enums::SubscriberSource;
+
+    use super::*;
+
+    #[test]
+    fn parses_csv_with_optional_columns_and_row_errors() {
+        let csv = "\u{feff}Name,Email,digest_frequency,timezone\r\n\
+                   \"Doe, Jane\",jane@example.com,daily,Europe/Berlin\r\n\
+                   Bob,bob@example.com,,\r\n\
+                   \r\n\
+                   Eve,eve@example.com,weekly,\r\n\
+                   Nobody,,,\n";
+
+        let rows = parse_import_csv(csv).unwrap();
+
+        assert_eq!(rows.len(), 4);
+        let jane = rows[0].1.as_ref().unwrap();
+        assert_eq!(jane.email, "jane@example.com");
+        assert_eq!(jane.digest_frequency, Some(DigestFrequency::Daily));
+        assert_eq!(jane.timezone.as_deref(), Some("Europe/Berlin"));
+        let bob = rows[1].1.as_ref().unwrap();
+        assert_eq!(bob.digest_frequency, None);
+        assert_eq!(bob.timezone, None);
+        assert!(rows[2].1.as_ref().unwrap_err().contains("weekly"));
+        assert_eq!(rows[3].1.as_ref().unwrap_err(), "Email is required");
+    }
+
+    #[test]
+    fn rows_keep_their_file_line_across_blank_lines() {
+        let csv = "email,digest_frequency\n\
+                   jane@example.com,daily\n\
+                   \n\
+                   eve@example.com,weekly\n\
+                   \"multi\nline\",\n\
+                   ,daily\n";
+
+        let rows = parse_import_csv(csv).unwrap();
+        let lines = rows.iter().map(|(line, _)| *line).collect::<Vec<_>>();
+
+        assert_eq!(lines, vec![2, 4, 5, 7]);
+        assert!(rows[1].1.as_ref().unwrap_err().contains("weekly"));
+        assert_eq!(rows[3].1.as_ref().unwrap_err(), "Email is required");
+    }
+
+    #[test]
+    fn rejects_csv_without_email_header_or_with_open_quotes() {
+        assert!(parse_import_csv("name\nJane\n").is_err());
+        assert!(parse_import_csv("email\n\"jane@example.com\n").is_err());
+    }
+
+    #[test]
+    fn export_quotes_fields_and_defuses_formulas() {
+        let now = chrono::Utc::now();
+        let csv = export_csv(&[SubscriberExportRow {
+            email: "jane@example.com".to_string(),
+            is_verified: true,
+            source: SubscriberSource::Import,
+            digest_frequency: DigestFrequency::Immediate,
+            timezone: "UTC".to_string(),
+            phone_verified: false,
+            consent_attestation: Some("=HYPERLINK(\"x\"), opted in".to_string()),
+            verification_sent_at: None,
+            verified_at: Some(now),
+            created_at: now,
+            updated_at: now,
+        }]);
+
+        let line = csv.lines().nth(1).unwrap();
+        assert!(line.starts_with("jane@example.com,verified,import,immediate,UTC,false,"));
+        assert!(line.contains("\"'=HYPERLINK(\"\"x\"\"), opted in\""));
+    }
+}
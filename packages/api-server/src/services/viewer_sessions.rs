@@ -0,0 +1,364 @@
+use std::net::IpAddr;
+
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use axum::http::{header, HeaderMap, HeaderValue};
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use shared::enums::PageVisibility;
+use shared::error::AppError;
+use shared::models::page_access::{email_domain_allowed, ip_allowed};
+use uuid::Uuid;
+
+use crate::db::page_access::PageAccess;
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub const VIEWER_TOKEN_HEADER: &str = "x-statuspage-viewer-token";
+pub const VIEWER_COOKIE: &str = "statuspage_viewer";
+pub const VIEWER_SESSION_TTL: Duration = Duration::days(7);
+pub const LOGIN_LINK_TTL: Duration = Duration::minutes(15);
+
+/// Who a viewer session was issued to. Password sessions carry no email.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewerClaims {
+    pub email: Option<String>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Signs a session as `<expiry>.<hex email>.<hex mac>`. The MAC also covers
+/// the org and visibility, so a session never outlives a change of either.
+pub fn issue_session(
+    access: &PageAccess,
+    email: Option<&str>,
+    now: DateTime<Utc>,
+) -> (String, DateTime<Utc>) {
+    let expires_at = now + VIEWER_SESSION_TTL;
+    let expiry = expires_at.timestamp();
+    let email = email.unwrap_or_default();
+    let signature = hex::encode(session_mac(access, expiry, email).finalize().into_bytes());
+
+    (
+        format!("{expiry}.{}.{signature}", hex::encode(email)),
+        expires_at,
+    )
+}
+
+/// Returns the session's claims when the signature is valid for this page
+/// and the session has not expired.
+pub fn verify_session(
+    access: &PageAccess,
+    token: &str,
+    now: DateTime<Utc>,
+) -> Option<ViewerClaims> {
+    let mut parts = token.trim().splitn(3, '.');
+    let expiry = parts.next()?.parse::<i64>().ok()?;
+    let email = String::from_utf8(hex::decode(parts.next()?).ok()?).ok()?;
+    let signature = hex::decode(parts.next()?).ok()?;
+
+    session_mac(access, expiry, &email)
+        .verify_slice(&signature)
+        .ok()?;
+
+    let expires_at = DateTime::<Utc>::from_timestamp(expiry, 0)?;
+    if expires_at <= now {
+        return None;
+    }
+
+    Some(ViewerClaims {
+        email: (!email.is_empty()).then_some(email),
+        expires_at,
+    })
+}
+
+fn session_mac(access: &PageAccess, expiry: i64, email: &str) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(access.viewer_session_secret.as_bytes())
+        .expect("HMAC accepts keys of any size");
+    mac.update(
+        format!(
+            "{}.{}.{expiry}.{email}",
+            access.org_id,
+            access.page_visibility.as_str()
+        )
+        .as_bytes(),
+    );
+    mac
+}
+
+/// Whether a request may read the page. Restricted pages accept a viewer
+/// session from the header or cookie; IP allowlists check the client
+/// address, as resolved by [`client_ip`], on every request.
+pub fn viewer_allowed(
+    access: &PageAccess,
+    headers: &HeaderMap,
+    client_ip: Option<IpAddr>,
+    now: DateTime<Utc>,
+) -> bool {
+    match access.page_visibility {
+        PageVisibility::Public => true,
+        PageVisibility::IpAllowlist => {
+            client_ip.is_some_and(|ip| ip_allowed(ip, &access.page_allowed_cidrs))
+        }
+        PageVisibility::Password => viewer_token(headers)
+            .and_then(|token| verify_session(access, &token, now))
+            .is_some(),
+        PageVisibility::EmailDomain => viewer_token(headers)
+            .and_then(|token| verify_session(access, &token, now))
+            .and_then(|claims| claims.email)
+            .is_some_and(|email| email_domain_allowed(&email, &access.page_allowed_email_domains)),
+    }
+}
+
+/// The error returned to viewers who are not allowed in.
+pub fn access_denied(access: &PageAccess) -> AppError {
+    match access.page_visibility {
+        PageVisibility::IpAllowlist => AppError::Forbidden(
+            "This status page is only available from allowlisted networks".to_string(),
+        ),
+        _ => AppError::Unauthorized,
+    }
+}
+
+fn viewer_token(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(VIEWER_TOKEN_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+        .or_else(|| {
+            headers
+                .get_all(header::COOKIE)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .flat_map(|cookies| cookies.split(';'))
+                .filter_map(|cookie| cookie.trim().split_once('='))
+                .find(|(name, _)| *name == VIEWER_COOKIE)
+                .map(|(_, value)| value.to_string())
+        })
+        .filter(|token| !token.is_empty())
+}
+
+/// The address a request came from. The peer is the client unless it is a
+/// trusted proxy; then `X-Forwarded-For` is read from the right and the
+/// first hop outside the trusted proxies wins, since entries further left
+/// are whatever the client chose to send. Requests that never leave the
+/// trusted proxies, or carry a hop that is not an address, have no client.
+pub fn client_ip(
+    headers: &HeaderMap,
+    peer: Option<IpAddr>,
+    trusted_proxies: &[String],
+) -> Option<IpAddr> {
+    let peer = peer?;
+    if !ip_allowed(peer, trusted_proxies) {
+        return Some(peer);
+    }
+
+    let hops = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .map(|value| value.to_str().ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    for hop in hops.iter().flat_map(|value| value.split(',')).rev() {
+        let ip = hop.trim().parse::<IpAddr>().ok()?;
+        if !ip_allowed(ip, trusted_proxies) {
+            return Some(ip);
+        }
+    }
+
+    None
+}
+
+/// A cookie carrying the session, scoped to the page's public API paths.
+pub fn session_cookie(slug: &str, token: &str, expires_at: DateTime<Utc>) -> HeaderValue {
+    let max_age = (expires_at - Utc::now()).num_seconds().max(0);
+    HeaderValue::from_str(&format!(
+        "{VIEWER_COOKIE}={token}; Path=/api/public/{slug}; Max-Age={max_age}; HttpOnly; Secure; SameSite=Lax"
+    ))
+    .expect("viewer cookie is valid ASCII")
+}
+
+pub fn hash_password(password: &str) -> Result<String, AppError> {
+    let salt = SaltString::encode_b64(Uuid::new_v4().as_bytes())
+        .map_err(|error| AppError::Internal(anyhow::anyhow!("Failed to build salt: {error}")))?;
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|error| AppError::Internal(anyhow::anyhow!("Failed to hash password: {error}")))
+}
+
+pub fn verify_password(password: &str, password_hash: &str) -> bool {
+    PasswordHash::new(password_hash)
+        .map(|hash| {
+            Argon2::default()
+                .verify_password(password.as_bytes(), &hash)
+                .is_ok()
+        })
+        .unwrap_or(false)
+}
+
+/// Login link tokens are stored hashed, like a password reset token.
+pub fn hash_login_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn access(visibility: PageVisibility) -> PageAccess {
+        PageAccess {
+            org_id: Uuid::new_v4(),
+            page_id: None,
+            slug: "acme".to_string(),
+            page_visibility: visibility,
+            page_password_hash: None,
+            page_allowed_email_domains: vec!["acme.com".to_string()],
+            page_allowed_cidrs: vec!["10.0.0.0/8".to_string()],
+            viewer_session_secret: "secret".to_string(),
+        }
+    }
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(*name, HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn sessions_round_trip_and_expire() {
+        let page = access(PageVisibility::EmailDomain);
+        let now = Utc::now();
+        let (token, expires_at) = issue_session(&page, Some("ana@acme.com"), now);
+
+        let claims = verify_session(&page, &token, now).unwrap();
+        assert_eq!(claims.email.as_deref(), Some("ana@acme.com"));
+        assert_eq!(claims.expires_at.timestamp(), expires_at.timestamp());
+        assert!(verify_session(&page, &token, expires_at + Duration::seconds(1)).is_none());
+    }
+
+    #[test]
+    fn sessions_are_bound_to_secret_and_visibility() {
+        let page = access(PageVisibility::Password);
+        let now = Utc::now();
+        let (token, _) = issue_session(&page, None, now);
+
+        let mut rotated = page.clone();
+        rotated.viewer_session_secret = "rotated".to_string();
+        assert!(verify_session(&rotated, &token, now).is_none());
+
+        let mut switched = page.clone();
+        switched.page_visibility = PageVisibility::EmailDomain;
+        assert!(verify_session(&switched, &token, now).is_none());
+
+        let forged = token.replacen('.', ".616e6140616363652e636f6d", 1);
+        assert!(verify_session(&page, &forged, now).is_none());
+    }
+
+    fn ip(value: &str) -> Option<IpAddr> {
+        Some(value.parse().unwrap())
+    }
+
+    #[test]
+    fn client_ip_only_believes_hops_added_by_trusted_proxies() {
+        let trusted = vec!["172.16.0.0/12".to_string()];
+        let forwarded = headers(&[("x-forwarded-for", "10.1.2.3, 1.1.1.1, 172.18.0.5")]);
+
+        // Direct connections are judged by the peer, whatever they claim.
+        assert_eq!(
+            client_ip(&forwarded, ip("8.8.8.8"), &trusted),
+            ip("8.8.8.8")
+        );
+        assert_eq!(client_ip(&forwarded, ip("8.8.8.8"), &[]), ip("8.8.8.8"));
+
+        // Behind proxies, the rightmost untrusted hop is the client.
+        assert_eq!(
+            client_ip(&forwarded, ip("172.18.0.3"), &trusted),
+            ip("1.1.1.1")
+        );
+
+        assert_eq!(
+            client_ip(&HeaderMap::new(), ip("172.18.0.3"), &trusted),
+            None
+        );
+        assert_eq!(
+            client_ip(
+                &headers(&[("x-forwarded-for", "1.1.1.1, unknown")]),
+                ip("172.18.0.3"),
+                &trusted
+            ),
+            None
+        );
+        assert_eq!(client_ip(&forwarded, None, &trusted), None);
+    }
+
+    #[test]
+    fn ip_allowlists_reject_a_spoofed_forwarded_for() {
+        let now = Utc::now();
+        let ip_page = access(PageVisibility::IpAllowlist);
+        let trusted = vec!["172.16.0.0/12".to_string()];
+        let spoofed = headers(&[("x-forwarded-for", "10.0.0.1")]);
+
+        let direct = client_ip(&spoofed, ip("1.1.1.1"), &trusted);
+        assert!(!viewer_allowed(&ip_page, &spoofed, direct, now));
+
+        let proxied = headers(&[("x-forwarded-for", "10.0.0.1, 1.1.1.1")]);
+        let behind_proxy = client_ip(&proxied, ip("172.18.0.3"), &trusted);
+        assert!(!viewer_allowed(&ip_page, &proxied, behind_proxy, now));
+    }
+
+    #[test]
+    fn viewer_checks_follow_the_page_visibility() {
+        let now = Utc::now();
+        assert!(viewer_allowed(
+            &access(PageVisibility::Public),
+            &HeaderMap::new(),
+            None,
+            now
+        ));
+
+        let ip_page = access(PageVisibility::IpAllowlist);
+        assert!(viewer_allowed(
+            &ip_page,
+            &HeaderMap::new(),
+            ip("10.1.2.3"),
+            now
+        ));
+        assert!(!viewer_allowed(
+            &ip_page,
+            &HeaderMap::new(),
+            ip("1.1.1.1"),
+            now
+        ));
+        assert!(!viewer_allowed(&ip_page, &HeaderMap::new(), None, now));
+
+        let email_page = access(PageVisibility::EmailDomain);
+        let (token, _) = issue_session(&email_page, Some("ana@acme.com"), now);
+        let cookie = format!("theme=dark; {VIEWER_COOKIE}={token}");
+        assert!(viewer_allowed(
+            &email_page,
+            &headers(&[("cookie", &cookie)]),
+            None,
+            now
+        ));
+
+        let mut narrowed = email_page.clone();
+        narrowed.page_allowed_email_domains = vec!["other.com".to_string()];
+        assert!(!viewer_allowed(
+            &narrowed,
+            &headers(&[(VIEWER_TOKEN_HEADER, &token)]),
+            None,
+            now
+        ));
+    }
+
+    #[test]
+    fn passwords_hash_and_verify() {
+        let hash = hash_password("correct horse").unwrap();
+        assert!(verify_password("correct horse", &hash));
+        assert!(!verify_password("wrong horse", &hash));
+        assert!(!verify_password("correct horse", "not a hash"));
+    }
+}
@@ -9,16 +9,24 @@ use uuid::Uuid;
 
 use crate::config::Config;
 use crate::db::webhook_deliveries::{self, DeliveryFailureUpdate, PendingWebhookDelivery};
+use crate::db::webhook_subscribers;
+use crate::services::webhook_subscribers::{literal_host_is_public, subscriber_client};
 
 type HmacSha256 = Hmac<Sha256>;
 
+/// Deliveries to a customer webhook subscriber that must exhaust all retries
+/// in a row before the subscription is switched off.
+const SUBSCRIBER_AUTO_UNSUBSCRIBE_AFTER: i32 = 3;
+
 pub fn spawn(pool: PgPool, config: Config) {
     tokio::spawn(async move {
-        let client = match Client::builder()
-            .timeout(Duration::from_secs(config.webhook_timeout_secs))
+        let timeout = Duration::from_secs(config.webhook_timeout_secs);
+        let (client, subscriber_client) = match Client::builder()
+            .timeout(timeout)
             .build()
+            .and_then(|client| Ok((client, subscriber_client(timeout)?)))
         {
-            Ok(client) => client,
+            Ok(clients) => clients,
             Err(error) => {
                 tracing::error!(error = %error, "Failed to build webhook HTTP client");
                 return;
@@ -45,15 +53,61 @@ pub fn spawn(pool: PgPool, config: Config) {
                     tracing::warn!(error = %error, "Failed to claim pending webhook deliveries");
                 }
             }
+
+            match webhook_subscribers::claim_pending(&pool, config.webhook_dispatch_batch_size)
+                .await
+            {
+                Ok(deliveries) => {
+                    for delivery in deliveries {
+                        if let Err(error) =
+                            deliver_to_subscriber_once(&pool, &subscriber_client, delivery).await
+                        {
+                            tracing::warn!(
+                                error = %error,
+                                "Webhook subscriber delivery attempt failed"
+                            );
+                        }
+                    }
+                }
+                Err(error) => {
+                    tracing::warn!(
+                        error = %error,
+                        "Failed to claim pending webhook subscriber deliveries"
+                    );
+                }
+            }
         }
     });
 }
 
-async fn deliver_once(
-    pool: &PgPool,
+struct AttemptOutcome {
+    success: bool,
+    response_status_code: Option<i32>,
+    response_body: Option<String>,
+    error_message: Option<String>,
+}
+
+impl AttemptOutcome {
+    fn failure_update(&self, delivery: &PendingWebhookDelivery) -> DeliveryFailureUpdate<'_> {
+        DeliveryFailureUpdate {
+            attempt_count: delivery.attempt_count,
+            max_attempts: delivery.max_attempts,
+            response_status_code: self.response_status_code,
+            response_body: self.response_body.as_deref(),
+            error_message: self.error_message.as_deref(),
+            next_retry_at: next_retry_at(
+                delivery.delivery_id,
+                delivery.attempt_count,
+                delivery.max_attempts,
+            ),
+        }
+    }
+}
+
+async fn attempt(
     client: &Client,
-    delivery: PendingWebhookDelivery,
-) -> anyhow::Result<()> {
+    delivery: &PendingWebhookDelivery,
+) -> anyhow::Result<AttemptOutcome> {
     let payload_text = serde_json::to_string(&delivery.payload.0)?;
     let timestamp = Utc::now().timestamp().to_string();
     let signature = sign_payload(&delivery.secret, &timestamp, &payload_text);
@@ -70,61 +124,96 @@ async fn deliver_once(
         .send()
         .await;
 
-    match response {
+    Ok(match response {
         Ok(response) => {
             let status = response.status();
             let body_text = response.text().await.unwrap_or_default();
-            let body_text = truncate(&body_text, 4000);
-
-            if status.is_success() {
-                webhook_deliveries::mark_success(
-                    pool,
-                    delivery.delivery_id,
-                    Some(status.as_u16() as i32),
-                    body_text.as_deref(),
-                )
-                .await?;
-            } else {
-                webhook_deliveries::mark_failure(
-                    pool,
-                    delivery.delivery_id,
-                    DeliveryFailureUpdate {
-                        attempt_count: delivery.attempt_count,
-                        max_attempts: delivery.max_attempts,
-                        response_status_code: Some(status.as_u16() as i32),
-                        response_body: body_text.as_deref(),
-                        error_message: Some(&format!(
-                            "Webhook endpoint returned HTTP {}",
-                            status.as_u16()
-                        )),
-                        next_retry_at: next_retry_at(
-                            delivery.delivery_id,
-                            delivery.attempt_count,
-                            delivery.max_attempts,
-                        ),
-                    },
-                )
-                .await?;
+
+            AttemptOutcome {
+                success: status.is_success(),
+                response_status_code: Some(status.as_u16() as i32),
+                response_body: truncate(&body_text, 4000),
+                error_message: (!status.is_success())
+                    .then(|| format!("Webhook endpoint returned HTTP {}", status.as_u16())),
             }
         }
-        Err(error) => {
-            webhook_deliveries::mark_failure(
-                pool,
-                delivery.delivery_id,
-                DeliveryFailureUpdate {
-                    attempt_count: delivery.attempt_count,
-                    max_attempts: delivery.max_attempts,
-                    response_status_code: None,
-                    response_body: None,
-                    error_message: Some(&error.to_string()),
-                    next_retry_at: next_retry_at(
-                        delivery.delivery_id,
-                        delivery.attempt_count,
-                        delivery.max_attempts,
-                    ),
-                },
-            )
-            .await?;
+        Err(error) => AttemptOutcome {
+            success: false,
+            response_status_code: None,
+            response_body: None,
+            error_message: Some(error.to_string()),
+        },
+    })
+}
+
+async fn deliver_once(
+    pool: &PgPool,
+    client: &Client,
+    delivery: PendingWebhookDelivery,
+) -> anyhow::Result<()> {
+    let outcome = attempt(client, &delivery).await?;
+
+    if outcome.success {
+        webhook_deliveries::mark_success(
+            pool,
+            delivery.delivery_id,
+            outcome.response_status_code,
+            outcome.response_body.as_deref(),
+        )
+        .await?;
+    } else {
+        webhook_deliveries::mark_failure(
+            pool,
+            delivery.delivery_id,
+            outcome.failure_update(&delivery),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Subscriber endpoints are public and unauthenticated, so `client` must be
+/// the subscriber client that re-checks addresses and ignores redirects.
+async fn deliver_to_subscriber_once(
+    pool: &PgPool,
+    client: &Client,
+    delivery: PendingWebhookDelivery,
+) -> anyhow::Result<()> {
+    let outcome = if literal_host_is_public(&delivery.url) {
+        attempt(client, &delivery).await?
+    } else {
+        AttemptOutcome {
+            success: false,
+            response_status_code: None,
+            response_body: None,
+            error_message: Some(
+                "Webhook URL does not point at a public internet address".to_string(),
+            ),
+        }
+    };
+
+    if outcome.success {
+        webhook_subscribers::mark_success(
+            pool,
+            delivery.delivery_id,
+            outcome.response_status_code,
+            outcome.response_body.as_deref(),
+        )
+        .await?;
+    } else {
+        let disabled = webhook_subscribers::mark_failure(
+            pool,
+            delivery.delivery_id,
+            outcome.failure_update(&delivery),
+            SUBSCRIBER_AUTO_UNSUBSCRIBE_AFTER,
+        )
+        .await?;
+        if disabled {
+            tracing::info!(
+                url = %delivery.url,
+                "Webhook subscriber unsubscribed after repeated delivery failures"
+            );
         }
     }
 
@@ -0,0 +1,111 @@
+// Issues a certificate from a local Pebble ACME server.
+// Start Pebble (https://github.com/letsencrypt/pebble) with its test config,
+// either with PEBBLE_VA_ALWAYS_VALID=1 or with the test domain resolving to
+// this machine so Pebble can fetch the challenge from port 5002, then run:
+//
+//   PEBBLE_DIRECTORY_URL=https://localhost:14000/dir \
+//   PEBBLE_CA_CERT=test/certs/pebble.minica.pem \
+//   cargo test -p api-server --test acme_pebble_test
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    use api_server::services::acme::{AcmeClient, Http01Responder};
+    use api_server::services::certificates::certificate_validity;
+    use async_trait::async_trait;
+    use axum::extract::{Path, State};
+    use axum::http::StatusCode;
+    use p256::ecdsa::SigningKey;
+
+    type Challenges = Arc<Mutex<HashMap<String, String>>>;
+
+    #[derive(Clone, Default)]
+    struct MemoryResponder {
+        challenges: Challenges,
+    }
+
+    #[async_trait]
+    impl Http01Responder for MemoryResponder {
+        async fn publish(
+            &self,
+            _domain: &str,
+            token: &str,
+            key_authorization: &str,
+        ) -> anyhow::Result<()> {
+            self.challenges
+                .lock()
+                .unwrap()
+                .insert(token.to_string(), key_authorization.to_string());
+            Ok(())
+        }
+
+        async fn withdraw(&self, token: &str) -> anyhow::Result<()> {
+            self.challenges.lock().unwrap().remove(token);
+            Ok(())
+        }
+    }
+
+    async fn serve_challenges(challenges: Challenges) {
+        let port = std::env::var("PEBBLE_HTTP_PORT").unwrap_or_else(|_| "5002".to_string());
+        let Ok(listener) = tokio::net::TcpListener::bind(format!("0.0.0.0:{port}")).await else {
+            eprintln!("Port {port} is busy; relying on PEBBLE_VA_ALWAYS_VALID");
+            return;
+        };
+        let app = axum::Router::new()
+            .route(
+                "/.well-known/acme-challenge/{token}",
+                axum::routing::get(
+                    |State(challenges): State<Challenges>, Path(token): Path<String>| async move {
+                        challenges
+                            .lock()
+                            .unwrap()
+                            .get(&token)
+                            .cloned()
+                            .ok_or(StatusCode::NOT_FOUND)
+                    },
+                ),
+            )
+            .with_state(challenges);
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+    }
+
+    #[tokio::test]
+    async fn issues_a_certificate_from_pebble() {
+        let Ok(directory_url) = std::env::var("PEBBLE_DIRECTORY_URL") else {
+            eprintln!("PEBBLE_DIRECTORY_URL not set, skipping ACME integration test");
+            return;
+        };
+        let ca_cert = std::env::var("PEBBLE_CA_CERT")
+            .ok()
+            .map(|path| std::fs::read(path).expect("PEBBLE_CA_CERT is readable"));
+        let domain = std::env::var("PEBBLE_TEST_DOMAIN")
+            .unwrap_or_else(|_| "status.statuspage.test".to_string());
+
+        let responder = MemoryResponder::default();
+        serve_challenges(responder.challenges.clone()).await;
+
+        let key = SigningKey::random(&mut aes_gcm::aead::OsRng);
+        let mut client = AcmeClient::connect(&directory_url, ca_cert.as_deref(), key)
+            .await
+            .expect("Pebble directory loads");
+        client
+            .register(Some("ops@statuspage.test"))
+            .await
+            .expect("account registers");
+
+        let issued = client
+            .issue(&domain, &responder)
+            .await
+            .expect("certificate issues");
+
+        assert!(issued.certificate_pem.contains("BEGIN CERTIFICATE"));
+        assert!(issued.private_key_pem.contains("BEGIN PRIVATE KEY"));
+        let (not_before, not_after) = certificate_validity(&issued.certificate_pem).unwrap();
+        assert!(not_before < not_after);
+        assert!(responder.challenges.lock().unwrap().is_empty());
+    }
+}
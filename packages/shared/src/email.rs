@@ -0,0 +1,893 @@
+//! Subscriber and member email templates.
+//!
+//! Every template renders a subject plus matching plain-text and HTML bodies so
+//! the dispatcher can send a `multipart/alternative` message. Both the API
+//! server and the monitor render through this module so emails look the same
+//! regardless of which process queued them.
+
+use crate::enums::{
+    DigestFrequency, IncidentImpact, IncidentStatus, Locale, MemberRole, ServiceStatus,
+};
+use crate::i18n::{format_message, message, Label};
+
+const DEFAULT_BRAND_COLOR: &str = "#3B82F6";
+
+#[derive(Debug, Clone)]
+pub struct EmailBranding {
+    pub org_name: String,
+    pub logo_url: Option<String>,
+    pub brand_color: String,
+    /// Language the template text is rendered in.
+    pub locale: Locale,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedEmail {
+    pub subject: String,
+    pub text: String,
+    pub html: String,
+}
+
+pub struct IncidentCreatedEmail<'a> {
+    pub title: &'a str,
+    pub impact: IncidentImpact,
+    pub affected_services: &'a [String],
+    pub history_url: &'a str,
+    pub unsubscribe_url: &'a str,
+}
+
+pub struct IncidentUpdatedEmail<'a> {
+    pub title: Option<&'a str>,
+    pub status: IncidentStatus,
+    pub message: &'a str,
+    pub history_url: &'a str,
+    pub unsubscribe_url: &'a str,
+}
+
+pub struct ServiceStatusChangedEmail<'a> {
+    pub service_name: &'a str,
+    pub old_status: ServiceStatus,
+    pub new_status: ServiceStatus,
+    pub status_url: &'a str,
+    pub unsubscribe_url: &'a str,
+}
+
+pub struct IncidentEscalationEmail<'a> {
+    pub title: &'a str,
+    pub impact: IncidentImpact,
+    pub unacknowledged_minutes: i64,
+    pub escalation: i32,
+    pub incident_url: &'a str,
+}
+
+pub struct CustomDomainMisconfiguredEmail<'a> {
+    pub domain: &'a str,
+    pub page_name: &'a str,
+    pub target: &'a str,
+    pub txt_record_name: &'a str,
+    pub failed_checks: i32,
+    /// Already rendered in the organization's timezone.
+    pub grace_ends_at: &'a str,
+    pub settings_url: &'a str,
+}
+
+pub struct CustomDomainRecoveredEmail<'a> {
+    pub domain: &'a str,
+    pub page_name: &'a str,
+    pub target: &'a str,
+    pub settings_url: &'a str,
+}
+
+pub struct DigestItem<'a> {
+    pub subject: &'a str,
+    pub occurred_at: &'a str,
+}
+
+pub struct SubscriberDigestEmail<'a> {
+    pub frequency: DigestFrequency,
+    pub items: &'a [DigestItem<'a>],
+    pub history_url: &'a str,
+    pub unsubscribe_url: &'a str,
+}
+
+pub fn subscription_verification(branding: &EmailBranding, verify_url: &str) -> RenderedEmail {
+    let locale = branding.locale;
+    let org_name = [("org_name", branding.org_name.as_str())];
+    let subject = format_message(locale, "verification.subject", &org_name);
+    let intro = format_message(locale, "verification.intro", &org_name);
+    let ignore = message(locale, "verification.ignore");
+    let text = format!(
+        "{intro}\n\n{}\n{verify_url}\n\n{ignore}",
+        message(locale, "verification.link_text")
+    );
+    let html = render_layout(
+        branding,
+        &subject,
+        &[
+            paragraph(&intro),
+            button(branding, message(locale, "verification.button"), verify_url),
+            muted(ignore),
+        ],
+        None,
+    );
+
+    RenderedEmail {
+        subject,
+        text,
+        html,
+    }
+}
+
+pub fn incident_created(
+    branding: &EmailBranding,
+    email: &IncidentCreatedEmail<'_>,
+) -> RenderedEmail {
+    let locale = branding.locale;
+    let title = email.title;
+    let affected = if email.affected_services.is_empty() {
+        message(locale, "common.not_applicable").to_string()
+    } else {
+        email.affected_services.join(", ")
+    };
+    let impact = email.impact.label(locale);
+    let subject = format_message(locale, "incident_created.subject", &[("title", title)]);
+    let intro = message(locale, "incident_created.intro");
+    let text = format!(
+        "{intro}\n\n{}: {title}\n{}: {impact}\n{}: {affected}\n\n{}",
+        message(locale, "common.title"),
+        message(locale, "common.impact"),
+        message(locale, "common.affected_services"),
+        follow_and_unsubscribe_text(locale, email.history_url, email.unsubscribe_url),
+    );
+    let html = render_layout(
+        branding,
+        &subject,
+        &[
+            paragraph(intro),
+            details_table(&[
+                (message(locale, "common.impact"), impact.to_string()),
+                (message(locale, "common.affected_services"), affected),
+            ]),
+            button(
+                branding,
+                message(locale, "common.follow_updates"),
+                email.history_url,
+            ),
+        ],
+        Some(email.unsubscribe_url),
+    );
+
+    RenderedEmail {
+        subject,
+        text,
+        html,
+    }
+}
+
+pub fn incident_updated(
+    branding: &EmailBranding,
+    email: &IncidentUpdatedEmail<'_>,
+) -> RenderedEmail {
+    let locale = branding.locale;
+    let status = email.status.label(locale);
+    let resolved = email.status == IncidentStatus::Resolved;
+    let subject = match (email.title, resolved) {
+        (Some(title), true) => {
+            format_message(locale, "incident_resolved.subject", &[("title", title)])
+        }
+        (Some(title), false) => {
+            format_message(locale, "incident_updated.subject", &[("title", title)])
+        }
+        (None, true) => message(locale, "incident_resolved.subject_untitled").to_string(),
+        (None, false) => format_message(
+            locale,
+            "incident_updated.subject_untitled",
+            &[("status", status)],
+        ),
+    };
+    let intro = match email.title {
+        Some(title) => format_message(locale, "incident_updated.intro", &[("title", title)]),
+        None => message(locale, "incident_updated.intro_untitled").to_string(),
+    };
+    let text = format!(
+        "{intro}\n\n{}: {status}\n{}: {}\n\n{}",
+        message(locale, "common.status"),
+        message(locale, "common.message"),
+        email.message,
+        follow_and_unsubscribe_text(locale, email.history_url, email.unsubscribe_url),
+    );
+    let html = render_layout(
+        branding,
+        &subject,
+        &[
+            paragraph(&intro),
+            details_table(&[(message(locale, "common.status"), status.to_string())]),
+            paragraph(email.message),
+            button(
+                branding,
+                message(locale, "common.follow_updates"),
+                email.history_url,
+            ),
+        ],
+        Some(email.unsubscribe_url),
+    );
+
+    RenderedEmail {
+        subject,
+        text,
+        html,
+    }
+}
+
+pub fn service_status_changed(
+    branding: &EmailBranding,
+    email: &ServiceStatusChangedEmail<'_>,
+) -> RenderedEmail {
+    let locale = branding.locale;
+    let service_name = email.service_name;
+    let subject = format_message(
+        locale,
+        "service_status.subject",
+        &[("service", service_name)],
+    );
+    let summary = format_message(
+        locale,
+        "service_status.summary",
+        &[
+            ("service", service_name),
+            ("old_status", email.old_status.label(locale)),
+            ("new_status", email.new_status.label(locale)),
+        ],
+    );
+    let text = format!(
+        "{summary}\n\n{}\n{}\n\n{}\n{}",
+        message(locale, "service_status.link_text"),
+        email.status_url,
+        message(locale, "common.unsubscribe_at"),
+        email.unsubscribe_url,
+    );
+    let html = render_layout(
+        branding,
+        &subject,
+        &[
+            paragraph(&summary),
+            button(
+                branding,
+                message(locale, "service_status.button"),
+                email.status_url,
+            ),
+        ],
+        Some(email.unsubscribe_url),
+    );
+
+    RenderedEmail {
+        subject,
+        text,
+        html,
+    }
+}
+
+/// Sent to organization admins, not subscribers, so there is no unsubscribe
+/// footer.
+pub fn incident_escalation(
+    branding: &EmailBranding,
+    email: &IncidentEscalationEmail<'_>,
+) -> RenderedEmail {
+    let locale = branding.locale;
+    let subject = format_message(locale, "escalation.subject", &[("title", email.title)]);
+    let summary = format_message(
+        locale,
+        "escalation.summary",
+        &[
+            ("title", email.title),
+            ("minutes", &email.unacknowledged_minutes.to_string()),
+        ],
+    );
+    let impact = email.impact.label(locale);
+    let text = format!(
+        "{summary}\n\n{}: {impact}\n{}: {}\n\n{}\n{}",
+        message(locale, "common.impact"),
+        message(locale, "escalation.escalation"),
+        email.escalation,
+        message(locale, "escalation.link_text"),
+        email.incident_url,
+    );
+    let html = render_layout(
+        branding,
+        &subject,
+        &[
+            paragraph(&summary),
+            details_table(&[
+                (message(locale, "common.impact"), impact.to_string()),
+                (
+                    message(locale, "escalation.escalation"),
+                    email.escalation.to_string(),
+                ),
+            ]),
+            button(
+                branding,
+                message(locale, "escalation.button"),
+                email.incident_url,
+            ),
+        ],
+        None,
+    );
+
+    RenderedEmail {
+        subject,
+        text,
+        html,
+    }
+}
+
+/// Sent to organization owners when background checks find a verified
+/// custom domain no longer pointing at us.
+pub fn custom_domain_misconfigured(
+    branding: &EmailBranding,
+    email: &CustomDomainMisconfiguredEmail<'_>,
+) -> RenderedEmail {
+    let locale = branding.locale;
+    let subject = format_message(
+        locale,
+        "custom_domain.misconfigured_subject",
+        &[("domain", email.domain), ("page_name", email.page_name)],
+    );
+    let summary = format_message(
+        locale,
+        "custom_domain.misconfigured_summary",
+        &[
+            ("domain", email.domain),
+            ("count", &email.failed_checks.to_string()),
+            ("target", email.target),
+            ("txt_name", email.txt_record_name),
+        ],
+    );
+    let grace = format_message(
+        locale,
+        "custom_domain.misconfigured_grace",
+        &[
+            ("page_name", email.page_name),
+            ("grace_ends_at", email.grace_ends_at),
+        ],
+    );
+    let text = format!(
+        "{summary}\n\n{grace}\n\n{}\n{}",
+        message(locale, "custom_domain.link_text"),
+        email.settings_url,
+    );
+    let html = render_layout(
+        branding,
+        &subject,
+        &[
+            paragraph(&summary),
+            paragraph(&grace),
+            button(
+                branding,
+                message(locale, "custom_domain.button"),
+                email.settings_url,
+            ),
+        ],
+        None,
+    );
+
+    RenderedEmail {
+        subject,
+        text,
+        html,
+    }
+}
+
+pub fn custom_domain_recovered(
+    branding: &EmailBranding,
+    email: &CustomDomainRecoveredEmail<'_>,
+) -> RenderedEmail {
+    let locale = branding.locale;
+    let subject = format_message(
+        locale,
+        "custom_domain.recovered_subject",
+        &[("domain", email.domain), ("page_name", email.page_name)],
+    );
+    let summary = format_message(
+        locale,
+        "custom_domain.recovered_summary",
+        &[
+            ("domain", email.domain),
+            ("target", email.target),
+            ("page_name", email.page_name),
+        ],
+    );
+    let text = format!("{summary}\n\n{}", email.settings_url);
+    let html = render_layout(
+        branding,
+        &subject,
+        &[
+            paragraph(&summary),
+            button(
+                branding,
+                message(locale, "custom_domain.button"),
+                email.settings_url,
+            ),
+        ],
+        None,
+    );
+
+    RenderedEmail {
+        subject,
+        text,
+        html,
+    }
+}
+
+pub fn invitation(branding: &EmailBranding, role: MemberRole, invite_url: &str) -> RenderedEmail {
+    let locale = branding.locale;
+    let org_name = branding.org_name.as_str();
+    let subject = format_message(locale, "invitation.subject", &[("org_name", org_name)]);
+    let intro = format_message(
+        locale,
+        "invitation.intro",
+        &[("org_name", org_name), ("role", role.label(locale))],
+    );
+    let note = message(locale, "invitation.note");
+    let text = format!(
+        "{intro}\n\n{}\n{invite_url}\n\n{note}",
+        message(locale, "invitation.link_text")
+    );
+    let html = render_layout(
+        branding,
+        &subject,
+        &[
+            paragraph(&intro),
+            button(branding, message(locale, "invitation.button"), invite_url),
+            muted(note),
+        ],
+        None,
+    );
+
+    RenderedEmail {
+        subject,
+        text,
+        html,
+    }
+}
+
+pub fn viewer_magic_link(branding: &EmailBranding, login_url: &str) -> RenderedEmail {
+    let locale = branding.locale;
+    let org_name = [("org_name", branding.org_name.as_str())];
+    let subject = format_message(locale, "magic_link.subject", &org_name);
+    let intro = format_message(locale, "magic_link.intro", &org_name);
+    let note = message(locale, "magic_link.note");
+    let text = format!("{intro}\n{login_url}\n\n{note}");
+    let html = render_layout(
+        branding,
+        &subject,
+        &[
+            paragraph(&intro),
+            button(branding, message(locale, "magic_link.button"), login_url),
+            muted(note),
+        ],
+        None,
+    );
+
+    RenderedEmail {
+        subject,
+        text,
+        html,
+    }
+}
+
+pub fn subscriber_digest(
+    branding: &EmailBranding,
+    email: &SubscriberDigestEmail<'_>,
+) -> RenderedEmail {
+    let locale = branding.locale;
+    let count = email.items.len().to_string();
+    let subject_key = if email.items.len() == 1 {
+        "digest.subject_one"
+    } else {
+        "digest.subject_other"
+    };
+    let subject = format_message(
+        locale,
+        subject_key,
+        &[("org_name", &branding.org_name), ("count", &count)],
+    );
+    let intro = message(
+        locale,
+        match email.frequency {
+            DigestFrequency::Hourly => "digest.intro_hourly",
+            DigestFrequency::Daily => "digest.intro_daily",
+            DigestFrequency::Immediate => "digest.intro_quiet_hours",
+        },
+    );
+    let lines = email
+        .items
+        .iter()
+        .map(|item| format!("- {} ({})", item.subject, item.occurred_at))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let text = format!(
+        "{intro}\n\n{lines}\n\n{}",
+        follow_and_unsubscribe_text(locale, email.history_url, email.unsubscribe_url),
+    );
+    let list_items = email
+        .items
+        .iter()
+        .map(|item| {
+            format!(
+                r#"<li style="margin:0 0 8px 0;">{}<br><span style="font-size:13px;color:#6b7280;">{}</span></li>"#,
+                escape_html(item.subject),
+                escape_html(item.occurred_at)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("");
+    let html = render_layout(
+        branding,
+        &subject,
+        &[
+            paragraph(intro),
+            format!(r#"<ul style="margin:16px 0 0 0;padding:0 0 0 20px;">{list_items}</ul>"#),
+            button(
+                branding,
+                message(locale, "common.follow_updates"),
+                email.history_url,
+            ),
+        ],
+        Some(email.unsubscribe_url),
+    );
+
+    RenderedEmail {
+        subject,
+        text,
+        html,
+    }
+}
+
+/// Wraps already-escaped body blocks in the shared branded layout.
+pub fn render_layout(
+    branding: &EmailBranding,
+    heading: &str,
+    blocks: &[String],
+    unsubscribe_url: Option<&str>,
+) -> String {
+    let locale = branding.locale;
+    let color = brand_color(branding);
+    let org_name = escape_html(&branding.org_name);
+    let header = match safe_logo_url(branding) {
+        Some(logo_url) => format!(
+            r#"<img src="{}" alt="{org_name}" height="32" style="display:block;height:32px;border:0;">"#,
+            escape_html(logo_url)
+        ),
+        None => format!(
+            r#"<span style="font-size:18px;font-weight:600;color:{color};">{org_name}</span>"#
+        ),
+    };
+    let org_name_arg = [("org_name", branding.org_name.as_str())];
+    let footer = match unsubscribe_url {
+        Some(url) => format!(
+            r#"{} <a href="{}" style="color:#6b7280;">{}</a>"#,
+            escape_html(&format_message(
+                locale,
+                "layout.subscribed_footer",
+                &org_name_arg
+            )),
+            escape_html(url),
+            escape_html(message(locale, "common.unsubscribe")),
+        ),
+        None => escape_html(&format_message(locale, "layout.sent_by", &org_name_arg)),
+    };
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="{lang}">
+<head><meta charset="utf-8"><meta name="viewport" content="width=device-width,initial-scale=1"><title>{heading}</title></head>
+<body style="margin:0;padding:0;background:#f3f4f6;font-family:-apple-system,BlinkMacSystemFont,'Segoe UI',Helvetica,Arial,sans-serif;color:#111827;">
+<table role="presentation" width="100%" cellpadding="0" cellspacing="0" style="background:#f3f4f6;padding:24px 0;">
+<tr><td align="center">
+<table role="presentation" width="560" cellpadding="0" cellspacing="0" style="max-width:560px;width:100%;background:#ffffff;border-radius:8px;border-top:4px solid {color};">
+<tr><td style="padding:24px 32px 0 32px;">{header}</td></tr>
+<tr><td style="padding:16px 32px 0 32px;"><h1 style="margin:0;font-size:20px;line-height:28px;">{heading}</h1></td></tr>
+<tr><td style="padding:8px 32px 24px 32px;font-size:15px;line-height:22px;">{body}</td></tr>
+</table>
+<p style="max-width:560px;margin:16px auto 0 auto;font-size:12px;line-height:18px;color:#6b7280;">{footer}</p>
+</td></tr>
+</table>
+</body>
+</html>
+"#,
+        lang = locale.as_str(),
+        heading = escape_html(heading),
+        body = blocks.join("\n"),
+    )
+}
+
+fn follow_and_unsubscribe_text(locale: Locale, history_url: &str, unsubscribe_url: &str) -> String {
+    format!(
+        "{}\n{history_url}\n\n{}\n{unsubscribe_url}",
+        message(locale, "common.follow_updates_at"),
+        message(locale, "common.unsubscribe_at"),
+    )
+}
+
+pub fn paragraph(text: &str) -> String {
+    let escaped = escape_html(text).replace('\n', "<br>");
+    format!(r#"<p style="margin:16px 0 0 0;">{escaped}</p>"#)
+}
+
+pub fn muted(text: &str) -> String {
+    format!(
+        r#"<p style="margin:16px 0 0 0;font-size:13px;color:#6b7280;">{}</p>"#,
+        escape_html(text)
+    )
+}
+
+pub fn button(branding: &EmailBranding, label: &str, url: &str) -> String {
+    format!(
+        r#"<p style="margin:24px 0 0 0;"><a href="{}" style="display:inline-block;padding:10px 18px;border-radius:6px;background:{};color:#ffffff;text-decoration:none;font-weight:600;">{}</a></p>"#,
+        escape_html(url),
+        brand_color(branding),
+        escape_html(label)
+    )
+}
+
+pub fn details_table(rows: &[(&str, String)]) -> String {
+    let rows = rows
+        .iter()
+        .map(|(label, value)| {
+            format!(
+                r#"<tr><td style="padding:4px 16px 4px 0;color:#6b7280;white-space:nowrap;">{}</td><td style="padding:4px 0;">{}</td></tr>"#,
+                escape_html(label),
+                escape_html(value)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        r#"<table role="presentation" cellpadding="0" cellspacing="0" style="margin:16px 0 0 0;font-size:14px;">{rows}</table>"#
+    )
+}
+
+pub fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Brand colors are validated on write, but older rows predate that check, so
+/// anything that is not `#RRGGBB` falls back to the default instead of being
+/// interpolated into inline CSS.
+fn brand_color(branding: &EmailBranding) -> &str {
+    let color = branding.brand_color.trim();
+    let is_hex = color.len() == 7
+        && color.starts_with('#')
+        && color[1..].chars().all(|ch| ch.is_ascii_hexdigit());
+
+    if is_hex {
+        color
+    } else {
+        DEFAULT_BRAND_COLOR
+    }
+}
+
+fn safe_logo_url(branding: &EmailBranding) -> Option<&str> {
+    branding
+        .logo_url
+        .as_deref()
+        .map(str::trim)
+        .filter(|url| url.starts_with("https://") || url.starts_with("http://"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn branding() -> EmailBranding {
+        EmailBranding {
+            org_name: "Acme <Cloud>".to_string(),
+            logo_url: Some("https://cdn.example.com/logo.png".to_string()),
+            brand_color: "#10B981".to_string(),
+            locale: Locale::En,
+        }
+    }
+
+    #[test]
+    fn incident_created_renders_text_and_branded_html() {
+        let services = vec!["API".to_string(), "Dashboard".to_string()];
+        let email = incident_created(
+            &branding(),
+            &IncidentCreatedEmail {
+                title: "Elevated errors",
+                impact: IncidentImpact::Major,
+                affected_services: &services,
+                history_url: "https://status.example.com/history",
+                unsubscribe_url: "https://status.example.com/unsubscribe?token=abc",
+            },
+        );
+
+        assert_eq!(email.subject, "New incident: Elevated errors");
+        assert!(email.text.contains("Affected services: API, Dashboard"));
+        assert!(email.text.contains("unsubscribe?token=abc"));
+        assert!(email.html.contains("#10B981"));
+        assert!(email.html.contains("https://cdn.example.com/logo.png"));
+        assert!(email.html.contains("Acme &lt;Cloud&gt;"));
+        assert!(email.html.contains("Unsubscribe</a>"));
+    }
+
+    #[test]
+    fn incident_escalation_links_to_dashboard_without_unsubscribe() {
+        let email = incident_escalation(
+            &branding(),
+            &IncidentEscalationEmail {
+                title: "API is experiencing issues",
+                impact: IncidentImpact::Major,
+                unacknowledged_minutes: 30,
+                escalation: 2,
+                incident_url: "https://app.example.com/dashboard/acme/incidents/1",
+            },
+        );
+
+        assert_eq!(
+            email.subject,
+            "Unacknowledged incident: API is experiencing issues"
+        );
+        assert!(email.text.contains("open for 30 minutes"));
+        assert!(email.html.contains("Acknowledge incident"));
+        assert!(!email.html.contains("Unsubscribe</a>"));
+    }
+
+    #[test]
+    fn custom_domain_alerts_render_in_the_branding_locale() {
+        let misconfigured = custom_domain_misconfigured(
+            &branding(),
+            &CustomDomainMisconfiguredEmail {
+                domain: "status.acme.com",
+                page_name: "Acme",
+                target: "pages.statuspage.test",
+                txt_record_name: "_statuspage-verification.status.acme.com",
+                failed_checks: 3,
+                grace_ends_at: "Mar 4, 2026, 10:15 CET",
+                settings_url: "https://app.example.com/dashboard/acme/settings",
+            },
+        );
+
+        assert_eq!(
+            misconfigured.subject,
+            "Action needed: status.acme.com no longer points at Acme"
+        );
+        assert!(misconfigured.text.contains("failed 3 times in a row"));
+        assert!(misconfigured.text.contains("until Mar 4, 2026, 10:15 CET"));
+        assert!(misconfigured.html.contains("Review DNS settings"));
+        assert!(!misconfigured.html.contains("Unsubscribe</a>"));
+
+        let recovered = custom_domain_recovered(
+            &EmailBranding {
+                locale: Locale::Fr,
+                ..branding()
+            },
+            &CustomDomainRecoveredEmail {
+                domain: "status.acme.com",
+                page_name: "Acme",
+                target: "pages.statuspage.test",
+                settings_url: "https://app.example.com/dashboard/acme/settings",
+            },
+        );
+
+        assert_eq!(recovered.subject, "status.acme.com sert de nouveau Acme");
+        assert!(recovered.html.contains(r#"<html lang="fr">"#));
+    }
+
+    #[test]
+    fn html_escapes_user_supplied_content() {
+        let email = incident_updated(
+            &branding(),
+            &IncidentUpdatedEmail {
+                title: Some("<script>alert(1)</script>"),
+                status: IncidentStatus::Monitoring,
+                message: "Fix \"deployed\" & monitoring",
+                history_url: "https://status.example.com/history",
+                unsubscribe_url: "https://status.example.com/unsubscribe?token=abc",
+            },
+        );
+
+        assert!(!email.html.contains("<script>"));
+        assert!(email.html.contains("&lt;script&gt;"));
+        assert!(email
+            .html
+            .contains("Fix &quot;deployed&quot; &amp; monitoring"));
+    }
+
+    #[test]
+    fn invalid_branding_falls_back_to_safe_defaults() {
+        let branding = EmailBranding {
+            org_name: "Acme".to_string(),
+            logo_url: Some("javascript:alert(1)".to_string()),
+            brand_color: "red;background:url(x)".to_string(),
+            locale: Locale::En,
+        };
+        let email = subscription_verification(&branding, "https://status.example.com/verify");
+
+        assert!(email.html.contains(DEFAULT_BRAND_COLOR));
+        assert!(!email.html.contains("javascript:"));
+        assert!(!email.html.contains("background:url"));
+    }
+
+    #[test]
+    fn digest_lists_every_held_update() {
+        let items = [
+            DigestItem {
+                subject: "New incident: Elevated errors",
+                occurred_at: "2026-03-01 09:15 Europe/Berlin",
+            },
+            DigestItem {
+                subject: "Incident resolved",
+                occurred_at: "2026-03-01 09:50 Europe/Berlin",
+            },
+        ];
+        let email = subscriber_digest(
+            &branding(),
+            &SubscriberDigestEmail {
+                frequency: DigestFrequency::Hourly,
+                items: &items,
+                history_url: "https://status.example.com/history",
+                unsubscribe_url: "https://status.example.com/unsubscribe?token=abc",
+            },
+        );
+
+        assert_eq!(email.subject, "Acme <Cloud>: 2 status updates");
+        assert!(email
+            .text
+            .contains("- Incident resolved (2026-03-01 09:50 Europe/Berlin)"));
+        assert_eq!(email.html.matches("<li ").count(), 2);
+    }
+
+    #[test]
+    fn incident_updated_subject_without_title_uses_status() {
+        let email = incident_updated(
+            &branding(),
+            &IncidentUpdatedEmail {
+                title: None,
+                status: IncidentStatus::Identified,
+                message: "Root cause found",
+                history_url: "https://status.example.com/history",
+                unsubscribe_url: "https://status.example.com/unsubscribe?token=abc",
+            },
+        );
+
+        assert_eq!(email.subject, "Incident update: Identified");
+    }
+
+    #[test]
+    fn templates_render_in_the_branding_locale() {
+        let branding = EmailBranding {
+            locale: Locale::De,
+            ..branding()
+        };
+        let email = service_status_changed(
+            &branding,
+            &ServiceStatusChangedEmail {
+                service_name: "API",
+                old_status: ServiceStatus::Operational,
+                new_status: ServiceStatus::MajorOutage,
+                status_url: "https://status.example.com",
+                unsubscribe_url: "https://status.example.com/unsubscribe?token=abc",
+            },
+        );
+
+        assert_eq!(email.subject, "Dienststatus geändert: API");
+        assert!(email
+            .text
+            .contains("API hat sich von Betriebsbereit zu Schwerer Ausfall geändert."));
+        assert!(email.html.contains(r#"<html lang="de">"#));
+        assert!(email.html.contains("Abmelden</a>"));
+    }
+}
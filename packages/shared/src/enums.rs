@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+use crate::i18n::Label;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
 #[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum ServiceStatus {
@@ -22,21 +26,29 @@ impl ServiceStatus {
             Self::UnderMaintenance => "under_maintenance",
         }
     }
+
+    /// Ordering used whenever several statuses collapse into one; higher is
+    /// worse.
+    pub fn severity(&self) -> u8 {
+        match self {
+            Self::MajorOutage => 4,
+            Self::PartialOutage => 3,
+            Self::DegradedPerformance => 2,
+            Self::UnderMaintenance => 1,
+            Self::Operational => 0,
+        }
+    }
 }
 
 impl fmt::Display for ServiceStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Operational => write!(f, "Operational"),
-            Self::DegradedPerformance => write!(f, "Degraded Performance"),
-            Self::PartialOutage => write!(f, "Partial Outage"),
-            Self::MajorOutage => write!(f, "Major Outage"),
-            Self::UnderMaintenance => write!(f, "Under Maintenance"),
-        }
+        f.write_str(self.label(Locale::En))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
 #[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum IncidentStatus {
@@ -74,16 +86,13 @@ impl IncidentStatus {
 
 impl fmt::Display for IncidentStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Investigating => write!(f, "Investigating"),
-            Self::Identified => write!(f, "Identified"),
-            Self::Monitoring => write!(f, "Monitoring"),
-            Self::Resolved => write!(f, "Resolved"),
-        }
+        f.write_str(self.label(Locale::En))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
 #[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum IncidentImpact {
@@ -111,20 +120,22 @@ impl IncidentImpact {
             Self::None => ServiceStatus::Operational,
         }
     }
+
+    /// Critical incidents are delivered immediately, even to digest subscribers.
+    pub fn bypasses_digest(&self) -> bool {
+        matches!(self, Self::Critical)
+    }
 }
 
 impl fmt::Display for IncidentImpact {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::None => write!(f, "None"),
-            Self::Minor => write!(f, "Minor"),
-            Self::Major => write!(f, "Major"),
-            Self::Critical => write!(f, "Critical"),
-        }
+        f.write_str(self.label(Locale::En))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
 #[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum MemberRole {
@@ -141,15 +152,13 @@ impl MemberRole {
 
 impl fmt::Display for MemberRole {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Owner => write!(f, "Owner"),
-            Self::Admin => write!(f, "Admin"),
-            Self::Member => write!(f, "Member"),
-        }
+        f.write_str(self.label(Locale::En))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
 #[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum MonitorType {
@@ -181,7 +190,9 @@ impl MonitorType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
 #[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum CheckStatus {
@@ -190,7 +201,9 @@ pub enum CheckStatus {
     Timeout,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
 #[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum OrganizationPlan {
@@ -208,6 +221,15 @@ impl OrganizationPlan {
         }
     }
 
+    /// Status pages an organization may publish, its primary page included.
+    pub fn max_status_pages(&self) -> Option<i64> {
+        match self {
+            Self::Free => Some(1),
+            Self::Pro => Some(3),
+            Self::Team => None,
+        }
+    }
+
     pub fn allows_custom_domain(&self) -> bool {
         !matches!(self, Self::Free)
     }
@@ -219,6 +241,19 @@ impl OrganizationPlan {
     pub fn has_priority_support(&self) -> bool {
         matches!(self, Self::Team)
     }
+
+    /// SMS messages an organization may send per calendar month (UTC).
+    pub fn monthly_sms_quota(&self) -> i64 {
+        match self {
+            Self::Free => 0,
+            Self::Pro => 500,
+            Self::Team => 5_000,
+        }
+    }
+
+    pub fn allows_sms(&self) -> bool {
+        self.monthly_sms_quota() > 0
+    }
 }
 
 impl fmt::Display for OrganizationPlan {
@@ -231,7 +266,9 @@ impl fmt::Display for OrganizationPlan {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
 #[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum SubscriptionStatus {
@@ -262,7 +299,9 @@ impl SubscriptionStatus {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
 #[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum DowngradeState {
@@ -273,7 +312,9 @@ pub enum DowngradeState {
     Canceled,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
 #[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum CustomDomainStatus {
@@ -281,16 +322,45 @@ pub enum CustomDomainStatus {
     PendingVerification,
     Verified,
     BlockedByPlan,
+    /// DNS is verified but no certificate could be issued yet; issuance is
+    /// retried with backoff.
+    CertificateFailed,
+    /// The certificate expired because renewals kept failing.
+    CertificateExpired,
+    /// Background re-verification kept failing. The domain still routes
+    /// until `custom_domain_grace_ends_at` and recovers on its own once DNS
+    /// points back at the managed target.
+    Misconfigured,
+}
+
+/// How a custom domain last proved it points at the managed target.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
+#[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum CustomDomainVerificationMethod {
+    /// The domain resolves to one of the managed target's addresses.
+    ARecord,
+    /// The domain is a CNAME for the managed target.
+    Cname,
+    /// `_statuspage-verification.<domain>` carries the page's token, for
+    /// domains behind a proxy.
+    TxtToken,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
 #[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum DisabledReason {
     PlanLimit,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
 #[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum InvitationDeliveryStatus {
@@ -311,10 +381,271 @@ impl fmt::Display for CheckStatus {
     }
 }
 
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
+#[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DigestFrequency {
+    Immediate,
+    Hourly,
+    Daily,
+}
+
+impl DigestFrequency {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Immediate => "immediate",
+            Self::Hourly => "hourly",
+            Self::Daily => "daily",
+        }
+    }
+}
+
+impl fmt::Display for DigestFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Immediate => write!(f, "Immediate"),
+            Self::Hourly => write!(f, "Hourly digest"),
+            Self::Daily => write!(f, "Daily digest"),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
+#[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriberSource {
+    SelfService,
+    Import,
+}
+
+impl SubscriberSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SelfService => "self_service",
+            Self::Import => "import",
+        }
+    }
+}
+
+impl fmt::Display for SubscriberSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SelfService => write!(f, "Self-service"),
+            Self::Import => write!(f, "Import"),
+        }
+    }
+}
+
+/// Languages status pages and emails are rendered in.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
+#[sqlx(type_name = "VARCHAR", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    De,
+    Es,
+    Fr,
+}
+
+impl Locale {
+    pub const ALL: [Self; 4] = [Self::En, Self::De, Self::Es, Self::Fr];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::De => "de",
+            Self::Es => "es",
+            Self::Fr => "fr",
+        }
+    }
+
+    /// Matches a language tag on its primary subtag, so `de-AT` and `de_CH`
+    /// are both German.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag.trim().split(['-', '_']).next()?.to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.as_str() == language)
+    }
+
+    /// The supported language an `Accept-Language` header prefers most.
+    pub fn negotiate(accept_language: &str) -> Option<Self> {
+        let mut best: Option<(Self, f32)> = None;
+        for range in accept_language.split(',') {
+            let mut parts = range.split(';');
+            let Some(locale) = parts.next().and_then(Self::from_tag) else {
+                continue;
+            };
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .and_then(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            if quality > 0.0 && best.is_none_or(|(_, best_quality)| quality > best_quality) {
+                best = Some((locale, quality));
+            }
+        }
+        best.map(|(locale, _)| locale)
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::En => write!(f, "English"),
+            Self::De => write!(f, "Deutsch"),
+            Self::Es => write!(f, "Español"),
+            Self::Fr => write!(f, "Français"),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
+#[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateVisibility {
+    #[default]
+    Public,
+    /// Only shown to organization members; never published or notified.
+    Internal,
+}
+
+impl UpdateVisibility {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Internal => "internal",
+        }
+    }
+}
+
+/// How a service group's status is derived from its members.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
+#[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum GroupStatusRule {
+    /// The group takes the worst member status.
+    #[default]
+    WorstOf,
+    /// The group takes the worst status shared by more than half of its
+    /// members, and shows degraded performance if a minority is impaired.
+    Majority,
+}
+
+impl GroupStatusRule {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::WorstOf => "worst_of",
+            Self::Majority => "majority",
+        }
+    }
+}
+
+/// What caused a recorded change to a service's own status.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
+#[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum StatusChangeSource {
+    Manual,
+    Incident,
+    Monitor,
+}
+
+impl StatusChangeSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Manual => "manual",
+            Self::Incident => "incident",
+            Self::Monitor => "monitor",
+        }
+    }
+}
+
+/// Who may read an organization's public status page and its embeds.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
+#[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PageVisibility {
+    #[default]
+    Public,
+    Password,
+    EmailDomain,
+    IpAllowlist,
+}
+
+impl PageVisibility {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Password => "password",
+            Self::EmailDomain => "email_domain",
+            Self::IpAllowlist => "ip_allowlist",
+        }
+    }
+
+    /// Whether viewers must present a signed viewer session. IP allowlists
+    /// are checked per request instead.
+    pub fn requires_viewer_session(&self) -> bool {
+        matches!(self, Self::Password | Self::EmailDomain)
+    }
+}
+
+/// Lifecycle of an owner-requested data export archive.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
+#[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ExportStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+    /// The archive passed its retention window and was dropped.
+    Expired,
+}
+
+/// Lifecycle of a scheduled organization deletion.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, utoipa::ToSchema,
+)]
+#[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum OrganizationDeletionStatus {
+    /// Waiting out the cool-off period; owners can still cancel.
+    Scheduled,
+    /// The purge has started and can no longer be canceled.
+    Processing,
+    Canceled,
+    Completed,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_update_visibility_defaults_to_public() {
+        assert_eq!(UpdateVisibility::default(), UpdateVisibility::Public);
+        let internal: UpdateVisibility = serde_json::from_str(r#""internal""#).unwrap();
+        assert_eq!(internal, UpdateVisibility::Internal);
+        assert_eq!(internal.as_str(), "internal");
+    }
+
     #[test]
     fn test_service_status_serialization() {
         let status = ServiceStatus::DegradedPerformance;
@@ -349,6 +680,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_locale_matches_primary_language_subtag() {
+        assert_eq!(Locale::from_tag("de-AT"), Some(Locale::De));
+        assert_eq!(Locale::from_tag("FR_ca"), Some(Locale::Fr));
+        assert_eq!(Locale::from_tag("pt-BR"), None);
+        assert_eq!(Locale::from_tag(""), None);
+    }
+
+    #[test]
+    fn test_locale_negotiation_honours_quality_values() {
+        assert_eq!(
+            Locale::negotiate("pt-BR, fr;q=0.7, es;q=0.9, *;q=0.1"),
+            Some(Locale::Es)
+        );
+        assert_eq!(Locale::negotiate("de-CH, de;q=0.9"), Some(Locale::De));
+        assert_eq!(Locale::negotiate("fr;q=0, ja"), None);
+    }
+
     #[test]
     fn test_member_role_admin_check() {
         assert!(MemberRole::Owner.is_admin_or_above());
@@ -361,10 +710,16 @@ mod tests {
         assert_eq!(OrganizationPlan::Free.max_monitors(), Some(3));
         assert_eq!(OrganizationPlan::Pro.max_monitors(), Some(20));
         assert_eq!(OrganizationPlan::Team.max_monitors(), None);
+        assert_eq!(OrganizationPlan::Free.max_status_pages(), Some(1));
+        assert_eq!(OrganizationPlan::Pro.max_status_pages(), Some(3));
+        assert_eq!(OrganizationPlan::Team.max_status_pages(), None);
         assert!(!OrganizationPlan::Free.allows_custom_domain());
         assert!(!OrganizationPlan::Free.allows_outbound_webhooks());
         assert!(OrganizationPlan::Pro.allows_custom_domain());
         assert!(OrganizationPlan::Team.has_priority_support());
+        assert!(!OrganizationPlan::Free.allows_sms());
+        assert_eq!(OrganizationPlan::Pro.monthly_sms_quota(), 500);
+        assert!(OrganizationPlan::Team.allows_sms());
     }
 
     #[test]
@@ -0,0 +1,683 @@
+//! Translated system strings.
+//!
+//! Status and impact labels, the message catalogs behind the email
+//! templates, and timestamps rendered in an organization's timezone. English
+//! is the source catalog: a key missing from another locale falls back to
+//! English, and a key missing everywhere renders as the key itself.
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+
+use crate::enums::{IncidentImpact, IncidentStatus, Locale, MemberRole, ServiceStatus};
+
+/// A human-readable name for an enum value in a given language.
+pub trait Label {
+    fn label(&self, locale: Locale) -> &'static str;
+}
+
+impl Label for ServiceStatus {
+    fn label(&self, locale: Locale) -> &'static str {
+        match (locale, self) {
+            (Locale::En, Self::Operational) => "Operational",
+            (Locale::En, Self::DegradedPerformance) => "Degraded Performance",
+            (Locale::En, Self::PartialOutage) => "Partial Outage",
+            (Locale::En, Self::MajorOutage) => "Major Outage",
+            (Locale::En, Self::UnderMaintenance) => "Under Maintenance",
+            (Locale::De, Self::Operational) => "Betriebsbereit",
+            (Locale::De, Self::DegradedPerformance) => "Eingeschränkte Leistung",
+            (Locale::De, Self::PartialOutage) => "Teilweiser Ausfall",
+            (Locale::De, Self::MajorOutage) => "Schwerer Ausfall",
+            (Locale::De, Self::UnderMaintenance) => "In Wartung",
+            (Locale::Es, Self::Operational) => "Operativo",
+            (Locale::Es, Self::DegradedPerformance) => "Rendimiento degradado",
+            (Locale::Es, Self::PartialOutage) => "Interrupción parcial",
+            (Locale::Es, Self::MajorOutage) => "Interrupción grave",
+            (Locale::Es, Self::UnderMaintenance) => "En mantenimiento",
+            (Locale::Fr, Self::Operational) => "Opérationnel",
+            (Locale::Fr, Self::DegradedPerformance) => "Performances dégradées",
+            (Locale::Fr, Self::PartialOutage) => "Panne partielle",
+            (Locale::Fr, Self::MajorOutage) => "Panne majeure",
+            (Locale::Fr, Self::UnderMaintenance) => "En maintenance",
+        }
+    }
+}
+
+impl Label for IncidentStatus {
+    fn label(&self, locale: Locale) -> &'static str {
+        match (locale, self) {
+            (Locale::En, Self::Investigating) => "Investigating",
+            (Locale::En, Self::Identified) => "Identified",
+            (Locale::En, Self::Monitoring) => "Monitoring",
+            (Locale::En, Self::Resolved) => "Resolved",
+            (Locale::De, Self::Investigating) => "Wird untersucht",
+            (Locale::De, Self::Identified) => "Ursache identifiziert",
+            (Locale::De, Self::Monitoring) => "Wird beobachtet",
+            (Locale::De, Self::Resolved) => "Behoben",
+            (Locale::Es, Self::Investigating) => "Investigando",
+            (Locale::Es, Self::Identified) => "Identificado",
+            (Locale::Es, Self::Monitoring) => "En observación",
+            (Locale::Es, Self::Resolved) => "Resuelto",
+            (Locale::Fr, Self::Investigating) => "Enquête en cours",
+            (Locale::Fr, Self::Identified) => "Cause identifiée",
+            (Locale::Fr, Self::Monitoring) => "Sous surveillance",
+            (Locale::Fr, Self::Resolved) => "Résolu",
+        }
+    }
+}
+
+impl Label for IncidentImpact {
+    fn label(&self, locale: Locale) -> &'static str {
+        match (locale, self) {
+            (Locale::En, Self::None) => "None",
+            (Locale::En, Self::Minor) => "Minor",
+            (Locale::En, Self::Major) => "Major",
+            (Locale::En, Self::Critical) => "Critical",
+            (Locale::De, Self::None) => "Keine",
+            (Locale::De, Self::Minor) => "Gering",
+            (Locale::De, Self::Major) => "Schwer",
+            (Locale::De, Self::Critical) => "Kritisch",
+            (Locale::Es, Self::None) => "Ninguno",
+            (Locale::Es, Self::Minor) => "Menor",
+            (Locale::Es, Self::Major) => "Grave",
+            (Locale::Es, Self::Critical) => "Crítico",
+            (Locale::Fr, Self::None) => "Aucun",
+            (Locale::Fr, Self::Minor) => "Mineur",
+            (Locale::Fr, Self::Major) => "Majeur",
+            (Locale::Fr, Self::Critical) => "Critique",
+        }
+    }
+}
+
+impl Label for MemberRole {
+    fn label(&self, locale: Locale) -> &'static str {
+        match (locale, self) {
+            (Locale::En, Self::Owner) => "Owner",
+            (Locale::En, Self::Admin) => "Admin",
+            (Locale::En, Self::Member) => "Member",
+            (Locale::De, Self::Owner) => "Inhaber",
+            (Locale::De, Self::Admin) => "Administrator",
+            (Locale::De, Self::Member) => "Mitglied",
+            (Locale::Es, Self::Owner) => "Propietario",
+            (Locale::Es, Self::Admin) => "Administrador",
+            (Locale::Es, Self::Member) => "Miembro",
+            (Locale::Fr, Self::Owner) => "Propriétaire",
+            (Locale::Fr, Self::Admin) => "Administrateur",
+            (Locale::Fr, Self::Member) => "Membre",
+        }
+    }
+}
+
+/// The catalog entry for `key`, falling back to English.
+pub fn message(locale: Locale, key: &str) -> &str {
+    lookup(catalog(locale), key)
+        .or_else(|| lookup(EN, key))
+        .unwrap_or(key)
+}
+
+/// `message` with `{name}` placeholders filled from `args`.
+pub fn format_message(locale: Locale, key: &str, args: &[(&str, &str)]) -> String {
+    args.iter()
+        .fold(message(locale, key).to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{name}}}"), value)
+        })
+}
+
+/// `at` as a reader in `timezone` would write it. Unknown timezones fall
+/// back to UTC.
+pub fn format_timestamp(at: DateTime<Utc>, timezone: &str, locale: Locale) -> String {
+    let timezone = timezone.parse::<Tz>().unwrap_or(Tz::UTC);
+    let pattern = match locale {
+        Locale::En => "%b %-d, %Y, %H:%M %Z",
+        Locale::De => "%d.%m.%Y, %H:%M %Z",
+        Locale::Es | Locale::Fr => "%d/%m/%Y %H:%M %Z",
+    };
+    at.with_timezone(&timezone).format(pattern).to_string()
+}
+
+fn catalog(locale: Locale) -> &'static [(&'static str, &'static str)] {
+    match locale {
+        Locale::En => EN,
+        Locale::De => DE,
+        Locale::Es => ES,
+        Locale::Fr => FR,
+    }
+}
+
+fn lookup(catalog: &'static [(&'static str, &'static str)], key: &str) -> Option<&'static str> {
+    catalog
+        .iter()
+        .find(|(candidate, _)| *candidate == key)
+        .map(|(_, text)| *text)
+}
+
+const EN: &[(&str, &str)] = &[
+    ("common.title", "Title"),
+    ("common.impact", "Impact"),
+    ("common.status", "Status"),
+    ("common.message", "Message"),
+    ("common.affected_services", "Affected services"),
+    ("common.not_applicable", "n/a"),
+    ("common.follow_updates", "Follow updates"),
+    ("common.follow_updates_at", "Follow updates at:"),
+    ("common.unsubscribe", "Unsubscribe"),
+    ("common.unsubscribe_at", "Unsubscribe:"),
+    (
+        "layout.subscribed_footer",
+        "You are receiving this because you subscribed to {org_name} status updates.",
+    ),
+    ("layout.sent_by", "Sent by {org_name} via StatusPage"),
+    (
+        "verification.subject",
+        "Confirm your subscription to {org_name}",
+    ),
+    ("verification.intro", "You requested updates for {org_name}."),
+    ("verification.link_text", "Confirm your subscription:"),
+    ("verification.button", "Confirm subscription"),
+    (
+        "verification.ignore",
+        "If you did not request this, you can ignore this email.",
+    ),
+    ("incident_created.subject", "New incident: {title}"),
+    ("incident_created.intro", "A new incident has been posted."),
+    ("incident_updated.subject", "Incident update: {title}"),
+    ("incident_updated.subject_untitled", "Incident update: {status}"),
+    ("incident_resolved.subject", "Incident resolved: {title}"),
+    ("incident_resolved.subject_untitled", "Incident resolved"),
+    ("incident_updated.intro", "Status update for {title}"),
+    (
+        "incident_updated.intro_untitled",
+        "An incident update has been posted.",
+    ),
+    ("service_status.subject", "Service status changed: {service}"),
+    (
+        "service_status.summary",
+        "{service} changed from {old_status} to {new_status}.",
+    ),
+    ("service_status.link_text", "See the latest status at:"),
+    ("service_status.button", "See the latest status"),
+    ("escalation.subject", "Unacknowledged incident: {title}"),
+    (
+        "escalation.summary",
+        "{title} has been open for {minutes} minutes without being acknowledged.",
+    ),
+    ("escalation.escalation", "Escalation"),
+    ("escalation.link_text", "Acknowledge it at:"),
+    ("escalation.button", "Acknowledge incident"),
+    ("invitation.subject", "Join {org_name} on StatusPage"),
+    (
+        "invitation.intro",
+        "You were invited to join {org_name} as a {role}.",
+    ),
+    ("invitation.link_text", "Accept the invitation:"),
+    ("invitation.button", "Accept invitation"),
+    (
+        "invitation.note",
+        "Sign in with the GitHub account that matches this email address.",
+    ),
+    (
+        "magic_link.subject",
+        "Your sign-in link for the {org_name} status page",
+    ),
+    (
+        "magic_link.intro",
+        "Use this link to view the {org_name} status page.",
+    ),
+    ("magic_link.button", "View status page"),
+    (
+        "magic_link.note",
+        "The link expires in 15 minutes and can be used once. If you did not request it, you can ignore this email.",
+    ),
+    ("digest.subject_one", "{org_name}: {count} status update"),
+    ("digest.subject_other", "{org_name}: {count} status updates"),
+    ("digest.intro_hourly", "Here is your hourly status digest."),
+    ("digest.intro_daily", "Here is your daily status digest."),
+    (
+        "digest.intro_quiet_hours",
+        "These updates were held during your quiet hours.",
+    ),
+    ("custom_domain.misconfigured_subject", "Action needed: {domain} no longer points at {page_name}"),
+    ("custom_domain.misconfigured_summary", "Our DNS checks for {domain} have failed {count} times in a row. It no longer resolves to {target}, is not a CNAME for it, and has no matching TXT record on {txt_name}."),
+    ("custom_domain.misconfigured_grace", "The domain keeps serving {page_name} until {grace_ends_at}. After that visitors need the default status page URL until DNS is fixed; the domain is restored automatically once a check passes."),
+    ("custom_domain.recovered_subject", "{domain} is serving {page_name} again"),
+    ("custom_domain.recovered_summary", "{domain} points at {target} again and is verified for {page_name}. No further action is needed."),
+    ("custom_domain.link_text", "Review the DNS settings:"),
+    ("custom_domain.button", "Review DNS settings"),
+];
+
+const DE: &[(&str, &str)] = &[
+    ("common.title", "Titel"),
+    ("common.impact", "Auswirkung"),
+    ("common.status", "Status"),
+    ("common.message", "Nachricht"),
+    ("common.affected_services", "Betroffene Dienste"),
+    ("common.not_applicable", "keine"),
+    ("common.follow_updates", "Updates verfolgen"),
+    ("common.follow_updates_at", "Updates verfolgen unter:"),
+    ("common.unsubscribe", "Abmelden"),
+    ("common.unsubscribe_at", "Abmelden:"),
+    (
+        "layout.subscribed_footer",
+        "Sie erhalten diese E-Mail, weil Sie Statusmeldungen von {org_name} abonniert haben.",
+    ),
+    ("layout.sent_by", "Gesendet von {org_name} über StatusPage"),
+    (
+        "verification.subject",
+        "Bestätigen Sie Ihr Abonnement für {org_name}",
+    ),
+    (
+        "verification.intro",
+        "Sie haben Statusmeldungen von {org_name} angefordert.",
+    ),
+    ("verification.link_text", "Abonnement bestätigen:"),
+    ("verification.button", "Abonnement bestätigen"),
+    (
+        "verification.ignore",
+        "Falls Sie das nicht angefordert haben, können Sie diese E-Mail ignorieren.",
+    ),
+    ("incident_created.subject", "Neuer Vorfall: {title}"),
+    (
+        "incident_created.intro",
+        "Ein neuer Vorfall wurde veröffentlicht.",
+    ),
+    ("incident_updated.subject", "Update zum Vorfall: {title}"),
+    ("incident_updated.subject_untitled", "Update zum Vorfall: {status}"),
+    ("incident_resolved.subject", "Vorfall behoben: {title}"),
+    ("incident_resolved.subject_untitled", "Vorfall behoben"),
+    ("incident_updated.intro", "Statusupdate zu {title}"),
+    (
+        "incident_updated.intro_untitled",
+        "Ein Update zu einem Vorfall wurde veröffentlicht.",
+    ),
+    ("service_status.subject", "Dienststatus geändert: {service}"),
+    (
+        "service_status.summary",
+        "{service} hat sich von {old_status} zu {new_status} geändert.",
+    ),
+    ("service_status.link_text", "Aktuellen Status ansehen unter:"),
+    ("service_status.button", "Aktuellen Status ansehen"),
+    ("escalation.subject", "Unbestätigter Vorfall: {title}"),
+    (
+        "escalation.summary",
+        "{title} ist seit {minutes} Minuten offen, ohne bestätigt worden zu sein.",
+    ),
+    ("escalation.escalation", "Eskalationsstufe"),
+    ("escalation.link_text", "Jetzt bestätigen unter:"),
+    ("escalation.button", "Vorfall bestätigen"),
+    ("invitation.subject", "Treten Sie {org_name} auf StatusPage bei"),
+    (
+        "invitation.intro",
+        "Sie wurden eingeladen, {org_name} als {role} beizutreten.",
+    ),
+    ("invitation.link_text", "Einladung annehmen:"),
+    ("invitation.button", "Einladung annehmen"),
+    (
+        "invitation.note",
+        "Melden Sie sich mit dem GitHub-Konto an, das zu dieser E-Mail-Adresse gehört.",
+    ),
+    (
+        "magic_link.subject",
+        "Ihr Anmeldelink für die Statusseite von {org_name}",
+    ),
+    (
+        "magic_link.intro",
+        "Mit diesem Link öffnen Sie die Statusseite von {org_name}.",
+    ),
+    ("magic_link.button", "Statusseite öffnen"),
+    (
+        "magic_link.note",
+        "Der Link ist 15 Minuten gültig und kann einmal verwendet werden. Falls Sie ihn nicht angefordert haben, können Sie diese E-Mail ignorieren.",
+    ),
+    ("digest.subject_one", "{org_name}: {count} Statusmeldung"),
+    ("digest.subject_other", "{org_name}: {count} Statusmeldungen"),
+    (
+        "digest.intro_hourly",
+        "Hier ist Ihre stündliche Statuszusammenfassung.",
+    ),
+    (
+        "digest.intro_daily",
+        "Hier ist Ihre tägliche Statuszusammenfassung.",
+    ),
+    (
+        "digest.intro_quiet_hours",
+        "Diese Meldungen wurden während Ihrer Ruhezeiten zurückgehalten.",
+    ),
+    ("custom_domain.misconfigured_subject", "Handlungsbedarf: {domain} verweist nicht mehr auf {page_name}"),
+    ("custom_domain.misconfigured_summary", "Unsere DNS-Prüfungen für {domain} sind {count}-mal in Folge fehlgeschlagen. Die Domain löst nicht mehr auf {target} auf, ist kein CNAME dafür und hat keinen passenden TXT-Eintrag unter {txt_name}."),
+    ("custom_domain.misconfigured_grace", "Die Domain liefert {page_name} noch bis {grace_ends_at} aus. Danach benötigen Besucher die Standard-URL der Statusseite, bis das DNS korrigiert ist; die Domain wird automatisch wiederhergestellt, sobald eine Prüfung erfolgreich ist."),
+    ("custom_domain.recovered_subject", "{domain} liefert {page_name} wieder aus"),
+    ("custom_domain.recovered_summary", "{domain} verweist wieder auf {target} und ist für {page_name} verifiziert. Es ist nichts weiter zu tun."),
+    ("custom_domain.link_text", "DNS-Einstellungen prüfen:"),
+    ("custom_domain.button", "DNS-Einstellungen prüfen"),
+];
+
+const ES: &[(&str, &str)] = &[
+    ("common.title", "Título"),
+    ("common.impact", "Impacto"),
+    ("common.status", "Estado"),
+    ("common.message", "Mensaje"),
+    ("common.affected_services", "Servicios afectados"),
+    ("common.not_applicable", "n/d"),
+    ("common.follow_updates", "Seguir las actualizaciones"),
+    ("common.follow_updates_at", "Sigue las actualizaciones en:"),
+    ("common.unsubscribe", "Cancelar suscripción"),
+    ("common.unsubscribe_at", "Cancelar suscripción:"),
+    (
+        "layout.subscribed_footer",
+        "Recibes este correo porque te suscribiste a las actualizaciones de estado de {org_name}.",
+    ),
+    ("layout.sent_by", "Enviado por {org_name} a través de StatusPage"),
+    ("verification.subject", "Confirma tu suscripción a {org_name}"),
+    (
+        "verification.intro",
+        "Solicitaste recibir actualizaciones de {org_name}.",
+    ),
+    ("verification.link_text", "Confirma tu suscripción:"),
+    ("verification.button", "Confirmar suscripción"),
+    (
+        "verification.ignore",
+        "Si no lo solicitaste, puedes ignorar este correo.",
+    ),
+    ("incident_created.subject", "Nuevo incidente: {title}"),
+    (
+        "incident_created.intro",
+        "Se ha publicado un nuevo incidente.",
+    ),
+    (
+        "incident_updated.subject",
+        "Actualización del incidente: {title}",
+    ),
+    (
+        "incident_updated.subject_untitled",
+        "Actualización del incidente: {status}",
+    ),
+    ("incident_resolved.subject", "Incidente resuelto: {title}"),
+    ("incident_resolved.subject_untitled", "Incidente resuelto"),
+    (
+        "incident_updated.intro",
+        "Actualización de estado de {title}",
+    ),
+    (
+        "incident_updated.intro_untitled",
+        "Se ha publicado una actualización de un incidente.",
+    ),
+    (
+        "service_status.subject",
+        "Cambio de estado del servicio: {service}",
+    ),
+    (
+        "service_status.summary",
+        "{service} pasó de {old_status} a {new_status}.",
+    ),
+    ("service_status.link_text", "Consulta el estado actual en:"),
+    ("service_status.button", "Ver el estado actual"),
+    ("escalation.subject", "Incidente sin confirmar: {title}"),
+    (
+        "escalation.summary",
+        "{title} lleva {minutes} minutos abierto sin que nadie lo haya confirmado.",
+    ),
+    ("escalation.escalation", "Escalado"),
+    ("escalation.link_text", "Confírmalo en:"),
+    ("escalation.button", "Confirmar incidente"),
+    ("invitation.subject", "Únete a {org_name} en StatusPage"),
+    (
+        "invitation.intro",
+        "Te invitaron a unirte a {org_name} como {role}.",
+    ),
+    ("invitation.link_text", "Acepta la invitación:"),
+    ("invitation.button", "Aceptar invitación"),
+    (
+        "invitation.note",
+        "Inicia sesión con la cuenta de GitHub que corresponde a esta dirección de correo.",
+    ),
+    (
+        "magic_link.subject",
+        "Tu enlace de acceso a la página de estado de {org_name}",
+    ),
+    (
+        "magic_link.intro",
+        "Usa este enlace para ver la página de estado de {org_name}.",
+    ),
+    ("magic_link.button", "Ver página de estado"),
+    (
+        "magic_link.note",
+        "El enlace caduca en 15 minutos y solo se puede usar una vez. Si no lo solicitaste, puedes ignorar este correo.",
+    ),
+    (
+        "digest.subject_one",
+        "{org_name}: {count} actualización de estado",
+    ),
+    (
+        "digest.subject_other",
+        "{org_name}: {count} actualizaciones de estado",
+    ),
+    (
+        "digest.intro_hourly",
+        "Este es tu resumen de estado de cada hora.",
+    ),
+    ("digest.intro_daily", "Este es tu resumen de estado diario."),
+    (
+        "digest.intro_quiet_hours",
+        "Estas actualizaciones se retuvieron durante tus horas de silencio.",
+    ),
+    ("custom_domain.misconfigured_subject", "Acción necesaria: {domain} ya no apunta a {page_name}"),
+    ("custom_domain.misconfigured_summary", "Nuestras comprobaciones de DNS para {domain} han fallado {count} veces seguidas. Ya no resuelve a {target}, no es un CNAME de este y no tiene un registro TXT coincidente en {txt_name}."),
+    ("custom_domain.misconfigured_grace", "El dominio seguirá mostrando {page_name} hasta {grace_ends_at}. Después, los visitantes necesitarán la URL predeterminada de la página de estado hasta que se corrija el DNS; el dominio se restablece automáticamente cuando una comprobación se supera."),
+    ("custom_domain.recovered_subject", "{domain} vuelve a mostrar {page_name}"),
+    ("custom_domain.recovered_summary", "{domain} vuelve a apuntar a {target} y está verificado para {page_name}. No es necesario hacer nada más."),
+    ("custom_domain.link_text", "Revisa la configuración de DNS:"),
+    ("custom_domain.button", "Revisar la configuración de DNS"),
+];
+
+const FR: &[(&str, &str)] = &[
+    ("common.title", "Titre"),
+    ("common.impact", "Impact"),
+    ("common.status", "Statut"),
+    ("common.message", "Message"),
+    ("common.affected_services", "Services concernés"),
+    ("common.not_applicable", "n/a"),
+    ("common.follow_updates", "Suivre les mises à jour"),
+    ("common.follow_updates_at", "Suivez les mises à jour sur :"),
+    ("common.unsubscribe", "Se désabonner"),
+    ("common.unsubscribe_at", "Se désabonner :"),
+    (
+        "layout.subscribed_footer",
+        "Vous recevez cet e-mail car vous êtes abonné aux mises à jour de statut de {org_name}.",
+    ),
+    ("layout.sent_by", "Envoyé par {org_name} via StatusPage"),
+    (
+        "verification.subject",
+        "Confirmez votre abonnement à {org_name}",
+    ),
+    (
+        "verification.intro",
+        "Vous avez demandé à recevoir les mises à jour de {org_name}.",
+    ),
+    ("verification.link_text", "Confirmez votre abonnement :"),
+    ("verification.button", "Confirmer l'abonnement"),
+    (
+        "verification.ignore",
+        "Si vous n'êtes pas à l'origine de cette demande, vous pouvez ignorer cet e-mail.",
+    ),
+    ("incident_created.subject", "Nouvel incident : {title}"),
+    ("incident_created.intro", "Un nouvel incident a été publié."),
+    ("incident_updated.subject", "Mise à jour de l'incident : {title}"),
+    (
+        "incident_updated.subject_untitled",
+        "Mise à jour de l'incident : {status}",
+    ),
+    ("incident_resolved.subject", "Incident résolu : {title}"),
+    ("incident_resolved.subject_untitled", "Incident résolu"),
+    ("incident_updated.intro", "Mise à jour du statut de {title}"),
+    (
+        "incident_updated.intro_untitled",
+        "Une mise à jour d'incident a été publiée.",
+    ),
+    (
+        "service_status.subject",
+        "Changement de statut du service : {service}",
+    ),
+    (
+        "service_status.summary",
+        "{service} est passé de {old_status} à {new_status}.",
+    ),
+    ("service_status.link_text", "Consultez le statut actuel sur :"),
+    ("service_status.button", "Voir le statut actuel"),
+    ("escalation.subject", "Incident non pris en charge : {title}"),
+    (
+        "escalation.summary",
+        "{title} est ouvert depuis {minutes} minutes sans avoir été pris en charge.",
+    ),
+    ("escalation.escalation", "Escalade"),
+    ("escalation.link_text", "Prenez-le en charge sur :"),
+    ("escalation.button", "Prendre en charge l'incident"),
+    ("invitation.subject", "Rejoignez {org_name} sur StatusPage"),
+    (
+        "invitation.intro",
+        "Vous avez été invité à rejoindre {org_name} en tant que {role}.",
+    ),
+    ("invitation.link_text", "Acceptez l'invitation :"),
+    ("invitation.button", "Accepter l'invitation"),
+    (
+        "invitation.note",
+        "Connectez-vous avec le compte GitHub associé à cette adresse e-mail.",
+    ),
+    (
+        "magic_link.subject",
+        "Votre lien de connexion à la page de statut de {org_name}",
+    ),
+    (
+        "magic_link.intro",
+        "Utilisez ce lien pour consulter la page de statut de {org_name}.",
+    ),
+    ("magic_link.button", "Voir la page de statut"),
+    (
+        "magic_link.note",
+        "Le lien expire dans 15 minutes et ne peut être utilisé qu'une fois. Si vous ne l'avez pas demandé, vous pouvez ignorer cet e-mail.",
+    ),
+    ("digest.subject_one", "{org_name} : {count} mise à jour de statut"),
+    (
+        "digest.subject_other",
+        "{org_name} : {count} mises à jour de statut",
+    ),
+    (
+        "digest.intro_hourly",
+        "Voici votre récapitulatif de statut horaire.",
+    ),
+    (
+        "digest.intro_daily",
+        "Voici votre récapitulatif de statut quotidien.",
+    ),
+    (
+        "digest.intro_quiet_hours",
+        "Ces mises à jour ont été retenues pendant vos heures de silence.",
+    ),
+    ("custom_domain.misconfigured_subject", "Action requise : {domain} ne pointe plus vers {page_name}"),
+    ("custom_domain.misconfigured_summary", "Nos vérifications DNS pour {domain} ont échoué {count} fois de suite. Le domaine ne résout plus vers {target}, n'est pas un CNAME de celui-ci et n'a pas d'enregistrement TXT correspondant sur {txt_name}."),
+    ("custom_domain.misconfigured_grace", "Le domaine continue de servir {page_name} jusqu'au {grace_ends_at}. Ensuite, les visiteurs devront utiliser l'URL par défaut de la page de statut jusqu'à la correction du DNS ; le domaine est rétabli automatiquement dès qu'une vérification réussit."),
+    ("custom_domain.recovered_subject", "{domain} sert de nouveau {page_name}"),
+    ("custom_domain.recovered_summary", "{domain} pointe de nouveau vers {target} et est vérifié pour {page_name}. Aucune autre action n'est nécessaire."),
+    ("custom_domain.link_text", "Vérifiez les paramètres DNS :"),
+    ("custom_domain.button", "Vérifier les paramètres DNS"),
+];
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn placeholders(text: &str) -> Vec<&str> {
+        let mut names = text
+            .split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    #[test]
+    fn every_catalog_translates_every_english_key_with_the_same_placeholders() {
+        for locale in Locale::ALL {
+            let catalog = catalog(locale);
+            assert_eq!(
+                catalog.len(),
+                EN.len(),
+                "{locale:?} has extra or missing keys"
+            );
+            for (key, english) in EN {
+                let translated =
+                    lookup(catalog, key).unwrap_or_else(|| panic!("{locale:?} is missing {key}"));
+                assert_eq!(
+                    placeholders(translated),
+                    placeholders(english),
+                    "{locale:?} {key} placeholders differ"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn english_labels_match_display() {
+        for status in [
+            ServiceStatus::Operational,
+            ServiceStatus::DegradedPerformance,
+            ServiceStatus::PartialOutage,
+            ServiceStatus::MajorOutage,
+            ServiceStatus::UnderMaintenance,
+        ] {
+            assert_eq!(status.label(Locale::En), status.to_string());
+        }
+        assert_eq!(
+            IncidentStatus::Monitoring.label(Locale::En),
+            IncidentStatus::Monitoring.to_string()
+        );
+        assert_eq!(
+            IncidentImpact::Critical.label(Locale::En),
+            IncidentImpact::Critical.to_string()
+        );
+        assert_eq!(
+            ServiceStatus::MajorOutage.label(Locale::De),
+            "Schwerer Ausfall"
+        );
+    }
+
+    #[test]
+    fn format_message_fills_placeholders() {
+        assert_eq!(
+            format_message(
+                Locale::Fr,
+                "service_status.summary",
+                &[
+                    ("service", "API"),
+                    ("old_status", "Opérationnel"),
+                    ("new_status", "Panne majeure"),
+                ],
+            ),
+            "API est passé de Opérationnel à Panne majeure."
+        );
+        assert_eq!(message(Locale::De, "unknown.key"), "unknown.key");
+    }
+
+    #[test]
+    fn timestamps_render_in_the_organization_timezone() {
+        let at = Utc.with_ymd_and_hms(2026, 3, 1, 9, 15, 0).unwrap();
+
+        assert_eq!(
+            format_timestamp(at, "Europe/Berlin", Locale::En),
+            "Mar 1, 2026, 10:15 CET"
+        );
+        assert_eq!(
+            format_timestamp(at, "Europe/Berlin", Locale::De),
+            "01.03.2026, 10:15 CET"
+        );
+        assert_eq!(
+            format_timestamp(at, "America/New_York", Locale::Es),
+            "01/03/2026 04:15 EST"
+        );
+        assert_eq!(
+            format_timestamp(at, "Not/AZone", Locale::Fr),
+            "01/03/2026 09:15 UTC"
+        );
+    }
+}
@@ -1,4 +1,6 @@
+pub mod email;
 pub mod enums;
 pub mod error;
+pub mod i18n;
 pub mod models;
 pub mod validation;
@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Default, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct StartCliLoginRequest {
+    /// Shown to the user when they approve the login, e.g. the hostname.
+    pub client_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct CliLoginStart {
+    /// Secret the CLI polls with; never shown to the user.
+    pub device_code: String,
+    /// Short code the user confirms in the browser, e.g. `WDJB-MJHT`.
+    pub user_code: String,
+    pub verification_url: String,
+    pub expires_at: DateTime<Utc>,
+    /// Seconds the CLI should wait between polls.
+    pub interval_seconds: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct PollCliLoginRequest {
+    pub device_code: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CliLoginStatus {
+    Pending,
+    Approved,
+    Denied,
+    Expired,
+}
+
+/// A credential as issued to the CLI. The token is only ever returned once.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct IssuedCliCredential {
+    pub id: Uuid,
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+    pub user_email: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct CliLoginPollResponse {
+    pub status: CliLoginStatus,
+    /// Set once, on the first poll after the login is approved.
+    pub credential: Option<IssuedCliCredential>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct CliLoginDetails {
+    pub user_code: String,
+    pub client_name: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct DecideCliLoginRequest {
+    pub user_code: String,
+    pub approve: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct CliCredential {
+    pub id: Uuid,
+    pub name: String,
+    pub expires_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
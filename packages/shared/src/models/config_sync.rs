@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::enums::{GroupStatusRule, MonitorType};
+
+/// Declarative description of an organization's service groups, services
+/// (with their monitors) and outbound webhooks. Resources are matched to
+/// existing ones by name; monitors by type and position within their service.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigSpec {
+    #[serde(default)]
+    pub service_groups: Vec<ServiceGroupSpec>,
+    #[serde(default)]
+    pub services: Vec<ServiceSpec>,
+    #[serde(default)]
+    pub webhooks: Vec<WebhookSpec>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ServiceGroupSpec {
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub collapsed_by_default: bool,
+    #[serde(default)]
+    pub status_rule: GroupStatusRule,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ServiceSpec {
+    pub name: String,
+    pub description: Option<String>,
+    /// Name of a service group declared in the same spec.
+    pub group: Option<String>,
+    #[serde(default = "default_true")]
+    pub is_visible: bool,
+    #[serde(default)]
+    pub monitors: Vec<MonitorSpec>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MonitorSpec {
+    #[serde(rename = "type")]
+    pub monitor_type: MonitorType,
+    /// Type-specific settings, validated as a `MonitorConfig`.
+    pub config: serde_json::Value,
+    #[serde(default = "default_interval_seconds")]
+    pub interval_seconds: i32,
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: i32,
+    #[serde(default = "default_failure_threshold")]
+    pub failure_threshold: i32,
+    pub recovery_threshold: Option<i32>,
+    #[serde(default = "default_true")]
+    pub is_active: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookSpec {
+    pub name: String,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<String>,
+    #[serde(default = "default_true")]
+    pub is_enabled: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_interval_seconds() -> i32 {
+    60
+}
+
+fn default_timeout_ms() -> i32 {
+    10_000
+}
+
+fn default_failure_threshold() -> i32 {
+    3
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct ConfigSyncRequest {
+    pub spec: ConfigSpec,
+    /// Also delete resources that are not in the spec.
+    #[serde(default)]
+    pub prune: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigAction {
+    Create,
+    Update,
+    Delete,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigResource {
+    ServiceGroup,
+    Service,
+    Monitor,
+    Webhook,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ConfigChange {
+    pub action: ConfigAction,
+    pub resource: ConfigResource,
+    pub name: String,
+    /// Existing resource being updated or deleted.
+    pub id: Option<Uuid>,
+    /// Fields that differ, for updates.
+    pub fields: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ConfigPlanSummary {
+    pub create: usize,
+    pub update: usize,
+    pub delete: usize,
+    /// Resources missing from the spec that only a prune would delete.
+    pub unmanaged: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ConfigPlan {
+    pub applied: bool,
+    pub prune: bool,
+    pub summary: ConfigPlanSummary,
+    pub changes: Vec<ConfigChange>,
+}
@@ -2,10 +2,10 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::enums::{IncidentImpact, IncidentStatus};
+use crate::enums::{IncidentImpact, IncidentStatus, ServiceStatus};
 use crate::models::incident_update::IncidentUpdate;
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct Incident {
     pub id: Uuid,
     pub org_id: Uuid,
@@ -18,34 +18,158 @@ pub struct Incident {
     pub created_by: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub assignee_user_id: Option<Uuid>,
+    pub acknowledged_at: Option<DateTime<Utc>>,
+    pub acknowledged_by: Option<Uuid>,
+    pub escalation_count: i32,
+    pub last_escalated_at: Option<DateTime<Utc>>,
+    /// Set on auto-incidents that resolved faster than the organization's
+    /// minimum public duration; they stay visible to members only.
+    pub hidden_from_public: bool,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
 pub struct IncidentWithDetails {
     #[serde(flatten)]
     pub incident: Incident,
     pub updates: Vec<IncidentUpdate>,
     pub affected_services: Vec<AffectedService>,
+    /// Internal workflow timeline (acknowledgements, assignments,
+    /// escalations). Never shown on the public page.
+    pub events: Vec<IncidentEvent>,
 }
 
-#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct IncidentEvent {
+    pub id: Uuid,
+    pub incident_id: Uuid,
+    pub event_type: String,
+    pub actor_user_id: Option<Uuid>,
+    pub details: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct AssignIncidentRequest {
+    /// `null` clears the assignee.
+    pub user_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct AffectedService {
     pub service_id: Uuid,
     pub service_name: String,
+    pub status: ServiceStatus,
 }
 
-#[derive(Debug, Deserialize)]
+/// Sets the status of one service within an incident. Services that are not
+/// yet affected are added to the incident.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ServiceStatusChange {
+    pub service_id: Uuid,
+    pub status: ServiceStatus,
+}
+
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct CreateIncidentRequest {
     pub title: String,
     pub status: Option<IncidentStatus>,
     pub impact: IncidentImpact,
     pub message: String,
     pub affected_service_ids: Vec<Uuid>,
+    /// Per-service overrides; other affected services follow `impact`.
+    #[serde(default)]
+    pub service_statuses: Vec<ServiceStatusChange>,
+    /// Backfills the incident as having started at this time.
+    pub started_at: Option<DateTime<Utc>>,
+    /// Skips subscriber emails and SMS, typically for backfilled incidents.
+    #[serde(default)]
+    pub suppress_notifications: bool,
+}
+
+impl CreateIncidentRequest {
+    /// The status an affected service starts with: its override if one was
+    /// given, otherwise the status implied by the incident's impact.
+    pub fn initial_service_status(&self, service_id: Uuid) -> ServiceStatus {
+        self.service_statuses
+            .iter()
+            .find(|change| change.service_id == service_id)
+            .map_or(self.impact.to_service_status(), |change| change.status)
+    }
+
+    pub fn initial_service_statuses(&self) -> Vec<ServiceStatusChange> {
+        self.affected_service_ids
+            .iter()
+            .map(|service_id| ServiceStatusChange {
+                service_id: *service_id,
+                status: self.initial_service_status(*service_id),
+            })
+            .collect()
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct UpdateIncidentRequest {
     pub title: Option<String>,
     pub status: Option<IncidentStatus>,
     pub impact: Option<IncidentImpact>,
+    #[serde(default)]
+    pub service_statuses: Vec<ServiceStatusChange>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IncidentSortOrder {
+    #[default]
+    Newest,
+    Oldest,
+}
+
+/// Query parameters for incident search. `from`/`to` match incidents that
+/// were open at any point in the range.
+#[derive(Debug, Default, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct IncidentSearchParams {
+    pub q: Option<String>,
+    pub status: Option<IncidentStatus>,
+    pub impact: Option<IncidentImpact>,
+    pub service_id: Option<Uuid>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub is_auto: Option<bool>,
+    #[serde(default)]
+    pub sort: IncidentSortOrder,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initial_service_status_prefers_override_over_impact() {
+        let api = Uuid::new_v4();
+        let dashboard = Uuid::new_v4();
+        let req = CreateIncidentRequest {
+            title: "API outage".to_string(),
+            status: None,
+            impact: IncidentImpact::Major,
+            message: "Investigating".to_string(),
+            affected_service_ids: vec![api, dashboard],
+            service_statuses: vec![ServiceStatusChange {
+                service_id: dashboard,
+                status: ServiceStatus::DegradedPerformance,
+            }],
+            started_at: None,
+            suppress_notifications: false,
+        };
+
+        assert_eq!(req.initial_service_status(api), ServiceStatus::MajorOutage);
+        assert_eq!(
+            req.initial_service_status(dashboard),
+            ServiceStatus::DegradedPerformance
+        );
+        assert_eq!(req.initial_service_statuses().len(), 2);
+    }
 }
@@ -2,9 +2,10 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::enums::IncidentStatus;
+use crate::enums::{IncidentStatus, UpdateVisibility};
+use crate::models::incident::ServiceStatusChange;
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct IncidentUpdate {
     pub id: Uuid,
     pub incident_id: Uuid,
@@ -12,10 +13,28 @@ pub struct IncidentUpdate {
     pub message: String,
     pub created_by: Option<Uuid>,
     pub created_at: DateTime<Utc>,
+    pub edited_at: Option<DateTime<Utc>>,
+    pub visibility: UpdateVisibility,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct CreateIncidentUpdateRequest {
     pub status: IncidentStatus,
     pub message: String,
+    #[serde(default)]
+    pub service_statuses: Vec<ServiceStatusChange>,
+    /// Backfills the update at this time instead of now.
+    pub created_at: Option<DateTime<Utc>>,
+    /// Skips subscriber emails and SMS, typically for backfilled updates.
+    #[serde(default)]
+    pub suppress_notifications: bool,
+    /// Internal notes keep the current status and are never published.
+    #[serde(default)]
+    pub visibility: UpdateVisibility,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct EditIncidentUpdateRequest {
+    pub message: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
 }
@@ -4,7 +4,7 @@ use uuid::Uuid;
 
 use crate::enums::{InvitationDeliveryStatus, MemberRole};
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct Invitation {
     pub id: Uuid,
     pub org_id: Uuid,
@@ -20,7 +20,7 @@ pub struct Invitation {
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct InvitationWithInviter {
     pub id: Uuid,
     pub org_id: Uuid,
@@ -39,7 +39,7 @@ pub struct InvitationWithInviter {
     pub delivery_status: InvitationDeliveryStatus,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
 pub struct CreateInvitationRequest {
     pub email: String,
     pub role: MemberRole,
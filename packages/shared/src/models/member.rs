@@ -4,7 +4,7 @@ use uuid::Uuid;
 
 use crate::enums::MemberRole;
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct Member {
     pub id: Uuid,
     pub org_id: Uuid,
@@ -13,7 +13,7 @@ pub struct Member {
     pub created_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct MemberWithUser {
     pub id: Uuid,
     pub org_id: Uuid,
@@ -25,13 +25,13 @@ pub struct MemberWithUser {
     pub user_image: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
 pub struct CreateMemberRequest {
     pub email: String,
     pub role: MemberRole,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
 pub struct UpdateMemberRequest {
     pub role: MemberRole,
 }
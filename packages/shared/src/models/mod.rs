@@ -1,11 +1,19 @@
+pub mod cli_auth;
+pub mod config_sync;
 pub mod incident;
 pub mod incident_update;
 pub mod invitation;
 pub mod member;
 pub mod monitor;
+pub mod monitoring_policy;
 pub mod notification_preference;
 pub mod organization;
+pub mod page_access;
 pub mod service;
+pub mod service_group;
+pub mod status_history;
+pub mod status_page;
+pub mod statuspage;
 pub mod subscriber;
 pub mod user;
 pub mod webhook;
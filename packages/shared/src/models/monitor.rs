@@ -5,7 +5,7 @@ use uuid::Uuid;
 
 use crate::enums::{CheckStatus, DisabledReason, MonitorType};
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct Monitor {
     pub id: Uuid,
     pub service_id: Uuid,
@@ -18,13 +18,16 @@ pub struct Monitor {
     pub is_active: bool,
     pub disabled_reason: Option<DisabledReason>,
     pub consecutive_failures: i32,
+    /// Overrides the organization's recovery threshold when set.
+    pub recovery_threshold: Option<i32>,
+    pub consecutive_successes: i32,
     pub last_checked_at: Option<DateTime<Utc>>,
     pub last_response_time_ms: Option<i32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct MonitorCheck {
     pub id: i64,
     pub monitor_id: Uuid,
@@ -35,7 +38,7 @@ pub struct MonitorCheck {
     pub checked_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct UptimeDaily {
     pub monitor_id: Uuid,
     pub date: chrono::NaiveDate,
@@ -47,7 +50,7 @@ pub struct UptimeDaily {
     pub uptime_percentage: Option<f64>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, utoipa::ToSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum MonitorConfig {
     Http(HttpConfig),
@@ -56,7 +59,7 @@ pub enum MonitorConfig {
     Ping(PingConfig),
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, utoipa::ToSchema)]
 pub struct HttpConfig {
     pub url: String,
     #[serde(default = "default_method")]
@@ -77,24 +80,24 @@ fn default_expected_status() -> u16 {
     200
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, utoipa::ToSchema)]
 pub struct TcpConfig {
     pub host: String,
     pub port: u16,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, utoipa::ToSchema)]
 pub struct DnsConfig {
     pub hostname: String,
     pub expected_ip: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, utoipa::ToSchema)]
 pub struct PingConfig {
     pub host: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
 pub struct CreateMonitorRequest {
     pub service_id: Uuid,
     pub monitor_type: MonitorType,
@@ -102,18 +105,23 @@ pub struct CreateMonitorRequest {
     pub interval_seconds: Option<i32>,
     pub timeout_ms: Option<i32>,
     pub failure_threshold: Option<i32>,
+    pub recovery_threshold: Option<i32>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct UpdateMonitorRequest {
     pub config: Option<serde_json::Value>,
     pub interval_seconds: Option<i32>,
     pub timeout_ms: Option<i32>,
     pub failure_threshold: Option<i32>,
+    pub recovery_threshold: Option<i32>,
+    /// Drops the per-monitor override so the organization policy applies.
+    #[serde(default)]
+    pub inherit_recovery_threshold: bool,
     pub is_active: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
 pub struct MonitorWithLatestCheck {
     #[serde(flatten)]
     pub monitor: Monitor,
@@ -122,7 +130,7 @@ pub struct MonitorWithLatestCheck {
     pub service_name: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
 pub struct ResponseTimePoint {
     pub timestamp: DateTime<Utc>,
     pub avg_response_time_ms: Option<f64>,
@@ -145,6 +153,15 @@ pub fn normalize_monitor_config(
     }
 }
 
+/// Deserializes a monitor config the way the monitor engine does before it
+/// schedules checks.
+pub fn parse_monitor_config(
+    monitor_type: MonitorType,
+    config: serde_json::Value,
+) -> Result<MonitorConfig, serde_json::Error> {
+    serde_json::from_value(normalize_monitor_config(monitor_type, config))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,6 +191,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_monitor_config_requires_type_specific_fields() {
+        let config = parse_monitor_config(
+            MonitorType::Tcp,
+            json!({ "host": "db.example.com", "port": 5432 }),
+        )
+        .unwrap();
+        assert!(matches!(
+            config,
+            MonitorConfig::Tcp(TcpConfig { port: 5432, .. })
+        ));
+
+        assert!(parse_monitor_config(MonitorType::Http, json!({ "host": "x" })).is_err());
+        assert!(
+            parse_monitor_config(MonitorType::Tcp, json!({ "host": "x", "port": 70000 })).is_err()
+        );
+    }
+
     #[test]
     fn normalize_monitor_config_leaves_non_object_values_unchanged() {
         let normalized = normalize_monitor_config(MonitorType::Dns, json!("bad-config"));
@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::enums::CheckStatus;
+
+/// Organization-wide rules that damp how automated monitoring opens and
+/// resolves incidents.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct MonitoringPolicy {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub recovery_threshold: i32,
+    pub flap_detection_enabled: bool,
+    pub flap_window_checks: i32,
+    pub flap_state_changes: i32,
+    pub auto_incident_cooldown_minutes: i32,
+    pub min_public_auto_incident_minutes: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MonitoringPolicy {
+    /// Consecutive successes a monitor needs before its service recovers.
+    pub fn recovery_threshold_for(&self, monitor_override: Option<i32>) -> i32 {
+        monitor_override.unwrap_or(self.recovery_threshold).max(1)
+    }
+
+    /// Whether the most recent checks (newest first) alternate often enough
+    /// to count as flapping.
+    pub fn is_flapping(&self, recent_checks: &[CheckStatus]) -> bool {
+        if !self.flap_detection_enabled {
+            return false;
+        }
+
+        let window = self.flap_window_checks.max(0) as usize;
+        count_state_changes(&recent_checks[..recent_checks.len().min(window)])
+            >= self.flap_state_changes
+    }
+}
+
+/// Number of pass/fail transitions in a run of checks. Failures and timeouts
+/// are treated as the same state.
+pub fn count_state_changes(checks: &[CheckStatus]) -> i32 {
+    checks
+        .windows(2)
+        .filter(|pair| (pair[0] == CheckStatus::Success) != (pair[1] == CheckStatus::Success))
+        .count() as i32
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct UpdateMonitoringPolicyRequest {
+    pub recovery_threshold: Option<i32>,
+    pub flap_detection_enabled: Option<bool>,
+    pub flap_window_checks: Option<i32>,
+    pub flap_state_changes: Option<i32>,
+    pub auto_incident_cooldown_minutes: Option<i32>,
+    pub min_public_auto_incident_minutes: Option<i32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(flap_detection_enabled: bool) -> MonitoringPolicy {
+        MonitoringPolicy {
+            id: Uuid::new_v4(),
+            org_id: Uuid::new_v4(),
+            recovery_threshold: 2,
+            flap_detection_enabled,
+            flap_window_checks: 6,
+            flap_state_changes: 3,
+            auto_incident_cooldown_minutes: 0,
+            min_public_auto_incident_minutes: 0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn count_state_changes_treats_timeouts_as_failures() {
+        use CheckStatus::*;
+
+        assert_eq!(count_state_changes(&[]), 0);
+        assert_eq!(count_state_changes(&[Failure, Timeout, Failure]), 0);
+        assert_eq!(
+            count_state_changes(&[Success, Timeout, Success, Failure]),
+            3
+        );
+    }
+
+    #[test]
+    fn is_flapping_only_looks_at_the_configured_window() {
+        use CheckStatus::*;
+
+        let checks = [
+            Success, Success, Success, Success, Failure, Success, Failure, Success,
+        ];
+        assert!(!policy(true).is_flapping(&checks));
+        assert!(policy(true).is_flapping(&checks[2..]));
+        assert!(!policy(false).is_flapping(&checks[2..]));
+    }
+
+    #[test]
+    fn recovery_threshold_prefers_monitor_override() {
+        assert_eq!(policy(false).recovery_threshold_for(None), 2);
+        assert_eq!(policy(false).recovery_threshold_for(Some(5)), 5);
+    }
+}
@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct NotificationPreferences {
     pub id: Uuid,
     pub org_id: Uuid,
@@ -20,7 +20,7 @@ pub struct NotificationPreferences {
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
 pub struct UpdateNotificationPreferencesRequest {
     pub email_on_incident_created: Option<bool>,
     pub email_on_incident_updated: Option<bool>,
@@ -2,28 +2,36 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::enums::{CustomDomainStatus, DowngradeState, OrganizationPlan, SubscriptionStatus};
+use crate::enums::{
+    CustomDomainStatus, CustomDomainVerificationMethod, DowngradeState, Locale, OrganizationPlan,
+    PageVisibility, SubscriptionStatus,
+};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct BillingEntitlements {
     pub max_monitors: Option<i64>,
+    /// Includes the organization's primary page.
+    pub max_status_pages: Option<i64>,
     pub custom_domain_enabled: bool,
     pub outbound_webhooks_enabled: bool,
     pub priority_support: bool,
+    pub monthly_sms_quota: i64,
 }
 
 impl From<OrganizationPlan> for BillingEntitlements {
     fn from(plan: OrganizationPlan) -> Self {
         Self {
             max_monitors: plan.max_monitors(),
+            max_status_pages: plan.max_status_pages(),
             custom_domain_enabled: plan.allows_custom_domain(),
             outbound_webhooks_enabled: plan.allows_outbound_webhooks(),
             priority_support: plan.has_priority_support(),
+            monthly_sms_quota: plan.monthly_sms_quota(),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct EntitlementViolation {
     pub code: String,
     pub message: String,
@@ -31,7 +39,7 @@ pub struct EntitlementViolation {
     pub allowed_count: Option<i64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct Organization {
     pub id: Uuid,
     pub name: String,
@@ -40,9 +48,19 @@ pub struct Organization {
     pub logo_url: Option<String>,
     pub brand_color: String,
     pub timezone: String,
+    /// Default language for emails and the public status page.
+    pub locale: Locale,
     pub custom_domain: Option<String>,
     pub custom_domain_verified_at: Option<DateTime<Utc>>,
     pub custom_domain_status: CustomDomainStatus,
+    /// Published as a TXT record on `_statuspage-verification.<domain>`
+    /// when the domain cannot point at the managed target directly.
+    pub custom_domain_verification_token: String,
+    pub custom_domain_verification_method: Option<CustomDomainVerificationMethod>,
+    /// Last background re-verification.
+    pub custom_domain_checked_at: Option<DateTime<Utc>>,
+    /// When a `misconfigured` domain stops routing.
+    pub custom_domain_grace_ends_at: Option<DateTime<Utc>>,
     pub stripe_customer_id: Option<String>,
     pub stripe_subscription_id: Option<String>,
     pub subscription_status: SubscriptionStatus,
@@ -56,22 +74,26 @@ pub struct Organization {
     pub downgrade_grace_ends_at: Option<DateTime<Utc>>,
     pub downgrade_state: DowngradeState,
     pub downgrade_warning_stage: i32,
+    pub page_visibility: PageVisibility,
+    pub page_allowed_email_domains: Vec<String>,
+    pub page_allowed_cidrs: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
 pub struct CreateOrganizationRequest {
     pub name: String,
     pub slug: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
 pub struct UpdateOrganizationRequest {
     pub name: Option<String>,
     pub slug: Option<String>,
     pub brand_color: Option<String>,
     pub timezone: Option<String>,
+    pub locale: Option<Locale>,
     pub logo_url: Option<String>,
     pub custom_domain: Option<String>,
 }
@@ -0,0 +1,247 @@
+//! Audience restrictions for an organization's public status page:
+//! password-protected pages, pages limited to allowlisted email domains via
+//! magic-link login, and pages limited to IP CIDR allowlists.
+
+use std::net::IpAddr;
+
+use chrono::{DateTime, Utc};
+use ipnet::IpNet;
+use serde::{Deserialize, Serialize};
+
+use crate::enums::PageVisibility;
+use crate::error::AppError;
+use crate::models::organization::Organization;
+use crate::models::status_page::StatusPage;
+
+pub const MIN_PAGE_PASSWORD_LENGTH: usize = 8;
+const MAX_ALLOWLIST_ENTRIES: usize = 100;
+
+/// Replaces the page's access settings. Omitted lists keep their current
+/// value; `password` is only needed when switching to, or rotating, a
+/// password-protected page.
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct UpdatePageAccessRequest {
+    pub visibility: PageVisibility,
+    pub password: Option<String>,
+    pub allowed_email_domains: Option<Vec<String>>,
+    pub allowed_cidrs: Option<Vec<String>>,
+}
+
+/// Current access settings, as shown to organization admins. The password
+/// itself is never returned.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct PageAccessSettings {
+    pub visibility: PageVisibility,
+    pub password_set: bool,
+    pub allowed_email_domains: Vec<String>,
+    pub allowed_cidrs: Vec<String>,
+}
+
+impl PageAccessSettings {
+    pub fn from_org(org: &Organization, password_set: bool) -> Self {
+        Self {
+            visibility: org.page_visibility,
+            password_set,
+            allowed_email_domains: org.page_allowed_email_domains.clone(),
+            allowed_cidrs: org.page_allowed_cidrs.clone(),
+        }
+    }
+
+    pub fn from_status_page(page: &StatusPage, password_set: bool) -> Self {
+        Self {
+            visibility: page.page_visibility,
+            password_set,
+            allowed_email_domains: page.page_allowed_email_domains.clone(),
+            allowed_cidrs: page.page_allowed_cidrs.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct ViewerPasswordRequest {
+    pub password: String,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct ViewerMagicLinkRequest {
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct ViewerMagicLinkVerifyRequest {
+    pub token: String,
+}
+
+/// A signed viewer session. Send it back in the `x-statuspage-viewer-token`
+/// header or the `statuspage_viewer` cookie.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct ViewerSessionResponse {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// What a viewer needs to do before the page will load.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct ViewerAccessResponse {
+    pub visibility: PageVisibility,
+    pub authorized: bool,
+}
+
+/// Lowercases and validates allowlisted email domains, dropping duplicates.
+/// A leading `@` is accepted and stripped.
+pub fn normalize_email_domains(domains: &[String]) -> Result<Vec<String>, AppError> {
+    let mut normalized: Vec<String> = Vec::new();
+    for domain in domains {
+        let domain = domain.trim().trim_start_matches('@').to_lowercase();
+        if domain.is_empty() {
+            continue;
+        }
+        let valid = domain.contains('.')
+            && !domain.starts_with('.')
+            && !domain.ends_with('.')
+            && domain.len() <= 253
+            && domain
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '.');
+        if !valid {
+            return Err(AppError::Validation(format!(
+                "'{domain}' is not a valid email domain"
+            )));
+        }
+        if !normalized.contains(&domain) {
+            normalized.push(domain);
+        }
+    }
+
+    if normalized.len() > MAX_ALLOWLIST_ENTRIES {
+        return Err(AppError::Validation(format!(
+            "At most {MAX_ALLOWLIST_ENTRIES} email domains can be allowlisted"
+        )));
+    }
+    Ok(normalized)
+}
+
+/// Parses allowlisted networks into canonical CIDR notation. A bare address
+/// is treated as a single-host network.
+pub fn normalize_cidrs(cidrs: &[String]) -> Result<Vec<String>, AppError> {
+    let mut normalized: Vec<String> = Vec::new();
+    for cidr in cidrs {
+        let cidr = cidr.trim();
+        if cidr.is_empty() {
+            continue;
+        }
+        let network = cidr
+            .parse::<IpNet>()
+            .or_else(|_| cidr.parse::<IpAddr>().map(IpNet::from))
+            .map_err(|_| AppError::Validation(format!("'{cidr}' is not a valid IP or CIDR")))?
+            .trunc()
+            .to_string();
+        if !normalized.contains(&network) {
+            normalized.push(network);
+        }
+    }
+
+    if normalized.len() > MAX_ALLOWLIST_ENTRIES {
+        return Err(AppError::Validation(format!(
+            "At most {MAX_ALLOWLIST_ENTRIES} networks can be allowlisted"
+        )));
+    }
+    Ok(normalized)
+}
+
+/// Whether the email's domain is one of the allowlisted domains. Subdomains
+/// of an allowlisted domain are not included.
+pub fn email_domain_allowed(email: &str, allowed_domains: &[String]) -> bool {
+    email
+        .rsplit_once('@')
+        .map(|(_, domain)| domain.to_lowercase())
+        .is_some_and(|domain| allowed_domains.contains(&domain))
+}
+
+/// Whether the address falls inside any allowlisted network. Entries that
+/// fail to parse never match.
+pub fn ip_allowed(ip: IpAddr, allowed_cidrs: &[String]) -> bool {
+    let ip = match ip {
+        IpAddr::V6(v6) => v6.to_ipv4_mapped().map(IpAddr::V4).unwrap_or(ip),
+        IpAddr::V4(_) => ip,
+    };
+    allowed_cidrs
+        .iter()
+        .filter_map(|cidr| cidr.parse::<IpNet>().ok())
+        .any(|network| network.contains(&ip))
+}
+
+/// Checks that the settings are enough to let anyone in at all.
+pub fn validate_page_access(
+    visibility: PageVisibility,
+    password_set: bool,
+    allowed_email_domains: &[String],
+    allowed_cidrs: &[String],
+) -> Result<(), AppError> {
+    match visibility {
+        PageVisibility::Public => Ok(()),
+        PageVisibility::Password if !password_set => Err(AppError::Validation(
+            "Set a password to make the page password-protected".to_string(),
+        )),
+        PageVisibility::EmailDomain if allowed_email_domains.is_empty() => Err(
+            AppError::Validation("Allowlist at least one email domain".to_string()),
+        ),
+        PageVisibility::IpAllowlist if allowed_cidrs.is_empty() => Err(AppError::Validation(
+            "Allowlist at least one IP range".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn email_domains_are_normalized_and_matched_exactly() {
+        let domains =
+            normalize_email_domains(&strings(&["@Example.com", "example.com", " corp.io "]))
+                .unwrap();
+        assert_eq!(domains, strings(&["example.com", "corp.io"]));
+        assert!(normalize_email_domains(&strings(&["not a domain"])).is_err());
+
+        assert!(email_domain_allowed("Ana@EXAMPLE.com", &domains));
+        assert!(!email_domain_allowed("ana@eu.example.com", &domains));
+        assert!(!email_domain_allowed("ana@example.com.evil.io", &domains));
+    }
+
+    #[test]
+    fn cidrs_are_canonicalized_and_matched() {
+        let cidrs =
+            normalize_cidrs(&strings(&["10.1.2.3/8", "192.168.1.7", "2001:db8::1/32"])).unwrap();
+        assert_eq!(
+            cidrs,
+            strings(&["10.0.0.0/8", "192.168.1.7/32", "2001:db8::/32"])
+        );
+        assert!(normalize_cidrs(&strings(&["10.0.0.0/33"])).is_err());
+
+        assert!(ip_allowed("10.200.0.1".parse().unwrap(), &cidrs));
+        assert!(ip_allowed("::ffff:192.168.1.7".parse().unwrap(), &cidrs));
+        assert!(ip_allowed("2001:db8:1::5".parse().unwrap(), &cidrs));
+        assert!(!ip_allowed("192.168.1.8".parse().unwrap(), &cidrs));
+    }
+
+    #[test]
+    fn restricted_pages_need_a_way_in() {
+        assert!(validate_page_access(PageVisibility::Public, false, &[], &[]).is_ok());
+        assert!(validate_page_access(PageVisibility::Password, false, &[], &[]).is_err());
+        assert!(validate_page_access(PageVisibility::Password, true, &[], &[]).is_ok());
+        assert!(validate_page_access(PageVisibility::EmailDomain, true, &[], &[]).is_err());
+        assert!(validate_page_access(
+            PageVisibility::IpAllowlist,
+            false,
+            &[],
+            &strings(&["10.0.0.0/8"])
+        )
+        .is_ok());
+    }
+}
@@ -1,10 +1,11 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 use crate::enums::ServiceStatus;
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct Service {
     pub id: Uuid,
     pub org_id: Uuid,
@@ -16,26 +17,131 @@ pub struct Service {
     pub is_visible: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Nearest upstream dependency whose own status is a partial or major
+    /// outage.
+    pub upstream_cause_service_id: Option<Uuid>,
+    pub group_id: Option<Uuid>,
 }
 
-#[derive(Debug, Deserialize)]
+impl Service {
+    pub fn effective_status(&self) -> ServiceStatus {
+        effective_status(
+            self.current_status,
+            self.upstream_cause_service_id.is_some(),
+        )
+    }
+}
+
+/// Status shown for a service once upstream outages are taken into account.
+/// An otherwise operational service is degraded while an upstream is down;
+/// its own incidents and maintenance take precedence.
+pub fn effective_status(own: ServiceStatus, upstream_outage: bool) -> ServiceStatus {
+    if upstream_outage && own == ServiceStatus::Operational {
+        ServiceStatus::DegradedPerformance
+    } else {
+        own
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ServiceDependency {
+    pub service_id: Uuid,
+    pub depends_on_service_id: Uuid,
+    pub depends_on_service_name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct AddServiceDependencyRequest {
+    pub depends_on_service_id: Uuid,
+}
+
+/// Whether adding `service_id -> depends_on` to the existing
+/// `(service, upstream)` edges would close a cycle.
+pub fn dependency_creates_cycle(
+    edges: &[(Uuid, Uuid)],
+    service_id: Uuid,
+    depends_on: Uuid,
+) -> bool {
+    let mut upstreams: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for (service, upstream) in edges {
+        upstreams.entry(*service).or_default().push(*upstream);
+    }
+
+    let mut seen = HashSet::new();
+    let mut stack = vec![depends_on];
+    while let Some(current) = stack.pop() {
+        if current == service_id {
+            return true;
+        }
+        if seen.insert(current) {
+            if let Some(next) = upstreams.get(&current) {
+                stack.extend(next.iter().copied());
+            }
+        }
+    }
+
+    false
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
 pub struct CreateServiceRequest {
     pub name: String,
     pub description: Option<String>,
+    pub group_id: Option<Uuid>,
+    /// Legacy alternative to `group_id`; finds or creates a group by name.
     pub group_name: Option<String>,
     pub is_visible: Option<bool>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct UpdateServiceRequest {
     pub name: Option<String>,
     pub description: Option<String>,
     pub current_status: Option<ServiceStatus>,
+    pub group_id: Option<Uuid>,
+    /// Legacy alternative to `group_id`; finds or creates a group by name.
     pub group_name: Option<String>,
     pub is_visible: Option<bool>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
 pub struct ReorderServicesRequest {
     pub service_ids: Vec<Uuid>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dependency_creates_cycle_detects_direct_and_transitive_loops() {
+        let (db, api, web) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let edges = [(api, db), (web, api)];
+
+        assert!(dependency_creates_cycle(&edges, db, db));
+        assert!(dependency_creates_cycle(&edges, db, api));
+        assert!(dependency_creates_cycle(&edges, db, web));
+        assert!(!dependency_creates_cycle(&edges, web, db));
+    }
+
+    #[test]
+    fn effective_status_only_degrades_operational_services() {
+        assert_eq!(
+            effective_status(ServiceStatus::Operational, true),
+            ServiceStatus::DegradedPerformance
+        );
+        assert_eq!(
+            effective_status(ServiceStatus::MajorOutage, true),
+            ServiceStatus::MajorOutage
+        );
+        assert_eq!(
+            effective_status(ServiceStatus::UnderMaintenance, true),
+            ServiceStatus::UnderMaintenance
+        );
+        assert_eq!(
+            effective_status(ServiceStatus::Operational, false),
+            ServiceStatus::Operational
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::enums::{GroupStatusRule, ServiceStatus};
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ServiceGroup {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub display_order: i32,
+    pub collapsed_by_default: bool,
+    pub status_rule: GroupStatusRule,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct CreateServiceGroupRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub collapsed_by_default: Option<bool>,
+    pub status_rule: Option<GroupStatusRule>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct UpdateServiceGroupRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub display_order: Option<i32>,
+    pub collapsed_by_default: Option<bool>,
+    pub status_rule: Option<GroupStatusRule>,
+}
+
+/// Derives a group's status from its members' statuses. Empty groups are
+/// operational.
+pub fn aggregate_group_status(rule: GroupStatusRule, members: &[ServiceStatus]) -> ServiceStatus {
+    let worst = members
+        .iter()
+        .copied()
+        .max_by_key(ServiceStatus::severity)
+        .unwrap_or(ServiceStatus::Operational);
+
+    match rule {
+        GroupStatusRule::WorstOf => worst,
+        GroupStatusRule::Majority => {
+            let majority = [
+                ServiceStatus::MajorOutage,
+                ServiceStatus::PartialOutage,
+                ServiceStatus::DegradedPerformance,
+                ServiceStatus::UnderMaintenance,
+            ]
+            .into_iter()
+            .find(|candidate| {
+                let at_or_above = members
+                    .iter()
+                    .filter(|status| status.severity() >= candidate.severity())
+                    .count();
+                at_or_above * 2 > members.len()
+            });
+
+            match majority {
+                Some(status) => status,
+                None if worst.severity() > ServiceStatus::UnderMaintenance.severity() => {
+                    ServiceStatus::DegradedPerformance
+                }
+                None => ServiceStatus::Operational,
+            }
+        }
+    }
+}
+
+/// Averages one day of a member metric such as uptime, skipping members
+/// without data.
+pub fn aggregate_group_uptime(members: &[Option<f64>]) -> Option<f64> {
+    let known: Vec<f64> = members.iter().flatten().copied().collect();
+    if known.is_empty() {
+        None
+    } else {
+        Some(known.iter().sum::<f64>() / known.len() as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ServiceStatus::*;
+
+    #[test]
+    fn worst_of_takes_the_most_severe_member() {
+        assert_eq!(
+            aggregate_group_status(
+                GroupStatusRule::WorstOf,
+                &[Operational, MajorOutage, DegradedPerformance]
+            ),
+            MajorOutage
+        );
+        assert_eq!(
+            aggregate_group_status(GroupStatusRule::WorstOf, &[]),
+            Operational
+        );
+    }
+
+    #[test]
+    fn majority_needs_more_than_half_of_members() {
+        let rule = GroupStatusRule::Majority;
+
+        assert_eq!(
+            aggregate_group_status(rule, &[MajorOutage, Operational, Operational]),
+            DegradedPerformance
+        );
+        assert_eq!(
+            aggregate_group_status(rule, &[MajorOutage, PartialOutage, Operational]),
+            PartialOutage
+        );
+        assert_eq!(
+            aggregate_group_status(rule, &[UnderMaintenance, Operational, Operational]),
+            Operational
+        );
+        assert_eq!(
+            aggregate_group_status(rule, &[Operational, Operational]),
+            Operational
+        );
+    }
+
+    #[test]
+    fn group_uptime_averages_members_with_data() {
+        assert_eq!(
+            aggregate_group_uptime(&[Some(100.0), None, Some(98.0)]),
+            Some(99.0)
+        );
+        assert_eq!(aggregate_group_uptime(&[None, None]), None);
+    }
+}
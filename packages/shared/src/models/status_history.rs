@@ -0,0 +1,175 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::enums::{ServiceStatus, StatusChangeSource};
+
+/// A span of time a service spent in one status. The current status is the
+/// open interval, with no `ended_at`.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ServiceStatusInterval {
+    pub id: Uuid,
+    pub service_id: Uuid,
+    pub status: ServiceStatus,
+    pub source: StatusChangeSource,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+/// Query parameters for a service's status history. Defaults to the last
+/// 30 days.
+#[derive(Debug, Default, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct StatusHistoryParams {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// Time a service spent in each kind of status within one window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatusSummary {
+    pub observed_seconds: i64,
+    pub outage_seconds: i64,
+    pub degraded_seconds: i64,
+    pub maintenance_seconds: i64,
+}
+
+impl StatusSummary {
+    pub fn outage_minutes(&self) -> i64 {
+        (self.outage_seconds + 30) / 60
+    }
+
+    /// Share of the window without a partial or major outage. Maintenance
+    /// is left out of the calculation; `None` when nothing else was
+    /// observed.
+    pub fn uptime_percentage(&self) -> Option<f64> {
+        let counted = self.observed_seconds - self.maintenance_seconds;
+        if counted <= 0 {
+            return None;
+        }
+        Some((counted - self.outage_seconds) as f64 * 100.0 / counted as f64)
+    }
+}
+
+/// Sums the time each interval overlaps `[from, to)`. Open intervals run
+/// until `now`.
+pub fn summarize_intervals(
+    intervals: &[ServiceStatusInterval],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> StatusSummary {
+    let mut summary = StatusSummary::default();
+
+    for interval in intervals {
+        let start = interval.started_at.max(from);
+        let end = interval.ended_at.unwrap_or(now).min(to).min(now);
+        let seconds = (end - start).num_seconds();
+        if seconds <= 0 {
+            continue;
+        }
+
+        summary.observed_seconds += seconds;
+        match interval.status {
+            ServiceStatus::PartialOutage | ServiceStatus::MajorOutage => {
+                summary.outage_seconds += seconds
+            }
+            ServiceStatus::DegradedPerformance => summary.degraded_seconds += seconds,
+            ServiceStatus::UnderMaintenance => summary.maintenance_seconds += seconds,
+            ServiceStatus::Operational => {}
+        }
+    }
+
+    summary
+}
+
+/// Combines uptime derived from status intervals with monitor check uptime.
+/// Either source alone can show downtime the other missed, such as a
+/// declared outage on a passing health check, so the lower value wins.
+pub fn combined_uptime(from_intervals: Option<f64>, from_monitors: Option<f64>) -> Option<f64> {
+    match (from_intervals, from_monitors) {
+        (Some(intervals), Some(monitors)) => Some(intervals.min(monitors)),
+        (intervals, monitors) => intervals.or(monitors),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn interval(
+        status: ServiceStatus,
+        started_at: DateTime<Utc>,
+        ended_at: Option<DateTime<Utc>>,
+    ) -> ServiceStatusInterval {
+        ServiceStatusInterval {
+            id: Uuid::new_v4(),
+            service_id: Uuid::nil(),
+            status,
+            source: StatusChangeSource::Manual,
+            started_at,
+            ended_at,
+        }
+    }
+
+    #[test]
+    fn summary_clips_intervals_to_the_window() {
+        let day = Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap();
+        let next_day = day + Duration::days(1);
+        let intervals = vec![
+            interval(
+                ServiceStatus::Operational,
+                day - Duration::days(3),
+                Some(day + Duration::hours(6)),
+            ),
+            interval(
+                ServiceStatus::MajorOutage,
+                day + Duration::hours(6),
+                Some(day + Duration::hours(7)),
+            ),
+            interval(
+                ServiceStatus::UnderMaintenance,
+                day + Duration::hours(7),
+                Some(day + Duration::hours(12)),
+            ),
+            interval(ServiceStatus::Operational, day + Duration::hours(12), None),
+        ];
+
+        let summary = summarize_intervals(&intervals, day, next_day, next_day + Duration::days(1));
+
+        assert_eq!(summary.observed_seconds, 24 * 3600);
+        assert_eq!(summary.outage_minutes(), 60);
+        assert_eq!(summary.maintenance_seconds, 5 * 3600);
+        let uptime = summary.uptime_percentage().unwrap();
+        assert!((uptime - 1800.0 / 19.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn open_interval_stops_at_now_and_gaps_are_unobserved() {
+        let day = Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap();
+        let now = day + Duration::hours(10);
+        let intervals = vec![interval(
+            ServiceStatus::PartialOutage,
+            day + Duration::hours(8),
+            None,
+        )];
+
+        let summary = summarize_intervals(&intervals, day, day + Duration::days(1), now);
+
+        assert_eq!(summary.observed_seconds, 2 * 3600);
+        assert_eq!(summary.uptime_percentage(), Some(0.0));
+        assert_eq!(
+            summarize_intervals(&[], day, now, now).uptime_percentage(),
+            None
+        );
+    }
+
+    #[test]
+    fn combined_uptime_takes_the_worse_source() {
+        assert_eq!(combined_uptime(Some(99.0), Some(100.0)), Some(99.0));
+        assert_eq!(combined_uptime(None, Some(98.5)), Some(98.5));
+        assert_eq!(combined_uptime(Some(97.0), None), Some(97.0));
+        assert_eq!(combined_uptime(None, None), None);
+    }
+}
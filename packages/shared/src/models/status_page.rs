@@ -0,0 +1,70 @@
+//! Additional status pages. Every organization's own slug, branding and
+//! custom domain form its primary page; status pages add audience-specific
+//! pages (internal, partner, ...) over the same services and incidents.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::enums::{
+    CustomDomainStatus, CustomDomainVerificationMethod, DisabledReason, PageVisibility,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct StatusPage {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub slug: String,
+    pub name: String,
+    /// `None` uses the organization's logo.
+    pub logo_url: Option<String>,
+    /// `None` uses the organization's brand color.
+    pub brand_color: Option<String>,
+    pub custom_domain: Option<String>,
+    pub custom_domain_status: CustomDomainStatus,
+    pub custom_domain_verified_at: Option<DateTime<Utc>>,
+    /// Published as a TXT record on `_statuspage-verification.<domain>`
+    /// when the domain cannot point at the managed target directly.
+    pub custom_domain_verification_token: String,
+    pub custom_domain_verification_method: Option<CustomDomainVerificationMethod>,
+    /// Last background re-verification.
+    pub custom_domain_checked_at: Option<DateTime<Utc>>,
+    /// When a `misconfigured` domain stops routing.
+    pub custom_domain_grace_ends_at: Option<DateTime<Utc>>,
+    pub page_visibility: PageVisibility,
+    pub page_allowed_email_domains: Vec<String>,
+    pub page_allowed_cidrs: Vec<String>,
+    /// Services the page lists. Empty lists every visible service.
+    pub service_ids: Vec<Uuid>,
+    /// Set while a downgrade leaves the organization over its page limit;
+    /// disabled pages are not served and send no notifications.
+    pub disabled_reason: Option<DisabledReason>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct CreateStatusPageRequest {
+    pub name: String,
+    /// Derived from `name` when omitted. Shares a namespace with
+    /// organization slugs.
+    pub slug: Option<String>,
+    pub logo_url: Option<String>,
+    pub brand_color: Option<String>,
+    pub custom_domain: Option<String>,
+    /// Services to list. Omitted or empty lists every visible service;
+    /// listed services show even when hidden from the primary page.
+    pub service_ids: Option<Vec<Uuid>>,
+}
+
+/// Omitted fields keep their value. An empty `logo_url`, `brand_color` or
+/// `custom_domain` clears it.
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct UpdateStatusPageRequest {
+    pub name: Option<String>,
+    pub slug: Option<String>,
+    pub logo_url: Option<String>,
+    pub brand_color: Option<String>,
+    pub custom_domain: Option<String>,
+    pub service_ids: Option<Vec<Uuid>>,
+}
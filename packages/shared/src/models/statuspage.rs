@@ -0,0 +1,196 @@
+//! Response shapes compatible with the Statuspage v2 public API
+//! (`/api/v2/status.json` and `/api/v2/summary.json`), so existing client
+//! libraries can read our status pages unchanged.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::enums::{IncidentImpact, IncidentStatus, ServiceStatus};
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, utoipa::ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusIndicator {
+    None,
+    Maintenance,
+    Minor,
+    Major,
+    Critical,
+}
+
+impl StatusIndicator {
+    pub fn from_service_status(status: ServiceStatus) -> Self {
+        match status {
+            ServiceStatus::Operational => Self::None,
+            ServiceStatus::UnderMaintenance => Self::Maintenance,
+            ServiceStatus::DegradedPerformance => Self::Minor,
+            ServiceStatus::PartialOutage => Self::Major,
+            ServiceStatus::MajorOutage => Self::Critical,
+        }
+    }
+
+    pub fn from_impact(impact: IncidentImpact) -> Self {
+        match impact {
+            IncidentImpact::None => Self::None,
+            IncidentImpact::Minor => Self::Minor,
+            IncidentImpact::Major => Self::Major,
+            IncidentImpact::Critical => Self::Critical,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::None => "All Systems Operational",
+            Self::Maintenance => "Service Under Maintenance",
+            Self::Minor => "Minor Service Outage",
+            Self::Major => "Partial System Outage",
+            Self::Critical => "Major System Outage",
+        }
+    }
+}
+
+/// Page-wide indicator: the worse of the overall component status and the
+/// impact of any unresolved incident.
+pub fn page_indicator(
+    overall_status: ServiceStatus,
+    active_impacts: &[IncidentImpact],
+) -> StatusIndicator {
+    active_impacts
+        .iter()
+        .copied()
+        .map(StatusIndicator::from_impact)
+        .fold(
+            StatusIndicator::from_service_status(overall_status),
+            StatusIndicator::max,
+        )
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct StatuspagePage {
+    pub id: Uuid,
+    pub name: String,
+    pub url: String,
+    pub time_zone: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct StatuspageStatus {
+    pub indicator: StatusIndicator,
+    pub description: String,
+}
+
+impl From<StatusIndicator> for StatuspageStatus {
+    fn from(indicator: StatusIndicator) -> Self {
+        Self {
+            indicator,
+            description: indicator.description().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct StatuspageStatusResponse {
+    pub page: StatuspagePage,
+    pub status: StatuspageStatus,
+}
+
+/// A service, or a service group when `group` is set. Group components list
+/// their members' ids in `components`.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct StatuspageComponent {
+    pub id: Uuid,
+    pub name: String,
+    pub status: ServiceStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub position: i32,
+    pub description: Option<String>,
+    pub showcase: bool,
+    pub start_date: Option<chrono::NaiveDate>,
+    pub group_id: Option<Uuid>,
+    pub page_id: Uuid,
+    pub group: bool,
+    pub only_show_if_degraded: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<Uuid>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct StatuspageIncidentUpdate {
+    pub id: Uuid,
+    pub status: IncidentStatus,
+    pub body: String,
+    pub incident_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub display_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct StatuspageIncident {
+    pub id: Uuid,
+    pub name: String,
+    pub status: IncidentStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub monitoring_at: Option<DateTime<Utc>>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub impact: IncidentImpact,
+    pub shortlink: String,
+    pub started_at: DateTime<Utc>,
+    pub page_id: Uuid,
+    pub incident_updates: Vec<StatuspageIncidentUpdate>,
+    pub components: Vec<StatuspageComponent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct StatuspageSummaryResponse {
+    pub page: StatuspagePage,
+    pub components: Vec<StatuspageComponent>,
+    pub incidents: Vec<StatuspageIncident>,
+    pub scheduled_maintenances: Vec<StatuspageIncident>,
+    pub status: StatuspageStatus,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_indicator_takes_the_worse_of_components_and_incidents() {
+        assert_eq!(
+            page_indicator(ServiceStatus::Operational, &[]),
+            StatusIndicator::None
+        );
+        assert_eq!(
+            page_indicator(
+                ServiceStatus::DegradedPerformance,
+                &[IncidentImpact::Critical]
+            ),
+            StatusIndicator::Critical
+        );
+        assert_eq!(
+            page_indicator(ServiceStatus::MajorOutage, &[IncidentImpact::Minor]),
+            StatusIndicator::Critical
+        );
+        assert_eq!(
+            page_indicator(ServiceStatus::UnderMaintenance, &[IncidentImpact::None]),
+            StatusIndicator::Maintenance
+        );
+    }
+
+    #[test]
+    fn status_serializes_like_statuspage() {
+        let status = StatuspageStatus::from(StatusIndicator::Major);
+        assert_eq!(
+            serde_json::to_value(status).unwrap(),
+            serde_json::json!({
+                "indicator": "major",
+                "description": "Partial System Outage",
+            })
+        );
+    }
+}
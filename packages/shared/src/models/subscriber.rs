@@ -1,22 +1,130 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+use crate::enums::{DigestFrequency, Locale, SubscriberSource};
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct Subscriber {
     pub id: Uuid,
     pub org_id: Uuid,
+    /// The additional status page subscribed to; `None` is the primary page.
+    pub status_page_id: Option<Uuid>,
     pub email: String,
     pub is_verified: bool,
     pub verification_token: Option<String>,
     pub verification_sent_at: Option<DateTime<Utc>>,
     pub verified_at: Option<DateTime<Utc>>,
     pub unsubscribe_token: String,
+    pub digest_frequency: DigestFrequency,
+    pub timezone: String,
+    /// Email language; `None` follows the organization default.
+    pub locale: Option<Locale>,
+    pub quiet_hours_start: Option<NaiveTime>,
+    pub quiet_hours_end: Option<NaiveTime>,
+    pub last_digest_sent_at: Option<DateTime<Utc>>,
+    pub phone_number: Option<String>,
+    pub phone_verified_at: Option<DateTime<Utc>>,
+    pub source: SubscriberSource,
+    pub consent_attestation: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Deserialize)]
+/// Subscribe with either an email address or a webhook URL. Webhook
+/// subscriptions ignore delivery preferences.
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
 pub struct SubscribeRequest {
+    pub email: Option<String>,
+    pub webhook_url: Option<String>,
+    #[serde(flatten)]
+    pub preferences: SubscriberPreferences,
+}
+
+/// Delivery preferences a subscriber manages with their unsubscribe token.
+/// Quiet hours are wall-clock times in `timezone`; notifications that arrive
+/// inside the window are held and delivered as a digest once it ends.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct SubscriberPreferences {
+    pub digest_frequency: Option<DigestFrequency>,
+    pub timezone: Option<String>,
+    pub locale: Option<Locale>,
+    pub quiet_hours_start: Option<NaiveTime>,
+    pub quiet_hours_end: Option<NaiveTime>,
+    /// Limits email and SMS to incidents and status changes touching these
+    /// service groups. An empty list restores notifications for everything.
+    pub group_ids: Option<Vec<Uuid>>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct SubscriberPhoneRequest {
+    pub phone_number: String,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct VerifySubscriberPhoneRequest {
+    pub code: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriberImportMode {
+    /// Create pending subscribers and email each one a confirmation link.
+    SendVerification,
+    /// Create verified subscribers without emailing them. Requires an
+    /// attestation describing how consent was collected.
+    PreVerified,
+}
+
+/// Import payload. Provide either `csv` (with an `email` header column and
+/// optional `digest_frequency` and `timezone` columns) or `subscribers`.
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct ImportSubscribersRequest {
+    pub mode: SubscriberImportMode,
+    pub attestation: Option<String>,
+    pub csv: Option<String>,
+    pub subscribers: Option<Vec<ImportSubscriberRow>>,
+}
+
+#[derive(Debug, Clone, Deserialize, utoipa::ToSchema)]
+pub struct ImportSubscriberRow {
     pub email: String,
+    pub digest_frequency: Option<DigestFrequency>,
+    pub timezone: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct SubscriberImportError {
+    /// The line the row starts on in an imported CSV, counting the header;
+    /// the 1-based position in `subscribers` for JSON imports.
+    pub row: usize,
+    pub email: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, utoipa::ToSchema)]
+pub struct SubscriberImportSummary {
+    pub total_rows: usize,
+    pub created: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    pub verification_emails_queued: usize,
+    pub errors: Vec<SubscriberImportError>,
+}
+
+/// Selects pending subscribers for a bulk verification resend. All filters
+/// are optional and combined with AND.
+#[derive(Debug, Clone, Default, Deserialize, utoipa::ToSchema)]
+pub struct ResendVerificationFilter {
+    pub ids: Option<Vec<Uuid>>,
+    pub source: Option<SubscriberSource>,
+    pub email_domain: Option<String>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub sent_before: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, utoipa::ToSchema)]
+pub struct BulkDeleteSubscribersRequest {
+    pub ids: Option<Vec<Uuid>>,
+    pub emails: Option<Vec<String>>,
 }
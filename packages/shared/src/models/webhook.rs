@@ -4,7 +4,7 @@ use uuid::Uuid;
 
 use crate::enums::DisabledReason;
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
 pub struct WebhookConfig {
     pub id: Uuid,
     pub org_id: Uuid,
@@ -17,7 +17,7 @@ pub struct WebhookConfig {
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
 pub struct CreateWebhookConfigRequest {
     pub name: String,
     pub url: String,
@@ -26,7 +26,7 @@ pub struct CreateWebhookConfigRequest {
     pub is_enabled: Option<bool>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
 pub struct UpdateWebhookConfigRequest {
     pub name: Option<String>,
     pub url: Option<String>,
@@ -34,3 +34,19 @@ pub struct UpdateWebhookConfigRequest {
     pub event_types: Option<Vec<String>>,
     pub is_enabled: Option<bool>,
 }
+
+/// A customer-owned endpoint subscribed through the public status page.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct WebhookSubscriber {
+    pub id: Uuid,
+    pub org_id: Uuid,
+    pub status_page_id: Option<Uuid>,
+    pub url: String,
+    pub verified_at: Option<DateTime<Utc>>,
+    pub consecutive_failures: i32,
+    pub disabled_at: Option<DateTime<Utc>>,
+    pub disabled_reason: Option<String>,
+    pub last_delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -148,6 +148,56 @@ pub fn validate_custom_domain(s: &str) -> Result<(), AppError> {
     Ok(())
 }
 
+/// Normalize a subscriber email address (trimmed, lowercased) and apply the
+/// same sanity checks as the public subscribe form.
+pub fn normalize_subscriber_email(s: &str) -> Result<String, AppError> {
+    let email = s.trim().to_lowercase();
+    let looks_valid = email.contains('@')
+        && email.contains('.')
+        && !email.starts_with('@')
+        && !email.ends_with('@')
+        && !email.chars().any(char::is_whitespace);
+    if !looks_valid || email.len() > 255 {
+        return Err(AppError::Validation(
+            "Enter a valid email address".to_string(),
+        ));
+    }
+    Ok(email)
+}
+
+/// Normalize a phone number to E.164 (`+` followed by 8 to 15 digits).
+/// Spaces, dashes, dots and parentheses are accepted as separators.
+pub fn normalize_phone_number(s: &str) -> Result<String, AppError> {
+    let trimmed = s.trim();
+    let Some(rest) = trimmed.strip_prefix('+') else {
+        return Err(AppError::Validation(
+            "Phone number must be in international format, starting with +".to_string(),
+        ));
+    };
+
+    let mut digits = String::with_capacity(rest.len());
+    for c in rest.chars() {
+        match c {
+            '0'..='9' => digits.push(c),
+            ' ' | '-' | '.' | '(' | ')' => {}
+            _ => {
+                return Err(AppError::Validation(
+                    "Phone number must contain only digits and separators".to_string(),
+                ))
+            }
+        }
+    }
+
+    if !(8..=15).contains(&digits.len()) || digits.starts_with('0') {
+        return Err(AppError::Validation(
+            "Phone number must have between 8 and 15 digits after the country code prefix"
+                .to_string(),
+        ));
+    }
+
+    Ok(format!("+{digits}"))
+}
+
 /// Generate a slug from an organization name.
 pub fn slugify(name: &str) -> String {
     name.to_lowercase()
@@ -224,6 +274,29 @@ mod tests {
         assert!(validate_custom_domain("bad_domain.example.com").is_err());
     }
 
+    #[test]
+    fn test_normalize_subscriber_email() {
+        assert_eq!(
+            normalize_subscriber_email("  Ops@Example.COM ").unwrap(),
+            "ops@example.com"
+        );
+        assert!(normalize_subscriber_email("ops").is_err());
+        assert!(normalize_subscriber_email("@example.com").is_err());
+        assert!(normalize_subscriber_email("ops team@example.com").is_err());
+    }
+
+    #[test]
+    fn test_normalize_phone_number() {
+        assert_eq!(
+            normalize_phone_number(" +1 (415) 555-0100 ").unwrap(),
+            "+14155550100"
+        );
+        assert!(normalize_phone_number("4155550100").is_err());
+        assert!(normalize_phone_number("+1 415 ext 9").is_err());
+        assert!(normalize_phone_number("+0123456789").is_err());
+        assert!(normalize_phone_number("+1234").is_err());
+    }
+
     #[test]
     fn test_slugify() {
         assert_eq!(slugify("Acme Corp"), "acme-corp");